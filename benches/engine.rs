@@ -0,0 +1,132 @@
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BatchSize;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+
+use copying::slay::driver;
+use copying::slay::driver::AdvanceGameResult;
+use copying::slay::game_context::GameBookKeeping;
+use copying::slay::state::game::Game;
+use copying::slay::state::initialize;
+use copying::slay::state::initialize::GameSetup;
+use copying::slay::strategy;
+
+/*
+	How fast the engine plays, which decides how deep the bots can search and how many games one
+	server can host.
+	Every game is seeded, so each run measures the same positions and the same playouts.
+
+	The baseline for main is checked in under benches/baselines, so compare a change against it with
+		CRITERION_HOME=benches/baselines cargo bench --bench engine -- --baseline main
+	and after a change that is meant to move the numbers, save it again with
+		CRITERION_HOME=benches/baselines cargo bench --bench engine -- --save-baseline main
+	Only the saved baselines are tracked, the latest run and the reports are ignored.
+*/
+
+// The seeds the checked-in baseline was saved with, each plays out without a panic.
+const SEEDS: [u64; 3] = [0, 2, 5];
+// How many choices are made before measuring a game in the middle of play.
+const MIDGAME_PICKS: usize = 30;
+// Random games rarely finish, so a playout is capped at this many choices.
+const MAX_PICKS: usize = 2000;
+
+fn new_game(seed: u64) -> (GameBookKeeping, Game) {
+	let mut context = GameBookKeeping::with_seed(seed);
+	let mut game = Game::new();
+	initialize::initialize_game(&mut context, &mut game, &GameSetup::default());
+	(context, game)
+}
+
+// Makes one random choice, returning false once the game can not go on.
+fn play_a_choice(context: &mut GameBookKeeping, game: &mut Game) -> bool {
+	if game.get_turn().over_the_limit() {
+		return false;
+	}
+	let Ok((player_index, choice_id)) = strategy::pick_a_random_choice(context, game) else {
+		return false;
+	};
+	if driver::make_selection(game, player_index, choice_id, &mut |_| {}).is_err() {
+		return false;
+	}
+	matches!(
+		driver::advance_game(context, game),
+		Ok(AdvanceGameResult::WaitingForPlayers)
+	)
+}
+
+fn midgame(seed: u64) -> (GameBookKeeping, Game) {
+	let (mut context, mut game) = new_game(seed);
+	for _ in 0..MIDGAME_PICKS {
+		if !play_a_choice(&mut context, &mut game) {
+			break;
+		}
+	}
+	(context, game)
+}
+
+fn playout(seed: u64) -> usize {
+	let (mut context, mut game) = new_game(seed);
+	let mut picks = 0;
+	while picks < MAX_PICKS && play_a_choice(&mut context, &mut game) {
+		picks += 1;
+	}
+	picks
+}
+
+fn bench_initialize_game(c: &mut Criterion) {
+	c.bench_function("initialize_game", |b| b.iter(|| new_game(0)));
+}
+
+fn bench_advance_game(c: &mut Criterion) {
+	let mut group = c.benchmark_group("advance_game");
+	for seed in SEEDS {
+		let (context, game) = midgame(seed);
+		group.bench_with_input(BenchmarkId::new("choice", seed), &seed, |b, _| {
+			b.iter_batched(
+				|| (context.clone(), game.clone()),
+				|(mut context, mut game)| {
+					play_a_choice(&mut context, &mut game);
+					(context, game)
+				},
+				BatchSize::SmallInput,
+			)
+		});
+	}
+	group.finish();
+}
+
+fn bench_clone(c: &mut Criterion) {
+	let (_, game) = midgame(0);
+	c.bench_function("game_clone", |b| b.iter(|| game.clone()));
+}
+
+fn bench_perspective(c: &mut Criterion) {
+	let (_, game) = midgame(0);
+	let mut group = c.benchmark_group("to_player_perspective");
+	group.bench_function("player", |b| b.iter(|| game.to_player_perspective(Some(0))));
+	group.bench_function("spectator", |b| b.iter(|| game.to_player_perspective(None)));
+	group.finish();
+}
+
+fn bench_playout(c: &mut Criterion) {
+	let mut group = c.benchmark_group("playout");
+	// A whole game takes long enough that the default hundred samples would take minutes.
+	group.sample_size(10);
+	for seed in SEEDS {
+		group.bench_with_input(BenchmarkId::new("random", seed), &seed, |b, seed| {
+			b.iter(|| playout(*seed))
+		});
+	}
+	group.finish();
+}
+
+criterion_group!(
+	benches,
+	bench_initialize_game,
+	bench_advance_game,
+	bench_clone,
+	bench_perspective,
+	bench_playout
+);
+criterion_main!(benches);
@@ -1,9 +1,63 @@
 use super::users::{PlayerInformation, UserId};
+use crate::slay::deadlines::Clock;
+use crate::slay::deadlines::SystemClock;
+use crate::slay::deadlines::Timers;
+use crate::slay::game_context::GameBookKeeping;
+use crate::slay::ids;
+use crate::slay::specification::GameVersion;
+use crate::slay::state::game::Game;
+use crate::slay::state::initialize;
+use crate::slay::state::initialize::GameSetup;
 use chrono::DateTime;
+use chrono::Duration;
 use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt;
 
-pub struct GameOptions {}
+const MIN_PLAYERS: usize = 2;
+const MAX_PLAYERS: usize = 6;
 
+pub fn heartbeat_timeout() -> Duration {
+	Duration::seconds(30)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameOptions {
+	pub number_of_players: usize,
+	pub versions: Vec<GameVersion>,
+	pub timers: Timers,
+}
+
+impl Default for GameOptions {
+	fn default() -> Self {
+		Self {
+			number_of_players: 4,
+			versions: vec![GameVersion::Original],
+			timers: Default::default(),
+		}
+	}
+}
+
+impl GameOptions {
+	pub fn validate(&self) -> Result<(), LobbyError> {
+		if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&self.number_of_players) {
+			return Err(LobbyError::InvalidOptions(format!(
+				"Games need between {} and {} players.",
+				MIN_PLAYERS, MAX_PLAYERS
+			)));
+		}
+		// The expansions do not come with enough cards to play on their own.
+		if !self.versions.contains(&GameVersion::Original) {
+			return Err(LobbyError::InvalidOptions(
+				"The original game is required.".to_string(),
+			));
+		}
+		Ok(())
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Copy, Serialize, Deserialize)]
 pub enum SlotSelection {
 	Any,
 	OnlyHuman,
@@ -11,6 +65,7 @@ pub enum SlotSelection {
 	RemoteBot,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Copy, Serialize, Deserialize)]
 pub enum SlotStatus {
 	Empty,
 	NotReady,
@@ -20,27 +75,134 @@ pub enum SlotStatus {
 	InGame,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Copy, Serialize, Deserialize)]
+pub enum LobbyStatus {
+	Gathering,
+	ReadyToStart,
+	InGame,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LobbyError {
+	InvalidOptions(String),
+	NoSuchSlot(ids::PlayerIndex),
+	AlreadySeated,
+	GameFull,
+	SlotTaken,
+	HumansOnly,
+	AlreadyStarted,
+	NotEveryoneReady,
+}
+
+impl fmt::Display for LobbyError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::InvalidOptions(reason) => write!(f, "{}", reason),
+			Self::NoSuchSlot(player_index) => write!(f, "There is no seat {}.", player_index),
+			Self::AlreadySeated => write!(f, "You are already seated in this game."),
+			Self::GameFull => write!(f, "The game is full."),
+			Self::SlotTaken => write!(f, "That seat is taken."),
+			Self::HumansOnly => write!(f, "That seat is for humans only."),
+			Self::AlreadyStarted => write!(f, "The game has already started."),
+			Self::NotEveryoneReady => write!(f, "Not everyone is ready."),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlotPerspective {
+	pub username: Option<String>,
+	pub selection: SlotSelection,
+	pub status: SlotStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LobbyPerspective {
+	pub options: GameOptions,
+	pub slots: Vec<SlotPerspective>,
+	pub status: LobbyStatus,
+}
+
 pub struct Slot {
 	pub selection: SlotSelection,
 	user: Option<PlayerInformation>,
 	last_heartbeat: DateTime<Utc>,
 	ready: bool,
+	in_game: bool,
 }
 
 impl Slot {
-	pub fn status(&self) -> SlotStatus {
+	pub fn new(selection: SlotSelection, now: DateTime<Utc>) -> Self {
+		Self {
+			selection,
+			user: None,
+			last_heartbeat: now,
+			ready: false,
+			in_game: false,
+		}
+	}
+
+	pub fn user(&self) -> Option<&PlayerInformation> {
+		self.user.as_ref()
+	}
+
+	pub fn is_bot(&self) -> bool {
+		self.selection == SlotSelection::LocalBot
+	}
+
+	pub fn is_ready(&self, now: DateTime<Utc>) -> bool {
+		matches!(self.status(now), SlotStatus::Ready)
+	}
+
+	fn accepts_humans(&self) -> bool {
+		self.user.is_none()
+			&& matches!(
+				self.selection,
+				SlotSelection::Any | SlotSelection::OnlyHuman
+			)
+	}
+
+	fn seat(&mut self, user: PlayerInformation, now: DateTime<Utc>) {
+		self.user = Some(user);
+		self.ready = false;
+		self.receive_heartbeat(now);
+	}
+
+	fn clear(&mut self) {
+		self.user = None;
+		self.ready = false;
+		if self.is_bot() {
+			self.selection = SlotSelection::Any;
+		}
+	}
+
+	pub fn status(&self, now: DateTime<Utc>) -> SlotStatus {
 		if self.user.is_none() {
 			return SlotStatus::Empty;
 		}
-		// Check heart beat
-		if self.ready {
-			SlotStatus::NotReady
-		} else {
+		// Bots live on the server, they cannot lose their connection.
+		if !self.is_bot() && now - self.last_heartbeat > heartbeat_timeout() {
+			return SlotStatus::Disconnected;
+		}
+		if self.in_game {
+			return SlotStatus::InGame;
+		}
+		if self.ready || self.is_bot() {
 			SlotStatus::Ready
+		} else {
+			SlotStatus::NotReady
 		}
 	}
-	pub fn receive_heartbeat(&mut self) {
-		self.last_heartbeat = chrono::offset::Utc::now();
+	pub fn receive_heartbeat(&mut self, now: DateTime<Utc>) {
+		self.last_heartbeat = now;
+	}
+
+	fn to_perspective(&self, now: DateTime<Utc>) -> SlotPerspective {
+		SlotPerspective {
+			username: self.user.as_ref().map(|user| user.username.to_owned()),
+			selection: self.selection,
+			status: self.status(now),
+		}
 	}
 }
 
@@ -49,8 +211,315 @@ pub struct Lobby {
 	pub created: DateTime<Utc>, // TODO:
 	pub options: GameOptions,
 	pub slots: Vec<Slot>,
+	// Heartbeats are timed with this, and the game started from the lobby gets a copy.
+	clock: Box<dyn Clock>,
 }
 
 impl Lobby {
-	pub fn status(&self) {}
+	pub fn new(creator: UserId, options: GameOptions) -> Result<Self, LobbyError> {
+		Self::with_clock(creator, options, Box::<SystemClock>::default())
+	}
+
+	pub fn with_clock(
+		creator: UserId,
+		options: GameOptions,
+		clock: Box<dyn Clock>,
+	) -> Result<Self, LobbyError> {
+		options.validate()?;
+		let now = clock.now();
+		Ok(Self {
+			creator,
+			created: now,
+			slots: (0..options.number_of_players)
+				.map(|_| Slot::new(SlotSelection::Any, now))
+				.collect(),
+			options,
+			clock,
+		})
+	}
+
+	pub fn now(&self) -> DateTime<Utc> {
+		self.clock.now()
+	}
+
+	pub fn status(&self) -> LobbyStatus {
+		if self.has_started() {
+			LobbyStatus::InGame
+		} else if self.slots.iter().all(|slot| slot.is_ready(self.now())) {
+			LobbyStatus::ReadyToStart
+		} else {
+			LobbyStatus::Gathering
+		}
+	}
+
+	pub fn has_started(&self) -> bool {
+		self.slots.iter().any(|slot| slot.in_game)
+	}
+
+	fn slot_mut(&mut self, player_index: ids::PlayerIndex) -> Result<&mut Slot, LobbyError> {
+		self
+			.slots
+			.get_mut(player_index)
+			.ok_or(LobbyError::NoSuchSlot(player_index))
+	}
+
+	fn check_not_started(&self) -> Result<(), LobbyError> {
+		if self.has_started() {
+			Err(LobbyError::AlreadyStarted)
+		} else {
+			Ok(())
+		}
+	}
+
+	fn seat_of(&self, user_id: &UserId) -> Option<ids::PlayerIndex> {
+		self
+			.slots
+			.iter()
+			.position(|slot| slot.user().map(|seated| &seated.user_id == user_id) == Some(true))
+	}
+
+	pub fn join(&mut self, user: PlayerInformation) -> Result<ids::PlayerIndex, LobbyError> {
+		if let Some(player_index) = self.seat_of(&user.user_id) {
+			// Players that lost their connection during the game come back to the same seat.
+			if self.has_started() && !self.slots[player_index].is_bot() {
+				let now = self.now();
+				self.slots[player_index].receive_heartbeat(now);
+				return Ok(player_index);
+			}
+			return Err(LobbyError::AlreadySeated);
+		}
+		self.check_not_started()?;
+		let player_index = self
+			.slots
+			.iter()
+			.position(|slot| slot.accepts_humans())
+			.ok_or(LobbyError::GameFull)?;
+		let now = self.now();
+		self.slots[player_index].seat(user, now);
+		Ok(player_index)
+	}
+
+	// Once the game has started the seat is kept, the player is just shown as disconnected.
+	pub fn leave(&mut self, player_index: ids::PlayerIndex) -> Result<(), LobbyError> {
+		self.check_not_started()?;
+		self.slot_mut(player_index)?.clear();
+		Ok(())
+	}
+
+	pub fn set_ready(
+		&mut self,
+		player_index: ids::PlayerIndex,
+		ready: bool,
+	) -> Result<(), LobbyError> {
+		self.check_not_started()?;
+		self.slot_mut(player_index)?.ready = ready;
+		Ok(())
+	}
+
+	pub fn receive_heartbeat(&mut self, player_index: ids::PlayerIndex) -> Result<(), LobbyError> {
+		let now = self.now();
+		self.slot_mut(player_index)?.receive_heartbeat(now);
+		Ok(())
+	}
+
+	pub fn fill_with_bot(&mut self, player_index: ids::PlayerIndex) -> Result<(), LobbyError> {
+		self.check_not_started()?;
+		let now = self.now();
+		let slot = self.slot_mut(player_index)?;
+		if slot.user.is_some() {
+			return Err(LobbyError::SlotTaken);
+		}
+		if slot.selection == SlotSelection::OnlyHuman {
+			return Err(LobbyError::HumansOnly);
+		}
+		slot.selection = SlotSelection::LocalBot;
+		slot.seat(
+			PlayerInformation {
+				user_id: format!("bot-{}", player_index),
+				username: format!("{} (Bot)", initialize::bot_name(player_index)),
+			},
+			now,
+		);
+		Ok(())
+	}
+
+	pub fn fill_empty_slots_with_bots(&mut self) -> Result<(), LobbyError> {
+		// Seats kept for humans stay empty until one joins.
+		let empty: Vec<ids::PlayerIndex> = (0..self.slots.len())
+			.filter(|player_index| {
+				let slot = &self.slots[*player_index];
+				slot.user.is_none() && slot.selection != SlotSelection::OnlyHuman
+			})
+			.collect();
+		for player_index in empty {
+			self.fill_with_bot(player_index)?;
+		}
+		Ok(())
+	}
+
+	pub fn bots(&self) -> Vec<ids::PlayerIndex> {
+		(0..self.slots.len())
+			.filter(|player_index| self.slots[*player_index].is_bot())
+			.collect()
+	}
+
+	// Players that stopped sending heartbeats before the game started lose their seat.
+	pub fn drop_disconnected(&mut self) -> Vec<ids::PlayerIndex> {
+		if self.has_started() {
+			return Vec::new();
+		}
+		let now = self.now();
+		let disconnected: Vec<ids::PlayerIndex> = (0..self.slots.len())
+			.filter(|player_index| self.slots[*player_index].status(now) == SlotStatus::Disconnected)
+			.collect();
+		for player_index in disconnected.iter() {
+			self.slots[*player_index].clear();
+		}
+		disconnected
+	}
+
+	pub fn start(
+		&mut self,
+		context: &mut GameBookKeeping,
+		game: &mut Game,
+	) -> Result<(), LobbyError> {
+		match self.status() {
+			LobbyStatus::InGame => return Err(LobbyError::AlreadyStarted),
+			LobbyStatus::Gathering => return Err(LobbyError::NotEveryoneReady),
+			LobbyStatus::ReadyToStart => {}
+		}
+		let setup = GameSetup {
+			player_names: self
+				.slots
+				.iter()
+				.filter_map(|slot| slot.user().map(|user| user.username.to_owned()))
+				.collect(),
+			versions: self.options.versions.to_owned(),
+		};
+		context.timers = self.options.timers.to_owned();
+		context.clock = self.clock.clone();
+		initialize::initialize_game(context, game, &setup);
+		self.slots.iter_mut().for_each(|slot| slot.in_game = true);
+		Ok(())
+	}
+
+	pub fn to_perspective(&self) -> LobbyPerspective {
+		LobbyPerspective {
+			options: self.options.to_owned(),
+			slots: self
+				.slots
+				.iter()
+				.map(|slot| slot.to_perspective(self.now()))
+				.collect(),
+			status: self.status(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::Duration;
+
+	use crate::backend::lobby::GameOptions;
+	use crate::backend::lobby::Lobby;
+	use crate::backend::lobby::LobbyError;
+	use crate::backend::lobby::LobbyStatus;
+	use crate::backend::lobby::SlotSelection;
+	use crate::backend::lobby::SlotStatus;
+	use crate::backend::users::PlayerInformation;
+	use crate::slay::deadlines;
+	use crate::slay::deadlines::ManualClock;
+	use crate::slay::game_context::GameBookKeeping;
+	use crate::slay::state::game::Game;
+
+	fn player(name: &str) -> PlayerInformation {
+		PlayerInformation {
+			user_id: name.to_owned(),
+			username: name.to_owned(),
+		}
+	}
+
+	// A human in seat 0 and a bot in seat 1.
+	fn lobby(clock: &ManualClock) -> Lobby {
+		let options = GameOptions {
+			number_of_players: 2,
+			..Default::default()
+		};
+		let mut lobby =
+			Lobby::with_clock(String::from("host"), options, Box::new(clock.clone())).unwrap();
+		assert_eq!(lobby.join(player("host")), Ok(0));
+		lobby.fill_with_bot(1).unwrap();
+		lobby
+	}
+
+	#[test]
+	fn a_player_that_stops_sending_heartbeats_loses_their_seat() {
+		let clock = ManualClock::new(deadlines::current_time());
+		let mut lobby = lobby(&clock);
+
+		clock.advance(Duration::seconds(20));
+		lobby.receive_heartbeat(0).unwrap();
+		clock.advance(Duration::seconds(20));
+		assert_eq!(lobby.slots[0].status(lobby.now()), SlotStatus::NotReady);
+		assert!(lobby.drop_disconnected().is_empty());
+
+		clock.advance(Duration::seconds(11));
+		assert_eq!(lobby.slots[0].status(lobby.now()), SlotStatus::Disconnected);
+		// Bots live on the server, they never miss a heartbeat.
+		assert_eq!(lobby.slots[1].status(lobby.now()), SlotStatus::Ready);
+		assert_eq!(lobby.drop_disconnected(), vec![0]);
+		assert_eq!(lobby.slots[0].status(lobby.now()), SlotStatus::Empty);
+	}
+
+	#[test]
+	fn a_player_that_disconnects_during_the_game_keeps_their_seat() {
+		let clock = ManualClock::new(deadlines::current_time());
+		let mut lobby = lobby(&clock);
+		lobby.set_ready(0, true).unwrap();
+		assert_eq!(lobby.status(), LobbyStatus::ReadyToStart);
+		lobby
+			.start(&mut GameBookKeeping::new(), &mut Game::new())
+			.unwrap();
+
+		clock.advance(Duration::seconds(31));
+		assert_eq!(lobby.slots[0].status(lobby.now()), SlotStatus::Disconnected);
+		assert!(lobby.drop_disconnected().is_empty());
+		lobby.receive_heartbeat(0).unwrap();
+		assert_eq!(lobby.slots[0].status(lobby.now()), SlotStatus::InGame);
+	}
+
+	#[test]
+	fn a_player_that_disconnects_during_the_game_can_rejoin_their_seat() {
+		let clock = ManualClock::new(deadlines::current_time());
+		let mut lobby = lobby(&clock);
+		lobby.set_ready(0, true).unwrap();
+		lobby
+			.start(&mut GameBookKeeping::new(), &mut Game::new())
+			.unwrap();
+
+		clock.advance(Duration::seconds(31));
+		assert_eq!(lobby.slots[0].status(lobby.now()), SlotStatus::Disconnected);
+		assert_eq!(lobby.join(player("host")), Ok(0));
+		assert_eq!(lobby.slots[0].status(lobby.now()), SlotStatus::InGame);
+		// Nobody else can take a seat, bots included.
+		assert_eq!(lobby.join(player("guest")), Err(LobbyError::AlreadyStarted));
+		assert_eq!(lobby.join(player("bot-1")), Err(LobbyError::AlreadySeated));
+	}
+
+	#[test]
+	fn bots_are_kept_out_of_seats_for_humans() {
+		let options = GameOptions {
+			number_of_players: 3,
+			..Default::default()
+		};
+		let mut lobby = Lobby::new(String::from("host"), options).unwrap();
+		lobby.slots[1].selection = SlotSelection::OnlyHuman;
+
+		assert_eq!(lobby.fill_with_bot(1), Err(LobbyError::HumansOnly));
+		lobby.fill_empty_slots_with_bots().unwrap();
+		assert_eq!(lobby.bots(), vec![0, 2]);
+		assert_eq!(lobby.slots[1].status(lobby.now()), SlotStatus::Empty);
+
+		assert_eq!(lobby.join(player("guest")), Ok(1));
+	}
 }
@@ -1,2 +1,4 @@
 pub mod lobby;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod server;
 pub mod users;
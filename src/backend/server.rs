@@ -0,0 +1,844 @@
+use std::collections::HashMap;
+use std::io;
+use std::net::TcpListener;
+use std::net::TcpStream;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+use tungstenite::Message;
+
+use crate::backend::lobby::Lobby;
+use crate::backend::lobby::LobbyStatus;
+use crate::backend::lobby::SlotStatus;
+use crate::backend::users::PlayerInformation;
+use crate::common::protocol;
+use crate::common::protocol::ClientMessage;
+use crate::common::protocol::GameId;
+use crate::common::protocol::GameUpdate;
+use crate::common::protocol::ProtocolError;
+use crate::common::protocol::ServerMessage;
+use crate::slay::driver;
+use crate::slay::driver::AdvanceGameResult;
+use crate::slay::errors::SlayResult;
+use crate::slay::fuzz;
+use crate::slay::game_context::GameBookKeeping;
+use crate::slay::ids;
+use crate::slay::notification::Notification;
+use crate::slay::state::diff::PerspectivePatch;
+use crate::slay::state::game::Game;
+use crate::slay::state::game::GamePerspective;
+use crate::slay::strategy;
+
+pub type ConnectionId = u32;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+const TICK_INTERVAL: Duration = Duration::from_secs(1);
+// A client that connects and never says hello should not keep its thread forever.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+// Random bots can pass a showdown back and forth, give the humans a turn eventually.
+const MAX_BOT_CHOICES: usize = 1000;
+
+enum HubEvent {
+	Connected(ConnectionId, mpsc::Sender<ServerMessage>),
+	Received(ConnectionId, ClientMessage),
+	Disconnected(ConnectionId),
+}
+
+struct Connection {
+	outbox: mpsc::Sender<ServerMessage>,
+	seat: Option<(GameId, ids::PlayerIndex)>,
+	// What this connection has been sent so far, updates are diffed against it.
+	last_perspective: Option<GamePerspective>,
+}
+
+struct RunningGame {
+	context: GameBookKeeping,
+	game: Game,
+}
+
+impl RunningGame {
+	fn play_bots(
+		&mut self,
+		bots: &[ids::PlayerIndex],
+		notifications: &mut Vec<Notification>,
+	) -> SlayResult<()> {
+		for _ in 0..MAX_BOT_CHOICES {
+			let (player_index, choice_id) = match strategy::pick_a_random_choice_among(
+				&mut self.context,
+				&mut self.game,
+				&|player_index| bots.contains(&player_index),
+			) {
+				Some(pick) => pick,
+				None => return Ok(()),
+			};
+			driver::make_selection(
+				&mut self.game,
+				player_index,
+				choice_id,
+				&mut |notification| notifications.push(notification),
+			)?;
+			let result = driver::advance_game(&mut self.context, &mut self.game)?;
+			notifications.extend(self.context.take_notifications());
+			if let AdvanceGameResult::GameOver | AdvanceGameResult::Livelocked = result {
+				return Ok(());
+			}
+		}
+		Ok(())
+	}
+
+	// The default choice of a player who let their deadline pass, if it is still on offer.
+	fn expired_default(&self) -> Option<(ids::PlayerIndex, ids::ChoiceId)> {
+		let now = self.context.now();
+		self.game.players.iter().find_map(|player| {
+			let choices = player.choices_.as_ref()?;
+			if !choices.timeline.is_complete(now) {
+				return None;
+			}
+			let choice_id = choices.default_choice?;
+			choices
+				.options
+				.iter()
+				.any(|option| option.id == choice_id)
+				.then_some((player.player_index, choice_id))
+		})
+	}
+
+	fn showdown_expired(&self) -> bool {
+		self
+			.game
+			.showdown
+			.current()
+			.map(|showdown| showdown.tracker().timeline.is_complete(self.context.now()))
+			.unwrap_or(false)
+	}
+
+	// Makes the default choice for everyone whose time ran out and finishes expired showdowns.
+	// Returns what happened, or nothing if nothing had expired.
+	fn expire(&mut self, bots: &[ids::PlayerIndex]) -> SlayResult<Option<Vec<Notification>>> {
+		let mut notifications = Vec::new();
+		let mut expired = false;
+		// Each default hands out choices with fresh deadlines, the bound is for zero second timers.
+		for _ in 0..MAX_BOT_CHOICES {
+			// An expired showdown is finished as it is, its choices go with it.
+			if !self.showdown_expired() {
+				let (player_index, choice_id) = match self.expired_default() {
+					Some(expired_default) => expired_default,
+					None => break,
+				};
+				driver::make_selection(
+					&mut self.game,
+					player_index,
+					choice_id,
+					&mut |notification| notifications.push(notification),
+				)?;
+			}
+			expired = true;
+			driver::advance_game(&mut self.context, &mut self.game)?;
+			notifications.extend(self.context.take_notifications());
+		}
+		if !expired {
+			return Ok(None);
+		}
+		self.play_bots(bots, &mut notifications)?;
+		Ok(Some(notifications))
+	}
+}
+
+struct HostedGame {
+	lobby: Lobby,
+	running: Option<RunningGame>,
+}
+
+// Games are not Send (the tasks are boxed trait objects without a Send bound),
+// so every game lives on the hub's thread and connections talk to it over channels.
+#[derive(Default)]
+struct Hub {
+	next_game_id: GameId,
+	games: HashMap<GameId, HostedGame>,
+	connections: HashMap<ConnectionId, Connection>,
+}
+
+impl Hub {
+	fn run(&mut self, events: mpsc::Receiver<HubEvent>) {
+		loop {
+			match events.recv_timeout(TICK_INTERVAL) {
+				Ok(event) => self.handle(event),
+				Err(mpsc::RecvTimeoutError::Timeout) => self.tick(),
+				Err(mpsc::RecvTimeoutError::Disconnected) => return,
+			}
+		}
+	}
+
+	fn handle(&mut self, event: HubEvent) {
+		match event {
+			HubEvent::Connected(connection_id, outbox) => {
+				self.connections.insert(
+					connection_id,
+					Connection {
+						outbox,
+						seat: None,
+						last_perspective: None,
+					},
+				);
+			}
+			HubEvent::Received(connection_id, message) => {
+				// The engine panics on its own bugs, that should only end the game it happened in.
+				match panic::catch_unwind(AssertUnwindSafe(|| self.receive(connection_id, message))) {
+					Ok(Ok(())) => {}
+					Ok(Err(reason)) => self.send(connection_id, ServerMessage::Error(reason)),
+					Err(payload) => {
+						let reason = fuzz::panic_message(payload);
+						match self.seat_of(connection_id) {
+							Ok((game_id, _)) => self.end_crashed_game(game_id, reason),
+							Err(_) => log::error!("Connection {} crashed the hub: {}", connection_id, reason),
+						}
+					}
+				}
+			}
+			HubEvent::Disconnected(connection_id) => self.disconnect(connection_id),
+		}
+	}
+
+	// Everyone seated is told why, and the game is gone for good.
+	fn end_crashed_game(&mut self, game_id: GameId, reason: String) {
+		log::error!("Game {} crashed: {}", game_id, reason);
+		for (connection_id, _) in self.seated(game_id) {
+			self.send(
+				connection_id,
+				ServerMessage::Error(ProtocolError::GameCrashed(reason.to_owned())),
+			);
+		}
+		for connection in self.connections.values_mut() {
+			if matches!(connection.seat, Some((seated_game_id, _)) if seated_game_id == game_id) {
+				connection.seat = None;
+				connection.last_perspective = None;
+			}
+		}
+		self.games.remove(&game_id);
+	}
+
+	fn send(&self, connection_id: ConnectionId, message: ServerMessage) {
+		if let Some(connection) = self.connections.get(&connection_id) {
+			// The connection thread may already be gone, it will be removed when it says so.
+			let _ = connection.outbox.send(message);
+		}
+	}
+
+	fn seat_of(
+		&self,
+		connection_id: ConnectionId,
+	) -> Result<(GameId, ids::PlayerIndex), ProtocolError> {
+		self
+			.connections
+			.get(&connection_id)
+			.and_then(|connection| connection.seat)
+			.ok_or(ProtocolError::NotJoined)
+	}
+
+	fn hosted_mut(&mut self, game_id: GameId) -> Result<&mut HostedGame, ProtocolError> {
+		self
+			.games
+			.get_mut(&game_id)
+			.ok_or(ProtocolError::NoSuchGame(game_id))
+	}
+
+	fn receive(
+		&mut self,
+		connection_id: ConnectionId,
+		message: ClientMessage,
+	) -> Result<(), ProtocolError> {
+		match message {
+			// The connection thread only forwards messages after a successful hello.
+			ClientMessage::Hello { .. } => Err(ProtocolError::HandshakeRequired),
+			ClientMessage::CreateLobby(user, options) => {
+				let lobby = Lobby::new(user.user_id.to_owned(), options)?;
+				let game_id = self.next_game_id;
+				self.next_game_id += 1;
+				self.games.insert(
+					game_id,
+					HostedGame {
+						lobby,
+						running: None,
+					},
+				);
+				self.join(connection_id, game_id, user)
+			}
+			ClientMessage::JoinLobby(game_id, user) => self.join(connection_id, game_id, user),
+			ClientMessage::LeaveLobby => self.leave(connection_id),
+			ClientMessage::SetReady(ready) => {
+				let (game_id, player_index) = self.seat_of(connection_id)?;
+				self
+					.hosted_mut(game_id)?
+					.lobby
+					.set_ready(player_index, ready)?;
+				self.start_if_ready(game_id)
+			}
+			ClientMessage::FillWithBots => {
+				let (game_id, _) = self.seat_of(connection_id)?;
+				self
+					.hosted_mut(game_id)?
+					.lobby
+					.fill_empty_slots_with_bots()?;
+				self.start_if_ready(game_id)
+			}
+			ClientMessage::MakeChoice(choice_id) => {
+				let (game_id, player_index) = self.seat_of(connection_id)?;
+				let hosted = self.hosted_mut(game_id)?;
+				let bots = hosted.lobby.bots();
+				let running = hosted.running.as_mut().ok_or(ProtocolError::NotStarted)?;
+				let is_available = running.game.players[player_index]
+					.choices_
+					.as_ref()
+					.map(|choices| choices.options.iter().any(|choice| choice.id == choice_id))
+					.unwrap_or(false);
+				if !is_available {
+					return Err(ProtocolError::ChoiceUnavailable(choice_id));
+				}
+				let mut notifications = Vec::new();
+				driver::make_selection(
+					&mut running.game,
+					player_index,
+					choice_id,
+					&mut |notification| notifications.push(notification),
+				)?;
+				driver::advance_game(&mut running.context, &mut running.game)?;
+				notifications.extend(running.context.take_notifications());
+				running.play_bots(&bots, &mut notifications)?;
+				self.broadcast(game_id, notifications);
+				Ok(())
+			}
+			// Only decides how the next showdowns are offered, so there is nothing to broadcast.
+			ClientMessage::SetAutoPass(auto_pass) => {
+				let (game_id, player_index) = self.seat_of(connection_id)?;
+				let running = self
+					.hosted_mut(game_id)?
+					.running
+					.as_mut()
+					.ok_or(ProtocolError::NotStarted)?;
+				running.game.players[player_index].auto_pass = auto_pass;
+				Ok(())
+			}
+			ClientMessage::Heartbeat => {
+				let (game_id, player_index) = self.seat_of(connection_id)?;
+				self
+					.hosted_mut(game_id)?
+					.lobby
+					.receive_heartbeat(player_index)?;
+				self.send(connection_id, ServerMessage::Heartbeat);
+				Ok(())
+			}
+		}
+	}
+
+	fn join(
+		&mut self,
+		connection_id: ConnectionId,
+		game_id: GameId,
+		user: PlayerInformation,
+	) -> Result<(), ProtocolError> {
+		let player_index = self.hosted_mut(game_id)?.lobby.join(user)?;
+		// A player rejoining a running game may still have a connection that has not timed out.
+		for connection in self.connections.values_mut() {
+			if connection.seat == Some((game_id, player_index)) {
+				connection.seat = None;
+				connection.last_perspective = None;
+			}
+		}
+		if let Some(connection) = self.connections.get_mut(&connection_id) {
+			connection.seat = Some((game_id, player_index));
+		}
+		self.send(connection_id, ServerMessage::Joined(game_id, player_index));
+		self.broadcast_lobby(game_id);
+		// The new connection has not been sent anything yet, so it gets the whole game.
+		self.broadcast(game_id, Vec::new());
+		Ok(())
+	}
+
+	// Seats in a running game are kept, the slot shows up as disconnected until its player rejoins.
+	fn disconnect(&mut self, connection_id: ConnectionId) {
+		let in_lobby = self
+			.seat_of(connection_id)
+			.ok()
+			.and_then(|(game_id, _)| self.games.get(&game_id))
+			.map(|hosted| !hosted.lobby.has_started())
+			.unwrap_or(false);
+		if in_lobby {
+			let _ = self.leave(connection_id);
+		}
+		self.connections.remove(&connection_id);
+	}
+
+	fn leave(&mut self, connection_id: ConnectionId) -> Result<(), ProtocolError> {
+		let (game_id, player_index) = self.seat_of(connection_id)?;
+		self.hosted_mut(game_id)?.lobby.leave(player_index)?;
+		if let Some(connection) = self.connections.get_mut(&connection_id) {
+			connection.seat = None;
+			connection.last_perspective = None;
+		}
+		self.broadcast_lobby(game_id);
+		Ok(())
+	}
+
+	fn start_if_ready(&mut self, game_id: GameId) -> Result<(), ProtocolError> {
+		let hosted = self.hosted_mut(game_id)?;
+		if hosted.lobby.status() == LobbyStatus::ReadyToStart {
+			let mut running = RunningGame {
+				context: GameBookKeeping::new(),
+				game: Game::new(),
+			};
+			hosted
+				.lobby
+				.start(&mut running.context, &mut running.game)?;
+			let mut notifications = Vec::new();
+			running.play_bots(&hosted.lobby.bots(), &mut notifications)?;
+			hosted.running = Some(running);
+			self.broadcast_lobby(game_id);
+			self.broadcast(game_id, notifications);
+		} else {
+			self.broadcast_lobby(game_id);
+		}
+		Ok(())
+	}
+
+	fn tick(&mut self) {
+		let mut changed_lobbies = Vec::new();
+		let mut expired = Vec::new();
+		let mut failed = Vec::new();
+		let mut crashed = Vec::new();
+		for (game_id, hosted) in self.games.iter_mut() {
+			if !hosted.lobby.drop_disconnected().is_empty() {
+				changed_lobbies.push(*game_id);
+			}
+			// Choices and showdowns can time out without anyone choosing anything.
+			let bots = hosted.lobby.bots();
+			let result = panic::catch_unwind(AssertUnwindSafe(|| match hosted.running.as_mut() {
+				Some(running) => running.expire(&bots),
+				None => Ok(None),
+			}));
+			match result {
+				Ok(Ok(Some(notifications))) => expired.push((*game_id, notifications)),
+				Ok(Ok(None)) => {}
+				Ok(Err(error)) => failed.push((*game_id, ProtocolError::from(error))),
+				Err(payload) => crashed.push((*game_id, fuzz::panic_message(payload))),
+			}
+		}
+		for (game_id, reason) in crashed {
+			self.end_crashed_game(game_id, reason);
+		}
+		for (game_id, error) in failed {
+			log::error!("Game {} could not time out its choices: {}", game_id, error);
+			for (connection_id, _) in self.seated(game_id) {
+				self.send(connection_id, ServerMessage::Error(error.to_owned()));
+			}
+		}
+		for game_id in changed_lobbies {
+			self.unseat_dropped(game_id);
+			self.broadcast_lobby(game_id);
+		}
+		for (game_id, notifications) in expired {
+			self.broadcast(game_id, notifications);
+		}
+	}
+
+	fn unseat_dropped(&mut self, game_id: GameId) {
+		let lobby = match self.games.get(&game_id) {
+			Some(hosted) => &hosted.lobby,
+			None => return,
+		};
+		for connection in self.connections.values_mut() {
+			if let Some((seated_game_id, player_index)) = connection.seat {
+				if seated_game_id == game_id
+					&& lobby.slots[player_index].status(lobby.now()) == SlotStatus::Empty
+				{
+					connection.seat = None;
+				}
+			}
+		}
+	}
+
+	fn seated(&self, game_id: GameId) -> Vec<(ConnectionId, ids::PlayerIndex)> {
+		self
+			.connections
+			.iter()
+			.filter_map(|(connection_id, connection)| match connection.seat {
+				Some((seated_game_id, player_index)) if seated_game_id == game_id => {
+					Some((*connection_id, player_index))
+				}
+				_ => None,
+			})
+			.collect()
+	}
+
+	fn broadcast_lobby(&self, game_id: GameId) {
+		if let Some(hosted) = self.games.get(&game_id) {
+			let perspective = hosted.lobby.to_perspective();
+			for (connection_id, _) in self.seated(game_id) {
+				self.send(connection_id, ServerMessage::Lobby(perspective.to_owned()));
+			}
+		}
+	}
+
+	fn broadcast(&mut self, game_id: GameId, notifications: Vec<Notification>) {
+		let running = match self
+			.games
+			.get(&game_id)
+			.and_then(|hosted| hosted.running.as_ref())
+		{
+			Some(running) => running,
+			None => return,
+		};
+		for connection in self.connections.values_mut() {
+			let player_index = match connection.seat {
+				Some((seated_game_id, player_index)) if seated_game_id == game_id => player_index,
+				_ => continue,
+			};
+			let perspective = running.game.to_player_perspective(Some(player_index));
+			let patch = match connection.last_perspective.as_ref() {
+				Some(previous) => previous.diff(&perspective),
+				None => PerspectivePatch::Replace(Box::new(perspective.to_owned())),
+			};
+			connection.last_perspective = Some(perspective);
+			// The connection thread may already be gone, it will be removed when it says so.
+			let _ = connection
+				.outbox
+				.send(ServerMessage::GameUpdate(Box::new(GameUpdate {
+					statics: running.game.to_statics(player_index),
+					perspective: patch,
+					notifications: notifications
+						.iter()
+						.filter(|notification| notification.is_visible_to(player_index))
+						.cloned()
+						.collect(),
+				})));
+		}
+	}
+}
+
+fn is_timeout(error: &tungstenite::Error) -> bool {
+	matches!(
+		error,
+		tungstenite::Error::Io(io_error)
+			if io_error.kind() == io::ErrorKind::WouldBlock || io_error.kind() == io::ErrorKind::TimedOut
+	)
+}
+
+type Socket = tungstenite::WebSocket<TcpStream>;
+
+fn write(socket: &mut Socket, message: &ServerMessage) -> Result<(), Box<tungstenite::Error>> {
+	let text = protocol::encode(message).map_err(|error| tungstenite::Error::Io(error.into()))?;
+	socket.write_message(Message::Text(text))?;
+	Ok(())
+}
+
+fn read(socket: &mut Socket) -> Result<Option<ClientMessage>, Box<tungstenite::Error>> {
+	match socket.read_message() {
+		Ok(Message::Text(text)) => match protocol::decode(&text) {
+			Ok(message) => Ok(Some(message)),
+			Err(error) => {
+				write(socket, &ServerMessage::Error(error))?;
+				Ok(None)
+			}
+		},
+		Ok(Message::Close(_)) => Err(Box::new(tungstenite::Error::ConnectionClosed)),
+		Ok(_) => Ok(None),
+		Err(error) if is_timeout(&error) => Ok(None),
+		Err(error) => Err(Box::new(error)),
+	}
+}
+
+// Nothing reaches the hub until the client has said hello with a version we understand.
+fn handshake(socket: &mut Socket) -> Result<bool, Box<tungstenite::Error>> {
+	let deadline = Instant::now() + HANDSHAKE_TIMEOUT;
+	loop {
+		if Instant::now() >= deadline {
+			log::info!("The client did not say hello in time.");
+			return Ok(false);
+		}
+		match read(socket)? {
+			Some(ClientMessage::Hello { version }) => {
+				return match protocol::check_version(version) {
+					Ok(()) => {
+						write(
+							socket,
+							&ServerMessage::Welcome {
+								version: protocol::PROTOCOL_VERSION,
+							},
+						)?;
+						Ok(true)
+					}
+					Err(error) => {
+						write(socket, &ServerMessage::Error(error))?;
+						Ok(false)
+					}
+				};
+			}
+			Some(_) => write(
+				socket,
+				&ServerMessage::Error(ProtocolError::HandshakeRequired),
+			)?,
+			None => {}
+		}
+	}
+}
+
+fn handle_connection(
+	stream: TcpStream,
+	connection_id: ConnectionId,
+	events: mpsc::Sender<HubEvent>,
+) -> Result<(), Box<tungstenite::Error>> {
+	// The websocket upgrade has to arrive within the handshake timeout as well.
+	stream
+		.set_read_timeout(Some(HANDSHAKE_TIMEOUT))
+		.map_err(tungstenite::Error::Io)?;
+	let mut socket = tungstenite::accept(stream).map_err(|error| match error {
+		tungstenite::HandshakeError::Failure(error) => error,
+		tungstenite::HandshakeError::Interrupted(_) => tungstenite::Error::ConnectionClosed,
+	})?;
+	socket
+		.get_ref()
+		.set_read_timeout(Some(POLL_INTERVAL))
+		.map_err(tungstenite::Error::Io)?;
+	if !handshake(&mut socket)? {
+		socket.close(None)?;
+		return Ok(());
+	}
+
+	let (outbox, inbox) = mpsc::channel();
+	if events
+		.send(HubEvent::Connected(connection_id, outbox))
+		.is_err()
+	{
+		return Ok(());
+	}
+	loop {
+		if let Some(message) = read(&mut socket)? {
+			if events
+				.send(HubEvent::Received(connection_id, message))
+				.is_err()
+			{
+				return Ok(());
+			}
+		}
+		while let Ok(message) = inbox.try_recv() {
+			write(&mut socket, &message)?;
+		}
+	}
+}
+
+pub fn serve(address: &str) -> io::Result<()> {
+	let listener = TcpListener::bind(address)?;
+	let (events, receiver) = mpsc::channel();
+	thread::spawn(move || Hub::default().run(receiver));
+
+	log::info!("Listening on {}", address);
+	for (connection_id, stream) in (0..).zip(listener.incoming()) {
+		let stream = stream?;
+		let events = events.clone();
+		thread::spawn(move || {
+			if let Err(error) = handle_connection(stream, connection_id, events.clone()) {
+				log::info!("Connection {} closed: {}", connection_id, error);
+			}
+			let _ = events.send(HubEvent::Disconnected(connection_id));
+		});
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use std::net::TcpListener;
+	use std::net::TcpStream;
+	use std::sync::mpsc;
+	use std::thread;
+
+	use tungstenite::Message;
+
+	use chrono::Duration;
+
+	use crate::backend::lobby::GameOptions;
+	use crate::backend::server;
+	use crate::backend::server::ConnectionId;
+	use crate::backend::server::Hub;
+	use crate::backend::server::HubEvent;
+	use crate::backend::server::RunningGame;
+	use crate::backend::users::PlayerInformation;
+	use crate::common::client::ClientGame;
+	use crate::common::protocol;
+	use crate::common::protocol::ClientMessage;
+	use crate::common::protocol::GameId;
+	use crate::common::protocol::ProtocolError;
+	use crate::common::protocol::ServerMessage;
+	use crate::common::protocol::PROTOCOL_VERSION;
+	use crate::slay::choices::Action;
+	use crate::slay::choices::Choice;
+	use crate::slay::deadlines;
+	use crate::slay::deadlines::ManualClock;
+	use crate::slay::game_context::GameBookKeeping;
+	use crate::slay::notification::Notification;
+	use crate::slay::state::builder::GameBuilder;
+	use crate::slay::state::deck::DeckPath;
+	use crate::slay::state::diff::PerspectivePatch;
+
+	// The connection creates a game against a bot and starts it.
+	fn start_game(hub: &mut Hub, connection_id: ConnectionId) -> mpsc::Receiver<ServerMessage> {
+		let (outbox, inbox) = mpsc::channel();
+		hub.handle(HubEvent::Connected(connection_id, outbox));
+		let user = PlayerInformation {
+			user_id: format!("user-{}", connection_id),
+			username: format!("User {}", connection_id),
+		};
+		let options = GameOptions {
+			number_of_players: 2,
+			..Default::default()
+		};
+		for message in [
+			ClientMessage::CreateLobby(user, options),
+			ClientMessage::FillWithBots,
+			ClientMessage::SetReady(true),
+		] {
+			hub.handle(HubEvent::Received(connection_id, message));
+		}
+		inbox
+	}
+
+	fn game_of(hub: &Hub, connection_id: ConnectionId) -> GameId {
+		hub.seat_of(connection_id).unwrap().0
+	}
+
+	#[test]
+	fn a_game_that_panics_is_ended_without_the_others() {
+		let mut hub = Hub::default();
+		let crashing = start_game(&mut hub, 0);
+		let _other = start_game(&mut hub, 1);
+		let crashing_id = game_of(&hub, 0);
+		let other_id = game_of(&hub, 1);
+
+		// Losing a card breaks an invariant, which panics in debug builds once the game advances.
+		let running = hub
+			.games
+			.get_mut(&crashing_id)
+			.unwrap()
+			.running
+			.as_mut()
+			.unwrap();
+		let card_id = running.game.players[0].hand.top_ids().next().unwrap();
+		running.game.take_stack(DeckPath::Hand(0), card_id).unwrap();
+		let choice_id = running.game.players[0].choices_.as_ref().unwrap().options[0].id;
+		hub.handle(HubEvent::Received(0, ClientMessage::MakeChoice(choice_id)));
+
+		assert!(!hub.games.contains_key(&crashing_id));
+		assert!(hub.seat_of(0).is_err());
+		assert!(crashing
+			.try_iter()
+			.any(|message| matches!(message, ServerMessage::Error(ProtocolError::GameCrashed(_)))));
+		assert!(hub.games[&other_id].running.is_some());
+		assert_eq!(game_of(&hub, 1), other_id);
+	}
+
+	#[test]
+	fn a_player_that_lets_their_deadline_pass_gets_the_default_choice() {
+		let clock = ManualClock::new(deadlines::current_time());
+		let mut context = GameBookKeeping::new();
+		context.clock = Box::new(clock.clone());
+		let game = GameBuilder::new(2).build(&mut context);
+		let mut running = RunningGame { context, game };
+
+		assert!(running.expire(&[]).unwrap().is_none());
+
+		clock.advance(Duration::seconds(301));
+		let notifications = running.expire(&[]).unwrap().unwrap();
+		assert!(notifications.contains(&Notification::PlayerChose(
+			0,
+			Choice::UseActionPoints(Action::Forfeit)
+		)));
+		assert_eq!(running.game.active_player_index(), 1);
+		assert!(running.expire(&[]).unwrap().is_none());
+	}
+
+	#[test]
+	fn a_client_applying_the_game_updates_sees_what_the_server_sees() {
+		let mut hub = Hub::default();
+		let inbox = start_game(&mut hub, 0);
+		let (game_id, player_index) = hub.seat_of(0).unwrap();
+		let mut client = None;
+		let mut patched = 0;
+		for _ in 0..30 {
+			for message in inbox.try_iter() {
+				if let ServerMessage::GameUpdate(update) = message {
+					if matches!(update.perspective, PerspectivePatch::Diff(_)) {
+						patched += 1;
+					}
+					ClientGame::receive(&mut client, *update).unwrap();
+				}
+			}
+			let game = &hub.games[&game_id].running.as_ref().unwrap().game;
+			assert_eq!(
+				client.as_ref().unwrap().perspective,
+				game.to_player_perspective(Some(player_index))
+			);
+			let Some(choices) = game.players[player_index].choices_.as_ref() else {
+				break;
+			};
+			let choice_id = choices.options[0].id;
+			hub.handle(HubEvent::Received(0, ClientMessage::MakeChoice(choice_id)));
+		}
+		assert!(patched > 0);
+	}
+
+	// Says hello over a real socket, returning the reply and whether the connection reached the hub.
+	fn say_hello(version: u32) -> (ServerMessage, bool) {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let address = listener.local_addr().unwrap();
+		let (events, received) = mpsc::channel();
+		let connection = thread::spawn(move || {
+			let (stream, _) = listener.accept().unwrap();
+			server::handle_connection(stream, 0, events)
+		});
+
+		let stream = TcpStream::connect(address).unwrap();
+		let (mut socket, _) = tungstenite::client(format!("ws://{}/", address), stream).unwrap();
+		let hello = protocol::encode(&ClientMessage::Hello { version }).unwrap();
+		socket.write_message(Message::Text(hello)).unwrap();
+		let reply = match socket.read_message().unwrap() {
+			Message::Text(text) => protocol::decode(&text).unwrap(),
+			message => panic!("Expected a reply, got {:?}", message),
+		};
+		drop(socket);
+		let _ = connection.join().unwrap();
+		let reached_hub = received
+			.try_iter()
+			.any(|event| matches!(event, HubEvent::Connected(..)));
+		(reply, reached_hub)
+	}
+
+	#[test]
+	fn a_client_speaking_the_same_version_is_welcomed() {
+		assert_eq!(
+			say_hello(PROTOCOL_VERSION),
+			(
+				ServerMessage::Welcome {
+					version: PROTOCOL_VERSION
+				},
+				true
+			)
+		);
+	}
+
+	#[test]
+	fn a_client_speaking_another_version_is_turned_away() {
+		assert_eq!(
+			say_hello(PROTOCOL_VERSION + 1),
+			(
+				ServerMessage::Error(ProtocolError::VersionMismatch {
+					client: PROTOCOL_VERSION + 1,
+					server: PROTOCOL_VERSION,
+				}),
+				false
+			)
+		);
+	}
+}
@@ -1,3 +1,6 @@
+use serde::Deserialize;
+use serde::Serialize;
+
 pub type UserId = String;
 
 pub struct UserInformation {
@@ -9,6 +12,7 @@ pub struct UserInformation {
 	pub bot: bool,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlayerInformation {
 	pub user_id: UserId,
 	pub username: String,
@@ -0,0 +1,19 @@
+use std::path::Path;
+use std::process::exit;
+
+use copying::slay::specs::cards::images;
+
+// Run from the directory the frontend is served from: cargo run --bin check_assets [root]
+pub fn main() {
+	let root = std::env::args().nth(1).unwrap_or_else(|| String::from("."));
+	for spec in images::cards_without_art() {
+		println!("No art yet, drawn as a placeholder: {:?}", spec);
+	}
+	let problems = images::check_assets(Path::new(&root));
+	for problem in problems.iter() {
+		println!("{}", problem);
+	}
+	if !problems.is_empty() {
+		exit(1);
+	}
+}
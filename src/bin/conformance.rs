@@ -0,0 +1,33 @@
+use std::panic;
+use std::process::exit;
+
+use copying::slay::conformance;
+
+pub fn main() {
+	// Panics are reported with the case they happened in.
+	panic::set_hook(Box::new(|_| {}));
+
+	let failures = conformance::run_all();
+	for failure in failures.iter() {
+		println!("{}", failure);
+	}
+	for case in conformance::known_failures().iter() {
+		println!(
+			"Known failure {:?}: {}",
+			case.card,
+			case.known_failure.unwrap_or_default()
+		);
+	}
+	let uncovered = conformance::uncovered_cards();
+	if !uncovered.is_empty() {
+		println!("No case plays: {:?}", uncovered);
+	}
+	println!(
+		"{} of {} cases failed.",
+		failures.len(),
+		conformance::cases::all_cases().len()
+	);
+	if !failures.is_empty() {
+		exit(1);
+	}
+}
@@ -0,0 +1,65 @@
+use std::panic;
+use std::process::exit;
+
+use copying::slay::fuzz;
+
+fn arg_or<T: std::str::FromStr>(index: usize, default: T) -> T {
+	std::env::args()
+		.nth(index)
+		.and_then(|arg| arg.parse().ok())
+		.unwrap_or(default)
+}
+
+fn picks_arg(index: usize) -> Vec<usize> {
+	std::env::args()
+		.nth(index)
+		.unwrap_or_default()
+		.split(',')
+		.filter_map(|pick| pick.trim().parse().ok())
+		.collect()
+}
+
+// cargo run --release --bin fuzz [games] [first seed] [max picks per game]
+// cargo run --release --bin fuzz replay <seed> <comma separated picks>
+// cargo run --release --bin fuzz trace <seed> <comma separated picks>
+pub fn main() {
+	// Panics are collected as failures, the default hook would print every one of them.
+	// The quiet hook still notes where each one happened for the report.
+	panic::set_hook(Box::new(|_| {}));
+	fuzz::record_panic_locations();
+
+	if std::env::args().nth(1).as_deref() == Some("trace") {
+		let (case, trace) = fuzz::trace(arg_or(2, 0u64), &picks_arg(3));
+		print!("{}", trace.render());
+		if let Some(case) = case {
+			println!("{}", case);
+			exit(1);
+		}
+		return;
+	}
+
+	if std::env::args().nth(1).as_deref() == Some("replay") {
+		let seed = arg_or(2, 0u64);
+		let picks = picks_arg(3);
+		match fuzz::replay(seed, &picks) {
+			Some(case) => {
+				println!("{}", case);
+				exit(1);
+			}
+			None => println!("Seed {} did not fail with those picks.", seed),
+		}
+		return;
+	}
+
+	let games = arg_or(1, 1000u64);
+	let first_seed = arg_or(2, 0u64);
+	let max_picks = arg_or(3, 2000usize);
+	let failures = fuzz::run(first_seed, games, max_picks);
+	for case in failures.iter() {
+		println!("{}", case);
+	}
+	println!("{} of {} games failed.", failures.len(), games);
+	if !failures.is_empty() {
+		exit(1);
+	}
+}
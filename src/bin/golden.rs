@@ -0,0 +1,45 @@
+use std::path::PathBuf;
+use std::process::exit;
+
+use copying::slay::golden;
+
+// cargo run --bin golden [golden directory]
+// cargo run --bin golden bless [golden directory]
+pub fn main() {
+	let mut args: Vec<String> = std::env::args().skip(1).collect();
+	let blessing = args.first().map(|arg| arg == "bless").unwrap_or(false);
+	if blessing {
+		args.remove(0);
+	}
+	let dir = PathBuf::from(args.first().map(String::as_str).unwrap_or("goldens"));
+
+	if blessing {
+		if let Err(error) = golden::bless(&dir, &golden::SEEDS, golden::MAX_STEPS) {
+			println!("Unable to write the snapshots: {}", error);
+			exit(1);
+		}
+		println!(
+			"Recorded {} snapshots in {}.",
+			golden::SEEDS.len(),
+			dir.display()
+		);
+		return;
+	}
+
+	match golden::check(&dir, &golden::SEEDS, golden::MAX_STEPS) {
+		Ok(mismatches) if mismatches.is_empty() => {
+			println!("All {} snapshots match.", golden::SEEDS.len());
+		}
+		Ok(mismatches) => {
+			for mismatch in mismatches.iter() {
+				println!("{}", mismatch);
+			}
+			println!("Run the bless mode to accept the changes.");
+			exit(1);
+		}
+		Err(error) => {
+			println!("Unable to read the snapshots: {}", error);
+			exit(1);
+		}
+	}
+}
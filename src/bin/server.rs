@@ -0,0 +1,8 @@
+use copying::backend::server;
+
+pub fn main() {
+	let address = std::env::args()
+		.nth(1)
+		.unwrap_or_else(|| String::from("0.0.0.0:8081"));
+	server::serve(&address).expect("The game server stopped.");
+}
@@ -0,0 +1,76 @@
+use crate::common::protocol::GameUpdate;
+use crate::common::protocol::ProtocolError;
+use crate::slay::notification::Notification;
+use crate::slay::state::diff::PerspectivePatch;
+use crate::slay::state::game::GamePerspective;
+use crate::slay::state::game::GameStaticInformation;
+
+// What a client knows of its game, kept up to date by applying the game updates it receives.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ClientGame {
+	pub statics: GameStaticInformation,
+	pub perspective: GamePerspective,
+}
+
+impl ClientGame {
+	// Only a game the client already has can be patched, the first update has to replace it.
+	pub fn receive(
+		game: &mut Option<Self>,
+		update: GameUpdate,
+	) -> Result<Vec<Notification>, ProtocolError> {
+		match (game.as_mut(), update.perspective) {
+			(_, PerspectivePatch::Replace(perspective)) => {
+				*game = Some(Self {
+					statics: update.statics,
+					perspective: *perspective,
+				});
+			}
+			(Some(known), patch) => {
+				known.statics = update.statics;
+				known.perspective.apply(patch);
+			}
+			(None, PerspectivePatch::Diff(_)) => {
+				return Err(ProtocolError::Malformed(String::from(
+					"Received changes to a game that was never sent.",
+				)))
+			}
+		}
+		Ok(update.notifications)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::common::client::ClientGame;
+	use crate::common::protocol::GameUpdate;
+	use crate::common::protocol::ProtocolError;
+	use crate::slay::game_context::GameBookKeeping;
+	use crate::slay::state::builder::GameBuilder;
+	use crate::slay::state::diff::PerspectivePatch;
+
+	#[test]
+	fn changes_to_a_game_that_was_never_sent_are_rejected() {
+		let game = GameBuilder::new(2).build(&mut GameBookKeeping::new());
+		let perspective = game.to_player_perspective(Some(0));
+		let update = |patch| GameUpdate {
+			statics: game.to_statics(0),
+			perspective: patch,
+			notifications: Vec::new(),
+		};
+
+		let mut client = None;
+		assert!(matches!(
+			ClientGame::receive(&mut client, update(perspective.diff(&perspective))),
+			Err(ProtocolError::Malformed(_))
+		));
+		assert_eq!(client, None);
+
+		ClientGame::receive(
+			&mut client,
+			update(PerspectivePatch::Replace(Box::new(perspective.to_owned()))),
+		)
+		.unwrap();
+		ClientGame::receive(&mut client, update(perspective.diff(&perspective))).unwrap();
+		assert_eq!(client.unwrap().perspective, perspective);
+	}
+}
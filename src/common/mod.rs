@@ -1 +1,3 @@
+pub mod client;
 pub mod perspective;
+pub mod protocol;
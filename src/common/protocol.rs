@@ -0,0 +1,224 @@
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::backend::lobby::GameOptions;
+use crate::backend::lobby::LobbyError;
+use crate::backend::lobby::LobbyPerspective;
+use crate::backend::users::PlayerInformation;
+use crate::slay::errors::SlayError;
+use crate::slay::ids;
+use crate::slay::notification::Notification;
+use crate::slay::showdown::completion::AutoPass;
+use crate::slay::state::diff::PerspectivePatch;
+use crate::slay::state::game::GameStaticInformation;
+
+// Bump this whenever a message changes shape.
+pub const PROTOCOL_VERSION: u32 = 9;
+
+pub type GameId = u32;
+
+// The first message on a connection must be a Hello, anything else is rejected until then.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ClientMessage {
+	Hello { version: u32 },
+	CreateLobby(PlayerInformation, GameOptions),
+	JoinLobby(GameId, PlayerInformation),
+	LeaveLobby,
+	SetReady(bool),
+	FillWithBots,
+	MakeChoice(ids::ChoiceId),
+	SetAutoPass(AutoPass),
+	Heartbeat,
+}
+
+// The first update after joining replaces the whole perspective, later ones only carry what changed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameUpdate {
+	pub statics: GameStaticInformation,
+	pub perspective: PerspectivePatch,
+	pub notifications: Vec<Notification>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ServerMessage {
+	Welcome { version: u32 },
+	Joined(GameId, ids::PlayerIndex),
+	Lobby(LobbyPerspective),
+	GameUpdate(Box<GameUpdate>),
+	Error(ProtocolError),
+	Heartbeat,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ProtocolError {
+	VersionMismatch { client: u32, server: u32 },
+	HandshakeRequired,
+	Malformed(String),
+	NotJoined,
+	NoSuchGame(GameId),
+	Lobby(LobbyError),
+	NotStarted,
+	ChoiceUnavailable(ids::ChoiceId),
+	Slay(String),
+	// The engine hit a bug, the game was ended.
+	GameCrashed(String),
+}
+
+impl From<SlayError> for ProtocolError {
+	fn from(error: SlayError) -> Self {
+		Self::Slay(error.to_string())
+	}
+}
+
+impl From<LobbyError> for ProtocolError {
+	fn from(error: LobbyError) -> Self {
+		Self::Lobby(error)
+	}
+}
+
+impl fmt::Display for ProtocolError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::VersionMismatch { client, server } => write!(
+				f,
+				"Client speaks protocol version {}, but the server speaks {}.",
+				client, server
+			),
+			Self::HandshakeRequired => write!(f, "Say hello before anything else."),
+			Self::Malformed(reason) => write!(f, "Could not read message: {}", reason),
+			Self::NotJoined => write!(f, "You have not joined a game."),
+			Self::NoSuchGame(game_id) => write!(f, "There is no game {}.", game_id),
+			Self::Lobby(error) => write!(f, "{}", error),
+			Self::NotStarted => write!(f, "The game has not started yet."),
+			Self::ChoiceUnavailable(choice_id) => write!(f, "Choice {} is not available.", choice_id),
+			Self::Slay(reason) => write!(f, "{}", reason),
+			Self::GameCrashed(reason) => write!(f, "The game ended after an internal error: {}", reason),
+		}
+	}
+}
+
+pub fn check_version(version: u32) -> Result<(), ProtocolError> {
+	if version == PROTOCOL_VERSION {
+		Ok(())
+	} else {
+		Err(ProtocolError::VersionMismatch {
+			client: version,
+			server: PROTOCOL_VERSION,
+		})
+	}
+}
+
+pub fn encode<M: Serialize>(message: &M) -> serde_json::Result<String> {
+	serde_json::to_string(message)
+}
+
+pub fn decode<M: DeserializeOwned>(text: &str) -> Result<M, ProtocolError> {
+	serde_json::from_str(text).map_err(|error| ProtocolError::Malformed(error.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+	use serde::de::DeserializeOwned;
+	use serde::Serialize;
+	use std::fmt::Debug;
+
+	use crate::backend::lobby::GameOptions;
+	use crate::backend::lobby::Lobby;
+	use crate::backend::lobby::LobbyError;
+	use crate::backend::users::PlayerInformation;
+	use crate::common::protocol;
+	use crate::common::protocol::ClientMessage;
+	use crate::common::protocol::GameUpdate;
+	use crate::common::protocol::ProtocolError;
+	use crate::common::protocol::ServerMessage;
+	use crate::common::protocol::PROTOCOL_VERSION;
+	use crate::slay::game_context::GameBookKeeping;
+	use crate::slay::notification::Notification;
+	use crate::slay::showdown::completion::AutoPass;
+	use crate::slay::specs::cards::card_type::SlayCardSpec;
+	use crate::slay::state::builder::GameBuilder;
+	use crate::slay::state::diff::PerspectivePatch;
+
+	fn assert_round_trip<M: Serialize + DeserializeOwned + PartialEq + Debug>(message: M) {
+		let text = protocol::encode(&message).unwrap();
+		assert_eq!(protocol::decode::<M>(&text), Ok(message));
+	}
+
+	fn user() -> PlayerInformation {
+		PlayerInformation {
+			user_id: String::from("user"),
+			username: String::from("User"),
+		}
+	}
+
+	#[test]
+	fn client_messages_read_back_the_same() {
+		for message in [
+			ClientMessage::Hello {
+				version: PROTOCOL_VERSION,
+			},
+			ClientMessage::CreateLobby(user(), GameOptions::default()),
+			ClientMessage::JoinLobby(3, user()),
+			ClientMessage::LeaveLobby,
+			ClientMessage::SetReady(true),
+			ClientMessage::FillWithBots,
+			ClientMessage::MakeChoice(12),
+			ClientMessage::SetAutoPass(AutoPass::Always),
+			ClientMessage::Heartbeat,
+		] {
+			assert_round_trip(message);
+		}
+	}
+
+	#[test]
+	fn server_messages_read_back_the_same() {
+		let context = &mut GameBookKeeping::new();
+		let game = GameBuilder::new(2).build(context);
+		let previous = game.to_player_perspective(None);
+		let lobby = Lobby::new(user().user_id, GameOptions::default()).unwrap();
+		for message in [
+			ServerMessage::Welcome {
+				version: PROTOCOL_VERSION,
+			},
+			ServerMessage::Joined(3, 1),
+			ServerMessage::Lobby(lobby.to_perspective()),
+			ServerMessage::GameUpdate(Box::new(GameUpdate {
+				statics: game.to_statics(1),
+				perspective: PerspectivePatch::Replace(Box::new(previous.to_owned())),
+				notifications: vec![Notification::PlayerDrew(1, SlayCardSpec::Challenge)],
+			})),
+			ServerMessage::GameUpdate(Box::new(GameUpdate {
+				statics: game.to_statics(1),
+				perspective: previous.diff(&game.to_player_perspective(Some(1))),
+				notifications: Vec::new(),
+			})),
+			ServerMessage::Error(ProtocolError::Lobby(LobbyError::GameFull)),
+			ServerMessage::Heartbeat,
+		] {
+			assert_round_trip(message);
+		}
+	}
+
+	#[test]
+	fn only_the_same_version_is_understood() {
+		assert_eq!(protocol::check_version(PROTOCOL_VERSION), Ok(()));
+		assert_eq!(
+			protocol::check_version(PROTOCOL_VERSION + 1),
+			Err(ProtocolError::VersionMismatch {
+				client: PROTOCOL_VERSION + 1,
+				server: PROTOCOL_VERSION,
+			})
+		);
+	}
+
+	#[test]
+	fn a_message_that_cannot_be_read_is_malformed() {
+		assert!(matches!(
+			protocol::decode::<ClientMessage>("{\"MakeChoice\": \"twelve\"}"),
+			Err(ProtocolError::Malformed(_))
+		));
+	}
+}
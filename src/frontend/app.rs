@@ -1,44 +1,141 @@
 use std::collections::VecDeque;
 use std::rc::Rc;
 use yew::Callback;
+use yew::Reducible;
 
+use crate::common::client::ClientGame;
+use crate::common::protocol;
+use crate::common::protocol::ClientMessage;
+use crate::common::protocol::GameUpdate;
+use crate::common::protocol::ProtocolError;
 use crate::frontend::card_modal::CardModalInfo;
 use crate::slay::choices::ChoicesPerspective;
 use crate::slay::driver;
 use crate::slay::driver::AdvanceGameResult;
 use crate::slay::game_context::GameBookKeeping;
+use crate::slay::i18n::keys::MessageKey;
+use crate::slay::i18n::Locale;
+use crate::slay::i18n::Localizer;
 use crate::slay::ids;
 use crate::slay::notification::Notification;
+use crate::slay::replay::GameRecord;
+use crate::slay::showdown::completion::AutoPass;
 use crate::slay::specs::cards::card_type::SlayCardSpec;
+use crate::slay::state::diff::PerspectivePatch;
 use crate::slay::state::game::Game;
 use crate::slay::state::game::GamePerspective;
 use crate::slay::state::game::GameStaticInformation;
 use crate::slay::state::initialize;
+use crate::slay::state::initialize::GameSetup;
 use crate::slay::strategy;
 
+pub const MAX_HOT_SEAT_HUMANS: usize = 4;
+
+// How long the bots wait before each of their choices, so the human can follow along.
+pub const BOT_DELAYS_MILLIS: [u32; 4] = [1500, 750, 250, 0];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewMode {
+	Player,
+	Spectator,
+	// Shows every hand, the draw pile and the task queues.
+	Omniscient,
+}
+
+impl ViewMode {
+	pub fn next(&self) -> Self {
+		match self {
+			Self::Player => Self::Spectator,
+			Self::Spectator => Self::Omniscient,
+			Self::Omniscient => Self::Player,
+		}
+	}
+
+	pub fn label_key(&self) -> MessageKey {
+		match self {
+			Self::Player => MessageKey::ViewModePlayer,
+			Self::Spectator => MessageKey::ViewModeSpectator,
+			Self::Omniscient => MessageKey::ViewModeOmniscient,
+		}
+	}
+}
+
 #[derive(Clone)]
 pub struct AppState {
 	pub context: GameBookKeeping,
 	pub game: Game,
+	// The human whose perspective is being shown.
 	pub my_player_index: ids::PlayerIndex,
+	pub humans: Vec<ids::PlayerIndex>,
+	pub bots: Vec<ids::PlayerIndex>,
+	// Set while waiting for the device to be passed to another human.
+	pub pending_handoff: Option<ids::PlayerIndex>,
+	pub bot_delay_millis: u32,
+	pub view_mode: ViewMode,
+	pub game_over: bool,
+	// Counts the choices made so far, the view uses it to know when to schedule the bots.
+	pub moves: usize,
+	// Test states are not created from a setup, so they cannot be replayed.
+	pub record: Option<GameRecord>,
 	pub notifications: VecDeque<Notification>,
+	// What is shown, kept up to date with the same game updates a server sends its clients.
+	client: Option<ClientGame>,
 }
 
 impl AppState {
+	fn with_game(
+		context: GameBookKeeping,
+		game: Game,
+		humans: Vec<ids::PlayerIndex>,
+		record: Option<GameRecord>,
+	) -> Self {
+		let bots = (0..game.number_of_players())
+			.filter(|player_index| !humans.contains(player_index))
+			.collect();
+		let mut state = Self {
+			context,
+			game,
+			my_player_index: humans[0],
+			humans,
+			bots,
+			pending_handoff: None,
+			bot_delay_millis: BOT_DELAYS_MILLIS[1],
+			view_mode: ViewMode::Player,
+			game_over: false,
+			moves: 0,
+			record,
+			notifications: Default::default(),
+			client: None,
+		};
+		if state.is_hot_seat() {
+			state.my_player_index = state.next_human().unwrap_or(state.my_player_index);
+			state.pending_handoff = Some(state.my_player_index);
+		}
+		state.publish();
+		state
+	}
+
 	pub fn new() -> Self {
+		Self::with_humans(1)
+	}
+
+	// The first seats go to the humans sharing the device, the rest are played by bots.
+	pub fn with_humans(number_of_humans: usize) -> Self {
 		// let object = JsValue::from("world");
 		// log::info!("Hello {}", object.as_string().unwrap());
 
 		let mut context = GameBookKeeping::new();
 		let mut game = Game::new();
-		initialize::initialize_game(&mut context, &mut game);
-		let player_index = game.active_player_index();
-		Self {
-			context,
-			game,
-			my_player_index: player_index,
-			notifications: Default::default(),
-		}
+		let setup = GameSetup::default();
+		let start_time = context.now();
+		initialize::initialize_game(&mut context, &mut game, &setup);
+		let record = GameRecord::new(context.seed(), setup, start_time);
+		let humans = if number_of_humans <= 1 {
+			vec![game.active_player_index()]
+		} else {
+			(0..number_of_humans.min(game.number_of_players())).collect()
+		};
+		Self::with_game(context, game, humans, Some(record))
 	}
 	pub fn test(card: &SlayCardSpec) -> Self {
 		// let object = JsValue::from("world");
@@ -47,47 +144,224 @@ impl AppState {
 		let mut context = GameBookKeeping::new();
 		let mut game = Game::new();
 		initialize::create_state_to_test(&mut context, &mut game, card);
-		let player_index = 0;
-		Self {
-			context,
-			game,
-			my_player_index: player_index,
-			notifications: Default::default(),
+		Self::with_game(context, game, vec![0], None)
+	}
+
+	pub fn is_hot_seat(&self) -> bool {
+		self.humans.len() > 1
+	}
+
+	fn has_choices(&self, player_index: ids::PlayerIndex) -> bool {
+		self.game.players[player_index].choices_.is_some()
+	}
+
+	// The human that should be holding the device, starting with the active player.
+	fn next_human(&self) -> Option<ids::PlayerIndex> {
+		let active_player_index = self.game.active_player_index();
+		let number_of_players = self.game.number_of_players();
+		(0..number_of_players)
+			.map(|offset| (active_player_index + offset) % number_of_players)
+			.find(|player_index| self.humans.contains(player_index) && self.has_choices(*player_index))
+	}
+
+	fn update_handoff(&mut self) {
+		if !self.is_hot_seat() || self.has_choices(self.my_player_index) {
+			self.pending_handoff = None;
+			return;
 		}
+		self.pending_handoff = self
+			.next_human()
+			.filter(|player_index| *player_index != self.my_player_index);
 	}
 
-	pub fn perspective(&self) -> GamePerspective {
-		self.game.to_player_perspective(Some(self.my_player_index))
+	pub fn accept_handoff(&self) -> Self {
+		let mut new_state = self.clone();
+		if let Some(player_index) = new_state.pending_handoff.take() {
+			new_state.my_player_index = player_index;
+		}
+		new_state
 	}
-	pub fn get_statics(&self) -> GameStaticInformation {
-		self.game.to_statics(self.my_player_index)
+
+	pub fn visible_notifications(&self) -> VecDeque<Notification> {
+		self
+			.notifications
+			.iter()
+			.filter(|notification| match self.view_mode {
+				ViewMode::Player => notification.is_visible_to(self.my_player_index),
+				ViewMode::Spectator => notification.is_public(),
+				ViewMode::Omniscient => true,
+			})
+			.cloned()
+			.collect()
 	}
 
-	fn make_selection(&mut self, choice_id: ids::ChoiceId) -> bool {
-		let new_notifications = &mut Vec::new();
-		{
-			let mut notify = |n| new_notifications.push(n);
-			driver::make_selection(&mut self.game, self.my_player_index, choice_id, &mut notify)
-				.expect("oops");
+	fn current_perspective(&self) -> GamePerspective {
+		match self.view_mode {
+			ViewMode::Player => self.game.to_player_perspective(Some(self.my_player_index)),
+			ViewMode::Spectator => self.game.to_player_perspective(None),
+			ViewMode::Omniscient => self.game.to_omniscient_perspective(),
 		}
+	}
+
+	// Sends only what changed since the last update.
+	// The app keeps the notifications itself, so it can filter them by view mode.
+	fn publish(&mut self) {
+		let perspective = self.current_perspective();
+		let patch = match self.client.as_ref() {
+			Some(client) => client.perspective.diff(&perspective),
+			None => PerspectivePatch::Replace(Box::new(perspective.to_owned())),
+		};
+		let update = GameUpdate {
+			statics: self.game.to_statics(self.my_player_index),
+			perspective: patch,
+			notifications: Vec::new(),
+		};
+		ClientGame::receive(&mut self.client, update).expect("The first update replaces the game.");
+		debug_assert_eq!(
+			self.client.as_ref().map(|client| &client.perspective),
+			Some(&perspective)
+		);
+	}
 
-		self.notifications.extend(new_notifications.drain(..));
+	fn client(&self) -> &ClientGame {
+		self
+			.client
+			.as_ref()
+			.expect("The state is published once it is created.")
+	}
+
+	pub fn perspective(&self) -> GamePerspective {
+		self.client().perspective.to_owned()
+	}
+
+	// Shows the hands of everyone sharing the device, so it is only for debugging single player games.
+	pub fn allows_omniscient_view(&self) -> bool {
+		cfg!(debug_assertions) && !self.is_hot_seat()
+	}
+
+	pub fn with_next_view_mode(&self) -> Self {
+		let mut new_state = self.clone();
+		new_state.view_mode = self.view_mode.next();
+		if new_state.view_mode == ViewMode::Omniscient && !self.allows_omniscient_view() {
+			new_state.view_mode = new_state.view_mode.next();
+		}
+		new_state
+	}
+	pub fn get_statics(&self) -> GameStaticInformation {
+		self.client().statics.to_owned()
+	}
 
-		match driver::advance_game(&mut self.context, &mut self.game).expect("uh oh") {
-			AdvanceGameResult::GameOver => true, // Need to return that the game is complete...
+	fn advance(&mut self) {
+		let result = driver::advance_game(&mut self.context, &mut self.game).expect("uh oh");
+		self.notifications.extend(self.context.take_notifications());
+		self.game_over = match result {
+			AdvanceGameResult::GameOver => true,
 			AdvanceGameResult::WaitingForPlayers => false,
+			// Nothing more can happen, so the game is as over as it gets.
+			AdvanceGameResult::Livelocked => true,
+		};
+	}
+
+	fn is_offered(&self, player_index: ids::PlayerIndex, choice_id: ids::ChoiceId) -> bool {
+		self.game.players[player_index]
+			.choices_
+			.as_ref()
+			.map(|choices| choices.options.iter().any(|option| option.id == choice_id))
+			.unwrap_or(false)
+	}
+
+	// Notifications are kept for everyone, each human only sees their own in hot seat mode.
+	fn make_selection(&mut self, player_index: ids::PlayerIndex, choice_id: ids::ChoiceId) {
+		// A deadline may have passed since the choice was offered, the replay does the same.
+		self.advance();
+		if self.is_offered(player_index, choice_id) {
+			if let Some(record) = self.record.as_mut() {
+				record.record(&self.game, player_index, choice_id, self.context.now());
+			}
+			let new_notifications = &mut Vec::new();
+			{
+				let mut notify = |n| new_notifications.push(n);
+				driver::make_selection(&mut self.game, player_index, choice_id, &mut notify).expect("oops");
+			}
+
+			self.notifications.extend(new_notifications.drain(..));
+			self.advance();
 		}
+		self.moves += 1;
+		self.update_handoff();
 	}
 
 	pub fn create_new_state(&self, choice_id: ids::ChoiceId) -> Self {
 		let mut new_state = self.clone();
-		new_state.make_selection(choice_id);
+		new_state.make_selection(self.my_player_index, choice_id);
+		new_state
+	}
 
-		let (player_index, _choice_id) =
-			strategy::pick_a_random_choice(&mut new_state.context, &mut new_state.game)
-				.expect("I knew it.");
+	pub fn bots_have_choices(&self) -> bool {
+		!self.game_over
+			&& self
+				.bots
+				.iter()
+				.any(|player_index| self.has_choices(*player_index))
+	}
 
-		new_state.my_player_index = player_index;
+	// The state after one of the bots made a choice, if any of them has something to decide.
+	pub fn bot_move(&self) -> Option<Self> {
+		if self.game_over {
+			return None;
+		}
+		let mut new_state = self.clone();
+		let bots = new_state.bots.to_owned();
+		let (player_index, choice_id) = strategy::pick_a_random_choice_among(
+			&mut new_state.context,
+			&mut new_state.game,
+			&|player_index| bots.contains(&player_index),
+		)?;
+		new_state.make_selection(player_index, choice_id);
+		Some(new_state)
+	}
+
+	// Only the human whose perspective is shown can change their own setting.
+	pub fn with_auto_pass(&self, auto_pass: AutoPass) -> Self {
+		let mut new_state = self.clone();
+		new_state.game.players[self.my_player_index].auto_pass = auto_pass;
+		new_state
+	}
+
+	// Answers what a client would send a server, on behalf of the human whose perspective is shown.
+	// The device plays its own game, so there is no lobby to go through.
+	pub fn handle(&self, message: ClientMessage) -> Result<Self, ProtocolError> {
+		match message {
+			ClientMessage::Hello { version } => protocol::check_version(version).map(|_| self.clone()),
+			ClientMessage::MakeChoice(choice_id) => {
+				if !self.is_offered(self.my_player_index, choice_id) {
+					return Err(ProtocolError::ChoiceUnavailable(choice_id));
+				}
+				Ok(self.create_new_state(choice_id))
+			}
+			ClientMessage::SetAutoPass(auto_pass) => Ok(self.with_auto_pass(auto_pass)),
+			ClientMessage::Heartbeat => Ok(self.clone()),
+			ClientMessage::CreateLobby(..)
+			| ClientMessage::JoinLobby(..)
+			| ClientMessage::LeaveLobby
+			| ClientMessage::SetReady(_)
+			| ClientMessage::FillWithBots => Err(ProtocolError::Malformed(String::from(
+				"There is no lobby on this device.",
+			))),
+		}
+	}
+
+	pub fn auto_pass(&self) -> AutoPass {
+		self.game.players[self.my_player_index].auto_pass
+	}
+
+	pub fn with_next_bot_delay(&self) -> Self {
+		let position = BOT_DELAYS_MILLIS
+			.iter()
+			.position(|delay| *delay == self.bot_delay_millis)
+			.unwrap_or(0);
+		let mut new_state = self.clone();
+		new_state.bot_delay_millis = BOT_DELAYS_MILLIS[(position + 1) % BOT_DELAYS_MILLIS.len()];
 		new_state
 	}
 }
@@ -98,6 +372,45 @@ impl Default for AppState {
 	}
 }
 
+// Everything that changes the app goes through here, so each change starts from the latest state.
+pub enum AppAction {
+	Restart(usize),
+	Test(SlayCardSpec),
+	Send(ClientMessage),
+	BotMove,
+	NextViewMode,
+	NextBotDelay,
+	AcceptHandoff,
+}
+
+impl Reducible for AppState {
+	type Action = AppAction;
+
+	fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+		let mut new_state = match action {
+			AppAction::Restart(number_of_humans) => Self::with_humans(number_of_humans),
+			AppAction::Test(card) => Self::test(&card),
+			AppAction::Send(message) => match self.handle(message) {
+				Ok(new_state) => new_state,
+				Err(error) => {
+					log::warn!("{}", error);
+					return self;
+				}
+			},
+			// The bots may have nothing to do any more, for example after a restart.
+			AppAction::BotMove => match self.bot_move() {
+				Some(new_state) => new_state,
+				None => return self,
+			},
+			AppAction::NextViewMode => self.with_next_view_mode(),
+			AppAction::NextBotDelay => self.with_next_bot_delay(),
+			AppAction::AcceptHandoff => self.accept_handoff(),
+		};
+		new_state.publish();
+		Rc::new(new_state)
+	}
+}
+
 // Rename this to common props
 #[derive(Clone, PartialEq)]
 pub struct CommonProps {
@@ -105,6 +418,7 @@ pub struct CommonProps {
 	pub statics: Rc<GameStaticInformation>,
 	pub perspective: Rc<GamePerspective>,
 	pub highlighted_choice: Option<ids::ChoiceId>,
+	pub locale: Locale,
 
 	pub choose: Option<Callback<ids::ChoiceId, ()>>,
 	pub view_card: Callback<Option<CardModalInfo>, ()>,
@@ -115,4 +429,8 @@ impl CommonProps {
 	pub fn get_choices(&self) -> &Option<ChoicesPerspective> {
 		&self.perspective.choices
 	}
+
+	pub fn localizer(&self) -> Localizer<'_> {
+		Localizer::for_game(self.locale, &self.statics, Some(self.statics.player_index))
+	}
 }
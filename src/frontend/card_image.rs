@@ -0,0 +1,40 @@
+use yew::classes;
+use yew::prelude::*;
+
+use crate::slay::i18n::Locale;
+use crate::slay::i18n::Localizer;
+use crate::slay::specs::cards::card_type::SlayCardSpec;
+
+#[derive(Properties, PartialEq)]
+pub struct CardImageProps {
+	pub spec: SlayCardSpec,
+	pub locale: Locale,
+	pub width: u32,
+}
+
+// Cards without art are drawn from their label, type icon and description, so they can be played anyway.
+#[function_component(CardImage)]
+pub fn view_card_image(props: &CardImageProps) -> Html {
+	let localizer = Localizer::new(props.locale);
+	let description = localizer.card_description(&props.spec);
+	if let Some(path) = props.spec.image_path() {
+		return html! {
+			<img src={path} alt={description} width={props.width.to_string()}/>
+		};
+	}
+	let icon = props.spec.hero_type().map(|hero_type| {
+		html! {
+			<img src={hero_type.icon()} alt={hero_type.label()} width={"60"}/>
+		}
+	});
+	html! {
+		<div
+			class={classes!("placeholder-card")}
+			style={format!("width: {}px; height: {}px;", props.width, props.width * 7 / 5)}
+		>
+			<h2>{ localizer.card_label(&props.spec) }</h2>
+			{ for icon }
+			<label>{ description }</label>
+		</div>
+	}
+}
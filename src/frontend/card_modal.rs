@@ -4,6 +4,7 @@ use yew::classes;
 use yew::prelude::*;
 
 use crate::frontend::app::CommonProps;
+use crate::frontend::card_image::CardImage;
 use crate::slay::choices::ChoicePerspective;
 use crate::slay::specs::cards::card_type::SlayCardSpec;
 
@@ -27,6 +28,7 @@ pub fn view_card_details(props: &CardModalProps) -> Html {
 		let view_card = props.common.view_card.clone();
 		move |_| view_card.emit(None)
 	};
+	let localizer = props.common.localizer();
 	let choices = props.info.represents.iter().map(|choice| {
 		let choose_this = {
 			let choose = props.common.choose.clone();
@@ -39,7 +41,7 @@ pub fn view_card_details(props: &CardModalProps) -> Html {
 					class={classes!("choice-button")}
 					onclick={choose_this}
 				>
-					{choice.choice.label()}
+					{localizer.render(&choice.label_message())}
 				</button>
 			</div>
 		}
@@ -48,18 +50,18 @@ pub fn view_card_details(props: &CardModalProps) -> Html {
 			<div class={classes!("card-modal")} onclick={clear_card}>
 					<div class={classes!("modal-content")}>
 							<h1>
-									{props.info.spec.label()}
+									{localizer.card_label(&props.info.spec)}
 							</h1>
 							<br/>
 							<label>
-									{props.info.spec.description()}
+									{localizer.card_description(&props.info.spec)}
 							</label>
 							<br/>
 							{for choices}
 							<br/>
-							<img
-									src={props.info.spec.image_path()}
-									alt={props.info.spec.description()}
+							<CardImage
+									spec={props.info.spec}
+									locale={props.common.locale}
 									width={500}
 							/>
 					</div>
@@ -59,7 +59,7 @@ pub fn view_deck(props: &DeckProps) -> Html {
 	};
 	html! {
 			<div class={classes!("deck-holder")}>
-					{props.deck.path.get_label()}
+					{props.common.localizer().render(&props.deck.path.label_message())}
 					{": "}
 					{deck_value}
 			</div>
@@ -14,6 +14,7 @@ use crate::frontend::player::PlayerView;
 use crate::frontend::showdown::challenge::ChallengeModalView;
 use crate::frontend::showdown::offer_modal::OfferChallengesView;
 use crate::frontend::showdown::roll_modal::RollModalView;
+use crate::slay::i18n::Locale;
 use crate::slay::ids;
 use crate::slay::state::game::GamePerspective;
 use crate::slay::state::game::GameStaticInformation;
@@ -23,6 +24,7 @@ pub struct GamePerspectiveProps {
 	pub game: Rc<GamePerspective>,
 	pub statics: Rc<GameStaticInformation>,
 	pub choose: Option<Callback<ids::ChoiceId, ()>>,
+	pub locale: Locale,
 }
 
 #[function_component(GamePerspectiveView)]
@@ -44,6 +46,7 @@ pub fn view_game(props: &GamePerspectiveProps) -> Html {
 		statics: props.statics.to_owned(),
 		perspective: props.game.to_owned(),
 		highlighted_choice: (*highlighted_choice).to_owned(),
+		locale: props.locale,
 		choose: props.choose.to_owned(),
 		view_card,
 		set_highlighted_choice,
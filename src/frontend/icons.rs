@@ -1,3 +1,4 @@
+use crate::slay::deadlines;
 use crate::slay::deadlines::Timeline;
 
 use gloo_timers::callback::Interval;
@@ -43,17 +44,17 @@ pub struct TimerProps {
 
 #[function_component(Timer)]
 pub fn timer(props: &TimerProps) -> Html {
-	let current_completion = use_state(|| props.timeline.completion());
+	let current_completion = use_state(|| props.timeline.completion(deadlines::current_time()));
 
 	let callback = {
 		let timeline = props.timeline.clone();
-		move || current_completion.set(timeline.completion())
+		move || current_completion.set(timeline.completion(deadlines::current_time()))
 	};
 	let timeout = Interval::new(20, callback);
 	timeout.forget();
 
 	// gloo timer
-	let completion_option = props.timeline.completion();
+	let completion_option = props.timeline.completion(deadlines::current_time());
 	if let Some(completion) = completion_option {
 		let mut width = (800f64 * completion.percent_complete) as i32;
 		width = width.clamp(0, 800);
@@ -1,4 +1,5 @@
 pub mod app;
+pub mod card_image;
 pub mod card_modal;
 pub mod choices;
 pub mod deck;
@@ -7,7 +8,10 @@ pub mod game;
 pub mod icons;
 pub mod messages;
 pub mod notifications;
+pub mod pass_device;
 pub mod player;
+pub mod replay;
 pub mod showdown;
 pub mod stack;
+pub mod tasks;
 pub mod view;
@@ -1,10 +1,19 @@
 use std::collections::VecDeque;
 use std::rc::Rc;
 
+use yew::classes;
 use yew::prelude::*;
 
+use crate::frontend::card_image::CardImage;
+use crate::slay::i18n::keys::MessageKey;
+use crate::slay::i18n::Arg;
+use crate::slay::i18n::Locale;
+use crate::slay::i18n::Localizer;
+use crate::slay::i18n::Message;
 use crate::slay::ids;
 use crate::slay::notification::Notification;
+use crate::slay::notification::NotificationCategory;
+use crate::slay::specs::cards::card_type::SlayCardSpec;
 use crate::slay::state::game::GameStaticInformation;
 
 #[derive(Properties, PartialEq)]
@@ -12,18 +21,214 @@ pub struct NotificationProps {
 	pub notifications: VecDeque<Notification>,
 	pub statics: Rc<GameStaticInformation>,
 	pub player_index: ids::PlayerIndex,
+	pub locale: Locale,
 }
 
-#[function_component(Notifications)]
-pub fn view_notifications(props: &NotificationProps) -> Html {
-	// Dry:
-	let notifications = props.notifications.iter().map(|notification| {
+// Notifications between two PlayersTurn notifications, the first turn has no such notification.
+struct TurnGroup<'a> {
+	player_index: Option<ids::PlayerIndex>,
+	notifications: Vec<&'a Notification>,
+}
+
+fn group_by_turn(notifications: &VecDeque<Notification>) -> Vec<TurnGroup<'_>> {
+	let mut groups = vec![TurnGroup {
+		player_index: None,
+		notifications: Vec::new(),
+	}];
+	for notification in notifications.iter() {
+		if let Notification::PlayersTurn(player_index) = notification {
+			groups.push(TurnGroup {
+				player_index: Some(*player_index),
+				notifications: Vec::new(),
+			});
+		}
+		groups.last_mut().unwrap().notifications.push(notification);
+	}
+	groups
+}
+
+#[derive(Properties, PartialEq)]
+pub struct NotificationEntryProps {
+	pub notification: Notification,
+	pub statics: Rc<GameStaticInformation>,
+	pub player_index: ids::PlayerIndex,
+	pub view_card: Callback<Option<SlayCardSpec>, ()>,
+	pub locale: Locale,
+}
+
+#[function_component(NotificationEntry)]
+pub fn view_notification_entry(props: &NotificationEntryProps) -> Html {
+	let localizer = Localizer::for_game(props.locale, &props.statics, Some(props.player_index));
+	let cards = props
+		.notification
+		.mentioned_cards()
+		.into_iter()
+		.map(|spec| {
+			let view_card = {
+				let view_card = props.view_card.clone();
+				move |_| view_card.emit(Some(spec))
+			};
+			html! {
+				<button onclick={view_card}>{ localizer.card_label(&spec) }</button>
+			}
+		});
+	html! {
+		<div>
+			{ localizer.render(&props.notification.message()) }
+			{ for cards }
+		</div>
+	}
+}
+
+#[derive(Properties, PartialEq)]
+pub struct NotificationHistoryProps {
+	pub notifications: VecDeque<Notification>,
+	pub statics: Rc<GameStaticInformation>,
+	pub player_index: ids::PlayerIndex,
+	pub close: Callback<MouseEvent, ()>,
+	pub locale: Locale,
+}
+
+#[function_component(NotificationHistory)]
+pub fn view_notification_history(props: &NotificationHistoryProps) -> Html {
+	let localizer = Localizer::for_game(props.locale, &props.statics, Some(props.player_index));
+	let player_filter = use_state(|| None::<ids::PlayerIndex>);
+	let hidden_categories = use_state(Vec::<NotificationCategory>::new);
+	let viewed_card = use_state(|| None::<SlayCardSpec>);
+
+	let view_card = {
+		let viewed_card = viewed_card.clone();
+		Callback::from(move |spec| viewed_card.set(spec))
+	};
+	let next_player_filter = {
+		let player_filter = player_filter.clone();
+		let number_of_players = props.statics.players.len();
+		move |_| {
+			player_filter.set(match *player_filter {
+				None => Some(0),
+				Some(player_index) if player_index + 1 < number_of_players => Some(player_index + 1),
+				Some(_) => None,
+			})
+		}
+	};
+	let category_toggles = NotificationCategory::all().into_iter().map(|category| {
+		let shown = !hidden_categories.contains(&category);
+		let toggle = {
+			let hidden_categories = hidden_categories.clone();
+			move |_| {
+				let mut hidden = (*hidden_categories).to_owned();
+				if shown {
+					hidden.push(category);
+				} else {
+					hidden.retain(|c| *c != category);
+				}
+				hidden_categories.set(hidden)
+			}
+		};
 		html! {
-			<div>
-				{notification.get_description(&props.statics, props.player_index)}
+			<button onclick={toggle}>
+				{
+					localizer.render(&Message::new(
+						if shown { MessageKey::HistoryHide } else { MessageKey::HistoryShow },
+						vec![category.label_key().into()],
+					))
+				}
+			</button>
+		}
+	});
+
+	let is_shown = |notification: &Notification| {
+		!hidden_categories.contains(&notification.category())
+			&& player_filter
+				.map(|player_index| notification.involved_players().contains(&player_index))
+				.unwrap_or(true)
+	};
+	let groups = group_by_turn(&props.notifications)
+		.into_iter()
+		.enumerate()
+		.filter(|(_, group)| group.notifications.iter().any(|n| is_shown(n)))
+		.map(|(turn, group)| {
+			let title = match group.player_index {
+				Some(player_index) => Message::new(
+					MessageKey::HistoryPlayerTurn,
+					vec![Arg::Number(turn as i32 + 1), Arg::PlayerName(player_index)],
+				),
+				None => Message::new(MessageKey::HistoryTurn, vec![Arg::Number(turn as i32 + 1)]),
+			};
+			let entries = group
+				.notifications
+				.into_iter()
+				.filter(|n| is_shown(n))
+				.map(|notification| {
+					html! {
+						<NotificationEntry
+							notification={notification.to_owned()}
+							statics={props.statics.to_owned()}
+							player_index={props.player_index}
+							view_card={view_card.to_owned()}
+							locale={props.locale}
+						/>
+					}
+				});
+			html! {
+				<div>
+					<h3>{ localizer.render(&title) }</h3>
+					{ for entries }
+				</div>
+			}
+		});
+
+	let card_view = viewed_card.as_ref().map(|spec| {
+		let clear_card = {
+			let view_card = view_card.clone();
+			move |_| view_card.emit(None)
+		};
+		html! {
+			<div class={classes!("card-modal")} onclick={clear_card}>
+				<div class={classes!("modal-content")}>
+					<h1>{ localizer.card_label(spec) }</h1>
+					<br/>
+					<label>{ localizer.card_description(spec) }</label>
+					<br/>
+					<CardImage spec={*spec} locale={props.locale} width={500}/>
+				</div>
 			</div>
 		}
 	});
+
+	html! {
+		<div>
+			{ for card_view }
+			<div>
+				<button onclick={next_player_filter}>
+					{
+						match *player_filter {
+							Some(player_index) => localizer.render(&Message::new(
+								MessageKey::HistoryPlayer,
+								vec![Arg::PlayerName(player_index)],
+							)),
+							None => localizer.text(MessageKey::HistoryEveryone),
+						}
+					}
+				</button>
+				{ for category_toggles }
+			</div>
+			{ for groups }
+			<br/>
+			<div onclick={props.close.to_owned()}>
+				<img
+					src={"imgs/icons/back.png"}
+					alt={"Go back"}
+					width={50}
+				/>
+			</div>
+		</div>
+	}
+}
+
+#[function_component(Notifications)]
+pub fn view_notifications(props: &NotificationProps) -> Html {
+	let localizer = Localizer::for_game(props.locale, &props.statics, Some(props.player_index));
 	let num_notifications = props.notifications.len();
 	let last_notifications = props
 		.notifications
@@ -33,12 +238,10 @@ pub fn view_notifications(props: &NotificationProps) -> Html {
 		.map(|(_, notification)| {
 			html! {
 				<div>
-					{notification.get_description(&props.statics, props.player_index)}
+					{localizer.render(&notification.message())}
 				</div>
 			}
 		});
-	// Does this need to be one higher?
-	// TODO: DRY?
 	let is_open = use_state(|| false);
 	let close = {
 		let open_handle = is_open.clone();
@@ -59,16 +262,12 @@ pub fn view_notifications(props: &NotificationProps) -> Html {
 		};
 	}
 	html! {
-			<div>
-					{ for notifications }
-					<br/>
-					<div onclick={close}>
-						<img
-							src={"imgs/icons/back.png"}
-							alt={"Go back"}
-							width={50}
-						/>
-					</div>
-			</div>
+		<NotificationHistory
+			notifications={props.notifications.to_owned()}
+			statics={props.statics.to_owned()}
+			player_index={props.player_index}
+			close={close}
+			locale={props.locale}
+		/>
 	}
 }
@@ -0,0 +1,31 @@
+use yew::classes;
+use yew::prelude::*;
+
+use crate::slay::i18n::keys::MessageKey;
+use crate::slay::i18n::Arg;
+use crate::slay::i18n::Locale;
+use crate::slay::i18n::Localizer;
+use crate::slay::i18n::Message;
+
+#[derive(Properties, PartialEq)]
+pub struct PassDeviceProps {
+	pub player_name: String,
+	pub accept: Callback<MouseEvent, ()>,
+	pub locale: Locale,
+}
+
+// Covers the table so the next player cannot see the previous player's hand.
+#[function_component(PassDeviceView)]
+pub fn view_pass_device(props: &PassDeviceProps) -> Html {
+	let localizer = Localizer::new(props.locale);
+	let with_name =
+		|key: MessageKey| Message::new(key, vec![Arg::Verbatim(props.player_name.to_owned())]);
+	html! {
+		<div>
+			<h2>{ localizer.render(&with_name(MessageKey::PassDevice)) }</h2>
+			<button class={classes!("border-blink")} onclick={props.accept.to_owned()}>
+				{ localizer.render(&with_name(MessageKey::PassDeviceAccept)) }
+			</button>
+		</div>
+	}
+}
@@ -0,0 +1,142 @@
+use std::rc::Rc;
+
+use yew::classes;
+use yew::prelude::*;
+
+use crate::frontend::game::GamePerspectiveView;
+use crate::slay::i18n::keys::MessageKey;
+use crate::slay::i18n::Arg;
+use crate::slay::i18n::Locale;
+use crate::slay::i18n::Localizer;
+use crate::slay::i18n::Message;
+use crate::slay::ids;
+use crate::slay::replay::Replay;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayViewer {
+	// Whoever makes the next choice, to see what they knew when they made it.
+	Chooser,
+	Player(ids::PlayerIndex),
+	Omniscient,
+}
+
+impl ReplayViewer {
+	fn next(&self, number_of_players: usize) -> Self {
+		match self {
+			Self::Chooser => Self::Player(0),
+			Self::Player(player_index) if player_index + 1 < number_of_players => {
+				Self::Player(player_index + 1)
+			}
+			Self::Player(_) => Self::Omniscient,
+			Self::Omniscient => Self::Chooser,
+		}
+	}
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ReplayProps {
+	pub replay: Rc<Replay>,
+	pub close: Callback<MouseEvent, ()>,
+	pub locale: Locale,
+}
+
+#[function_component(ReplayView)]
+pub fn view_replay(props: &ReplayProps) -> Html {
+	let step = use_state(|| 0usize);
+	let viewer = use_state(|| ReplayViewer::Chooser);
+
+	let last_step = props.replay.number_of_steps() - 1;
+	let turn_starts = props.replay.turn_starts();
+	let go_to = |target: usize| {
+		let step = step.clone();
+		Callback::from(move |_: MouseEvent| step.set(target.min(last_step)))
+	};
+	let previous_turn = turn_starts
+		.iter()
+		.rev()
+		.find(|start| **start < *step)
+		.copied()
+		.unwrap_or(0);
+	let next_turn = turn_starts
+		.iter()
+		.find(|start| **start > *step)
+		.copied()
+		.unwrap_or(last_step);
+	let change_viewer = {
+		let viewer = viewer.clone();
+		let number_of_players = props.replay.record.setup.player_names.len();
+		Callback::from(move |_: MouseEvent| viewer.set(viewer.next(number_of_players)))
+	};
+
+	let game = props.replay.game_at(*step);
+	let choice = props.replay.choice_at(*step);
+	let viewed_player_index = match *viewer {
+		ReplayViewer::Chooser => choice.map(|choice| choice.player_index),
+		ReplayViewer::Player(player_index) => Some(player_index),
+		ReplayViewer::Omniscient => None,
+	};
+	let perspective = match viewed_player_index {
+		Some(player_index) => game.to_player_perspective(Some(player_index)),
+		None => game.to_omniscient_perspective(),
+	};
+	let statics = Rc::new(game.to_statics(viewed_player_index.unwrap_or(0)));
+	let localizer = Localizer::for_game(props.locale, &statics, viewed_player_index);
+	let viewer_label = match viewed_player_index {
+		Some(player_index) => Arg::PlayerName(player_index),
+		None => MessageKey::ReplayEveryone.into(),
+	};
+	let next_choice = match choice {
+		Some(choice) => localizer.render(&Message::new(
+			MessageKey::ReplayNextChoice,
+			vec![
+				Arg::PlayerName(choice.player_index),
+				choice.choice.label_message().into(),
+			],
+		)),
+		None => localizer.text(MessageKey::ReplayEnd),
+	};
+
+	let turns = turn_starts.iter().enumerate().map(|(turn, start)| {
+		html! {
+			<button onclick={go_to(*start)}>{ format!("{}", turn + 1) }</button>
+		}
+	});
+
+	html! {
+		<div>
+			<div>
+				<button class={classes!("border-blink")} onclick={props.close.to_owned()}>
+					{ localizer.text(MessageKey::ReplayBack) }
+				</button>
+				<button onclick={go_to(0)}>{ "<<" }</button>
+				<button onclick={go_to(previous_turn)}>{ localizer.text(MessageKey::ReplayPreviousTurn) }</button>
+				<button onclick={go_to(step.saturating_sub(1))}>{ "<" }</button>
+				<label>
+					{
+						localizer.render(&Message::new(
+							MessageKey::ReplayStep,
+							vec![Arg::Number(*step as i32), Arg::Number(last_step as i32)],
+						))
+					}
+				</label>
+				<button onclick={go_to(*step + 1)}>{ ">" }</button>
+				<button onclick={go_to(next_turn)}>{ localizer.text(MessageKey::ReplayNextTurn) }</button>
+				<button onclick={go_to(last_step)}>{ ">>" }</button>
+				<button onclick={change_viewer}>
+					{ localizer.render(&Message::new(MessageKey::ReplayViewingAs, vec![viewer_label])) }
+				</button>
+			</div>
+			<div>
+				<label>{ localizer.text(MessageKey::ReplayTurns) }</label>
+				{ for turns }
+			</div>
+			<div>{ next_choice }</div>
+			<GamePerspectiveView
+				game={Rc::new(perspective)}
+				statics={statics}
+				choose={None::<Callback<ids::ChoiceId, ()>>}
+				locale={props.locale}
+			/>
+		</div>
+	}
+}
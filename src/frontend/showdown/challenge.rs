@@ -12,43 +12,43 @@ use crate::frontend::showdown::common::RollHistory;
 use crate::frontend::showdown::common::RollTotal;
 use crate::frontend::stack::CardSpecView;
 use crate::frontend::stack::ExtraSpecProps;
+use crate::slay::choices::Choice;
+use crate::slay::i18n::keys::MessageKey;
+use crate::slay::i18n::Arg;
+use crate::slay::i18n::Message;
+use crate::slay::ids;
 use crate::slay::showdown::challenge::ChallengePerspective;
 use crate::slay::showdown::roll::ChallengeReason;
+use crate::slay::showdown::roll_modification::ModificationPath;
+use crate::slay::specs::cards::card_type::SlayCardSpec;
 
 #[function_component(ChallengeDescription)]
 pub fn view_challenge_description(props: &ChallengeModalProps) -> Html {
+	let localizer = props.common.localizer();
+	let players = || {
+		vec![
+			Arg::PlayerName(props.challenge.challenger.player_index),
+			Arg::PlayerName(props.challenge.initiator.player_index),
+		]
+	};
 	let text = match &props.challenge.reason {
 		ChallengeReason::PlaceHeroCard(spec) => html! {
 			<>
 				<div class={classes!("row")}>
-				{
-					format!(
-						"{} challenged {}'s choice to place ",
-						props.challenge.challenger.roller_name(&props.common.statics),
-						props.challenge.initiator.roller_name(&props.common.statics),
-					)
-				}
+				{ localizer.render(&Message::new(MessageKey::ChallengeDescriptionHero, players())) }
 					<CardSpecView
 						spec={spec.to_owned()}
 						common={props.common.to_owned()}
 						extra_specs={ExtraSpecProps::default()}
 					/>
-					{
-						format!("in his party.")
-					}
+					{ localizer.text(MessageKey::ChallengeDescriptionHeroAfter) }
 				</div>
 			</>
 		},
 		ChallengeReason::PlaceItem(spec) => html! {
 			<>
 				<div class={classes!("row")}>
-				{
-					format!(
-						"{} challenged {}'s choice to place the item ",
-						props.challenge.challenger.roller_name(&props.common.statics),
-						props.challenge.initiator.roller_name(&props.common.statics),
-					)
-				}
+				{ localizer.render(&Message::new(MessageKey::ChallengeDescriptionItem, players())) }
 					<CardSpecView
 						spec={spec.to_owned()}
 						common={props.common.to_owned()}
@@ -60,13 +60,7 @@ pub fn view_challenge_description(props: &ChallengeModalProps) -> Html {
 		ChallengeReason::CastMagic(spec) => html! {
 			<>
 				<div class={classes!("row")}>
-				{
-					format!(
-						"{} challenged {}'s choice to play magic card",
-						props.challenge.challenger.roller_name(&props.common.statics),
-						props.challenge.initiator.roller_name(&props.common.statics),
-					)
-				}
+				{ localizer.render(&Message::new(MessageKey::ChallengeDescriptionMagic, players())) }
 					<CardSpecView
 						spec={spec.to_owned()}
 						common={props.common.to_owned()}
@@ -117,6 +111,13 @@ pub struct ChallengeRollProps {
 #[function_component(ChallengeRollsView)]
 pub fn view_challenge_roll(props: &ChallengeRollProps) -> Html {
 	let _open = use_state(|| false);
+	let localizer = props.common.localizer();
+	let roll_of = |player_index: ids::PlayerIndex| {
+		localizer.render(&Message::new(
+			MessageKey::ChallengeRoll,
+			vec![Arg::PlayerName(player_index)],
+		))
+	};
 	html! {
 		<div
 			class={classes!("column")}
@@ -124,23 +125,13 @@ pub fn view_challenge_roll(props: &ChallengeRollProps) -> Html {
 			<div class={classes!("row")}>
 				<div class={classes!("column")}>
 					<label>
-						{
-							format!(
-								"{}'s roll",
-								props.challenge.initiator.roller_name(&props.common.statics)
-							)
-						}
+						{ roll_of(props.challenge.initiator.player_index) }
 					</label>
 					<Dice roll={props.challenge.initiator.initial.to_owned()}/>
 				</div>
 				<div class={classes!("column")}>
 					<label>
-						{
-							format!(
-								"{}'s roll",
-								props.challenge.challenger.roller_name(&props.common.statics)
-							)
-						}
+						{ roll_of(props.challenge.challenger.player_index) }
 					</label>
 					<Dice roll={props.challenge.challenger.initial.to_owned()}/>
 				</div>
@@ -183,6 +174,56 @@ pub fn view_challenge_roll(props: &ChallengeRollProps) -> Html {
 	}
 }
 
+// Who currently wins the challenge, and what each of the viewer's modifiers would change.
+#[function_component(ChallengeOdds)]
+pub fn view_challenge_odds(props: &ChallengeRollProps) -> Html {
+	let localizer = props.common.localizer();
+	let winner = |challenger_wins: bool| {
+		Arg::PlayerName(if challenger_wins {
+			props.challenge.challenger.player_index
+		} else {
+			props.challenge.initiator.player_index
+		})
+	};
+	let modifications = props
+		.common
+		.get_choices()
+		.iter()
+		.flat_map(|choices| choices.options.iter())
+		.filter_map(|choice| match choice.choice {
+			Choice::Modify(path, kinds, amount) => Some(html! {
+				<div>
+					{
+						localizer.render(&Message::new(
+							MessageKey::ChallengeOddsModification,
+							vec![
+								Arg::Card(SlayCardSpec::ModifierCard(kinds)),
+								Arg::Signed(amount),
+								Arg::PlayerName(match path {
+									ModificationPath::Challenger => props.challenge.challenger.player_index,
+									_ => props.challenge.initiator.player_index,
+								}),
+								winner(props.challenge.challenger_wins_with(path, amount)),
+							],
+						))
+					}
+				</div>
+			}),
+			_ => None,
+		});
+	html! {
+		<div>
+			{
+				localizer.render(&Message::new(
+					MessageKey::ChallengeOddsSummary,
+					vec![winner(props.challenge.is_challenger_victories())],
+				))
+			}
+			{ for modifications }
+		</div>
+	}
+}
+
 #[derive(Properties, PartialEq)]
 pub struct ChallengeModalProps {
 	pub challenge: ChallengePerspective,
@@ -224,6 +265,11 @@ pub fn view_challenge_modal(props: &ChallengeModalProps) -> Html {
 					common={props.common.to_owned()}
 				/>
 				<br/>
+				<ChallengeOdds
+					challenge={props.challenge.to_owned()}
+					common={props.common.to_owned()}
+				/>
+				<br/>
 				<CompletionsView
 					completions={props.challenge.completion_tracker.completions.to_vec()}
 					common={props.common.to_owned()}
@@ -12,6 +12,10 @@ use crate::frontend::showdown::common::RollHistory;
 use crate::frontend::showdown::common::RollTotal;
 use crate::frontend::stack::CardSpecView;
 use crate::frontend::stack::ExtraSpecProps;
+use crate::slay::choices::Choice;
+use crate::slay::i18n::keys::MessageKey;
+use crate::slay::i18n::Arg;
+use crate::slay::i18n::Message;
 use crate::slay::showdown::roll_state::RollPerspective;
 use crate::slay::showdown::roll_state::RollReason;
 use crate::slay::specs::cards::card_type::SlayCardSpec;
@@ -50,26 +54,19 @@ pub struct RollModalProps {
 
 #[function_component(RollDescription)]
 pub fn view_roll_context(props: &RollModalProps) -> Html {
+	let localizer = props.common.localizer();
+	let roller = || vec![Arg::PlayerName(props.roll.roller_index)];
 	let text = match &props.roll.reason {
 		RollReason::UseHeroAbility(spec) => html! {
 			<>
 				<div class={classes!("row")}>
-				{
-					format!(
-						"{} is rolling for ",
-						props.roll.roller_name(&props.common.statics).to_owned()
-					)
-				}
+				{ localizer.render(&Message::new(MessageKey::RollDescriptionHero, roller())) }
 					<CardSpecView
 						spec={SlayCardSpec::HeroCard(*spec)}
 						common={props.common.to_owned()}
 						extra_specs={ExtraSpecProps::default()}
 					/>
-					{
-						format!(
-							"'s ability'",
-						)
-					}
+					{ localizer.text(MessageKey::RollDescriptionHeroAfter) }
 				</div>
 				<br/>
 				{
@@ -87,12 +84,7 @@ pub fn view_roll_context(props: &RollModalProps) -> Html {
 		RollReason::AttackMonster(spec) => html! {
 			<>
 				<div class={classes!("row")}>
-					{
-						format!(
-							"{} is attacking ",
-							props.roll.roller_name(&props.common.statics).to_owned()
-						)
-					}
+					{ localizer.render(&Message::new(MessageKey::RollDescriptionMonster, roller())) }
 					<CardSpecView
 						spec={SlayCardSpec::MonsterCard(*spec)}
 						common={props.common.to_owned()}
@@ -137,6 +129,49 @@ pub fn view_roll_context(props: &RollModalProps) -> Html {
 	}
 }
 
+// What the roll currently results in, and what each of the viewer's modifiers would change.
+#[function_component(RollOdds)]
+pub fn view_roll_odds(props: &RollModalProps) -> Html {
+	let localizer = props.common.localizer();
+	let modifications = props
+		.roll
+		.choices(props.common.get_choices())
+		.into_iter()
+		.filter_map(|choice| match choice.choice {
+			Choice::Modify(_, kinds, amount) => Some(html! {
+				<div>
+					{
+						localizer.render(&Message::new(
+							MessageKey::RollOddsModification,
+							vec![
+								Arg::Card(SlayCardSpec::ModifierCard(kinds)),
+								Arg::Signed(amount),
+								Arg::Number(props.roll.calculate_roll_total() + amount),
+								props.roll.outlook_with(amount).label_key().into(),
+							],
+						))
+					}
+				</div>
+			}),
+			_ => None,
+		});
+	html! {
+		<div>
+			{
+				localizer.render(&Message::new(
+					MessageKey::RollOddsSummary,
+					vec![
+						props.roll.win_condition.label_message().into(),
+						Arg::Number(props.roll.calculate_roll_total()),
+						props.roll.outlook().label_key().into(),
+					],
+				))
+			}
+			{ for modifications }
+		</div>
+	}
+}
+
 #[function_component(RollTimer)]
 fn view_roll_timer(props: &SimplerRollModalProps) -> Html {
 	html! {
@@ -196,6 +231,11 @@ pub fn view_roll_modal(props: &RollModalProps) -> Html {
 					common={props.common.to_owned()}
 				/>
 				<br/>
+				<RollOdds
+					roll={props.roll.to_owned()}
+					common={props.common.to_owned()}
+				/>
+				<br/>
 				<CompletionsView
 					completions={props.roll.completion_tracker.completions.to_vec()}
 					common={props.common.to_owned()}
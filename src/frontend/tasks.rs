@@ -0,0 +1,32 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::slay::state::game::GameStaticInformation;
+use crate::slay::tasks::player_tasks::TasksPerspective;
+
+#[derive(Properties, PartialEq)]
+pub struct TasksProps {
+	pub tasks: Vec<TasksPerspective>,
+	pub statics: Rc<GameStaticInformation>,
+}
+
+#[function_component(TasksView)]
+pub fn view_tasks(props: &TasksProps) -> Html {
+	let players = props.tasks.iter().enumerate().map(|(player_index, tasks)| {
+		html! {
+			<div>
+				<b>{ props.statics.player_name(player_index) }</b>
+				<ol>
+					{ for tasks.labels.iter().map(|label| html! { <li>{ label }</li> }) }
+				</ol>
+				<code>{ &tasks.params }</code>
+			</div>
+		}
+	});
+	html! {
+		<div>
+			{ for players }
+		</div>
+	}
+}
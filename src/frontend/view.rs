@@ -1,27 +1,121 @@
 use std::rc::Rc;
 
+use gloo_timers::callback::Timeout;
+use web_sys::HtmlTextAreaElement;
 use yew::prelude::*;
 
+use crate::common::protocol::ClientMessage;
+use crate::frontend::app::AppAction;
 use crate::frontend::app::AppState;
+use crate::frontend::app::ViewMode;
+use crate::frontend::app::MAX_HOT_SEAT_HUMANS;
 use crate::frontend::game::GamePerspectiveView;
 use crate::frontend::notifications::Notifications;
+use crate::frontend::pass_device::PassDeviceView;
+use crate::frontend::replay::ReplayView;
+use crate::frontend::tasks::TasksView;
+use crate::slay::i18n::keys::MessageKey;
+use crate::slay::i18n::Arg;
+use crate::slay::i18n::Locale;
+use crate::slay::i18n::Localizer;
+use crate::slay::i18n::Message;
+use crate::slay::replay::GameRecord;
+use crate::slay::replay::Replay;
 use crate::slay::specs::cards::card_type::SlayCardSpec;
 use crate::slay::specs::hero::HeroAbilityType;
 
 #[function_component(App)]
 fn app() -> Html {
-	let current_game: UseStateHandle<AppState> = use_state(AppState::new);
-	let _player_index = use_state(|| 0usize);
+	let current_game = use_reducer(AppState::new);
+	let replay: UseStateHandle<Option<Rc<Replay>>> = use_state(|| None);
+	let replay_error = use_state(|| None::<String>);
+	// A game record as json, to be watched.
+	let record_json = use_state(String::new);
+	// Kept outside of the game state so that it survives restarts.
+	let locale = use_state(Locale::default);
 
 	let restart = {
 		let current_game = current_game.clone();
-		move |_| current_game.set(AppState::new())
+		move |_| current_game.dispatch(AppAction::Restart(current_game.humans.len()))
+	};
+
+	let change_humans = {
+		let current_game = current_game.clone();
+		move |_| {
+			let number_of_humans = current_game.humans.len() % MAX_HOT_SEAT_HUMANS + 1;
+			current_game.dispatch(AppAction::Restart(number_of_humans))
+		}
+	};
+
+	let change_view_mode = {
+		let current_game = current_game.clone();
+		move |_| current_game.dispatch(AppAction::NextViewMode)
+	};
+
+	let watch_replay = {
+		let current_game = current_game.clone();
+		let replay = replay.clone();
+		let replay_error = replay_error.clone();
+		move |_| {
+			if let Some(record) = current_game.record.as_ref() {
+				match Replay::new(record.to_owned()) {
+					Ok(loaded) => replay.set(Some(Rc::new(loaded))),
+					Err(error) => replay_error.set(Some(error.to_string())),
+				}
+			}
+		}
+	};
+
+	let show_record = {
+		let current_game = current_game.clone();
+		let record_json = record_json.clone();
+		let replay_error = replay_error.clone();
+		move |_| {
+			if let Some(record) = current_game.record.as_ref() {
+				match record.to_json() {
+					Ok(json) => record_json.set(json),
+					Err(error) => replay_error.set(Some(error.to_string())),
+				}
+			}
+		}
+	};
+
+	let edit_record = {
+		let record_json = record_json.clone();
+		move |event: InputEvent| {
+			record_json.set(event.target_unchecked_into::<HtmlTextAreaElement>().value())
+		}
+	};
+
+	let load_record = {
+		let record_json = record_json.clone();
+		let replay = replay.clone();
+		let replay_error = replay_error.clone();
+		move |_| match GameRecord::from_json(&record_json).and_then(Replay::new) {
+			Ok(loaded) => replay.set(Some(Rc::new(loaded))),
+			Err(error) => replay_error.set(Some(error.to_string())),
+		}
+	};
+
+	let change_locale = {
+		let locale = locale.clone();
+		move |_| locale.set(locale.next())
+	};
+
+	let close_replay = {
+		let replay = replay.clone();
+		Callback::from(move |_| replay.set(None))
+	};
+
+	let accept_handoff = {
+		let current_game = current_game.clone();
+		Callback::from(move |_| current_game.dispatch(AppAction::AcceptHandoff))
 	};
 
 	let test = {
 		let current_game = current_game.clone();
 		move |_| {
-			current_game.set(AppState::test(&SlayCardSpec::HeroCard(
+			current_game.dispatch(AppAction::Test(SlayCardSpec::HeroCard(
 				HeroAbilityType::PlunderingPuma,
 			)))
 		}
@@ -29,26 +123,162 @@ fn app() -> Html {
 
 	let choose = {
 		let current_game = current_game.clone();
-		Callback::from(move |choice_id| current_game.set(current_game.create_new_state(choice_id)))
+		Callback::from(move |choice_id| {
+			current_game.dispatch(AppAction::Send(ClientMessage::MakeChoice(choice_id)))
+		})
+	};
+
+	let change_bot_speed = {
+		let current_game = current_game.clone();
+		move |_| current_game.dispatch(AppAction::NextBotDelay)
+	};
+
+	let change_auto_pass = {
+		let current_game = current_game.clone();
+		move |_| {
+			let auto_pass = current_game.auto_pass().next();
+			current_game.dispatch(AppAction::Send(ClientMessage::SetAutoPass(auto_pass)))
+		}
 	};
 
+	{
+		// Let the bots take their turns one choice at a time, the timeout is cancelled when dropped.
+		// The move is made from the state when the timeout fires, not the one it was scheduled in.
+		let deps = (current_game.moves, current_game.bot_delay_millis);
+		let current_game = current_game.clone();
+		use_effect_with_deps(
+			move |_| {
+				let delay = current_game.bot_delay_millis;
+				let timeout = current_game.bots_have_choices().then(|| {
+					let dispatcher = current_game.dispatcher();
+					Timeout::new(delay, move || dispatcher.dispatch(AppAction::BotMove))
+				});
+				move || drop(timeout)
+			},
+			deps,
+		);
+	}
+
 	let statics = Rc::new(current_game.get_statics());
+	// Watchers cannot make choices for the seat they are looking from.
+	let choose = match current_game.view_mode {
+		ViewMode::Player => Some(choose),
+		ViewMode::Spectator | ViewMode::Omniscient => None,
+	};
+	let tasks = match current_game.view_mode {
+		ViewMode::Omniscient => html! {
+			<TasksView tasks={current_game.game.to_tasks_perspective()} statics={statics.to_owned()}/>
+		},
+		ViewMode::Player | ViewMode::Spectator => html! {},
+	};
+	// Watchers are not tied to a seat, so there is nothing to hand over.
+	let handoff = current_game
+		.pending_handoff
+		.filter(|_| current_game.view_mode == ViewMode::Player);
+	let table = if let Some(player_index) = handoff {
+		html! {
+			<PassDeviceView
+				player_name={statics.player_name(player_index).to_owned()}
+				accept={accept_handoff}
+				locale={*locale}
+			/>
+		}
+	} else {
+		html! {
+			<>
+				<Notifications
+					notifications={current_game.visible_notifications()}
+					statics={statics.to_owned()}
+					player_index={current_game.my_player_index}
+					locale={*locale}
+				/>
+				<GamePerspectiveView
+					game={Rc::new(current_game.perspective())}
+					statics={statics.to_owned()}
+					choose={choose}
+					locale={*locale}
+				/>
+				{ tasks }
+			</>
+		}
+	};
+	let localizer = Localizer::new(*locale);
+	if let Some(replay) = replay.as_ref() {
+		return html! {
+			<>
+				<h1>{ "Here to Slay! (Replay)" }</h1>
+				<ReplayView replay={replay.to_owned()} close={close_replay} locale={*locale}/>
+			</>
+		};
+	}
 	html! {
 			<>
 					<h1>{ "Here to Slay!" }</h1>
 					<div>
-							<button class={classes!("border-blink")} onclick={restart}>{ "Restart" } </button>
-							<button class={classes!("border-blink")} onclick={test}>{ "Test next" } </button>
-							<Notifications
-								notifications={current_game.notifications.to_owned()}
-								statics={statics.to_owned()}
-								player_index={current_game.my_player_index}
-							/>
-							<GamePerspectiveView
-								game={Rc::new(current_game.perspective())}
-								statics={statics.to_owned()}
-								choose={choose.to_owned()}
-							/>
+							<button class={classes!("border-blink")} onclick={restart}>
+								{ localizer.text(MessageKey::ButtonRestart) }
+							</button>
+							<button class={classes!("border-blink")} onclick={test}>
+								{ localizer.text(MessageKey::ButtonTestNext) }
+							</button>
+							<button class={classes!("border-blink")} onclick={change_humans}>
+								{
+									localizer.render(&Message::new(
+										MessageKey::ButtonHumans,
+										vec![Arg::Number(current_game.humans.len() as i32)],
+									))
+								}
+							</button>
+							<button class={classes!("border-blink")} onclick={change_view_mode}>
+								{
+									localizer.render(&Message::new(
+										MessageKey::ButtonViewMode,
+										vec![current_game.view_mode.label_key().into()],
+									))
+								}
+							</button>
+							<button class={classes!("border-blink")} onclick={change_bot_speed}>
+								{
+									localizer.render(&Message::new(
+										MessageKey::ButtonBotDelay,
+										vec![Arg::Number(current_game.bot_delay_millis as i32)],
+									))
+								}
+							</button>
+							<button class={classes!("border-blink")} onclick={change_auto_pass}>
+								{
+									localizer.render(&Message::new(
+										MessageKey::ButtonAutoPass,
+										vec![current_game.auto_pass().label_key().into()],
+									))
+								}
+							</button>
+							<button class={classes!("border-blink")} onclick={watch_replay}>
+								{ localizer.text(MessageKey::ButtonReplay) }
+							</button>
+							<button class={classes!("border-blink")} onclick={change_locale}>
+								{
+									localizer.render(&Message::new(
+										MessageKey::LanguageButton,
+										vec![Arg::Verbatim(locale.name().to_string())],
+									))
+								}
+							</button>
+							{ for replay_error.iter().map(|error| html! { <span>{ error }</span> }) }
+							<div>
+								<textarea
+									value={(*record_json).to_owned()}
+									placeholder={localizer.text(MessageKey::ReplayRecordPlaceholder)}
+									oninput={edit_record}
+								/>
+								<button class={classes!("border-blink")} onclick={show_record}>
+									{ localizer.text(MessageKey::ReplayShowRecord) }
+								</button>
+								<button class={classes!("border-blink")} onclick={load_record}>
+									{ localizer.text(MessageKey::ReplayLoadRecord) }
+								</button>
+							</div>
+							{ table }
 					</div>
 			</>
 	}
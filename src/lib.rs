@@ -0,0 +1,7 @@
+pub mod backend;
+pub mod common;
+pub mod frontend;
+pub mod slay;
+
+#[macro_use]
+extern crate guard;
@@ -1,10 +1,4 @@
-pub mod backend;
-pub mod common;
-pub mod frontend;
-pub mod slay;
-
-#[macro_use]
-extern crate guard;
+use copying::frontend;
 
 pub fn main() {
 	// There should be a way to tell if an action is not needed, as in, don't roll for something that you can't do
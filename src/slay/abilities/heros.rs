@@ -1,10 +1,11 @@
 use crate::slay::errors::SlayResult;
 use crate::slay::game_context::GameBookKeeping;
 use crate::slay::ids;
+use crate::slay::state::deck::DeckPath;
 use crate::slay::state::game::Game;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::task_params::PlayerParam;
 
 // #[derive(Debug, Clone, PartialEq, Eq, Copy)]
 // pub enum Ability {
@@ -178,12 +179,12 @@ use crate::slay::tasks::task_params::TaskParamName;
 
 #[derive(Debug, Clone)]
 pub struct VictimDraws {
-	pub param_name: TaskParamName,
+	pub param_name: PlayerParam,
 	pub number_to_draw: usize,
 }
 
 impl VictimDraws {
-	pub fn create(param_name: TaskParamName) -> Box<dyn PlayerTask> {
+	pub fn create(param_name: PlayerParam) -> Box<dyn PlayerTask> {
 		Box::new(Self {
 			param_name,
 			number_to_draw: 1,
@@ -201,11 +202,13 @@ impl PlayerTask for VictimDraws {
 		let victim_index = game.players[player_index]
 			.tasks
 			.get_player_value(&self.param_name);
-		if let Some(_victim_index) = victim_index {
+		if let Some(victim_index) = victim_index {
 			game.replentish_for(self.number_to_draw);
-			game.players[player_index]
-				.hand
-				.extend(game.draw.drain(0..self.number_to_draw));
+			game.move_stacks(
+				DeckPath::Draw,
+				DeckPath::Hand(victim_index),
+				0..self.number_to_draw,
+			);
 			Ok(TaskProgressResult::TaskComplete)
 		} else {
 			Ok(TaskProgressResult::NothingDone)
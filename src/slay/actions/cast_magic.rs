@@ -11,6 +11,7 @@ use crate::slay::showdown::roll::ChallengeReason;
 use crate::slay::specs::magic::MagicSpell;
 use crate::slay::state::game::Game;
 use crate::slay::status_effects::effect::PlayerStatusEffect;
+use crate::slay::tasks::core::draw::DrawTask;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::tasks::add_tasks::AddTasks;
 use crate::slay::tasks::tasks::magic::MagicTask;
@@ -27,9 +28,14 @@ pub fn create_cast_magic_task(
 	card_path: CardPath,
 	spell: MagicSpell,
 ) -> Box<dyn PlayerTask> {
-	let cast_magic = Box::new(MagicTask::new(spell)) as Box<dyn PlayerTask>;
+	let mut cast_magic = vec![Box::new(MagicTask::new(spell)) as Box<dyn PlayerTask>];
+	if game.player_has_effect(player_index, PlayerStatusEffect::DrawOnPlayMagic) {
+		cast_magic.push(DrawTask::create(1));
+	}
 	if cannot_be_challenged(game, player_index) {
-		return cast_magic;
+		let mut tasks = vec![card_path.get_discard_task()];
+		tasks.extend(cast_magic);
+		return AddTasks::create(tasks);
 	}
 	AddTasks::create(vec![
 		card_path.get_discard_task(),
@@ -38,7 +44,7 @@ pub fn create_cast_magic_task(
 			RollConsequences {
 				success: RollConsequence {
 					condition: Condition::challenge_denied(),
-					tasks: vec![cast_magic],
+					tasks: cast_magic,
 				},
 				loss: None,
 			},
@@ -14,6 +14,7 @@ use crate::slay::choices::DisplayPath;
 use crate::slay::choices::TasksChoice;
 use crate::slay::deadlines;
 use crate::slay::game_context::GameBookKeeping;
+use crate::slay::i18n::keys::MessageKey;
 use crate::slay::ids;
 use crate::slay::specification::HeroType;
 use crate::slay::specs::cards::card_type::SlayCardSpec;
@@ -22,10 +23,10 @@ use crate::slay::state::deck::DeckPath;
 use crate::slay::state::game::Game;
 use crate::slay::state::player::HeroTypeCounter;
 use crate::slay::tasks::core::pull::PullFromTask;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::player_tasks::PlayerTask;
+use crate::slay::tasks::task_params::ParamScope;
 use crate::slay::tasks::tasks::card_used::CardUsedTask;
 use crate::slay::tasks::tasks::params::ChoosePlayerParameterTask;
-use crate::slay::tasks::tasks::params::ClearParamsTask;
 use crate::slay::tasks::tasks::remove_action_points::RemoveActionPointsTask;
 
 fn create_hand_action_choice(
@@ -89,17 +90,21 @@ fn create_party_action_choice(
 		game.card(card_path).card_type,
 		SlayCardSpec::PartyLeader(HeroType::Thief)
 	) {
+		let mut params = ParamScope::new();
+		let victim = params.player(MessageKey::PromptStealFrom);
+		let mut tasks: Vec<Box<dyn PlayerTask>> = vec![
+			Box::new(RemoveActionPointsTask::new(1)),
+			Box::new(CardUsedTask::new(player_index, card_path.get_card_id())),
+		];
+		tasks.extend(params.chain(vec![
+			ChoosePlayerParameterTask::exclude_self(victim),
+			PullFromTask::create(victim),
+		]));
 		return Some(TasksChoice::new(
 			context.id_generator.generate(),
 			Choice::UseActionPoints(Action::UseLeader(HeroType::Thief)),
 			ChoiceDisplayType::HighlightPath(DisplayPath::CardAt(card_path)),
-			vec![
-				Box::new(RemoveActionPointsTask::new(1)),
-				Box::new(CardUsedTask::new(player_index, card_path.get_card_id())),
-				ChoosePlayerParameterTask::exclude_self(TaskParamName::ShadowClawVictim),
-				PullFromTask::create(TaskParamName::ShadowClawVictim),
-				ClearParamsTask::create(),
-			],
+			tasks,
 		));
 	}
 	None
@@ -125,14 +130,14 @@ pub fn assign_action_choices(context: &mut GameBookKeeping, game: &mut Game) {
 		));
 	}
 	if remaining_action_points >= 2 {
-		for monster_card in game.monsters.tops() {
+		for monster_card in game.monsters.tops(&game.cards) {
 			// move this into attack.rs
 			if let SlayCardSpec::MonsterCard(monster) = monster_card.card_type {
 				// /////////////////////////////////////////////////////////////////////
 				//  Just write some unit tests for this....
 				// /////////////////////////////////////////////////////////////////////
 				let hero_type_counts = &mut HeroTypeCounter::new();
-				game.players[player_index].count_hero_types(hero_type_counts);
+				game.players[player_index].count_hero_types(&game.cards, hero_type_counts);
 				let requirements = &mut monster.create_spec().requirements.to_vec();
 				/*				println!(
 					"Does\n\t\tleader={:?}\n\t\tparty={:?}\nsatisfy requirements\n\t\t{:?}?",
@@ -152,7 +157,6 @@ pub fn assign_action_choices(context: &mut GameBookKeeping, game: &mut Game) {
 				// /////////////////////////////////////////////////////////////////////
 				options.push(attack::create_attack_monster_choice(
 					context,
-					game,
 					player_index,
 					CardPath::TopCardIn(DeckPath::ActiveMonsters, monster_card.id),
 					monster,
@@ -176,7 +180,7 @@ pub fn assign_action_choices(context: &mut GameBookKeeping, game: &mut Game) {
 			context,
 			game,
 			player_index,
-			CardPath::Leader(player_index, game.players[player_index].leader.id),
+			CardPath::Leader(player_index, game.players[player_index].leader),
 		) {
 			options.push(hand_choice);
 		}
@@ -192,6 +196,6 @@ pub fn assign_action_choices(context: &mut GameBookKeeping, game: &mut Game) {
 		choices_type: ChoicesType::SpendActionPoints,
 		options,
 		default_choice: Some(default_choice),
-		timeline: deadlines::get_action_point_choice_deadline(),
+		timeline: deadlines::get_action_point_choice_deadline(context),
 	});
 }
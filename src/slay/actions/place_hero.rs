@@ -22,14 +22,12 @@ use super::cast_magic::cannot_be_challenged;
 use super::roll_for_ability;
 
 pub fn create_place_hero_challenges(
-	context: &mut GameBookKeeping,
 	game: &Game,
 	player_index: ids::PlayerIndex,
 	card_path: CardPath,
 	hero_card: HeroAbilityType,
 ) -> Vec<Box<dyn PlayerTask>> {
 	let roll = roll_for_ability::create_roll_for_ability_task(
-		context,
 		game,
 		player_index,
 		game.card(card_path),
@@ -72,7 +70,6 @@ pub fn create_place_hero_choice(
 		vec![
 			Box::new(RemoveActionPointsTask::new(1)),
 			AddTasks::create(create_place_hero_challenges(
-				context,
 				game,
 				player_index,
 				card_path,
@@ -3,6 +3,7 @@ use crate::slay::choices::Choice;
 use crate::slay::choices::ChoiceDisplayType;
 use crate::slay::choices::TasksChoice;
 use crate::slay::game_context::GameBookKeeping;
+use crate::slay::i18n::keys::MessageKey;
 use crate::slay::ids;
 use crate::slay::notification::Notification;
 use crate::slay::showdown::consequences::Condition;
@@ -16,24 +17,32 @@ use crate::slay::state::game::Game;
 use crate::slay::state::stack::Card;
 use crate::slay::status_effects::effect::PlayerStatusEffect;
 use crate::slay::tasks::player_tasks::PlayerTask;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::task_params::ParamScope;
 use crate::slay::tasks::tasks::add_tasks::AddTasks;
 use crate::slay::tasks::tasks::move_card::MoveCardTask;
 use crate::slay::tasks::tasks::offer_challenges::OfferChallengesTask;
+use crate::slay::tasks::tasks::params::ChooseCardFromPlayerParameterTask;
 use crate::slay::tasks::tasks::params::ChoosePlayerParameterTask;
-use crate::slay::tasks::tasks::params::ClearParamsTask;
+use crate::slay::tasks::tasks::place_item::PlaceItem;
 use crate::slay::tasks::tasks::remove_action_points::RemoveActionPointsTask;
 
 use super::cast_magic::cannot_be_challenged;
 
-pub fn create_place_item_task(players_with_stacks: Vec<ids::PlayerIndex>) -> Box<dyn PlayerTask> {
-	Box::new(AddTasks {
-		tasks: vec![
-			ChoosePlayerParameterTask::one_of(TaskParamName::PlayerToGiveItem, players_with_stacks),
-			// TODO
-			ClearParamsTask::create(),
-		],
-	})
+pub fn create_place_item_task(
+	item_id: ids::CardId,
+	players_with_stacks: Vec<ids::PlayerIndex>,
+	then: Vec<Box<dyn PlayerTask>>,
+) -> Box<dyn PlayerTask> {
+	let mut params = ParamScope::new();
+	let recipient = params.player(MessageKey::PromptPlayerToGiveItem);
+	let hero = params.card(MessageKey::PromptStackToPlaceItemOn);
+	let mut tasks = params.chain(vec![
+		ChoosePlayerParameterTask::one_of(recipient, players_with_stacks),
+		ChooseCardFromPlayerParameterTask::from_party(recipient, hero),
+		PlaceItem::create(item_id, recipient, hero),
+	]);
+	tasks.extend(then);
+	Box::new(AddTasks { tasks })
 }
 
 fn items_cannot_be_challenged(game: &Game, player_index: ids::PlayerIndex) -> bool {
@@ -46,6 +55,17 @@ pub fn create_place_item_challenge_offer(
 	game: &Game,
 	player_index: ids::PlayerIndex,
 	card: &Card,
+) -> Option<Box<dyn PlayerTask>> {
+	create_place_item_challenge_offer_then(context, game, player_index, card, Vec::new())
+}
+
+// The tasks in then run once the item has been placed, or discarded after a sustained challenge.
+pub fn create_place_item_challenge_offer_then(
+	context: &mut GameBookKeeping,
+	game: &Game,
+	player_index: ids::PlayerIndex,
+	card: &Card,
+	then: Vec<Box<dyn PlayerTask>>,
 ) -> Option<Box<dyn PlayerTask>> {
 	let players_with_stacks = game.players_with_stacks();
 	if players_with_stacks.is_empty() {
@@ -53,7 +73,7 @@ pub fn create_place_item_challenge_offer(
 		context.emit(&Notification::NoWhereToPlaceItem);
 		return None;
 	}
-	let place_item = create_place_item_task(players_with_stacks);
+	let place_item = create_place_item_task(card.id, players_with_stacks, then.clone());
 	if items_cannot_be_challenged(game, player_index) {
 		return Some(place_item);
 	}
@@ -66,14 +86,17 @@ pub fn create_place_item_challenge_offer(
 			},
 			loss: Some(RollConsequence {
 				condition: Condition::challenge_sustained(),
-				tasks: vec![Box::new(MoveCardTask {
+				tasks: [Box::new(MoveCardTask {
 					source: DeckPath::Hand(player_index),
 					destination: DeckPath::Discard,
 					card_id: card.id,
-				}) as Box<dyn PlayerTask>],
+				}) as Box<dyn PlayerTask>]
+				.into_iter()
+				.chain(then)
+				.collect(),
 			}),
 		},
-		ChallengeReason::PlaceHeroCard(card.card_type),
+		ChallengeReason::PlaceItem(card.card_type),
 	))) as Box<dyn PlayerTask>)
 }
 
@@ -5,11 +5,11 @@ use crate::slay::choices::TasksChoice;
 use crate::slay::game_context::GameBookKeeping;
 use crate::slay::ids;
 use crate::slay::showdown::roll_state::RollReason;
-use crate::slay::showdown::roll_state::RollState;
 use crate::slay::specs::hero::HeroAbilityType;
 use crate::slay::state::game::Game;
 use crate::slay::state::stack::Card;
 use crate::slay::status_effects::effect::HeroStatusEffect;
+use crate::slay::tasks::core::discard::Discard;
 use crate::slay::tasks::core::draw::DrawTask;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::tasks::add_tasks::AddTasks;
@@ -25,8 +25,8 @@ pub struct RollForAbilityEffects {
 
 impl RollForAbilityEffects {
 	pub fn push_success_tasks(&self, tasks: &mut Vec<Box<dyn PlayerTask>>) {
-		if self.num_draw > 0 {
-			tasks.push(DrawTask::create(self.num_draw as usize));
+		if self.num_discard > 0 {
+			tasks.push(Discard::create(self.num_discard));
 		}
 	}
 	pub fn create_loss_tasks(&self) -> Option<Vec<Box<dyn PlayerTask>>> {
@@ -39,7 +39,6 @@ impl RollForAbilityEffects {
 }
 
 pub fn create_roll_for_ability_task(
-	context: &mut GameBookKeeping,
 	game: &Game,
 	player_index: ids::PlayerIndex,
 	card: &Card,
@@ -53,7 +52,7 @@ pub fn create_roll_for_ability_task(
 		)
 	);
 	if stack
-		.hero_effects()
+		.hero_effects(&game.cards)
 		.any(|effect| matches!(effect.effect.effect, HeroStatusEffect::RemoveAbility))
 	{
 		return None;
@@ -61,20 +60,18 @@ pub fn create_roll_for_ability_task(
 	Some(Box::new(AddTasks {
 		tasks: vec![
 			Box::new(CardUsedTask::new(player_index, card.id)),
-			Box::new(DoRollTask::new(RollState::create(
-				context,
-				game,
+			DoRollTask::create(
 				player_index,
 				hero_card.to_consequences(&RollForAbilityEffects {
 					num_draw: stack
-						.hero_effects()
+						.hero_effects(&game.cards)
 						.map(|effect| match effect.effect.effect {
 							HeroStatusEffect::DrawOnUnsuccessfulRollForAbility(num) => num,
 							_ => 0,
 						})
 						.sum::<u32>(),
 					num_discard: stack
-						.hero_effects()
+						.hero_effects(&game.cards)
 						.map(|effect| match effect.effect.effect {
 							HeroStatusEffect::DiscardOnSuccessfulRollForAbility(num) => num,
 							_ => 0,
@@ -82,7 +79,7 @@ pub fn create_roll_for_ability_task(
 						.sum::<u32>(),
 				}),
 				RollReason::UseHeroAbility(hero_card),
-			))) as Box<dyn PlayerTask>,
+			),
 		],
 	}))
 }
@@ -94,14 +91,12 @@ pub fn create_roll_for_ability_choice(
 	card_path: CardPath,
 	hero_card: HeroAbilityType,
 ) -> Option<TasksChoice> {
-	create_roll_for_ability_task(context, game, player_index, game.card(card_path), hero_card).map(
-		|roll| {
-			TasksChoice::new(
-				context.id_generator.generate(),
-				Choice::UseActionPoints(Action::RollForAbility(hero_card)),
-				card_path.display().to_highlight(),
-				vec![Box::new(RemoveActionPointsTask::new(1)), roll],
-			)
-		},
-	)
+	create_roll_for_ability_task(game, player_index, game.card(card_path), hero_card).map(|roll| {
+		TasksChoice::new(
+			context.id_generator.generate(),
+			Choice::UseActionPoints(Action::RollForAbility(hero_card)),
+			card_path.display().to_highlight(),
+			vec![Box::new(RemoveActionPointsTask::new(1)), roll],
+		)
+	})
 }
@@ -1,8 +1,15 @@
+use serde::Deserialize;
+use serde::Serialize;
 use std::fmt::Debug;
 use std::io::BufWriter;
 use std::io::Write;
 
 use crate::slay::deadlines::Timeline;
+use crate::slay::i18n::keys::MessageKey;
+use crate::slay::i18n::Arg;
+use crate::slay::i18n::Locale;
+use crate::slay::i18n::Localizer;
+use crate::slay::i18n::Message;
 use crate::slay::ids;
 use crate::slay::showdown::completion::Completion;
 use crate::slay::showdown::roll_modification::ModificationPath;
@@ -21,16 +28,17 @@ use super::specs::magic::MagicSpell;
 use super::specs::modifier::ModifierKinds;
 use super::specs::monster::Monster;
 use super::state::game::GameStaticInformation;
-use super::tasks::task_params::TaskParamName;
+use super::tasks::task_params::CardParam;
+use super::tasks::task_params::PlayerParam;
 use super::tasks::tasks::search_discard::SearchDiscardFilters;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ChoicesType {
 	ChooseCardToGive(ids::PlayerIndex),
 	SpendActionPoints,
 	SearchDiscard(SearchDiscardFilters),
-	ChoosePlayerParam(TaskParamName),
-	ChooseCardParam(TaskParamName),
+	ChoosePlayerParam(PlayerParam),
+	ChooseCardParam(CardParam),
 	OfferChallenges,
 	PlayImmediately(SlayCardSpec), // Does it really need an argument?
 	PlayOneOfImmediately,
@@ -56,14 +64,14 @@ pub struct Choices {
 	pub timeline: Timeline,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct ChoicesPerspective {
 	pub choices_type: ChoicesType,
 	pub timeline: Timeline,
 	pub options: Vec<ChoicePerspective>,
 }
 
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Action {
 	Forfeit,
 	PlaceHeroInParty(HeroAbilityType),
@@ -83,7 +91,7 @@ pub enum Action {
 // #[derive(Clone, PartialEq, Debug)]
 // pub enum CardParameter {}
 
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Choice {
 	UseActionPoints(Action),
 	SetCompletion(Completion),
@@ -92,8 +100,8 @@ pub enum Choice {
 	ChooseDiscardedCard(SlayCardSpec),
 	ReturnItem(AnotherItemType, HeroAbilityType),
 
-	SetPlayerParam(TaskParamName, ids::PlayerIndex),
-	SetCardParameter(TaskParamName, SlayCardSpec),
+	SetPlayerParam(PlayerParam, ids::PlayerIndex),
+	SetCardParameter(CardParam, SlayCardSpec),
 
 	PlayImmediately(SlayCardSpec),
 	DoNotPlayImmediately,
@@ -120,12 +128,14 @@ pub struct TasksChoice {
 	prepend: bool,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct ChoicePerspective {
 	pub is_default: bool,
 	pub choice_id: ids::ChoiceId,
 	pub choice: Choice,
 	pub display: ChoiceDisplayType,
+	// Filled in for choices that start a roll, see showdown::odds.
+	pub success_probability: Option<f32>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -155,174 +165,185 @@ pub struct ChoiceDisplay {
 // }
 
 impl Choice {
-	pub fn label(&self) -> String {
+	pub fn label_message(&self) -> Message {
 		match self {
 			Choice::UseActionPoints(action) => match action {
-				Action::Forfeit => String::from("Do nothing this round."),
-				Action::PlaceHeroInParty(hero_card) => format!("Place {} in your party", hero_card.label()),
-				Action::CastMagic(magic_card) => format!("Play {}", magic_card.label()),
-				Action::PlaceItem(item_card) => format!("Place {} on some hero card.", item_card.label()),
-				Action::Draw => String::from("Draw a card."),
-				Action::ReplaceHand => String::from("Use 3 action points to replace your entire hand."),
-				Action::AttackMonster(monster) => format!("Attack {}", monster.label()),
-				Action::UseLeader(_) => String::from("Use Shadow Claw to pull from another player's hand."),
-				Action::RollForAbility(hero_card) => format!("Roll for {}", hero_card.label()),
+				Action::Forfeit => MessageKey::ActionForfeit.into(),
+				Action::PlaceHeroInParty(hero_card) => Message::new(
+					MessageKey::ActionPlaceHero,
+					vec![Arg::Card(SlayCardSpec::HeroCard(*hero_card))],
+				),
+				Action::CastMagic(magic_card) => Message::new(
+					MessageKey::ActionCastMagic,
+					vec![Arg::Card(SlayCardSpec::MagicCard(*magic_card))],
+				),
+				Action::PlaceItem(item_card) => Message::new(
+					MessageKey::ActionPlaceItem,
+					vec![Arg::Card(SlayCardSpec::Item(*item_card))],
+				),
+				Action::Draw => MessageKey::ActionDraw.into(),
+				Action::ReplaceHand => MessageKey::ActionReplaceHand.into(),
+				Action::AttackMonster(monster) => Message::new(
+					MessageKey::ActionAttackMonster,
+					vec![Arg::Card(SlayCardSpec::MonsterCard(*monster))],
+				),
+				Action::UseLeader(_) => MessageKey::ActionUseLeader.into(),
+				Action::RollForAbility(hero_card) => Message::new(
+					MessageKey::ActionRollForAbility,
+					vec![Arg::Card(SlayCardSpec::HeroCard(*hero_card))],
+				),
 			},
 			Choice::SetCompletion(completion) => match completion {
 				Completion::Thinking => todo!(),
-				Completion::DoneUntilModification => {
-					String::from("Do not modify this roll, unless someone else does.")
-				}
-				Completion::AllDone => String::from("Do not modify this roll, even if someone else does."),
+				Completion::DoneUntilModification => MessageKey::CompletionDoneUntilModification.into(),
+				Completion::AllDone => MessageKey::CompletionAllDone.into(),
 			},
-			Choice::Modify(path, kind, amount) => {
-				format!("Use {:?} to modify {:?} by {}", kind, path, amount,)
-			}
-			Choice::Challenge => String::from("Challenge!"),
-			Choice::SetPlayerParam(parameter, player_index) => {
-				format!("Set {:?} to player {}", parameter, player_index + 1)
+			Choice::Modify(path, kind, amount) => Message::new(
+				MessageKey::ChoiceModify,
+				vec![
+					Arg::Card(SlayCardSpec::ModifierCard(*kind)),
+					path.label_key().into(),
+					Arg::Signed(*amount),
+				],
+			),
+			Choice::Challenge => MessageKey::ChoiceChallenge.into(),
+			Choice::SetPlayerParam(parameter, player_index) => Message::new(
+				MessageKey::ChoiceSetPlayerParam,
+				vec![parameter.prompt_key().into(), Arg::Player(*player_index)],
+			),
+			Choice::SetCardParameter(parameter, card) => Message::new(
+				MessageKey::ChoiceSetCardParam,
+				vec![parameter.prompt_key().into(), Arg::Card(*card)],
+			),
+			Choice::ChooseDiscardedCard(spec) => {
+				Message::new(MessageKey::ChoiceDiscardedCard, vec![Arg::Card(*spec)])
 			}
-			Choice::SetCardParameter(parameter, card) => {
-				format!("Set {:?} to {}.", parameter, card.label())
+			Choice::ReturnItem(item, hero) => Message::new(
+				MessageKey::ChoiceReturnItem,
+				vec![
+					Arg::Card(SlayCardSpec::Item(*item)),
+					Arg::Card(SlayCardSpec::HeroCard(*hero)),
+				],
+			),
+			Choice::PlayImmediately(card) => {
+				Message::new(MessageKey::ChoicePlayImmediately, vec![Arg::Card(*card)])
 			}
-			Choice::ChooseDiscardedCard(spec) => spec.label().to_owned(),
-			Choice::ReturnItem(item, hero) => format!("Return {} from {}", item.label(), hero.label(),),
-			Choice::PlayImmediately(card) => format!("Play {} immediately", card.label(),),
-			Choice::DoNotPlayImmediately => String::from("Do not play immediately"),
-			Choice::Discard(card) => format!("Sacrifice {}", card.label(),),
-			Choice::Sacrifice(hero_card) => format!("Sacrifice {}", hero_card.label(),),
-			Choice::ChooseCardToGive(card, player_index) => {
-				format!("Give {} to player {}.", card.label(), player_index,)
+			Choice::DoNotPlayImmediately => MessageKey::ChoiceDoNotPlayImmediately.into(),
+			Choice::Discard(card) => Message::new(MessageKey::ChoiceDiscard, vec![Arg::Card(*card)]),
+			Choice::Sacrifice(hero_card) => Message::new(
+				MessageKey::ChoiceSacrifice,
+				vec![Arg::Card(SlayCardSpec::HeroCard(*hero_card))],
+			),
+			Choice::ChooseCardToGive(card, player_index) => Message::new(
+				MessageKey::ChoiceGiveCard,
+				vec![Arg::Card(*card), Arg::PlayerName(*player_index)],
+			),
+			Choice::QuitAction => MessageKey::ChoiceQuit.into(),
+			Choice::ContinueDiscardingAndDestroying => {
+				MessageKey::ChoiceContinueDiscardingAndDestroying.into()
 			}
-			Choice::QuitAction => String::from("No"),
-			Choice::ContinueDiscardingAndDestroying => "Discard and destroy again".to_string(),
 			// Are we sure it was just pulled?q
-			Choice::RevealChallengeAndDestroy => {
-				"Reveal that you pulled a challenge card, so you can destroy a hero card.".to_string()
-			}
-			Choice::PlaceHeroImmediately(hero_card) => {
-				format!("Place {} in you party immediately.", hero_card.label(),)
+			Choice::RevealChallengeAndDestroy => MessageKey::ChoiceRevealChallengeAndDestroy.into(),
+			Choice::PlaceHeroImmediately(hero_card) => Message::new(
+				MessageKey::ChoicePlaceHeroImmediately,
+				vec![Arg::Card(SlayCardSpec::HeroCard(*hero_card))],
+			),
+			Choice::BullseyeKeep(spec) => {
+				Message::new(MessageKey::ChoiceBullseyeKeep, vec![Arg::Card(*spec)])
 			}
-			Choice::BullseyeKeep(spec) => format!("Place {} in your hand.", spec.label(),),
-			Choice::BullseyeReorder => String::from("Change the order."),
-			Choice::BullseyeDoNotReorder => String::from("Keep the current order."),
+			Choice::BullseyeReorder => MessageKey::ChoiceBullseyeReorder.into(),
+			Choice::BullseyeDoNotReorder => MessageKey::ChoiceBullseyeDoNotReorder.into(),
 		}
 	}
-	pub fn get_notification(
-		&self,
-		game: &GameStaticInformation,
-		player_index: ids::PlayerIndex,
-	) -> String {
-		// Player {} chose to
+
+	pub fn label(&self) -> String {
+		self.label_message().to_english()
+	}
+
+	pub fn notification_message(&self, player_index: ids::PlayerIndex) -> Message {
+		let player = Arg::PlayerName(player_index);
+		let with_card = |key: MessageKey, spec: SlayCardSpec| {
+			Message::new(key, vec![Arg::PlayerName(player_index), Arg::Card(spec)])
+		};
 		match self {
 			Choice::UseActionPoints(action) => match action {
-				Action::Forfeit => "To do nothing, lol.".to_string(),
-				Action::PlaceHeroInParty(hero_card) => format!(
-					"Player {} chose to place {} in their party.",
-					game.player_name(player_index),
-					hero_card.label(),
-				),
-				Action::CastMagic(magic_card) => format!(
-					"{} chose to use the magic card {:?}",
-					game.player_name(player_index),
-					magic_card,
-				),
-				Action::PlaceItem(item) => format!(
-					"{} chose to place the item {:?}",
-					game.player_name(player_index),
-					item,
+				Action::Forfeit => Message::new(MessageKey::ChoseForfeit, vec![player]),
+				Action::PlaceHeroInParty(hero_card) => with_card(
+					MessageKey::ChosePlaceHero,
+					SlayCardSpec::HeroCard(*hero_card),
 				),
-				Action::Draw => format!("{} chose to draw a card", game.player_name(player_index),),
-				Action::ReplaceHand => format!(
-					"{} chose to replace their hand with 5 new cards",
-					game.player_name(player_index),
+				Action::CastMagic(magic_card) => with_card(
+					MessageKey::ChoseCastMagic,
+					SlayCardSpec::MagicCard(*magic_card),
 				),
-				Action::AttackMonster(monster) => format!(
-					"{} chose to attack {}",
-					game.player_name(player_index),
-					monster.label(),
+				Action::PlaceItem(item) => with_card(MessageKey::ChosePlaceItem, SlayCardSpec::Item(*item)),
+				Action::Draw => Message::new(MessageKey::ChoseDraw, vec![player]),
+				Action::ReplaceHand => Message::new(MessageKey::ChoseReplaceHand, vec![player]),
+				Action::AttackMonster(monster) => with_card(
+					MessageKey::ChoseAttackMonster,
+					SlayCardSpec::MonsterCard(*monster),
 				),
-				Action::UseLeader(_) => format!(
-					"{} chose to user their thiefy party leader's ability (and pull a card from somebody's hand).",
-					game.player_name(player_index),
-				),
-				Action::RollForAbility(hero_card) => format!(
-					"{} chose to roll for {}'s ability.",
-					game.player_name(player_index),
-					hero_card.label(),
+				Action::UseLeader(_) => Message::new(MessageKey::ChoseUseLeader, vec![player]),
+				Action::RollForAbility(hero_card) => with_card(
+					MessageKey::ChoseRollForAbility,
+					SlayCardSpec::HeroCard(*hero_card),
 				),
 			},
-			Choice::SetCompletion(persist) => format!(
-				"{}'s completion is {:?}.",
-				game.player_name(player_index),
-				persist,
+			Choice::SetCompletion(persist) => Message::new(
+				match persist {
+					Completion::Thinking => MessageKey::ChoseThinking,
+					Completion::DoneUntilModification => MessageKey::ChoseDoneUntilModification,
+					Completion::AllDone => MessageKey::ChoseAllDone,
+				},
+				vec![player],
 			),
-			Choice::Modify(_, _, _) => format!(
-				"{} chose to modify something by something.",
-				game.player_name(player_index),
+			Choice::Modify(path, _, amount) => Message::new(
+				MessageKey::ChoseModify,
+				vec![player, path.label_key().into(), Arg::Signed(*amount)],
 			),
-			Choice::Challenge => format!("{} chose to challenge!", game.player_name(player_index),),
-			Choice::SetPlayerParam(_, _) => {
-				format!("{} chose a player.", game.player_name(player_index),)
+			Choice::Challenge => Message::new(MessageKey::ChoseChallenge, vec![player]),
+			Choice::SetPlayerParam(_, _) => Message::new(MessageKey::ChosePlayer, vec![player]),
+			Choice::SetCardParameter(_, _) => Message::new(MessageKey::ChoseCard, vec![player]),
+			Choice::ChooseDiscardedCard(_) => Message::new(MessageKey::ChoseDiscardedCard, vec![player]),
+			Choice::ReturnItem(_, _) => Message::new(MessageKey::ChoseReturnItem, vec![player]),
+			Choice::PlayImmediately(card) => with_card(MessageKey::ChosePlayImmediately, *card),
+			Choice::DoNotPlayImmediately => {
+				Message::new(MessageKey::ChoseDoNotPlayImmediately, vec![player])
 			}
-			Choice::SetCardParameter(_, _) => format!("{} chose a card", game.player_name(player_index),),
-			Choice::ChooseDiscardedCard(_) => format!(
-				"{} chose a card from the discard pile",
-				game.player_name(player_index),
-			),
-			Choice::ReturnItem(_, _) => format!(
-				"{} chose to return an item card.",
-				game.player_name(player_index),
-			),
-			Choice::PlayImmediately(card) => format!(
-				"{} decided whether to play {} immediately.",
-				game.player_name(player_index),
-				card.label(),
+			Choice::Discard(_) => Message::new(MessageKey::ChoseDiscard, vec![player]),
+			Choice::Sacrifice(_) => Message::new(MessageKey::ChoseSacrifice, vec![player]),
+			Choice::ChooseCardToGive(_, recipient) => Message::new(
+				MessageKey::ChoseGiveCard,
+				vec![player, Arg::PlayerName(*recipient)],
 			),
-			Choice::DoNotPlayImmediately => format!(
-				"{} does not want to play immediately",
-				game.player_name(player_index),
+			Choice::QuitAction => Message::new(MessageKey::ChoseQuit, vec![player]),
+			Choice::ContinueDiscardingAndDestroying => Message::new(
+				MessageKey::ChoseContinueDiscardingAndDestroying,
+				vec![player],
 			),
-			Choice::Discard(_) => format!(
-				"{} chose to discard a certain card.",
-				game.player_name(player_index),
-			),
-			Choice::Sacrifice(_) => format!(
-				"{} chose to sacrifice a certain something.",
-				game.player_name(player_index),
-			),
-			Choice::ChooseCardToGive(_, recipient) => format!(
-				"{} gave {} a secret card",
-				game.player_name(player_index),
-				game.player_name(*recipient),
-			),
-			Choice::QuitAction => format!("{} chose to stop.", game.player_name(player_index),),
-			Choice::ContinueDiscardingAndDestroying => format!(
-				"{} is going to continue discarding and destroying.",
-				game.player_name(player_index),
-			),
-			Choice::RevealChallengeAndDestroy => format!(
-				"{} drew a challenge card, and will now destroy a hero card.",
-				game.player_name(player_index)
-			),
-			Choice::PlaceHeroImmediately(hero_card) => format!(
-				"{} placed {} in their party immediately",
-				game.player_name(player_index),
-				hero_card.label(),
-			),
-			Choice::BullseyeKeep(_) => {
-				format!("{} kept one of the cards.", game.player_name(player_index),)
+			Choice::RevealChallengeAndDestroy => {
+				Message::new(MessageKey::ChoseRevealChallengeAndDestroy, vec![player])
 			}
-			Choice::BullseyeReorder => format!(
-				"{} changed the order of the next two cards.",
-				game.player_name(player_index),
-			),
-			Choice::BullseyeDoNotReorder => format!(
-				"{} did not change the order of the next two cards.",
-				game.player_name(player_index),
+			Choice::PlaceHeroImmediately(hero_card) => with_card(
+				MessageKey::ChosePlaceHeroImmediately,
+				SlayCardSpec::HeroCard(*hero_card),
 			),
+			Choice::BullseyeKeep(_) => Message::new(MessageKey::ChoseBullseyeKeep, vec![player]),
+			Choice::BullseyeReorder => Message::new(MessageKey::ChoseBullseyeReorder, vec![player]),
+			Choice::BullseyeDoNotReorder => {
+				Message::new(MessageKey::ChoseBullseyeDoNotReorder, vec![player])
+			}
 		}
 	}
+
+	pub fn get_notification(
+		&self,
+		game: &GameStaticInformation,
+		player_index: ids::PlayerIndex,
+	) -> String {
+		Localizer::for_game(Locale::English, game, None)
+			.render(&self.notification_message(player_index))
+	}
 }
 
 /*
@@ -330,50 +351,47 @@ impl Choice {
 */
 
 impl ChoicesType {
-	pub fn get_instructions(&self) -> String {
+	pub fn instructions_message(&self) -> Message {
 		match self {
-			ChoicesType::SpendActionPoints => {
-				String::from("How would you like to use your action points?")
-			}
-			ChoicesType::SearchDiscard(filters) => {
-				format!("Search the discard pile for {}.", filters.description())
-			}
-			ChoicesType::ModifyChallenge => String::from("Choose whether to modify the challenge."),
-			ChoicesType::OfferChallenges => String::from("Choose whether to challenge."),
-			ChoicesType::PlayImmediately(card) => format!(
-				"You have received {}, would you like to play it immediately?",
-				card.label(),
+			ChoicesType::SpendActionPoints => MessageKey::InstructSpendActionPoints.into(),
+			ChoicesType::SearchDiscard(filters) => Message::new(
+				MessageKey::InstructSearchDiscard,
+				vec![filters.description_key().into()],
 			),
-			ChoicesType::ModifyRoll => String::from("Choose whether to modify the current roll."),
-			ChoicesType::Discard => String::from("Choose a card in your hand to discard."),
-			ChoicesType::ReturnAnItemCard => {
-				String::from("Return an item card to someone's (TODO) hand.")
-			}
-			ChoicesType::ChoosePlayerParam(_) => String::from("Choose a player"),
-			ChoicesType::ChooseCardParam(_) => String::from("Choose a card."),
-			ChoicesType::Sacrifice => String::from("Choose a hero card to sacrifice."),
-			ChoicesType::ChooseCardToGive(recipient) => {
-				format!("Pick a card to give to player {}", recipient)
+			ChoicesType::ModifyChallenge => MessageKey::InstructModifyChallenge.into(),
+			ChoicesType::OfferChallenges => MessageKey::InstructOfferChallenges.into(),
+			ChoicesType::PlayImmediately(card) => {
+				Message::new(MessageKey::InstructPlayImmediately, vec![Arg::Card(*card)])
 			}
-			ChoicesType::ContinueDiscardingAndDestroying(num_remaining) => format!(
-				"Would you like to discard a card so that you can destroy a hero card? (#{})",
-				num_remaining,
+			ChoicesType::ModifyRoll => MessageKey::InstructModifyRoll.into(),
+			ChoicesType::Discard => MessageKey::InstructDiscard.into(),
+			ChoicesType::ReturnAnItemCard => MessageKey::InstructReturnItem.into(),
+			ChoicesType::ChoosePlayerParam(_) => MessageKey::InstructChoosePlayer.into(),
+			ChoicesType::ChooseCardParam(_) => MessageKey::InstructChooseCard.into(),
+			ChoicesType::Sacrifice => MessageKey::InstructSacrifice.into(),
+			ChoicesType::ChooseCardToGive(recipient) => Message::new(
+				MessageKey::InstructGiveCard,
+				vec![Arg::PlayerName(*recipient)],
 			),
-			ChoicesType::RevealAndDestroy => String::from(
-				"Would you like to reveal and destroy?", // TODO: reveal what? (the card you drew? that it was a hero?)
+			ChoicesType::ContinueDiscardingAndDestroying(num_remaining) => Message::new(
+				MessageKey::InstructContinueDiscardingAndDestroying,
+				vec![Arg::Number(*num_remaining as i32)],
 			),
-			ChoicesType::PlaceAHeroCard => {
-				String::from("whice hero would you like to place in your party?")
-			}
-			ChoicesType::PlayOneOfImmediately => String::from("Which card would you like to play?"),
-			ChoicesType::BullseyeKeep => String::from("Which card would you like to keep?"),
-			ChoicesType::BullseyeOrdering(first, second) => format!(
-				"The next two cards are {} and then {}. Would you like to swap the order?",
-				first.label(),
-				second.label(),
+			// TODO: reveal what? (the card you drew? that it was a hero?)
+			ChoicesType::RevealAndDestroy => MessageKey::InstructRevealAndDestroy.into(),
+			ChoicesType::PlaceAHeroCard => MessageKey::InstructPlaceAHero.into(),
+			ChoicesType::PlayOneOfImmediately => MessageKey::InstructPlayOneOfImmediately.into(),
+			ChoicesType::BullseyeKeep => MessageKey::InstructBullseyeKeep.into(),
+			ChoicesType::BullseyeOrdering(first, second) => Message::new(
+				MessageKey::InstructBullseyeOrdering,
+				vec![Arg::Card(*first), Arg::Card(*second)],
 			),
 		}
 	}
+
+	pub fn get_instructions(&self) -> String {
+		self.instructions_message().to_english()
+	}
 }
 
 impl Choices {
@@ -429,7 +447,7 @@ impl Summarizable for Choices {
 }
 
 // TODO: move this
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy, Serialize, Deserialize)]
 pub enum CardPath {
 	TopCardIn(DeckPath, ids::CardId),
 	ModifyingCardIn(DeckPath, ids::CardId, ids::CardId),
@@ -486,7 +504,7 @@ impl CardPath {
 	}
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy, Serialize, Deserialize)]
 pub enum DisplayPath {
 	DeckAt(DeckPath),
 	CardAt(CardPath),
@@ -599,7 +617,7 @@ impl TasksChoice {
 		if self.prepend {
 			game.players[player_index]
 				.tasks
-				.prepend_from(&mut self.tasks);
+				.prepend_answer_from(&mut self.tasks);
 		} else {
 			game.players[player_index].tasks.take_from(&mut self.tasks);
 		}
@@ -612,6 +630,7 @@ impl TasksChoice {
 			choice_id: self.id,
 			choice: self.choice.to_owned(),
 			display: self.display.to_owned(),
+			success_probability: None,
 		}
 	}
 }
@@ -654,6 +673,23 @@ impl ChoicesPerspective {
 }
 
 impl ChoicePerspective {
+	pub fn label_message(&self) -> Message {
+		match self.success_probability {
+			Some(probability) => Message::new(
+				MessageKey::ChoiceWithOdds,
+				vec![
+					self.choice.label_message().into(),
+					Arg::Number((probability * 100.0).round() as i32),
+				],
+			),
+			None => self.choice.label_message(),
+		}
+	}
+
+	pub fn label(&self) -> String {
+		self.label_message().to_english()
+	}
+
 	// fn new(
 	// 	choices: &ChoicesPerspective,
 	// 	choice: &ChoicePerspective,
@@ -708,14 +744,14 @@ impl ChoicePerspective {
 }
 
 // Defines how this choice should be viewed.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum ChoiceDisplayType {
 	// TODO: rename this tp "represented with" ...
 	HighlightPath(DisplayPath),
 	Modify(RollModificationChoiceType),
 	Challenge(SlayCardSpec),
 	SetCompletion(Completion),
-	Text(&'static str),
+	Text(String),
 	Card_(SlayCardSpec),
 	Yes,
 	No,
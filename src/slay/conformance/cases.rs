@@ -0,0 +1,1291 @@
+use crate::slay::choices::Action;
+use crate::slay::choices::Choice;
+use crate::slay::conformance::CardCase;
+use crate::slay::conformance::Expect;
+use crate::slay::conformance::Step;
+use crate::slay::ids;
+use crate::slay::showdown::roll::Roll;
+use crate::slay::showdown::roll_modification::ModificationPath;
+use crate::slay::specification::HeroType;
+use crate::slay::specs::cards::card_type::SlayCardSpec;
+use crate::slay::specs::hero::HeroAbilityType;
+use crate::slay::specs::items::AnotherItemType;
+use crate::slay::specs::items::Item;
+use crate::slay::specs::magic::MagicSpell;
+use crate::slay::specs::modifier::ModifierKinds;
+use crate::slay::specs::monster::Monster;
+use crate::slay::state::builder::GameBuilder;
+use crate::slay::state::builder::StackBuilder;
+use crate::slay::status_effects::effect::PlayerStatusEffect;
+
+/*
+	One or more cases per card, following the card's description.
+	Player 0 plays the card unless the case says otherwise, and all party leaders are thieves
+	so that no leader bonus changes a roll. Where a card says "may" and the engine has no
+	choice for it, the case expects the player to have taken it.
+*/
+
+const SUCCESS: Roll = Roll { die1: 6, die2: 6 };
+const FAILURE: Roll = Roll { die1: 1, die2: 1 };
+
+fn roll(total: u32) -> Roll {
+	Roll::new(total / 2, total - total / 2)
+}
+
+fn hero(hero: HeroAbilityType) -> SlayCardSpec {
+	SlayCardSpec::HeroCard(hero)
+}
+
+fn magic(spell: MagicSpell) -> SlayCardSpec {
+	SlayCardSpec::MagicCard(spell)
+}
+
+fn item(item: Item) -> SlayCardSpec {
+	SlayCardSpec::Item(AnotherItemType::NotMask(item))
+}
+
+fn mask(hero_type: HeroType) -> SlayCardSpec {
+	SlayCardSpec::Item(AnotherItemType::MaskCard(hero_type))
+}
+
+fn modifier(kind: ModifierKinds) -> SlayCardSpec {
+	SlayCardSpec::ModifierCard(kind)
+}
+
+fn filler(number: usize) -> Vec<SlayCardSpec> {
+	vec![hero(HeroAbilityType::NappingNibbles); number]
+}
+
+// Followed by filler, so that there is always something to draw.
+fn draw_pile(top: Vec<SlayCardSpec>) -> Vec<SlayCardSpec> {
+	let mut ret = top;
+	let remaining = 10usize.saturating_sub(ret.len());
+	ret.extend(filler(remaining));
+	ret
+}
+
+fn choose(player_index: ids::PlayerIndex, choice: Choice) -> Step {
+	Step::Choose(player_index, choice)
+}
+
+fn act(player_index: ids::PlayerIndex, action: Action) -> Step {
+	Step::Choose(player_index, Choice::UseActionPoints(action))
+}
+
+fn player_param(player_index: ids::PlayerIndex, victim: ids::PlayerIndex) -> Step {
+	Step::ChoosePlayer(player_index, victim)
+}
+
+fn card_param(player_index: ids::PlayerIndex, spec: SlayCardSpec) -> Step {
+	Step::ChooseCard(player_index, spec)
+}
+
+fn discard(player_index: ids::PlayerIndex) -> Step {
+	Step::Choose(
+		player_index,
+		Choice::Discard(hero(HeroAbilityType::NappingNibbles)),
+	)
+}
+
+fn destroy(
+	player_index: ids::PlayerIndex,
+	victim: ids::PlayerIndex,
+	spec: SlayCardSpec,
+) -> Vec<Step> {
+	vec![
+		player_param(player_index, victim),
+		card_param(player_index, spec),
+	]
+}
+
+fn steal(
+	player_index: ids::PlayerIndex,
+	victim: ids::PlayerIndex,
+	spec: SlayCardSpec,
+) -> Vec<Step> {
+	vec![
+		player_param(player_index, victim),
+		card_param(player_index, spec),
+	]
+}
+
+fn steps(groups: Vec<Vec<Step>>) -> Vec<Step> {
+	groups.into_iter().flatten().collect()
+}
+
+fn quiet_board() -> GameBuilder {
+	GameBuilder::new(3)
+		.leader(0, HeroType::Thief)
+		.leader(1, HeroType::Thief)
+		.leader(2, HeroType::Thief)
+}
+
+// Player 0 has the hero in their party, the others have a hero and a hand of three.
+fn hero_board(ability: HeroAbilityType) -> GameBuilder {
+	quiet_board()
+		.party(0, vec![StackBuilder::new(hero(ability))])
+		.party(1, vec![StackBuilder::new(hero(HeroAbilityType::Peanut))])
+		.party(2, vec![StackBuilder::new(hero(HeroAbilityType::BadAxe))])
+		.hand(1, filler(3))
+		.hand(2, filler(3))
+		.draw_pile(draw_pile(Vec::new()))
+}
+
+fn roll_for(ability: HeroAbilityType) -> Vec<Step> {
+	vec![act(0, Action::RollForAbility(ability)), Step::Pass]
+}
+
+fn hero_on(ability: HeroAbilityType, board: GameBuilder) -> CardCase {
+	CardCase::new(hero(ability), board).dice(vec![SUCCESS])
+}
+
+fn hero_case(ability: HeroAbilityType) -> CardCase {
+	hero_on(ability, hero_board(ability))
+}
+
+// Rolls for the hero and then makes the choices the hero asks for.
+fn rolled(case: CardCase, ability: HeroAbilityType, then: Vec<Step>) -> CardCase {
+	case.steps(steps(vec![roll_for(ability), then]))
+}
+
+fn hero_cases() -> Vec<CardCase> {
+	use HeroAbilityType::*;
+	vec![
+		rolled(
+			hero_case(PlunderingPuma),
+			PlunderingPuma,
+			vec![player_param(0, 1)],
+		)
+		.expect(vec![
+			Expect::HandSize(0, 2),
+			Expect::HandSize(1, 2),
+			Expect::HandSize(2, 3),
+		]),
+		rolled(
+			hero_case(SlipperyPaws),
+			SlipperyPaws,
+			vec![player_param(0, 1), discard(0)],
+		)
+		.expect(vec![
+			Expect::HandSize(0, 1),
+			Expect::HandSize(1, 1),
+			Expect::DiscardSize(1),
+		]),
+		rolled(
+			hero_on(
+				SmoothMimimeow,
+				// Party leaders count as part of the party, so player 2 is led by someone else.
+				hero_board(SmoothMimimeow)
+					.party(1, vec![StackBuilder::new(hero(KitNapper))])
+					.leader(2, HeroType::Fighter),
+			),
+			SmoothMimimeow,
+			Vec::new(),
+		)
+		.expect(vec![
+			Expect::HandSize(0, 1),
+			Expect::HandSize(1, 2),
+			Expect::HandSize(2, 3),
+		]),
+		rolled(
+			hero_case(Meowzio),
+			Meowzio,
+			vec![player_param(0, 1), card_param(0, hero(Peanut))],
+		)
+		.expect(vec![
+			Expect::InParty(0, hero(Peanut)),
+			Expect::NotInParty(1, hero(Peanut)),
+			Expect::HandSize(0, 1),
+			Expect::HandSize(1, 2),
+		]),
+		rolled(
+			hero_on(
+				Shurikitty,
+				hero_board(Shurikitty).party(
+					1,
+					vec![StackBuilder::new(hero(Peanut)).with(item(Item::ReallyBigRing))],
+				),
+			),
+			Shurikitty,
+			vec![player_param(0, 1), card_param(0, hero(Peanut))],
+		)
+		.expect(vec![
+			Expect::NotInParty(1, hero(Peanut)),
+			Expect::InDiscard(hero(Peanut)),
+			Expect::InHand(0, item(Item::ReallyBigRing)),
+		]),
+		rolled(hero_case(KitNapper), KitNapper, steal(0, 1, hero(Peanut))).expect(vec![
+			Expect::InParty(0, hero(Peanut)),
+			Expect::NotInParty(1, hero(Peanut)),
+		]),
+		rolled(
+			hero_on(
+				SilentShadow,
+				hero_board(SilentShadow).hand(
+					1,
+					vec![hero(Hook), hero(NappingNibbles), hero(NappingNibbles)],
+				),
+			),
+			SilentShadow,
+			vec![player_param(0, 1), card_param(0, hero(Hook))],
+		)
+		.expect(vec![
+			Expect::InHand(0, hero(Hook)),
+			Expect::HandSize(1, 2),
+			Expect::InParty(1, hero(Peanut)),
+		]),
+		rolled(
+			hero_on(
+				SlyPickings,
+				hero_board(SlyPickings).hand(1, vec![item(Item::ReallyBigRing)]),
+			),
+			SlyPickings,
+			vec![player_param(0, 1)],
+		)
+		.expect(vec![
+			Expect::InHand(0, item(Item::ReallyBigRing)),
+			Expect::Offered(0, Choice::PlayImmediately(item(Item::ReallyBigRing))),
+		]),
+		rolled(
+			hero_on(
+				HolyCurselifter,
+				hero_board(HolyCurselifter).party(
+					0,
+					vec![StackBuilder::new(hero(HolyCurselifter)).with(item(Item::CurseOfTheSnakesEyes))],
+				),
+			),
+			HolyCurselifter,
+			vec![choose(
+				0,
+				Choice::ReturnItem(
+					AnotherItemType::NotMask(Item::CurseOfTheSnakesEyes),
+					HolyCurselifter,
+				),
+			)],
+		)
+		.expect(vec![
+			Expect::InHand(0, item(Item::CurseOfTheSnakesEyes)),
+			Expect::PartySize(0, 1),
+		]),
+		rolled(
+			hero_on(
+				IronResolve,
+				hero_board(IronResolve)
+					.hand(0, vec![hero(Hook)])
+					.hand(1, vec![SlayCardSpec::Challenge]),
+			),
+			IronResolve,
+			vec![act(0, Action::PlaceHeroInParty(Hook))],
+		)
+		.expect(vec![
+			Expect::NotOffered(1, Choice::Challenge),
+			Expect::InParty(0, hero(Hook)),
+		]),
+		// A spell that cannot be challenged still goes to the discard pile.
+		rolled(
+			hero_on(
+				IronResolve,
+				hero_board(IronResolve).hand(0, vec![magic(MagicSpell::EnchangedSpell)]),
+			),
+			IronResolve,
+			vec![act(0, Action::CastMagic(MagicSpell::EnchangedSpell))],
+		)
+		.named("spells cast afterwards")
+		.expect(vec![
+			Expect::HasEffect(0, PlayerStatusEffect::AddToAllRolls(2)),
+			Expect::InDiscard(magic(MagicSpell::EnchangedSpell)),
+			Expect::HandSize(0, 0),
+		]),
+		rolled(hero_case(CalmingVoice), CalmingVoice, Vec::new()).expect(vec![Expect::HasEffect(
+			0,
+			PlayerStatusEffect::NoCardsCanBeStolen,
+		)]),
+		rolled(hero_case(VibrantGlow), VibrantGlow, Vec::new()).expect(vec![Expect::HasEffect(
+			0,
+			PlayerStatusEffect::AddToAllRolls(5),
+		)]),
+		rolled(hero_case(MightyBlade), MightyBlade, Vec::new()).expect(vec![Expect::HasEffect(
+			0,
+			PlayerStatusEffect::NoCardsCanBeDestroyed,
+		)]),
+		rolled(
+			hero_on(
+				RadiantHorn,
+				hero_board(RadiantHorn).discard_pile(vec![hero(Hook), modifier(ModifierKinds::Plus4)]),
+			),
+			RadiantHorn,
+			vec![choose(
+				0,
+				Choice::ChooseDiscardedCard(modifier(ModifierKinds::Plus4)),
+			)],
+		)
+		.expect(vec![
+			Expect::InHand(0, modifier(ModifierKinds::Plus4)),
+			Expect::DiscardSize(1),
+		]),
+		rolled(
+			hero_on(
+				GuidingLight,
+				hero_board(GuidingLight).discard_pile(vec![modifier(ModifierKinds::Plus4), hero(Hook)]),
+			),
+			GuidingLight,
+			vec![choose(0, Choice::ChooseDiscardedCard(hero(Hook)))],
+		)
+		.expect(vec![Expect::InHand(0, hero(Hook)), Expect::DiscardSize(1)]),
+		rolled(hero_case(WiseShield), WiseShield, Vec::new()).expect(vec![Expect::HasEffect(
+			0,
+			PlayerStatusEffect::AddToAllRolls(3),
+		)]),
+		rolled(
+			hero_on(QiBear, hero_board(QiBear).hand(0, filler(1))),
+			QiBear,
+			steps(vec![
+				vec![
+					choose(0, Choice::ContinueDiscardingAndDestroying),
+					discard(0),
+				],
+				destroy(0, 1, hero(Peanut)),
+				vec![choose(0, Choice::QuitAction)],
+			]),
+		)
+		.expect(vec![
+			Expect::HandSize(0, 0),
+			Expect::NotInParty(1, hero(Peanut)),
+			Expect::InParty(2, hero(BadAxe)),
+		]),
+		rolled(
+			hero_on(
+				PanChucks,
+				hero_board(PanChucks).draw_pile(draw_pile(vec![SlayCardSpec::Challenge])),
+			),
+			PanChucks,
+			steps(vec![
+				vec![choose(0, Choice::RevealChallengeAndDestroy)],
+				destroy(0, 1, hero(Peanut)),
+			]),
+		)
+		.expect(vec![
+			Expect::HandSize(0, 2),
+			Expect::NotInParty(1, hero(Peanut)),
+		]),
+		rolled(
+			hero_case(HeavyBear),
+			HeavyBear,
+			vec![player_param(0, 1), discard(1), discard(1)],
+		)
+		.expect(vec![Expect::HandSize(1, 1), Expect::DiscardSize(2)]),
+		rolled(hero_case(BadAxe), BadAxe, destroy(0, 1, hero(Peanut))).expect(vec![
+			Expect::NotInParty(1, hero(Peanut)),
+			Expect::InDiscard(hero(Peanut)),
+		]),
+		rolled(hero_case(ToughTeddy), ToughTeddy, vec![discard(2)])
+			.expect(vec![Expect::HandSize(1, 3), Expect::HandSize(2, 2)]),
+		rolled(hero_case(BearClaw), BearClaw, vec![player_param(0, 1)])
+			.expect(vec![Expect::HandSize(0, 2), Expect::HandSize(1, 1)]),
+		rolled(
+			hero_on(
+				FuryKnuckle,
+				hero_board(FuryKnuckle).hand(1, vec![SlayCardSpec::Challenge; 3]),
+			),
+			FuryKnuckle,
+			vec![player_param(0, 1)],
+		)
+		.expect(vec![Expect::HandSize(0, 2), Expect::HandSize(1, 1)]),
+		rolled(
+			hero_case(BearyWise),
+			BearyWise,
+			vec![
+				discard(1),
+				discard(2),
+				choose(0, Choice::ChooseDiscardedCard(hero(NappingNibbles))),
+			],
+		)
+		.known_failure(
+			"BearyWise only makes the others discard, nothing offers the discarded cards yet.",
+		)
+		.expect(vec![
+			Expect::HandSize(0, 1),
+			Expect::HandSize(1, 2),
+			Expect::HandSize(2, 2),
+			Expect::DiscardSize(1),
+		]),
+		rolled(
+			hero_on(
+				Hook,
+				hero_board(Hook).hand(0, vec![item(Item::ReallyBigRing)]),
+			),
+			Hook,
+			vec![
+				choose(0, Choice::PlayImmediately(item(Item::ReallyBigRing))),
+				Step::Pass,
+				player_param(0, 0),
+				card_param(0, hero(Hook)),
+			],
+		)
+		.expect(vec![
+			Expect::Equipped(0, hero(Hook), item(Item::ReallyBigRing)),
+			Expect::HandSize(0, 1),
+		]),
+		rolled(hero_case(Wildshot), Wildshot, vec![discard(0)]).expect(vec![
+			Expect::HandSize(0, 2),
+			Expect::DrawSize(7),
+			Expect::DiscardSize(1),
+		]),
+		rolled(
+			hero_case(SeriousGrey),
+			SeriousGrey,
+			destroy(0, 1, hero(Peanut)),
+		)
+		.expect(vec![
+			Expect::NotInParty(1, hero(Peanut)),
+			Expect::HandSize(0, 1),
+		]),
+		rolled(
+			hero_on(WilyRed, hero_board(WilyRed).hand(0, filler(2))),
+			WilyRed,
+			Vec::new(),
+		)
+		.expect(vec![Expect::HandSize(0, 7), Expect::DrawSize(5)]),
+		rolled(
+			hero_on(
+				QuickDraw,
+				hero_board(QuickDraw).draw_pile(draw_pile(vec![item(Item::ReallyBigRing)])),
+			),
+			QuickDraw,
+			Vec::new(),
+		)
+		.expect(vec![
+			Expect::HandSize(0, 2),
+			Expect::Offered(0, Choice::PlayImmediately(item(Item::ReallyBigRing))),
+		]),
+		rolled(
+			hero_on(
+				LookieRookie,
+				hero_board(LookieRookie).discard_pile(vec![hero(Hook), item(Item::ReallyBigRing)]),
+			),
+			LookieRookie,
+			vec![choose(
+				0,
+				Choice::ChooseDiscardedCard(item(Item::ReallyBigRing)),
+			)],
+		)
+		.expect(vec![
+			Expect::InHand(0, item(Item::ReallyBigRing)),
+			Expect::DiscardSize(1),
+		]),
+		rolled(
+			hero_on(
+				Bullseye,
+				hero_board(Bullseye).draw_pile(draw_pile(vec![
+					hero(Hook),
+					item(Item::ReallyBigRing),
+					SlayCardSpec::Challenge,
+				])),
+			),
+			Bullseye,
+			vec![
+				choose(0, Choice::BullseyeKeep(hero(Hook))),
+				choose(0, Choice::BullseyeDoNotReorder),
+			],
+		)
+		.expect(vec![
+			Expect::InHand(0, hero(Hook)),
+			Expect::HandSize(0, 1),
+			Expect::DrawSize(9),
+		]),
+		rolled(hero_case(SharpFox), SharpFox, vec![player_param(0, 1)])
+			.expect(vec![Expect::HandVisibleTo(1, 0)]),
+		rolled(
+			hero_on(
+				FuzzyCheeks,
+				hero_board(FuzzyCheeks).hand(0, vec![hero(Hook)]),
+			),
+			FuzzyCheeks,
+			vec![choose(0, Choice::PlaceHeroImmediately(Hook))],
+		)
+		.expect(vec![Expect::InParty(0, hero(Hook)), Expect::HandSize(0, 1)]),
+		rolled(hero_case(Peanut), Peanut, Vec::new())
+			.expect(vec![Expect::HandSize(0, 2), Expect::DrawSize(8)]),
+		rolled(hero_case(NappingNibbles), NappingNibbles, Vec::new()).expect(vec![
+			Expect::HandSize(0, 0),
+			Expect::DrawSize(10),
+			Expect::ActionPoints(0, 2),
+		]),
+		rolled(
+			hero_case(TipsyTootie),
+			TipsyTootie,
+			vec![player_param(0, 1), card_param(0, hero(Peanut))],
+		)
+		.expect(vec![
+			Expect::InParty(0, hero(Peanut)),
+			Expect::NotInParty(0, hero(TipsyTootie)),
+			Expect::InParty(1, hero(TipsyTootie)),
+		]),
+		rolled(
+			hero_on(
+				MellowDee,
+				hero_board(MellowDee).draw_pile(draw_pile(vec![hero(Hook)])),
+			),
+			MellowDee,
+			Vec::new(),
+		)
+		.expect(vec![
+			Expect::InHand(0, hero(Hook)),
+			Expect::Offered(0, Choice::PlayImmediately(hero(Hook))),
+		]),
+		rolled(
+			hero_on(LuckBucky, hero_board(LuckBucky).hand(1, vec![hero(Hook)])),
+			LuckBucky,
+			vec![player_param(0, 1)],
+		)
+		.expect(vec![
+			Expect::InHand(0, hero(Hook)),
+			Expect::Offered(0, Choice::PlayImmediately(hero(Hook))),
+		]),
+		rolled(
+			hero_on(
+				DodgyDealer,
+				hero_board(DodgyDealer).hand(0, vec![hero(Hook)]),
+			),
+			DodgyDealer,
+			vec![player_param(0, 1)],
+		)
+		.expect(vec![
+			Expect::HandSize(0, 3),
+			Expect::InHand(1, hero(Hook)),
+			Expect::HandSize(1, 1),
+		]),
+		rolled(
+			hero_case(GreedyCheeks),
+			GreedyCheeks,
+			vec![
+				choose(1, Choice::ChooseCardToGive(hero(NappingNibbles), 0)),
+				choose(2, Choice::ChooseCardToGive(hero(NappingNibbles), 0)),
+			],
+		)
+		.expect(vec![
+			Expect::HandSize(0, 2),
+			Expect::HandSize(1, 2),
+			Expect::HandSize(2, 2),
+		]),
+		rolled(
+			hero_case(Fluffy),
+			Fluffy,
+			steps(vec![
+				destroy(0, 1, hero(Peanut)),
+				destroy(0, 2, hero(BadAxe)),
+			]),
+		)
+		.expect(vec![Expect::PartySize(1, 0), Expect::PartySize(2, 0)]),
+		rolled(
+			hero_case(Wiggles).dice(vec![SUCCESS, SUCCESS]),
+			Wiggles,
+			vec![
+				player_param(0, 1),
+				card_param(0, hero(Peanut)),
+				choose(0, Choice::PlayImmediately(hero(Peanut))),
+				Step::Pass,
+			],
+		)
+		.expect(vec![
+			Expect::InParty(0, hero(Peanut)),
+			Expect::HandSize(0, 2),
+		]),
+		rolled(
+			hero_on(
+				Spooky,
+				hero_board(Spooky)
+					.party(1, vec![hero(Peanut).into(), hero(Hook).into()])
+					.party(2, vec![hero(BadAxe).into(), hero(Hook).into()]),
+			),
+			Spooky,
+			vec![
+				choose(1, Choice::Sacrifice(Peanut)),
+				choose(2, Choice::Sacrifice(BadAxe)),
+			],
+		)
+		.expect(vec![
+			Expect::NotInParty(1, hero(Peanut)),
+			Expect::NotInParty(2, hero(BadAxe)),
+			Expect::PartySize(1, 1),
+			Expect::PartySize(2, 1),
+		]),
+		rolled(
+			hero_on(
+				Snowball,
+				hero_board(Snowball).draw_pile(draw_pile(vec![magic(MagicSpell::EnchangedSpell)])),
+			),
+			Snowball,
+			vec![
+				choose(
+					0,
+					Choice::PlayImmediately(magic(MagicSpell::EnchangedSpell)),
+				),
+				Step::Pass,
+			],
+		)
+		.expect(vec![
+			Expect::HasEffect(0, PlayerStatusEffect::AddToAllRolls(2)),
+			Expect::HandSize(0, 1),
+			Expect::InDiscard(magic(MagicSpell::EnchangedSpell)),
+		]),
+		rolled(
+			hero_on(
+				Buttons,
+				hero_board(Buttons).hand(1, vec![magic(MagicSpell::EnchangedSpell)]),
+			),
+			Buttons,
+			vec![player_param(0, 1)],
+		)
+		.expect(vec![
+			Expect::InHand(0, magic(MagicSpell::EnchangedSpell)),
+			Expect::Offered(
+				0,
+				Choice::PlayImmediately(magic(MagicSpell::EnchangedSpell)),
+			),
+		]),
+		rolled(
+			hero_on(
+				BunBun,
+				hero_board(BunBun).discard_pile(vec![hero(Hook), magic(MagicSpell::EnchangedSpell)]),
+			),
+			BunBun,
+			vec![choose(
+				0,
+				Choice::ChooseDiscardedCard(magic(MagicSpell::EnchangedSpell)),
+			)],
+		)
+		.expect(vec![
+			Expect::InHand(0, magic(MagicSpell::EnchangedSpell)),
+			Expect::DiscardSize(1),
+		]),
+		rolled(
+			hero_on(
+				Hopper,
+				hero_board(Hopper).party(1, vec![hero(Peanut).into(), hero(Hook).into()]),
+			),
+			Hopper,
+			vec![player_param(0, 1), choose(1, Choice::Sacrifice(Peanut))],
+		)
+		.expect(vec![
+			Expect::NotInParty(1, hero(Peanut)),
+			Expect::PartySize(1, 1),
+			Expect::InDiscard(hero(Peanut)),
+		]),
+		rolled(
+			hero_case(Whiskers),
+			Whiskers,
+			steps(vec![steal(0, 1, hero(Peanut)), destroy(0, 2, hero(BadAxe))]),
+		)
+		.expect(vec![
+			Expect::InParty(0, hero(Peanut)),
+			Expect::PartySize(1, 0),
+			Expect::PartySize(2, 0),
+		]),
+	]
+}
+
+// Player 1 places a hero and player 0 challenges it, the initiator rolling first.
+fn challenge_case(
+	card: SlayCardSpec,
+	board: GameBuilder,
+	initiator: u32,
+	challenger: u32,
+) -> CardCase {
+	CardCase::new(
+		card,
+		board
+			.party(
+				0,
+				vec![StackBuilder::new(hero(HeroAbilityType::NappingNibbles))],
+			)
+			.hand(0, vec![SlayCardSpec::Challenge])
+			.hand(1, vec![hero(HeroAbilityType::Hook)])
+			.active_player(1)
+			.draw_pile(draw_pile(Vec::new())),
+	)
+	.dice(vec![roll(initiator), roll(challenger)])
+	.steps(vec![
+		act(1, Action::PlaceHeroInParty(HeroAbilityType::Hook)),
+		choose(0, Choice::Challenge),
+		Step::Pass,
+	])
+	.expect(vec![
+		Expect::NotInParty(1, hero(HeroAbilityType::Hook)),
+		Expect::InDiscard(hero(HeroAbilityType::Hook)),
+	])
+}
+
+fn peanut_board() -> GameBuilder {
+	quiet_board()
+		.party(0, vec![StackBuilder::new(hero(HeroAbilityType::Peanut))])
+		.draw_pile(draw_pile(Vec::new()))
+}
+
+// Peanut needs a 7, so the roll only succeeds with the card's bonus.
+fn peanut_case(card: SlayCardSpec, board: GameBuilder, total: u32) -> CardCase {
+	CardCase::new(card, board)
+		.dice(vec![roll(total)])
+		.steps(roll_for(HeroAbilityType::Peanut))
+		.expect(vec![Expect::HandSize(0, 2)])
+}
+
+fn leader_cases() -> Vec<CardCase> {
+	vec![
+		peanut_case(
+			SlayCardSpec::PartyLeader(HeroType::Bard),
+			peanut_board().leader(0, HeroType::Bard),
+			6,
+		),
+		CardCase::new(
+			SlayCardSpec::PartyLeader(HeroType::Wizard),
+			peanut_board()
+				.leader(0, HeroType::Wizard)
+				.hand(0, vec![magic(MagicSpell::EnchangedSpell)]),
+		)
+		.steps(vec![
+			act(0, Action::CastMagic(MagicSpell::EnchangedSpell)),
+			Step::Pass,
+		])
+		.expect(vec![
+			Expect::HandSize(0, 1),
+			Expect::InDiscard(magic(MagicSpell::EnchangedSpell)),
+		]),
+		challenge_case(
+			SlayCardSpec::PartyLeader(HeroType::Fighter),
+			quiet_board().leader(0, HeroType::Fighter),
+			7,
+			6,
+		),
+		CardCase::new(
+			SlayCardSpec::PartyLeader(HeroType::Gaurdian),
+			peanut_board()
+				.leader(0, HeroType::Gaurdian)
+				.hand(0, vec![modifier(ModifierKinds::Plus4)]),
+		)
+		.known_failure("ModifierBonus is never applied, so modifiers are only offered at face value.")
+		.dice(vec![roll(2)])
+		.steps(vec![act(
+			0,
+			Action::RollForAbility(HeroAbilityType::Peanut),
+		)])
+		.expect(vec![
+			Expect::Offered(
+				0,
+				Choice::Modify(ModificationPath::Roll, ModifierKinds::Plus4, 5),
+			),
+			Expect::Offered(
+				0,
+				Choice::Modify(ModificationPath::Roll, ModifierKinds::Plus4, 3),
+			),
+		]),
+		// A hero more than Anuran Cauldron requires, like in mask_case.
+		CardCase::new(
+			SlayCardSpec::PartyLeader(HeroType::Ranger),
+			quiet_board()
+				.leader(0, HeroType::Ranger)
+				.party(
+					0,
+					vec![StackBuilder::new(hero(HeroAbilityType::NappingNibbles)); 4],
+				)
+				.monsters(vec![Monster::AnuranCauldron])
+				.draw_pile(draw_pile(Vec::new())),
+		)
+		.dice(vec![roll(6)])
+		.steps(vec![
+			act(0, Action::AttackMonster(Monster::AnuranCauldron)),
+			Step::Pass,
+		])
+		.expect(vec![Expect::SlainMonsters(0, 1)]),
+		CardCase::new(
+			SlayCardSpec::PartyLeader(HeroType::Thief),
+			quiet_board().hand(1, filler(3)),
+		)
+		.steps(vec![
+			act(0, Action::UseLeader(HeroType::Thief)),
+			player_param(0, 1),
+		])
+		.expect(vec![
+			Expect::HandSize(0, 1),
+			Expect::HandSize(1, 2),
+			Expect::ActionPoints(0, 2),
+		]),
+	]
+}
+
+fn slain(monster: Monster, board: GameBuilder) -> CardCase {
+	CardCase::new(
+		SlayCardSpec::MonsterCard(monster),
+		board.slain_monsters(0, vec![monster]),
+	)
+}
+
+// Player 0 has only the defender, and player 1 rolls for Bad Axe to destroy it.
+fn attacked_board(defender: StackBuilder) -> GameBuilder {
+	quiet_board()
+		.party(0, vec![defender])
+		.party(1, vec![StackBuilder::new(hero(HeroAbilityType::BadAxe))])
+		.active_player(1)
+		.draw_pile(draw_pile(Vec::new()))
+}
+
+fn attack_with_bad_axe() -> Vec<Step> {
+	vec![
+		act(1, Action::RollForAbility(HeroAbilityType::BadAxe)),
+		Step::Pass,
+	]
+}
+
+fn monster_cases() -> Vec<CardCase> {
+	use HeroAbilityType::*;
+	vec![
+		peanut_case(
+			SlayCardSpec::MonsterCard(Monster::AnuranCauldron),
+			peanut_board().slain_monsters(0, vec![Monster::AnuranCauldron]),
+			6,
+		),
+		challenge_case(
+			SlayCardSpec::MonsterCard(Monster::TitanWyvern),
+			quiet_board().slain_monsters(0, vec![Monster::TitanWyvern]),
+			8,
+			7,
+		),
+		peanut_case(
+			SlayCardSpec::MonsterCard(Monster::DarkDragonKing),
+			peanut_board().slain_monsters(0, vec![Monster::DarkDragonKing]),
+			6,
+		),
+		slain(
+			Monster::AbyssQueen,
+			peanut_board().hand(1, vec![modifier(ModifierKinds::Minus4)]),
+		)
+		.known_failure("AddOnModify has no listener yet, see modifier_listeners::player_modified.")
+		.dice(vec![roll(10)])
+		.steps(vec![
+			act(0, Action::RollForAbility(Peanut)),
+			choose(
+				1,
+				Choice::Modify(ModificationPath::Roll, ModifierKinds::Minus4, -4),
+			),
+			Step::Pass,
+		])
+		.expect(vec![Expect::HandSize(0, 2)]),
+		slain(
+			Monster::RexMajor,
+			peanut_board().draw_pile(draw_pile(vec![modifier(ModifierKinds::Plus4)])),
+		)
+		.known_failure(
+			"RevealModifiersAndDrawAgain has no listener yet, see modifier_listeners::player_drew_modifier.",
+		)
+		.steps(vec![act(0, Action::Draw)])
+		.expect(vec![
+			Expect::InHand(0, modifier(ModifierKinds::Plus4)),
+			Expect::HandSize(0, 2),
+		]),
+		slain(
+			Monster::CorruptedSabretooth,
+			quiet_board()
+				.party(0, vec![StackBuilder::new(hero(BadAxe))])
+				.party(1, vec![StackBuilder::new(hero(Peanut))])
+				.draw_pile(draw_pile(Vec::new())),
+		)
+		.known_failure(
+			"StealInsteadOfSacrifice is not checked when a hero is destroyed yet, see modifier_listeners::hero_is_destroyable.",
+		)
+		.dice(vec![SUCCESS])
+		.steps(steps(vec![roll_for(BadAxe), destroy(0, 1, hero(Peanut))]))
+		.expect(vec![
+			Expect::InParty(0, hero(Peanut)),
+			Expect::NotInParty(1, hero(Peanut)),
+		]),
+		slain(
+			Monster::CrownedSerpent,
+			peanut_board().hand(0, vec![modifier(ModifierKinds::Plus4)]),
+		)
+		.known_failure("DrawOnModify has no listener yet, see modifier_listeners::player_modified.")
+		.dice(vec![SUCCESS])
+		.steps(vec![
+			act(0, Action::RollForAbility(Peanut)),
+			choose(
+				0,
+				Choice::Modify(ModificationPath::Roll, ModifierKinds::Plus4, 4),
+			),
+			Step::Pass,
+		])
+		.expect(vec![Expect::HandSize(0, 3), Expect::DrawSize(7)]),
+		slain(
+			Monster::WarwornOwlbear,
+			peanut_board()
+				.hand(0, vec![item(Item::ReallyBigRing)])
+				.hand(1, vec![SlayCardSpec::Challenge]),
+		)
+		.steps(vec![act(
+			0,
+			Action::PlaceItem(AnotherItemType::NotMask(Item::ReallyBigRing)),
+		)])
+		.expect(vec![Expect::NotOffered(1, Choice::Challenge)]),
+		slain(
+			Monster::Dracos,
+			attacked_board(StackBuilder::new(hero(NappingNibbles))),
+		)
+		.known_failure(
+			"DrawOnDestroy has no listener yet, see modifier_listeners::hero_is_destroyable.",
+		)
+		.dice(vec![SUCCESS])
+		.steps(steps(vec![
+			attack_with_bad_axe(),
+			destroy(1, 0, hero(NappingNibbles)),
+		]))
+		.expect(vec![Expect::PartySize(0, 0), Expect::HandSize(0, 1)]),
+		slain(
+			Monster::Malammoth,
+			peanut_board().draw_pile(draw_pile(vec![item(Item::ReallyBigRing)])),
+		)
+		.steps(vec![act(0, Action::Draw)])
+		.expect(vec![Expect::Offered(
+			0,
+			Choice::PlayImmediately(item(Item::ReallyBigRing)),
+		)]),
+		slain(
+			Monster::Bloodwing,
+			quiet_board()
+				.party(1, vec![StackBuilder::new(hero(NappingNibbles))])
+				.hand(0, vec![hero(Hook)])
+				.hand(1, vec![SlayCardSpec::Challenge, hero(NappingNibbles)])
+				.draw_pile(draw_pile(Vec::new())),
+		)
+		.known_failure(
+			"DiscardOnChallenge has no listener yet, see modifier_listeners::player_was_challenged.",
+		)
+		.dice(vec![roll(12), roll(2)])
+		.steps(vec![
+			act(0, Action::PlaceHeroInParty(Hook)),
+			choose(1, Choice::Challenge),
+			Step::Pass,
+			discard(1),
+		])
+		.expect(vec![Expect::InParty(0, hero(Hook)), Expect::HandSize(1, 0)]),
+		slain(Monster::ArcticAries, peanut_board())
+			.known_failure(
+				"DrawOnSuccessfulAbility has no listener yet, see modifier_listeners::player_successfully_rolled_for_ability.",
+			)
+			.dice(vec![SUCCESS])
+			.steps(roll_for(Peanut))
+			.expect(vec![Expect::HandSize(0, 3)]),
+		slain(Monster::MegaSlime, peanut_board()).expect(vec![Expect::ActionPoints(0, 4)]),
+		slain(
+			Monster::Orthus,
+			peanut_board().draw_pile(draw_pile(vec![magic(MagicSpell::EnchangedSpell)])),
+		)
+		.steps(vec![act(0, Action::Draw)])
+		.expect(vec![Expect::Offered(
+			0,
+			Choice::PlayImmediately(magic(MagicSpell::EnchangedSpell)),
+		)]),
+		slain(
+			Monster::Terratuga,
+			attacked_board(StackBuilder::new(hero(NappingNibbles))),
+		)
+		.known_failure(
+			"UndestroyableHeros is not checked when choosing what to destroy yet, see modifier_listeners::hero_is_destroyable.",
+		)
+		.dice(vec![SUCCESS])
+		.steps(attack_with_bad_axe())
+		.expect(vec![Expect::NotOfferedPlayer(1, 0)]),
+	]
+}
+
+// Player 0 casts the spell from their hand, and nobody challenges it.
+fn spell_case(spell: MagicSpell, board: GameBuilder, then: Vec<Step>) -> CardCase {
+	CardCase::new(magic(spell), board).steps(steps(vec![
+		vec![act(0, Action::CastMagic(spell)), Step::Pass],
+		then,
+	]))
+}
+
+fn spell_board(spell: MagicSpell) -> GameBuilder {
+	hero_board(HeroAbilityType::NappingNibbles).hand(0, vec![magic(spell)])
+}
+
+fn magic_cases() -> Vec<CardCase> {
+	use HeroAbilityType::*;
+	use MagicSpell::*;
+	vec![
+		spell_case(
+			EnganglingTrap,
+			spell_board(EnganglingTrap).hand(0, vec![magic(EnganglingTrap), hero(Hook), hero(Hook)]),
+			steps(vec![
+				vec![
+					choose(0, Choice::Discard(hero(Hook))),
+					choose(0, Choice::Discard(hero(Hook))),
+				],
+				steal(0, 1, hero(Peanut)),
+			]),
+		)
+		.expect(vec![
+			Expect::HandSize(0, 0),
+			Expect::InParty(0, hero(Peanut)),
+			Expect::NotInParty(1, hero(Peanut)),
+		]),
+		spell_case(CriticalBoost, spell_board(CriticalBoost), vec![discard(0)]).expect(vec![
+			Expect::HandSize(0, 2),
+			Expect::DrawSize(7),
+			Expect::InDiscard(magic(CriticalBoost)),
+		]),
+		spell_case(
+			DestructiveSpell,
+			spell_board(DestructiveSpell).hand(0, vec![magic(DestructiveSpell), hero(Hook)]),
+			steps(vec![
+				vec![choose(0, Choice::Discard(hero(Hook)))],
+				destroy(0, 1, hero(Peanut)),
+			]),
+		)
+		.expect(vec![
+			Expect::HandSize(0, 0),
+			Expect::NotInParty(1, hero(Peanut)),
+			Expect::InDiscard(hero(Peanut)),
+		]),
+		spell_case(
+			WindsOfChange,
+			spell_board(WindsOfChange).party(
+				1,
+				vec![StackBuilder::new(hero(Peanut)).with(item(Item::ReallyBigRing))],
+			),
+			vec![choose(
+				0,
+				Choice::ReturnItem(AnotherItemType::NotMask(Item::ReallyBigRing), Peanut),
+			)],
+		)
+		.expect(vec![
+			Expect::InHand(1, item(Item::ReallyBigRing)),
+			Expect::HandSize(0, 1),
+		]),
+		spell_case(EnchangedSpell, spell_board(EnchangedSpell), Vec::new()).expect(vec![
+			Expect::HasEffect(0, PlayerStatusEffect::AddToAllRolls(2)),
+			Expect::InDiscard(magic(EnchangedSpell)),
+		]),
+		spell_case(
+			ForcedExchange,
+			spell_board(ForcedExchange),
+			vec![
+				player_param(0, 1),
+				card_param(0, hero(Peanut)),
+				card_param(0, hero(NappingNibbles)),
+			],
+		)
+		.expect(vec![
+			Expect::InParty(0, hero(Peanut)),
+			Expect::NotInParty(0, hero(NappingNibbles)),
+			Expect::InParty(1, hero(NappingNibbles)),
+		]),
+		spell_case(
+			ForcefulWinds,
+			spell_board(ForcefulWinds)
+				.party(
+					1,
+					vec![StackBuilder::new(hero(Peanut)).with(item(Item::ReallyBigRing))],
+				)
+				.party(
+					2,
+					vec![StackBuilder::new(hero(BadAxe)).with(item(Item::CurseOfTheSnakesEyes))],
+				),
+			Vec::new(),
+		)
+		.expect(vec![
+			Expect::InHand(1, item(Item::ReallyBigRing)),
+			Expect::InHand(2, item(Item::CurseOfTheSnakesEyes)),
+			Expect::HandSize(0, 0),
+		]),
+		spell_case(
+			CallToTheFallen,
+			spell_board(CallToTheFallen).discard_pile(vec![item(Item::ReallyBigRing), hero(Hook)]),
+			vec![choose(0, Choice::ChooseDiscardedCard(hero(Hook)))],
+		)
+		.expect(vec![
+			Expect::InHand(0, hero(Hook)),
+			Expect::InDiscard(magic(CallToTheFallen)),
+		]),
+	]
+}
+
+fn equipped(item_card: SlayCardSpec) -> GameBuilder {
+	quiet_board()
+		.party(
+			0,
+			vec![StackBuilder::new(hero(HeroAbilityType::Peanut)).with(item_card)],
+		)
+		.draw_pile(draw_pile(Vec::new()))
+}
+
+// With the mask, the party has the hero type that the monster requires.
+// Parties here have a hero more than monsters require, so that only the mask is being checked.
+fn mask_case(
+	hero_type: HeroType,
+	wearer: HeroAbilityType,
+	leader: HeroType,
+	monster: Monster,
+) -> CardCase {
+	CardCase::new(
+		mask(hero_type),
+		quiet_board()
+			.leader(0, leader)
+			.party(
+				0,
+				vec![
+					StackBuilder::new(hero(wearer)).with(mask(hero_type)),
+					StackBuilder::new(hero(wearer)),
+					StackBuilder::new(hero(wearer)),
+				],
+			)
+			.monsters(vec![monster])
+			.draw_pile(draw_pile(Vec::new())),
+	)
+	.expect(vec![Expect::Offered(
+		0,
+		Choice::UseActionPoints(Action::AttackMonster(monster)),
+	)])
+}
+
+fn item_cases() -> Vec<CardCase> {
+	use HeroAbilityType::*;
+	vec![
+		CardCase::new(
+			item(Item::DecoyDoll),
+			attacked_board(StackBuilder::new(hero(NappingNibbles)).with(item(Item::DecoyDoll))),
+		)
+		.dice(vec![SUCCESS])
+		.steps(steps(vec![
+			attack_with_bad_axe(),
+			destroy(1, 0, hero(NappingNibbles)),
+		]))
+		.expect(vec![
+			Expect::InParty(0, hero(NappingNibbles)),
+			Expect::InDiscard(item(Item::DecoyDoll)),
+		]),
+		peanut_case(
+			item(Item::ReallyBigRing),
+			equipped(item(Item::ReallyBigRing)),
+			5,
+		),
+		CardCase::new(
+			item(Item::ParticularlyRustyCoin),
+			equipped(item(Item::ParticularlyRustyCoin)),
+		)
+		.dice(vec![FAILURE])
+		.steps(roll_for(Peanut))
+		.expect(vec![Expect::HandSize(0, 1)]),
+		CardCase::new(item(Item::SealingKey), equipped(item(Item::SealingKey))).expect(vec![
+			Expect::NotOffered(0, Choice::UseActionPoints(Action::RollForAbility(Peanut))),
+		]),
+		CardCase::new(
+			item(Item::SuspiciouslyShinyCoin),
+			equipped(item(Item::SuspiciouslyShinyCoin)).hand(0, filler(1)),
+		)
+		.dice(vec![SUCCESS])
+		.steps(steps(vec![roll_for(Peanut), vec![discard(0)]]))
+		.expect(vec![Expect::HandSize(0, 2), Expect::DiscardSize(1)]),
+		CardCase::new(
+			item(Item::CurseOfTheSnakesEyes),
+			equipped(item(Item::CurseOfTheSnakesEyes)),
+		)
+		.dice(vec![roll(8)])
+		.steps(roll_for(Peanut))
+		.expect(vec![Expect::HandSize(0, 0)]),
+		// Items can go on the placer's own heroes, not only on the other players'.
+		CardCase::new(
+			item(Item::ReallyBigRing),
+			peanut_board().hand(0, vec![item(Item::ReallyBigRing)]),
+		)
+		.named("placed on the placer's own hero")
+		.steps(vec![
+			act(
+				0,
+				Action::PlaceItem(AnotherItemType::NotMask(Item::ReallyBigRing)),
+			),
+			Step::Pass,
+			player_param(0, 0),
+			card_param(0, hero(Peanut)),
+		])
+		.expect(vec![
+			Expect::Equipped(0, hero(Peanut), item(Item::ReallyBigRing)),
+			Expect::HandSize(0, 0),
+		]),
+		mask_case(
+			HeroType::Bard,
+			Hook,
+			HeroType::Thief,
+			Monster::DarkDragonKing,
+		),
+		mask_case(HeroType::Wizard, Hook, HeroType::Thief, Monster::Orthus),
+		mask_case(
+			HeroType::Fighter,
+			Hook,
+			HeroType::Thief,
+			Monster::TitanWyvern,
+		),
+		mask_case(HeroType::Gaurdian, Hook, HeroType::Thief, Monster::RexMajor),
+		mask_case(
+			HeroType::Ranger,
+			Peanut,
+			HeroType::Thief,
+			Monster::Malammoth,
+		),
+		mask_case(
+			HeroType::Thief,
+			Hook,
+			HeroType::Ranger,
+			Monster::WarwornOwlbear,
+		),
+	]
+}
+
+pub fn all_cases() -> Vec<CardCase> {
+	let mut ret = hero_cases();
+	ret.extend(leader_cases());
+	ret.extend(monster_cases());
+	ret.extend(magic_cases());
+	ret.extend(item_cases());
+	ret
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::slay::conformance;
+	use crate::slay::conformance::CardCase;
+
+	fn assert_all_pass(cases: Vec<CardCase>) {
+		let failures = conformance::run_cases(&cases);
+		let report: Vec<String> = failures.iter().map(|failure| failure.to_string()).collect();
+		assert!(failures.is_empty(), "\n{}", report.join("\n"));
+	}
+
+	#[test]
+	fn heroes_do_what_their_cards_say() {
+		assert_all_pass(super::hero_cases());
+	}
+
+	#[test]
+	fn leaders_do_what_their_cards_say() {
+		assert_all_pass(super::leader_cases());
+	}
+
+	#[test]
+	fn monsters_do_what_their_cards_say() {
+		assert_all_pass(super::monster_cases());
+	}
+
+	#[test]
+	fn spells_do_what_their_cards_say() {
+		assert_all_pass(super::magic_cases());
+	}
+
+	#[test]
+	fn items_do_what_their_cards_say() {
+		assert_all_pass(super::item_cases());
+	}
+
+	#[test]
+	fn every_card_is_played_by_a_case() {
+		assert_eq!(conformance::uncovered_cards(), Vec::new());
+	}
+}
@@ -0,0 +1,430 @@
+pub mod cases;
+
+use std::fmt;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+
+use enum_iterator::all;
+
+use crate::slay::choices::Choice;
+use crate::slay::choices::ChoicesType;
+use crate::slay::driver;
+use crate::slay::fuzz;
+use crate::slay::game_context::GameBookKeeping;
+use crate::slay::ids;
+use crate::slay::showdown::roll::Roll;
+use crate::slay::specification::GameVersion;
+use crate::slay::specs::cards::card_type::SlayCardSpec;
+use crate::slay::state::builder::GameBuilder;
+use crate::slay::state::deck::DeckPath;
+use crate::slay::state::game::Game;
+use crate::slay::status_effects::effect::PlayerStatusEffect;
+
+/*
+	Plays every card through the real make_selection / advance_game path and compares the board
+	with what the card's description says should happen.
+	A case is a board, the dice, the choices made (by value, so they survive id changes) and what
+	should be true afterwards. Showdowns nobody scripted a part in are passed on everyone's behalf.
+	Run them with: cargo test conformance, or cargo run --bin conformance for the whole report.
+*/
+
+#[derive(Debug, Clone)]
+pub enum Step {
+	Choose(ids::PlayerIndex, Choice),
+	// Task params have no names, so they are chosen by the player or card they are set to.
+	ChoosePlayer(ids::PlayerIndex, ids::PlayerIndex),
+	ChooseCard(ids::PlayerIndex, SlayCardSpec),
+	// Everyone lets the current offer, roll or challenge go by.
+	Pass,
+}
+
+#[derive(Debug, Clone)]
+pub enum Expect {
+	HandSize(ids::PlayerIndex, usize),
+	InHand(ids::PlayerIndex, SlayCardSpec),
+	NotInHand(ids::PlayerIndex, SlayCardSpec),
+	PartySize(ids::PlayerIndex, usize),
+	InParty(ids::PlayerIndex, SlayCardSpec),
+	NotInParty(ids::PlayerIndex, SlayCardSpec),
+	// The item or modifier is attached to that hero in the player's party.
+	Equipped(ids::PlayerIndex, SlayCardSpec, SlayCardSpec),
+	SlainMonsters(ids::PlayerIndex, usize),
+	InDiscard(SlayCardSpec),
+	DiscardSize(usize),
+	DrawSize(usize),
+	ActionPoints(ids::PlayerIndex, u32),
+	HasEffect(ids::PlayerIndex, PlayerStatusEffect),
+	// The player is being asked something, for cards that say "may".
+	Offered(ids::PlayerIndex, Choice),
+	NotOffered(ids::PlayerIndex, Choice),
+	// The first player is not asked to pick the second one for anything.
+	NotOfferedPlayer(ids::PlayerIndex, ids::PlayerIndex),
+	HandVisibleTo(ids::PlayerIndex, ids::PlayerIndex),
+}
+
+#[derive(Debug, Clone)]
+pub struct CardCase {
+	pub card: SlayCardSpec,
+	// What the case checks, when a card has more than one.
+	pub name: &'static str,
+	pub board: GameBuilder,
+	pub dice: Vec<Roll>,
+	pub steps: Vec<Step>,
+	pub expected: Vec<Expect>,
+	// Why the engine does not do what the card says yet. The case still runs, and is reported
+	// once it passes so that the mark comes off.
+	pub known_failure: Option<&'static str>,
+}
+
+impl CardCase {
+	pub fn new(card: SlayCardSpec, board: GameBuilder) -> Self {
+		Self {
+			card,
+			name: "",
+			board,
+			dice: Vec::new(),
+			steps: Vec::new(),
+			expected: Vec::new(),
+			known_failure: None,
+		}
+	}
+
+	pub fn named(mut self, name: &'static str) -> Self {
+		self.name = name;
+		self
+	}
+
+	pub fn dice(mut self, dice: Vec<Roll>) -> Self {
+		self.dice = dice;
+		self
+	}
+
+	pub fn steps(mut self, steps: Vec<Step>) -> Self {
+		self.steps = steps;
+		self
+	}
+
+	pub fn expect(mut self, expected: Vec<Expect>) -> Self {
+		self.expected = expected;
+		self
+	}
+
+	pub fn known_failure(mut self, reason: &'static str) -> Self {
+		self.known_failure = Some(reason);
+		self
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct CaseFailure {
+	pub card: SlayCardSpec,
+	pub name: &'static str,
+	pub reason: String,
+}
+
+impl fmt::Display for CaseFailure {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{:?}", self.card)?;
+		if !self.name.is_empty() {
+			write!(f, " ({})", self.name)?;
+		}
+		write!(f, ": {}\n\t\"{}\"", self.reason, self.card.description())
+	}
+}
+
+fn offered(game: &Game, player_index: ids::PlayerIndex) -> Vec<Choice> {
+	game.players[player_index]
+		.choices_
+		.iter()
+		.flat_map(|choices| {
+			choices
+				.options
+				.iter()
+				.map(|option| option.choice.to_owned())
+		})
+		.collect()
+}
+
+fn is_showdown_choice(choices_type: &ChoicesType) -> bool {
+	matches!(
+		choices_type,
+		ChoicesType::OfferChallenges | ChoicesType::ModifyRoll | ChoicesType::ModifyChallenge
+	)
+}
+
+fn choose(
+	context: &mut GameBookKeeping,
+	game: &mut Game,
+	player_index: ids::PlayerIndex,
+	choice_id: ids::ChoiceId,
+) -> Result<(), String> {
+	driver::make_selection(game, player_index, choice_id, &mut |_| {})
+		.map_err(|error| error.to_string())?;
+	driver::advance_game(context, game).map_err(|error| error.to_string())?;
+	Ok(())
+}
+
+fn pass(context: &mut GameBookKeeping, game: &mut Game) -> Result<(), String> {
+	// Each pass can only lead to more showdowns by way of a card, so this ends.
+	for _ in 0..100 {
+		let default = game.players.iter().find_map(|player| {
+			player.choices_.as_ref().and_then(|choices| {
+				if is_showdown_choice(&choices.choices_type) {
+					choices
+						.default_choice
+						.map(|choice_id| (player.player_index, choice_id))
+				} else {
+					None
+				}
+			})
+		});
+		match default {
+			Some((player_index, choice_id)) => choose(context, game, player_index, choice_id)?,
+			None => return Ok(()),
+		}
+	}
+	Err(String::from("The showdowns never ended."))
+}
+
+fn choose_matching(
+	context: &mut GameBookKeeping,
+	game: &mut Game,
+	player_index: ids::PlayerIndex,
+	wanted: &dyn std::fmt::Debug,
+	matches: &dyn Fn(&Choice) -> bool,
+) -> Result<(), String> {
+	let choice_id = game.players[player_index]
+		.choices_
+		.as_ref()
+		.and_then(|choices| {
+			choices
+				.options
+				.iter()
+				.find(|option| matches(&option.choice))
+		})
+		.map(|option| option.id)
+		.ok_or_else(|| {
+			format!(
+				"Player {} was not offered {:?}, only {:?}",
+				player_index,
+				wanted,
+				offered(game, player_index)
+			)
+		})?;
+	choose(context, game, player_index, choice_id)
+}
+
+fn sets_player(choice: &Choice, target: ids::PlayerIndex) -> bool {
+	matches!(choice, Choice::SetPlayerParam(_, player_index) if *player_index == target)
+}
+
+fn take_step(context: &mut GameBookKeeping, game: &mut Game, step: &Step) -> Result<(), String> {
+	match step {
+		Step::Choose(player_index, choice) => {
+			choose_matching(context, game, *player_index, choice, &|offered| {
+				offered == choice
+			})
+		}
+		Step::ChoosePlayer(player_index, target) => {
+			choose_matching(context, game, *player_index, step, &|offered| {
+				sets_player(offered, *target)
+			})
+		}
+		Step::ChooseCard(player_index, spec) => choose_matching(
+			context,
+			game,
+			*player_index,
+			step,
+			&|offered| matches!(offered, Choice::SetCardParameter(_, card) if card == spec),
+		),
+		Step::Pass => pass(context, game),
+	}
+}
+
+fn party_has(game: &Game, player_index: ids::PlayerIndex, spec: &SlayCardSpec) -> bool {
+	game.players[player_index]
+		.party
+		.tops(&game.cards)
+		.any(|card| card.card_type == *spec)
+}
+
+fn check(game: &Game, expect: &Expect) -> Option<String> {
+	let (holds, actual) = match expect {
+		Expect::HandSize(player_index, size) => {
+			let actual = game.players[*player_index].hand.num_top_cards();
+			(actual == *size, format!("{}", actual))
+		}
+		Expect::InHand(player_index, spec) | Expect::NotInHand(player_index, spec) => {
+			let hand = &game.players[*player_index].hand;
+			let found = hand.tops(&game.cards).any(|card| card.card_type == *spec);
+			let cards: Vec<SlayCardSpec> = hand.tops(&game.cards).map(|card| card.card_type).collect();
+			(
+				found == matches!(expect, Expect::InHand(_, _)),
+				format!("{:?}", cards),
+			)
+		}
+		Expect::PartySize(player_index, size) => {
+			let actual = game.players[*player_index].party.num_top_cards();
+			(actual == *size, format!("{}", actual))
+		}
+		Expect::InParty(player_index, spec) | Expect::NotInParty(player_index, spec) => {
+			let party = &game.players[*player_index].party;
+			let cards: Vec<SlayCardSpec> = party.tops(&game.cards).map(|card| card.card_type).collect();
+			(
+				party_has(game, *player_index, spec) == matches!(expect, Expect::InParty(_, _)),
+				format!("{:?}", cards),
+			)
+		}
+		Expect::Equipped(player_index, hero, modifier) => {
+			let stacks: Vec<(SlayCardSpec, Vec<SlayCardSpec>)> = game.players[*player_index]
+				.party
+				.stacks()
+				.map(|stack| {
+					(
+						game.cards.card_type(stack.top),
+						stack
+							.modifiers
+							.iter()
+							.map(|card_id| game.cards.card_type(*card_id))
+							.collect(),
+					)
+				})
+				.collect();
+			(
+				stacks
+					.iter()
+					.any(|(top, modifiers)| top == hero && modifiers.contains(modifier)),
+				format!("{:?}", stacks),
+			)
+		}
+		Expect::SlainMonsters(player_index, size) => {
+			let actual = game.players[*player_index].slain_monsters.num_top_cards();
+			(actual == *size, format!("{}", actual))
+		}
+		Expect::InDiscard(spec) => {
+			let cards: Vec<SlayCardSpec> = game
+				.discard
+				.tops(&game.cards)
+				.map(|card| card.card_type)
+				.collect();
+			(cards.contains(spec), format!("{:?}", cards))
+		}
+		Expect::DiscardSize(size) => {
+			let actual = game.deck(DeckPath::Discard).num_top_cards();
+			(actual == *size, format!("{}", actual))
+		}
+		Expect::DrawSize(size) => {
+			let actual = game.deck(DeckPath::Draw).num_top_cards();
+			(actual == *size, format!("{}", actual))
+		}
+		Expect::ActionPoints(player_index, points) => {
+			let actual = game.players[*player_index].get_remaining_action_points();
+			(actual == *points, format!("{}", actual))
+		}
+		Expect::HasEffect(player_index, effect) => {
+			let effects: Vec<PlayerStatusEffect> = game.players[*player_index]
+				.player_effects(&game.cards)
+				.map(|entry| entry.modifier)
+				.collect();
+			(effects.contains(effect), format!("{:?}", effects))
+		}
+		Expect::Offered(player_index, choice) | Expect::NotOffered(player_index, choice) => {
+			let choices = offered(game, *player_index);
+			(
+				choices.contains(choice) == matches!(expect, Expect::Offered(_, _)),
+				format!("{:?}", choices),
+			)
+		}
+		Expect::NotOfferedPlayer(player_index, target) => {
+			let choices = offered(game, *player_index);
+			(
+				!choices.iter().any(|choice| sets_player(choice, *target)),
+				format!("{:?}", choices),
+			)
+		}
+		Expect::HandVisibleTo(owner_index, viewer_index) => {
+			let visible = &game.players[*viewer_index].visible_hands;
+			(visible.contains(owner_index), format!("{:?}", visible))
+		}
+	};
+	if holds {
+		None
+	} else {
+		Some(format!("Expected {:?}, found {}", expect, actual))
+	}
+}
+
+fn play(case: &CardCase) -> Result<(), String> {
+	let context = &mut GameBookKeeping::with_seed(0);
+	context.script_dice(case.dice.to_owned());
+	let game = &mut case.board.build(context);
+	for (index, step) in case.steps.iter().enumerate() {
+		take_step(context, game, step).map_err(|reason| format!("Step {}: {}", index + 1, reason))?;
+	}
+	if let Some(violation) = game.check_invariants().first() {
+		return Err(format!("Invariant violated: {}", violation));
+	}
+	let failures: Vec<String> = case
+		.expected
+		.iter()
+		.filter_map(|expect| check(game, expect))
+		.collect();
+	if failures.is_empty() {
+		Ok(())
+	} else {
+		Err(failures.join("\n\t"))
+	}
+}
+
+pub fn run_case(case: &CardCase) -> Result<(), CaseFailure> {
+	let result = panic::catch_unwind(AssertUnwindSafe(|| play(case)))
+		.unwrap_or_else(|payload| Err(format!("Panicked: {}", fuzz::panic_message(payload))));
+	result.map_err(|reason| CaseFailure {
+		card: case.card,
+		name: case.name,
+		reason,
+	})
+}
+
+// The cases that fail, other than the known failures, and the known failures that pass.
+pub fn run_cases(cases: &[CardCase]) -> Vec<CaseFailure> {
+	cases
+		.iter()
+		.filter_map(|case| match (run_case(case), case.known_failure) {
+			(Err(failure), None) => Some(failure),
+			(Ok(()), Some(reason)) => Some(CaseFailure {
+				card: case.card,
+				name: case.name,
+				reason: format!("Passes, but is marked as a known failure: {}", reason),
+			}),
+			_ => None,
+		})
+		.collect()
+}
+
+pub fn run_all() -> Vec<CaseFailure> {
+	run_cases(&cases::all_cases())
+}
+
+pub fn known_failures() -> Vec<CardCase> {
+	cases::all_cases()
+		.into_iter()
+		.filter(|case| case.known_failure.is_some())
+		.collect()
+}
+
+// The cards of the original game, other than modifiers and challenges, that no case plays.
+pub fn uncovered_cards() -> Vec<SlayCardSpec> {
+	let covered: Vec<SlayCardSpec> = cases::all_cases().iter().map(|case| case.card).collect();
+	all::<SlayCardSpec>()
+		.filter(|spec| spec.game_version() == GameVersion::Original)
+		.filter(|spec| !spec.get_card_spec_creation().ignore)
+		.filter(|spec| {
+			!matches!(
+				spec,
+				SlayCardSpec::ModifierCard(_) | SlayCardSpec::Challenge
+			)
+		})
+		.filter(|spec| !covered.contains(spec))
+		.collect()
+}
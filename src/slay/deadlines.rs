@@ -1,6 +1,59 @@
 use chrono::DateTime;
 use chrono::Duration;
 use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::slay::game_context::GameBookKeeping;
+
+dyn_clone::clone_trait_object!(Clock);
+
+// Where the engine and the lobby get the time from, so that tests and replays can decide it.
+pub trait Clock: Debug + dyn_clone::DynClone {
+	fn now(&self) -> DateTime<Utc>;
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SystemClock {}
+
+impl Clock for SystemClock {
+	fn now(&self) -> DateTime<Utc> {
+		chrono::offset::Utc::now()
+	}
+}
+
+// Only moves when told to. Clones share the same time, so a test can keep one to move the
+// clock it handed to the game.
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+	now: Arc<Mutex<DateTime<Utc>>>,
+}
+
+impl ManualClock {
+	pub fn new(now: DateTime<Utc>) -> Self {
+		Self {
+			now: Arc::new(Mutex::new(now)),
+		}
+	}
+
+	pub fn set(&self, now: DateTime<Utc>) {
+		*self.now.lock().expect("The clock was poisoned") = now;
+	}
+
+	pub fn advance(&self, duration: Duration) {
+		*self.now.lock().expect("The clock was poisoned") += duration;
+	}
+}
+
+impl Clock for ManualClock {
+	fn now(&self) -> DateTime<Utc> {
+		*self.now.lock().expect("The clock was poisoned")
+	}
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct TimelineCompletion {
@@ -8,7 +61,7 @@ pub struct TimelineCompletion {
 	pub seconds_remaining: f64,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Timeline {
 	// #[serde(with = "ts_milliseconds_option")]
 	begin_time: DateTime<Utc>,
@@ -17,8 +70,7 @@ pub struct Timeline {
 }
 
 impl Timeline {
-	pub fn new(duration_seconds: Option<i64>) -> Self {
-		let now = chrono::offset::Utc::now();
+	pub fn new(now: DateTime<Utc>, duration_seconds: Option<i64>) -> Self {
 		Self {
 			begin_time: now,
 			duration_seconds,
@@ -32,8 +84,7 @@ impl Timeline {
 			.map(|duration_seconds| self.begin_time + Duration::seconds(duration_seconds))
 	}
 
-	pub fn completion(&self) -> Option<TimelineCompletion> {
-		let now = chrono::offset::Utc::now();
+	pub fn completion(&self, now: DateTime<Utc>) -> Option<TimelineCompletion> {
 		self.compute_deadline().map(|deadline| TimelineCompletion {
 			percent_complete: (now - self.begin_time).num_milliseconds() as f64
 				/ (deadline - self.begin_time).num_milliseconds() as f64,
@@ -41,45 +92,148 @@ impl Timeline {
 		})
 	}
 
-	pub(crate) fn reset(&mut self) {
-		self.begin_time = chrono::offset::Utc::now();
+	pub(crate) fn reset(&mut self, now: DateTime<Utc>) {
+		self.begin_time = now;
 	}
 
-	pub(crate) fn is_complete(&self) -> bool {
+	pub fn is_complete(&self, now: DateTime<Utc>) -> bool {
 		if let Some(deadline) = self.compute_deadline() {
-			chrono::offset::Utc::now() > deadline
+			now > deadline
 		} else {
 			false
 		}
 	}
 }
 
+// The wall clock, for views that count down. The engine asks its GameBookKeeping instead.
 pub fn current_time() -> DateTime<Utc> {
 	chrono::offset::Utc::now()
 }
 
-pub fn get_action_point_choice_deadline() -> Timeline {
-	Timeline::new(Some(300))
+// How long players get to respond, chosen when the game is created.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Timers {
+	pub choice_seconds: Option<i64>,
+	pub showdown_seconds: Option<i64>,
+}
+
+impl Default for Timers {
+	fn default() -> Self {
+		Self {
+			choice_seconds: Some(300),
+			showdown_seconds: Some(300),
+		}
+	}
+}
+
+pub fn get_action_point_choice_deadline(context: &GameBookKeeping) -> Timeline {
+	Timeline::new(context.now(), context.timers.choice_seconds)
 }
 
-pub fn get_refactor_me_deadline() -> Timeline {
-	Timeline::new(Some(300))
+pub fn get_refactor_me_deadline(context: &GameBookKeeping) -> Timeline {
+	Timeline::new(context.now(), context.timers.choice_seconds)
 }
-pub fn get_discard_deadline() -> Timeline {
-	Timeline::new(Some(300))
+pub fn get_discard_deadline(context: &GameBookKeeping) -> Timeline {
+	Timeline::new(context.now(), context.timers.choice_seconds)
 }
 
-pub fn get_sacrifice_deadline() -> Timeline {
-	Timeline::new(Some(300))
+pub fn get_sacrifice_deadline(context: &GameBookKeeping) -> Timeline {
+	Timeline::new(context.now(), context.timers.choice_seconds)
 }
 
-pub fn get_roll_deadline() -> Timeline {
-	Timeline::new(Some(300))
+pub fn get_roll_deadline(context: &GameBookKeeping) -> Timeline {
+	Timeline::new(context.now(), context.timers.showdown_seconds)
 }
 
-pub fn get_challenge_deadline() -> Timeline {
-	Timeline::new(Some(300))
+pub fn get_challenge_deadline(context: &GameBookKeeping) -> Timeline {
+	Timeline::new(context.now(), context.timers.showdown_seconds)
 }
-pub fn get_offer_challenges_deadline() -> Timeline {
-	Timeline::new(Some(300))
+pub fn get_offer_challenges_deadline(context: &GameBookKeeping) -> Timeline {
+	Timeline::new(context.now(), context.timers.showdown_seconds)
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::Duration;
+
+	use crate::slay::choices::Action;
+	use crate::slay::choices::Choice;
+	use crate::slay::deadlines;
+	use crate::slay::deadlines::Clock;
+	use crate::slay::deadlines::ManualClock;
+	use crate::slay::deadlines::Timeline;
+	use crate::slay::deadlines::TimelineCompletion;
+	use crate::slay::deadlines::Timers;
+	use crate::slay::driver;
+	use crate::slay::game_context::GameBookKeeping;
+	use crate::slay::showdown::roll::Roll;
+	use crate::slay::specs::cards::card_type::SlayCardSpec;
+	use crate::slay::specs::hero::HeroAbilityType;
+	use crate::slay::specs::modifier::ModifierKinds;
+	use crate::slay::state::builder::GameBuilder;
+	use crate::slay::state::builder::StackBuilder;
+
+	#[test]
+	fn a_timeline_completes_once_its_deadline_has_passed() {
+		let clock = ManualClock::new(deadlines::current_time());
+		let timeline = Timeline::new(clock.now(), Some(10));
+		clock.advance(Duration::seconds(5));
+		assert_eq!(
+			timeline.completion(clock.now()),
+			Some(TimelineCompletion {
+				percent_complete: 0.5,
+				seconds_remaining: 5.0,
+			})
+		);
+		clock.advance(Duration::seconds(5));
+		assert!(!timeline.is_complete(clock.now()));
+		clock.advance(Duration::milliseconds(1));
+		assert!(timeline.is_complete(clock.now()));
+		assert!(!Timeline::new(clock.now(), None).is_complete(clock.now() + Duration::days(1)));
+	}
+
+	#[test]
+	fn a_roll_nobody_answers_finishes_at_its_deadline() {
+		let clock = ManualClock::new(deadlines::current_time());
+		let context = &mut GameBookKeeping::new();
+		context.clock = Box::new(clock.clone());
+		context.timers = Timers {
+			choice_seconds: None,
+			showdown_seconds: Some(10),
+		};
+		let peanut = SlayCardSpec::HeroCard(HeroAbilityType::Peanut);
+		let modifier = SlayCardSpec::ModifierCard(ModifierKinds::Plus4);
+		let mut game = GameBuilder::new(2)
+			.party(0, vec![StackBuilder::new(peanut)])
+			.hand(1, vec![modifier])
+			.draw_pile(vec![modifier; 4])
+			.build(context);
+		context.script_dice(vec![Roll::new(1, 1)]);
+
+		let roll_for_peanut = Choice::UseActionPoints(Action::RollForAbility(HeroAbilityType::Peanut));
+		let choice_id = game.players[0]
+			.choices_
+			.as_ref()
+			.and_then(|choices| {
+				choices
+					.options
+					.iter()
+					.find(|option| option.choice == roll_for_peanut)
+			})
+			.unwrap()
+			.id;
+		driver::make_selection(&mut game, 0, choice_id, &mut |_| {}).unwrap();
+		driver::advance_game(context, &mut game).unwrap();
+		assert!(game.showdown.get_roll().is_some());
+		assert!(game.players[1].choices_.is_some());
+
+		clock.advance(Duration::seconds(10));
+		driver::advance_game(context, &mut game).unwrap();
+		assert!(game.showdown.get_roll().is_some());
+
+		clock.advance(Duration::seconds(1));
+		driver::advance_game(context, &mut game).unwrap();
+		assert!(game.showdown.get_roll().is_none());
+		assert!(game.players[1].choices_.is_none());
+	}
 }
@@ -4,23 +4,25 @@ use crate::slay::errors::SlayResult;
 use crate::slay::game_context::GameBookKeeping;
 use crate::slay::ids;
 use crate::slay::notification::Notification;
+use crate::slay::showdown::current_showdown;
+use crate::slay::state::arena::CardArena;
 use crate::slay::state::game::Game;
 use crate::slay::state::initialize;
+use crate::slay::state::initialize::GameSetup;
 use crate::slay::state::player::Player;
-use crate::slay::state::summarizable::Summarizable;
+use crate::slay::state::summarizable;
 use crate::slay::strategy;
 use crate::slay::tasks::player_tasks;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
 
 use std::collections::HashSet;
-use std::io::BufWriter;
 
 use log::LevelFilter;
 // use simple_logging;
 
-pub fn player_has_won(player: &Player) -> bool {
+pub fn player_has_won(cards: &CardArena, player: &Player) -> bool {
 	let hero_types = &mut HashSet::new();
-	player.collect_hero_types(hero_types);
+	player.collect_hero_types(cards, hero_types);
 
 	let num_monsters = player.slain_monsters.num_top_cards();
 	if num_monsters >= 3 {
@@ -46,7 +48,7 @@ pub fn player_has_won(player: &Player) -> bool {
 
 pub fn game_is_over(game: &Game) -> Option<ids::PlayerIndex> {
 	for player in game.players.iter() {
-		if player_has_won(player) {
+		if player_has_won(&game.cards, player) {
 			return Some(player.player_index);
 		}
 	}
@@ -63,13 +65,17 @@ fn use_action_points(context: &mut GameBookKeeping, game: &mut Game) {
 	game.increment();
 	context.emit(&Notification::PlayersTurn(game.active_player_index()));
 	game.clear_expired_modifiers();
-	game.current_player_mut().turn_begin();
+	game.begin_turn();
 	list_actions::assign_action_choices(context, game);
 }
 
+// Tasks that keep making progress without ever needing a player are a bug, not a game.
+const MAX_ADVANCE_ITERATIONS: u32 = 10000;
+
 pub enum AdvanceGameResult {
 	GameOver,
 	WaitingForPlayers,
+	Livelocked,
 	// ContinueAdvancing,
 }
 
@@ -91,6 +97,20 @@ fn run_tasks(context: &mut GameBookKeeping, game: &mut Game) -> SlayResult<TaskP
 	Ok(result)
 }
 
+// Debug builds stop at the first step that breaks the game, rather than wherever it shows up later.
+fn debug_check_invariants(game: &Game) {
+	if !cfg!(debug_assertions) {
+		return;
+	}
+	let violations = game.check_invariants();
+	for violation in violations.iter() {
+		log::error!("Invariant violated: {}", violation);
+	}
+	if let Some(violation) = violations.first() {
+		panic!("Invariant violated: {}", violation);
+	}
+}
+
 pub fn advance_game(
 	context: &mut GameBookKeeping,
 	game: &mut Game,
@@ -98,22 +118,33 @@ pub fn advance_game(
 	let mut iteration = 0;
 	loop {
 		iteration += 1;
-		if iteration > 10000 {
-			unreachable!();
+		if iteration > MAX_ADVANCE_ITERATIONS {
+			log::error!(
+				"The game made no progress towards a choice in {} steps.",
+				MAX_ADVANCE_ITERATIONS
+			);
+			return Ok(AdvanceGameResult::Livelocked);
 		}
+		debug_check_invariants(game);
 
 		if let Some(winner_index) = game_is_over(game) {
 			context.emit(&Notification::PlayerWon(winner_index));
 			return Ok(AdvanceGameResult::GameOver);
 		}
-		if let Some(mut showdown) = game.showdown.take_complete() {
-			game.clear_choices();
+		if let Some(mut showdown) = game.showdown.take_complete(context.now()) {
+			current_showdown::clear_showdown_choices(game);
 			showdown.finish(context, game);
 			continue;
 		}
 
 		match run_tasks(context, game)? {
-			TaskProgressResult::NothingDone => break,
+			// A suspended showdown carries on once everything started on top of it is done.
+			TaskProgressResult::NothingDone => {
+				if !waiting_for_players(game) && current_showdown::resume(context, game) {
+					continue;
+				}
+				break;
+			}
 			TaskProgressResult::ProgressMade | TaskProgressResult::TaskComplete => continue,
 		}
 	}
@@ -124,6 +155,7 @@ pub fn advance_game(
 		}
 		use_action_points(context, game);
 	}
+	debug_check_invariants(game);
 	Ok(AdvanceGameResult::WaitingForPlayers)
 }
 
@@ -159,13 +191,7 @@ pub fn make_selection(
 }
 
 fn game_to_string(game: &Game) -> String {
-	let mut writer = BufWriter::new(Vec::new());
-	game
-		.summarize(&mut writer, 0)
-		.expect("Error writing to file");
-	let bytes = writer.into_inner().expect("whoops");
-
-	String::from_utf8(bytes).expect("error logging state")
+	summarizable::summary(game)
 }
 
 pub fn game_loop() -> SlayResult<()> {
@@ -174,7 +200,7 @@ pub fn game_loop() -> SlayResult<()> {
 	let context = &mut GameBookKeeping::new();
 	let game = &mut Game::new();
 
-	initialize::initialize_game(context, game);
+	initialize::initialize_game(context, game, &GameSetup::default());
 
 	let mut iteration = 0;
 	'turns: loop {
@@ -186,7 +212,8 @@ pub fn game_loop() -> SlayResult<()> {
 			// return Err(SlayError::new("Hit maximum iterations"));
 		}
 
-		{
+		// Summarizing the whole game costs more than playing a choice, so only do it when it is logged.
+		if log::log_enabled!(log::Level::Info) {
 			// log::info!("Writing iteration {} to file.", iteration);
 			// let write_file = File::create(
 			// 	format!("./output/iteration_{:04}.txt", iteration))
@@ -228,6 +255,10 @@ pub fn game_loop() -> SlayResult<()> {
 				return Ok(());
 			}
 			AdvanceGameResult::WaitingForPlayers => continue 'turns,
+			AdvanceGameResult::Livelocked => {
+				log::warn!("The game livelocked: {}", game_to_string(game));
+				return Err(SlayError::new("The game stopped making progress."));
+			}
 		}
 	}
 }
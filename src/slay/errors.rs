@@ -10,6 +10,8 @@ pub struct SlayError {
 pub type SlayResult<T> = std::result::Result<T, SlayError>;
 
 impl SlayError {
+	// These panic for now, so the panic is reported where the error was raised.
+	#[track_caller]
 	pub fn n(reason: String) -> SlayError {
 		log::info!("Reason: {}", reason);
 		unreachable!();
@@ -17,6 +19,7 @@ impl SlayError {
 		// 	reason: reason.to_string(),
 		// }
 	}
+	#[track_caller]
 	pub fn new(reason: &'static str) -> SlayError {
 		log::info!("Reason: {}", reason);
 		unreachable!();
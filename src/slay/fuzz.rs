@@ -0,0 +1,436 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::mem;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+
+use rand::Rng;
+
+use crate::slay::driver;
+use crate::slay::driver::AdvanceGameResult;
+use crate::slay::game_context::GameBookKeeping;
+use crate::slay::ids;
+use crate::slay::state::game::Game;
+use crate::slay::state::initialize;
+use crate::slay::state::initialize::GameSetup;
+use crate::slay::tasks::trace::TaskTrace;
+
+/*
+	Plays seeded random games, checking the invariants after every choice.
+	A game is its seed and the picks made, where a pick is an index into every option offered at
+	that point (players in seat order, then their options in order). Picks are taken modulo the
+	number of options, so any list of numbers replays as some game.
+	That makes failures easy to shrink: drop picks, or make them smaller, while the game still fails
+	the same way.
+*/
+
+// Each replay while shrinking is a whole game, so shrinking stops after this many for each pick
+// the failing game took.
+const SHRINK_REPLAYS_PER_PICK: usize = 8;
+
+thread_local! {
+	// Where the last panic on this thread happened, once record_panic_locations has been called.
+	static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FuzzFailure {
+	Panicked {
+		message: String,
+		location: Option<String>,
+	},
+	EngineError(String),
+	InvariantViolated(String),
+	Livelocked,
+	NoChoices,
+}
+
+impl fmt::Display for FuzzFailure {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Panicked {
+				message,
+				location: Some(location),
+			} => write!(f, "The engine panicked at {}: {}", location, message),
+			Self::Panicked {
+				message,
+				location: None,
+			} => write!(f, "The engine panicked: {}", message),
+			Self::EngineError(reason) => write!(f, "The engine failed: {}", reason),
+			Self::InvariantViolated(violation) => write!(f, "Invariant violated: {}", violation),
+			Self::Livelocked => write!(f, "The game stopped making progress."),
+			Self::NoChoices => write!(f, "Nobody had anything to choose."),
+		}
+	}
+}
+
+impl FuzzFailure {
+	// Panic messages and violations name ids that move around while shrinking, where a panic
+	// happened does not.
+	fn same_kind(&self, other: &FuzzFailure) -> bool {
+		match (self, other) {
+			(
+				Self::Panicked { location, .. },
+				Self::Panicked {
+					location: other_location,
+					..
+				},
+			) => location == other_location,
+			_ => mem::discriminant(self) == mem::discriminant(other),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzCase {
+	pub seed: u64,
+	pub picks: Vec<usize>,
+	pub failure: FuzzFailure,
+}
+
+impl fmt::Display for FuzzCase {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"Seed {} after {} picks {:?}: {}",
+			self.seed,
+			self.picks.len(),
+			self.picks,
+			self.failure
+		)
+	}
+}
+
+fn offered_choices(game: &Game) -> Vec<(ids::PlayerIndex, ids::ChoiceId)> {
+	game
+		.players
+		.iter()
+		.flat_map(|player| {
+			player.choices_.iter().flat_map(move |choices| {
+				choices
+					.options
+					.iter()
+					.map(move |option| (player.player_index, option.id))
+			})
+		})
+		.collect()
+}
+
+// What makes a game fail, besides panics and errors.
+type Check = fn(&Game) -> Option<FuzzFailure>;
+
+fn check_invariants(game: &Game) -> Option<FuzzFailure> {
+	game
+		.check_invariants()
+		.first()
+		.map(|violation| FuzzFailure::InvariantViolated(violation.to_string()))
+}
+
+fn play_until_failure(
+	context: &mut GameBookKeeping,
+	game: &mut Game,
+	check: Check,
+	picks: &mut Vec<usize>,
+	next_pick: &mut dyn FnMut(&mut GameBookKeeping, usize) -> Option<usize>,
+) -> Option<FuzzFailure> {
+	initialize::initialize_game(context, game, &GameSetup::default());
+	loop {
+		if let Some(failure) = check(game) {
+			return Some(failure);
+		}
+		if game.get_turn().over_the_limit() {
+			return None;
+		}
+		let offered = offered_choices(game);
+		if offered.is_empty() {
+			return Some(FuzzFailure::NoChoices);
+		}
+		let pick = next_pick(context, offered.len())? % offered.len();
+		picks.push(pick);
+		let (player_index, choice_id) = offered[pick];
+		if let Err(error) = driver::make_selection(game, player_index, choice_id, &mut |_| {}) {
+			return Some(FuzzFailure::EngineError(error.to_string()));
+		}
+		match driver::advance_game(context, game) {
+			Ok(AdvanceGameResult::GameOver) => return check(game),
+			Ok(AdvanceGameResult::WaitingForPlayers) => {}
+			Ok(AdvanceGameResult::Livelocked) => return Some(FuzzFailure::Livelocked),
+			Err(error) => return Some(FuzzFailure::EngineError(error.to_string())),
+		}
+	}
+}
+
+// A panic's payload only has its message, so the hook notes where it happened as well.
+// The hook that was installed before still runs afterwards.
+pub fn record_panic_locations() {
+	let previous = panic::take_hook();
+	panic::set_hook(Box::new(move |info| {
+		let location = info
+			.location()
+			.map(|location| format!("{}:{}", location.file(), location.line()));
+		PANIC_LOCATION.with(|last| *last.borrow_mut() = location);
+		previous(info);
+	}));
+}
+
+pub(crate) fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+	if let Some(message) = payload.downcast_ref::<&str>() {
+		message.to_string()
+	} else if let Some(message) = payload.downcast_ref::<String>() {
+		message.to_owned()
+	} else {
+		String::from("unknown panic")
+	}
+}
+
+// The picks that were made, up to and including the one that failed.
+fn play(
+	context: &mut GameBookKeeping,
+	check: Check,
+	next_pick: &mut dyn FnMut(&mut GameBookKeeping, usize) -> Option<usize>,
+) -> (Vec<usize>, Option<FuzzFailure>) {
+	let mut picks = Vec::new();
+	let failure = catch_panics(|| {
+		let game = &mut Game::new();
+		play_until_failure(context, game, check, &mut picks, next_pick)
+	})
+	.unwrap_or_else(Some);
+	(picks, failure)
+}
+
+fn catch_panics<T>(f: impl FnOnce() -> T) -> Result<T, FuzzFailure> {
+	PANIC_LOCATION.with(|last| last.borrow_mut().take());
+	panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| FuzzFailure::Panicked {
+		message: panic_message(payload),
+		location: PANIC_LOCATION.with(|last| last.borrow_mut().take()),
+	})
+}
+
+// Picks at random, the way the bots do.
+pub fn explore(seed: u64, max_picks: usize) -> Option<FuzzCase> {
+	explore_with(seed, max_picks, check_invariants)
+}
+
+fn explore_with(seed: u64, max_picks: usize, check: Check) -> Option<FuzzCase> {
+	let mut remaining = max_picks;
+	let context = &mut GameBookKeeping::with_seed(seed);
+	let (picks, failure) = play(context, check, &mut |context, number_of_options| {
+		if remaining == 0 {
+			return None;
+		}
+		remaining -= 1;
+		Some(context.bot_rng.gen_range(0..number_of_options))
+	});
+	failure.map(|failure| FuzzCase {
+		seed,
+		picks,
+		failure,
+	})
+}
+
+// Stops without failing once the picks run out.
+pub fn replay(seed: u64, picks: &[usize]) -> Option<FuzzCase> {
+	replay_with(seed, picks, check_invariants)
+}
+
+fn replay_with(seed: u64, picks: &[usize], check: Check) -> Option<FuzzCase> {
+	let mut remaining = picks.iter();
+	let context = &mut GameBookKeeping::with_seed(seed);
+	let (picks, failure) = play(context, check, &mut |_, _| remaining.next().copied());
+	failure.map(|failure| FuzzCase {
+		seed,
+		picks,
+		failure,
+	})
+}
+
+// Replays the game, recording every task that ran up to where it stopped.
+pub fn trace(seed: u64, picks: &[usize]) -> (Option<FuzzCase>, TaskTrace) {
+	let mut remaining = picks.iter();
+	let context = &mut GameBookKeeping::with_seed(seed);
+	context.trace_tasks();
+	let (picks, failure) = play(context, check_invariants, &mut |_, _| {
+		remaining.next().copied()
+	});
+	let case = failure.map(|failure| FuzzCase {
+		seed,
+		picks,
+		failure,
+	});
+	(case, context.task_trace().cloned().unwrap_or_default())
+}
+
+type Replay<'a> = &'a mut dyn FnMut(u64, &[usize]) -> Option<FuzzCase>;
+
+fn still_fails(best: &FuzzCase, candidate: &[usize], replay: Replay) -> Option<FuzzCase> {
+	replay(best.seed, candidate).filter(|shrunk| {
+		let smaller = (shrunk.picks.len(), &shrunk.picks) < (best.picks.len(), &best.picks);
+		smaller && shrunk.failure.same_kind(&best.failure)
+	})
+}
+
+// Drops runs of picks, from half of them down to one at a time. A run that could be dropped is
+// followed by the next one at the same place, so nothing is tried twice.
+fn drop_picks(case: FuzzCase, replay: Replay, budget: &mut usize) -> FuzzCase {
+	let mut best = case;
+	let mut chunk = best.picks.len() / 2;
+	while chunk > 0 {
+		let mut start = 0;
+		while start < best.picks.len() {
+			if *budget == 0 {
+				return best;
+			}
+			*budget -= 1;
+			let mut candidate = best.picks[..start].to_vec();
+			candidate.extend_from_slice(&best.picks[(start + chunk).min(best.picks.len())..]);
+			match still_fails(&best, &candidate, replay) {
+				Some(shrunk) => best = shrunk,
+				None => start += chunk,
+			}
+		}
+		chunk = chunk.min(best.picks.len()) / 2;
+	}
+	best
+}
+
+// Makes each pick in turn the smallest one that still fails the same way.
+fn lower_picks(case: FuzzCase, replay: Replay, budget: &mut usize) -> FuzzCase {
+	let mut best = case;
+	let mut index = 0;
+	while index < best.picks.len() {
+		for smaller in 0..best.picks[index] {
+			if *budget == 0 {
+				return best;
+			}
+			*budget -= 1;
+			let mut candidate = best.picks.to_owned();
+			candidate[index] = smaller;
+			if let Some(shrunk) = still_fails(&best, &candidate, replay) {
+				best = shrunk;
+				break;
+			}
+		}
+		index += 1;
+	}
+	best
+}
+
+// Smaller picks can make more of them droppable, so both go on until neither helps.
+fn shrink_with(case: FuzzCase, replay: Replay) -> FuzzCase {
+	let mut budget = SHRINK_REPLAYS_PER_PICK * case.picks.len();
+	let mut best = case;
+	loop {
+		let shrunk = lower_picks(
+			drop_picks(best.to_owned(), replay, &mut budget),
+			replay,
+			&mut budget,
+		);
+		if shrunk == best || budget == 0 {
+			return shrunk;
+		}
+		best = shrunk;
+	}
+}
+
+// Keeps the smallest list of picks that still fails the same way.
+pub fn shrink(case: FuzzCase) -> FuzzCase {
+	shrink_with(case, &mut |seed, picks| replay(seed, picks))
+}
+
+// Every failing game among the seeds, shrunk, with the smallest first.
+pub fn run(first_seed: u64, games: u64, max_picks: usize) -> Vec<FuzzCase> {
+	let mut failures: Vec<FuzzCase> = (first_seed..first_seed + games)
+		.filter_map(|seed| explore(seed, max_picks))
+		.map(shrink)
+		.collect();
+	failures.sort_by_key(|case| case.picks.len());
+	failures
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::slay::fuzz;
+	use crate::slay::fuzz::FuzzCase;
+	use crate::slay::fuzz::FuzzFailure;
+	use crate::slay::state::game::Game;
+
+	fn panicked_at(location: &str) -> FuzzFailure {
+		FuzzFailure::Panicked {
+			message: String::from("entered unreachable code"),
+			location: Some(location.to_owned()),
+		}
+	}
+
+	// Stands in for a game that panics once a pick of two or more is followed by a pick of one.
+	fn replay(seed: u64, picks: &[usize]) -> Option<FuzzCase> {
+		let first = picks.iter().position(|pick| *pick >= 2)?;
+		let last = first + 1 + picks[first + 1..].iter().position(|pick| *pick == 1)?;
+		Some(FuzzCase {
+			seed,
+			picks: picks[..=last].to_vec(),
+			failure: panicked_at("src/slay/state/game.rs:185"),
+		})
+	}
+
+	#[test]
+	fn shrinks_to_the_fewest_and_smallest_picks() {
+		let picks = vec![0, 1, 0, 4, 0, 3, 5, 0, 2, 0, 3, 0, 0, 5, 1, 3, 0, 1];
+		let case = replay(7, &picks).unwrap();
+		let shrunk = fuzz::shrink_with(case, &mut replay);
+		assert_eq!(shrunk.seed, 7);
+		assert_eq!(shrunk.picks, vec![2, 1]);
+	}
+
+	#[test]
+	fn does_not_shrink_into_a_different_panic() {
+		let mut elsewhere = |seed: u64, picks: &[usize]| {
+			replay(seed, picks).map(|mut case| {
+				if case.picks.len() < 4 {
+					case.failure = panicked_at("src/slay/errors.rs:22");
+				}
+				case
+			})
+		};
+		let case = replay(7, &[0, 3, 0, 4, 1]).unwrap();
+		let shrunk = fuzz::shrink_with(case, &mut elsewhere);
+		assert_eq!(shrunk.picks.len(), 4);
+	}
+
+	// A stand in for a bug, that takes a real game a while to run into.
+	fn a_monster_was_slain(game: &Game) -> Option<FuzzFailure> {
+		game
+			.players
+			.iter()
+			.any(|player| player.slain_monsters.num_top_cards() > 0)
+			.then(|| FuzzFailure::InvariantViolated(String::from("A monster was slain.")))
+	}
+
+	#[test]
+	fn shrinks_a_long_game_to_a_few_picks() {
+		let case = fuzz::explore_with(3, 2000, a_monster_was_slain).unwrap();
+		assert!(case.picks.len() > 300, "{}", case);
+		let shrunk = fuzz::shrink_with(case, &mut |seed, picks| {
+			fuzz::replay_with(seed, picks, a_monster_was_slain)
+		});
+		assert!(shrunk.picks.len() < 40, "{}", shrunk);
+		assert_eq!(
+			fuzz::replay_with(3, &shrunk.picks, a_monster_was_slain),
+			Some(shrunk)
+		);
+	}
+
+	#[test]
+	fn panics_are_reported_where_they_happened() {
+		fuzz::record_panic_locations();
+		let failure = fuzz::catch_panics(|| -> usize { panic!("boom") }).unwrap_err();
+		let FuzzFailure::Panicked {
+			message,
+			location: Some(location),
+		} = failure
+		else {
+			panic!("No location for {:?}", failure);
+		};
+		assert_eq!(message, "boom");
+		assert!(location.starts_with("src/slay/fuzz.rs:"), "{}", location);
+	}
+}
@@ -1,15 +1,40 @@
+use crate::slay::deadlines::Clock;
+use crate::slay::deadlines::SystemClock;
+use crate::slay::deadlines::Timers;
 use crate::slay::ids;
+use crate::slay::showdown::dice::DiceSource;
+use crate::slay::showdown::dice::RandomDice;
+use crate::slay::showdown::dice::ScriptedDice;
+use crate::slay::showdown::roll::Roll;
+use crate::slay::tasks::trace::TaskTrace;
+use crate::slay::tasks::trace::TraceStep;
 
-use rand::rngs::ThreadRng;
+use chrono::DateTime;
+use chrono::Utc;
+use rand::rngs::StdRng;
 use rand::thread_rng;
+use rand::Rng;
+use rand::SeedableRng;
 
 use super::notification::Notification;
 
 #[derive(Clone)]
 pub struct GameBookKeeping {
 	pub id_generator: ids::IdGenerator,
-	pub rng: ThreadRng,
+	// Seeded so that a game can be replayed from its recorded choices.
+	pub rng: StdRng,
+	// Bots draw from their own rng, so that picking a choice does not change the game's rolls.
+	pub bot_rng: StdRng,
+	// Rolls from rng unless a test scripted them.
+	pub dice: Box<dyn DiceSource>,
+	seed: u64,
+	pub timers: Timers,
+	// Deadlines are measured against this, tests and replays swap in a ManualClock.
+	pub clock: Box<dyn Clock>,
 	// pub notifier: Option<Box<dyn Fn(Notification) -> ()>>,
+	notifications: Vec<Notification>,
+	// Only recorded once asked for, it grows with every task that runs.
+	task_trace: Option<TaskTrace>,
 }
 
 impl Default for GameBookKeeping {
@@ -20,14 +45,67 @@ impl Default for GameBookKeeping {
 
 impl GameBookKeeping {
 	pub fn new() -> Self {
+		Self::with_seed(thread_rng().gen())
+	}
+
+	pub fn with_seed(seed: u64) -> Self {
 		GameBookKeeping {
-			rng: thread_rng(),
+			rng: StdRng::seed_from_u64(seed),
+			bot_rng: StdRng::seed_from_u64(seed.wrapping_add(1)),
+			dice: Box::<RandomDice>::default(),
+			seed,
 			id_generator: ids::IdGenerator::new(),
+			timers: Default::default(),
+			clock: Box::<SystemClock>::default(),
+			notifications: Default::default(),
+			task_trace: None,
 		}
 	}
 
+	pub fn seed(&self) -> u64 {
+		self.seed
+	}
+
+	pub fn roll_dice(&mut self) -> Roll {
+		self.dice.roll(&mut self.rng)
+	}
+
+	// The next rolls are the given ones, in order.
+	pub fn script_dice(&mut self, rolls: Vec<Roll>) {
+		self.dice = Box::new(ScriptedDice::new(rolls));
+	}
+
+	pub fn now(&self) -> DateTime<Utc> {
+		self.clock.now()
+	}
+
 	pub fn emit(&mut self, notification: &Notification) {
 		log::info!("Notification: {:?}", notification);
 		// self.notifier.iter().for_each(|f| f(notification.to_owned()));
+		self.notifications.push(notification.to_owned());
+	}
+
+	// Hands over everything emitted since the last call.
+	pub fn take_notifications(&mut self) -> Vec<Notification> {
+		self.notifications.drain(..).collect()
+	}
+
+	// Records every task that runs from now on.
+	pub fn trace_tasks(&mut self) {
+		self.task_trace = Some(Default::default());
+	}
+
+	pub fn is_tracing_tasks(&self) -> bool {
+		self.task_trace.is_some()
+	}
+
+	pub(crate) fn trace_task(&mut self, step: TraceStep) {
+		if let Some(trace) = self.task_trace.as_mut() {
+			trace.record(step);
+		}
+	}
+
+	pub fn task_trace(&self) -> Option<&TaskTrace> {
+		self.task_trace.as_ref()
 	}
 }
@@ -0,0 +1,237 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use rand::Rng;
+
+use crate::slay::driver;
+use crate::slay::driver::AdvanceGameResult;
+use crate::slay::game_context::GameBookKeeping;
+use crate::slay::ids;
+use crate::slay::notification::Notification;
+use crate::slay::state::game::Game;
+use crate::slay::state::initialize;
+use crate::slay::state::initialize::GameSetup;
+use crate::slay::state::summarizable;
+
+/*
+	Golden snapshots of seeded games.
+	A snapshot is the summary of the game after every step, along with the choice that was made and
+	the notifications it caused. The summaries are canonical, so the same seed always writes the same
+	file, and a change to the engine shows up as a diff of how the game played out.
+*/
+
+// The seeds recorded in the goldens directory, and how far each game is recorded.
+pub const SEEDS: [u64; 3] = [0, 1, 2];
+pub const MAX_STEPS: usize = 40;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GoldenMismatch {
+	Missing(String),
+	Changed {
+		file: String,
+		line: usize,
+		expected: String,
+		actual: String,
+	},
+}
+
+impl fmt::Display for GoldenMismatch {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Missing(file) => write!(f, "{} has not been recorded yet.", file),
+			Self::Changed {
+				file,
+				line,
+				expected,
+				actual,
+			} => write!(
+				f,
+				"{} changed at line {}:\n  - {}\n  + {}",
+				file, line, expected, actual
+			),
+		}
+	}
+}
+
+fn offered_choices(game: &Game) -> Vec<(ids::PlayerIndex, &str, ids::ChoiceId, String)> {
+	game
+		.players
+		.iter()
+		.flat_map(|player| {
+			player.choices_.iter().flat_map(move |choices| {
+				choices.options.iter().map(move |option| {
+					(
+						player.player_index,
+						player.name.as_str(),
+						option.id,
+						option.choice.label(),
+					)
+				})
+			})
+		})
+		.collect()
+}
+
+fn write_notifications(snapshot: &mut String, notifications: &[Notification]) {
+	for notification in notifications.iter() {
+		snapshot.push_str(&summarizable::summary(notification));
+	}
+}
+
+// Plays the seed the way the bots would, for at most max_steps choices.
+pub fn record(seed: u64, max_steps: usize) -> String {
+	let context = &mut GameBookKeeping::with_seed(seed);
+	let game = &mut Game::new();
+	initialize::initialize_game(context, game, &GameSetup::default());
+
+	let mut snapshot = format!("seed {}\n", seed);
+	snapshot.push_str(&summarizable::summary(game));
+	for step in 1..=max_steps {
+		let offered = offered_choices(game);
+		if offered.is_empty() {
+			snapshot.push_str("nobody has anything to choose\n");
+			break;
+		}
+		let pick = context.bot_rng.gen_range(0..offered.len());
+		let (player_index, player_name, choice_id, label) = &offered[pick];
+		snapshot.push_str(&format!(
+			"\nstep {}: {} chose {}\n",
+			step, player_name, label
+		));
+
+		let mut notifications = Vec::new();
+		let selected = driver::make_selection(game, *player_index, *choice_id, &mut |notification| {
+			notifications.push(notification)
+		});
+		write_notifications(&mut snapshot, &notifications);
+		if let Err(error) = selected {
+			snapshot.push_str(&format!("error: {}\n", error));
+			break;
+		}
+		let advanced = driver::advance_game(context, game);
+		write_notifications(&mut snapshot, &context.take_notifications());
+		snapshot.push_str(&summarizable::summary(game));
+		match advanced {
+			Ok(AdvanceGameResult::WaitingForPlayers) => {}
+			Ok(AdvanceGameResult::GameOver) => {
+				snapshot.push_str("game over\n");
+				break;
+			}
+			Ok(AdvanceGameResult::Livelocked) => {
+				snapshot.push_str("livelocked\n");
+				break;
+			}
+			Err(error) => {
+				snapshot.push_str(&format!("error: {}\n", error));
+				break;
+			}
+		}
+	}
+	snapshot
+}
+
+fn file_name(seed: u64) -> String {
+	format!("seed_{}.txt", seed)
+}
+
+// Only the first changed line, the rest of the game usually follows from it.
+fn first_difference(file: String, expected: &str, actual: &str) -> Option<GoldenMismatch> {
+	let mut expected_lines = expected.lines();
+	let mut actual_lines = actual.lines();
+	let mut line = 1;
+	loop {
+		match (expected_lines.next(), actual_lines.next()) {
+			(None, None) => return None,
+			(expected, actual) if expected == actual => line += 1,
+			(expected, actual) => {
+				return Some(GoldenMismatch::Changed {
+					file,
+					line,
+					expected: expected.unwrap_or("<end of file>").to_owned(),
+					actual: actual.unwrap_or("<end of file>").to_owned(),
+				})
+			}
+		}
+	}
+}
+
+// Compares each seed against the snapshot recorded in the directory.
+pub fn check(dir: &Path, seeds: &[u64], max_steps: usize) -> io::Result<Vec<GoldenMismatch>> {
+	let mut mismatches = Vec::new();
+	for seed in seeds.iter() {
+		let name = file_name(*seed);
+		let path = dir.join(&name);
+		if !path.exists() {
+			mismatches.push(GoldenMismatch::Missing(name));
+			continue;
+		}
+		let expected = fs::read_to_string(path)?;
+		let actual = record(*seed, max_steps);
+		if let Some(mismatch) = first_difference(name, &expected, &actual) {
+			mismatches.push(mismatch);
+		}
+	}
+	Ok(mismatches)
+}
+
+// Accepts the current behaviour, overwriting whatever was recorded before.
+pub fn bless(dir: &Path, seeds: &[u64], max_steps: usize) -> io::Result<()> {
+	fs::create_dir_all(dir)?;
+	for seed in seeds.iter() {
+		fs::write(dir.join(file_name(*seed)), record(*seed, max_steps))?;
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use std::path::Path;
+
+	use crate::slay::golden;
+	use crate::slay::golden::GoldenMismatch;
+
+	#[test]
+	fn the_recorded_games_still_play_out_the_same() {
+		let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("goldens");
+		let mismatches = golden::check(&dir, &golden::SEEDS, golden::MAX_STEPS).unwrap();
+		assert!(
+			mismatches.is_empty(),
+			"{}\nRun `cargo run --bin golden bless` to accept the changes.",
+			mismatches
+				.iter()
+				.map(|mismatch| mismatch.to_string())
+				.collect::<Vec<_>>()
+				.join("\n")
+		);
+	}
+
+	#[test]
+	fn recording_a_seed_again_writes_the_same_snapshot() {
+		assert_eq!(golden::record(3, 10), golden::record(3, 10));
+	}
+
+	#[test]
+	fn only_the_first_changed_line_is_reported() {
+		let mismatch = golden::first_difference(
+			String::from("seed_0.txt"),
+			"seed 0\nstep 1\nstep 2\n",
+			"seed 0\nstep one\nstep two\n",
+		);
+		assert_eq!(
+			mismatch,
+			Some(GoldenMismatch::Changed {
+				file: String::from("seed_0.txt"),
+				line: 2,
+				expected: String::from("step 1"),
+				actual: String::from("step one"),
+			})
+		);
+		let mismatch = golden::first_difference(String::from("seed_0.txt"), "seed 0\n", "");
+		assert!(matches!(
+			mismatch,
+			Some(GoldenMismatch::Changed { line: 1, actual, .. }) if actual == "<end of file>"
+		));
+	}
+}
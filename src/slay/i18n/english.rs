@@ -0,0 +1,256 @@
+use crate::slay::i18n::keys::MessageKey;
+
+// Every key needs an English template, since the other locales fall back to it.
+pub fn template(key: MessageKey) -> &'static str {
+	match key {
+		MessageKey::You => "You",
+		MessageKey::PlayerNumber => "Player {0}",
+		MessageKey::NotApplicable => "N/A",
+		MessageKey::LanguageButton => "Language: {0}",
+
+		MessageKey::ButtonRestart => "Restart",
+		MessageKey::ButtonTestNext => "Test next",
+		MessageKey::ButtonHumans => "Humans: {0}",
+		MessageKey::ButtonViewMode => "View: {0}",
+		MessageKey::ButtonBotDelay => "Bot delay: {0}ms",
+		MessageKey::ButtonAutoPass => "Auto pass: {0}",
+		MessageKey::ButtonReplay => "Replay",
+		MessageKey::ViewModePlayer => "Player",
+		MessageKey::ViewModeSpectator => "Spectator",
+		MessageKey::ViewModeOmniscient => "God view",
+		MessageKey::AutoPassNever => "Never",
+		MessageKey::AutoPassWhenNoResponse => "When I can't respond",
+		MessageKey::AutoPassAlways => "Always",
+		MessageKey::PassDevice => "Pass the device to {0}",
+		MessageKey::PassDeviceAccept => "I am {0}",
+
+		MessageKey::ActionForfeit => "Do nothing this round.",
+		MessageKey::ActionPlaceHero => "Place {0} in your party",
+		MessageKey::ActionCastMagic => "Play {0}",
+		MessageKey::ActionPlaceItem => "Place {0} on some hero card.",
+		MessageKey::ActionDraw => "Draw a card.",
+		MessageKey::ActionReplaceHand => "Use 3 action points to replace your entire hand.",
+		MessageKey::ActionAttackMonster => "Attack {0}",
+		MessageKey::ActionUseLeader => "Use Shadow Claw to pull from another player's hand.",
+		MessageKey::ActionRollForAbility => "Roll for {0}",
+		MessageKey::CompletionDoneUntilModification => {
+			"Do not modify this roll, unless someone else does."
+		}
+		MessageKey::CompletionAllDone => "Do not modify this roll, even if someone else does.",
+		MessageKey::ChoiceModify => "Use {0} to modify {1} by {2}",
+		MessageKey::ChoiceChallenge => "Challenge!",
+		MessageKey::ChoiceSetPlayerParam => "Pick {1} for: {0}",
+		MessageKey::ChoiceSetCardParam => "Pick {1} for: {0}",
+		MessageKey::ChoiceDiscardedCard => "{0}",
+		MessageKey::ChoiceReturnItem => "Return {0} from {1}",
+		MessageKey::ChoicePlayImmediately => "Play {0} immediately",
+		MessageKey::ChoiceDoNotPlayImmediately => "Do not play immediately",
+		MessageKey::ChoiceDiscard => "Discard {0}",
+		MessageKey::ChoiceSacrifice => "Sacrifice {0}",
+		MessageKey::ChoiceGiveCard => "Give {0} to {1}.",
+		MessageKey::ChoiceQuit => "No",
+		MessageKey::ChoiceContinueDiscardingAndDestroying => "Discard and destroy again",
+		MessageKey::ChoiceRevealChallengeAndDestroy => {
+			"Reveal that you pulled a challenge card, so you can destroy a hero card."
+		}
+		MessageKey::ChoicePlaceHeroImmediately => "Place {0} in your party immediately.",
+		MessageKey::ChoiceBullseyeKeep => "Place {0} in your hand.",
+		MessageKey::ChoiceBullseyeReorder => "Change the order.",
+		MessageKey::ChoiceBullseyeDoNotReorder => "Keep the current order.",
+		MessageKey::ChoiceWithOdds => "{0} ({1}% chance)",
+		MessageKey::PathRoll => "the roll",
+		MessageKey::PathChallenger => "the challenger's roll",
+		MessageKey::PathInitiator => "the challenged roll",
+
+		MessageKey::ChoseForfeit => "{0} chose to do nothing.",
+		MessageKey::ChosePlaceHero => "{0} chose to place {1} in their party.",
+		MessageKey::ChoseCastMagic => "{0} chose to use the magic card {1}",
+		MessageKey::ChosePlaceItem => "{0} chose to place the item {1}",
+		MessageKey::ChoseDraw => "{0} chose to draw a card",
+		MessageKey::ChoseReplaceHand => "{0} chose to replace their hand with 5 new cards",
+		MessageKey::ChoseAttackMonster => "{0} chose to attack {1}",
+		MessageKey::ChoseUseLeader => {
+			"{0} chose to use their thiefy party leader's ability (and pull a card from somebody's hand)."
+		}
+		MessageKey::ChoseRollForAbility => "{0} chose to roll for {1}'s ability.",
+		MessageKey::ChoseThinking => "{0} is still thinking.",
+		MessageKey::ChoseDoneUntilModification => {
+			"{0} is done modifying, unless someone else modifies."
+		}
+		MessageKey::ChoseAllDone => "{0} is done modifying.",
+		MessageKey::ChoseModify => "{0} chose to modify {1} by {2}.",
+		MessageKey::ChoseChallenge => "{0} chose to challenge!",
+		MessageKey::ChosePlayer => "{0} chose a player.",
+		MessageKey::ChoseCard => "{0} chose a card",
+		MessageKey::ChoseDiscardedCard => "{0} chose a card from the discard pile",
+		MessageKey::ChoseReturnItem => "{0} chose to return an item card.",
+		MessageKey::ChosePlayImmediately => "{0} decided whether to play {1} immediately.",
+		MessageKey::ChoseDoNotPlayImmediately => "{0} does not want to play immediately",
+		MessageKey::ChoseDiscard => "{0} chose to discard a certain card.",
+		MessageKey::ChoseSacrifice => "{0} chose to sacrifice a certain something.",
+		MessageKey::ChoseGiveCard => "{0} gave {1} a secret card",
+		MessageKey::ChoseQuit => "{0} chose to stop.",
+		MessageKey::ChoseContinueDiscardingAndDestroying => {
+			"{0} is going to continue discarding and destroying."
+		}
+		MessageKey::ChoseRevealChallengeAndDestroy => {
+			"{0} drew a challenge card, and will now destroy a hero card."
+		}
+		MessageKey::ChosePlaceHeroImmediately => "{0} placed {1} in their party immediately",
+		MessageKey::ChoseBullseyeKeep => "{0} kept one of the cards.",
+		MessageKey::ChoseBullseyeReorder => "{0} changed the order of the next two cards.",
+		MessageKey::ChoseBullseyeDoNotReorder => "{0} did not change the order of the next two cards.",
+
+		MessageKey::InstructSpendActionPoints => "How would you like to use your action points?",
+		MessageKey::InstructSearchDiscard => "Search the discard pile for {0}.",
+		MessageKey::InstructModifyChallenge => "Choose whether to modify the challenge.",
+		MessageKey::InstructOfferChallenges => "Choose whether to challenge.",
+		MessageKey::InstructPlayImmediately => {
+			"You have received {0}, would you like to play it immediately?"
+		}
+		MessageKey::InstructModifyRoll => "Choose whether to modify the current roll.",
+		MessageKey::InstructDiscard => "Choose a card in your hand to discard.",
+		MessageKey::InstructReturnItem => "Return an item card to someone's (TODO) hand.",
+		MessageKey::InstructChoosePlayer => "Choose a player",
+		MessageKey::InstructChooseCard => "Choose a card.",
+		MessageKey::InstructSacrifice => "Choose a hero card to sacrifice.",
+		MessageKey::InstructGiveCard => "Pick a card to give to {0}",
+		MessageKey::InstructContinueDiscardingAndDestroying => {
+			"Would you like to discard a card so that you can destroy a hero card? (#{0})"
+		}
+		MessageKey::InstructRevealAndDestroy => "Would you like to reveal and destroy?",
+		MessageKey::InstructPlaceAHero => "Which hero would you like to place in your party?",
+		MessageKey::InstructPlayOneOfImmediately => "Which card would you like to play?",
+		MessageKey::InstructBullseyeKeep => "Which card would you like to keep?",
+		MessageKey::InstructBullseyeOrdering => {
+			"The next two cards are {0} and then {1}. Would you like to swap the order?"
+		}
+		MessageKey::FilterHero => "a hero card",
+		MessageKey::FilterModifier => "a modifier card",
+		MessageKey::FilterItem => "an item card",
+		MessageKey::FilterMagic => "a magic card",
+
+		MessageKey::PromptPullMagic => {
+			"Who would you like to pull from? (If it is magic, you can play it immediately.)"
+		}
+		MessageKey::PromptPullHero => {
+			"Who would you like to pull from? (If it is a hero card, you can play it immediately.)"
+		}
+		MessageKey::PromptPullChallenge => {
+			"Who would you like to pull from? (If it is a challenge card, you can pull again.)"
+		}
+		MessageKey::PromptPullFrom => "Choose a player to pull from.",
+		MessageKey::PromptStealFromPlayer => "Which player would you like to steal from?",
+		MessageKey::PromptStealFrom => "Choose a player to steal from.",
+		MessageKey::PromptStealHero => "Which hero card would you like to steal?",
+		MessageKey::PromptStealCardFrom => "Who would you like to steal a card from?",
+		MessageKey::PromptStealCard => "Which card would you like to steal?",
+		MessageKey::PromptStealMagic => {
+			"Who do you want to steal from? (If it is magic, you can play it immediately.)"
+		}
+		MessageKey::PromptStealAndPull => "Choose a player to steal and pull from.",
+		MessageKey::PromptPlayerToGiveItem => {
+			"Which player has the hero card you would like to place this item on?"
+		}
+		MessageKey::PromptStackToPlaceItemOn => "Which hero card would you like to place this item on?",
+		MessageKey::PromptPlayerToDestroy => "Which player should destroy a hero card?",
+		MessageKey::PromptCardToDestroy => "Which hero card would you like to destroy?",
+		MessageKey::PromptDestroyFromPlayer => {
+			"Who would you like to destroy a card from? (You will receive that card's items.)"
+		}
+		MessageKey::PromptDestroyForItems => {
+			"Which hero card would you like to destroy? (You will receive that card's items.)"
+		}
+		MessageKey::PromptSeeHand => "Whose hand would you like to see?",
+		MessageKey::PromptTakeCard => "Which card would you like to take?",
+		MessageKey::PromptSlipperyPaws => {
+			"Choose a player to pull 2 cards from, you will have to discard one of them."
+		}
+		MessageKey::PromptDiscardTwo => "Who should discard 2 cards?",
+		MessageKey::PromptTradeHands => "Who do you want to trade hands with?",
+		MessageKey::PromptSacrificeHero => "Who should sacrifice a hero card?",
+		MessageKey::PromptWiggles => "Wiggles: Who do you want to steal a hero card from?",
+		MessageKey::PromptForcedExchange => "Choose a player to forcefully exchange heros with.",
+		MessageKey::PromptDonateHero => "Which hero card would you like to move to their hand?",
+
+		MessageKey::DeckDraw => "Draw pile",
+		MessageKey::DeckDiscard => "Discard pile",
+		MessageKey::DeckPartyLeaders => "Unused Party leaders",
+		MessageKey::DeckMonsters => "Monsters",
+		MessageKey::DeckNextMonsters => "Next monsters",
+		MessageKey::DeckHand => "{0}'s hand",
+		MessageKey::DeckParty => "{0}'s party",
+		MessageKey::DeckSlainMonsters => "{0}'s monsters",
+
+		MessageKey::NotifyChoosing => "{0} is choosing {1}",
+		MessageKey::NotifyModification => "{1} was applied to {0}.",
+		MessageKey::ModificationFromPlayer => "{0} from {1}",
+		MessageKey::ModificationFromBuff => "{0} from {1}",
+		MessageKey::NotifyRoll => {
+			"{0} rolled {1} + {2} {3} and needed {4}{5}, for a total of {6}: {7}."
+		}
+		MessageKey::NotifyRollModifiedBy => ", modified by {0}",
+		MessageKey::RollReasonHero => "to use {0}",
+		MessageKey::RollReasonMonster => "to attack {0}",
+		MessageKey::RollReasonChallenged => "against a challenge",
+		MessageKey::RollReasonChallenging => "to challenge",
+		MessageKey::ConditionAtLeast => "{0} or more",
+		MessageKey::ConditionAtMost => "{0} or less",
+		MessageKey::OutlookSuccess => "success",
+		MessageKey::OutlookFailure => "failure",
+		MessageKey::OutlookNoEffect => "no effect",
+		MessageKey::NotifyChallenged => "{0} challenged {1}.",
+		MessageKey::NotifyNotChallenged => "Nobody challenged {0}.",
+		MessageKey::NotifyChallengeResult => "{0} rolled {1} against {2}'s {3} over {4}: {5} won.",
+		MessageKey::NotifyWon => "The game is over! {0} won!",
+		MessageKey::NotifyTurn => "It is now {0}'s turn.",
+		MessageKey::NotifyNoWhereToPlaceItem => "There was no where to place an item card.",
+		MessageKey::NotifyDrew => "{0} drew {1}",
+		MessageKey::NotifyCanPullAgain => "{0} can pull again.",
+		MessageKey::NotifyCannotPullAgain => "{0} cannot pull again.",
+		MessageKey::NotifyCanPlayAgain => "{0} can play again.",
+		MessageKey::NotifyCannotPlayAgain => "{0} cannot play again.",
+		MessageKey::NotifyDestroyed => "{0} destroyed {1}'s {2}.",
+		MessageKey::NotifyStole => "{0} stole {1} from {2}.",
+		MessageKey::NotifySacrificed => "{0} sacrificed {1}.",
+
+		MessageKey::CategoryTurns => "Turns",
+		MessageKey::CategoryChoices => "Choices",
+		MessageKey::CategoryRolls => "Rolls",
+		MessageKey::CategoryChallenges => "Challenges",
+		MessageKey::CategoryCards => "Cards",
+		MessageKey::HistoryTurn => "Turn {0}",
+		MessageKey::HistoryPlayerTurn => "Turn {0}: {1}",
+		MessageKey::HistoryEveryone => "Player: Everyone",
+		MessageKey::HistoryPlayer => "Player: {0}",
+		MessageKey::HistoryHide => "Hide {0}",
+		MessageKey::HistoryShow => "Show {0}",
+
+		MessageKey::RollOddsSummary => "Success needs {0}, the total of {1} is currently a {2}.",
+		MessageKey::RollOddsModification => "Playing {0} ({1}) makes it {2}: {3}.",
+		MessageKey::ChallengeOddsSummary => "{0} is currently winning, ties go to the challenger.",
+		MessageKey::ChallengeOddsModification => "Playing {0} ({1}) on {2}'s roll means {3} wins.",
+
+		MessageKey::ReplayNextChoice => "Next, {0} chooses: {1}",
+		MessageKey::ReplayEnd => "This is the end of the recording.",
+		MessageKey::ReplayShowRecord => "Show this game's record",
+		MessageKey::ReplayLoadRecord => "Replay this record",
+		MessageKey::ReplayRecordPlaceholder => "Paste a game's record here to watch it.",
+		MessageKey::ReplayEveryone => "Everyone",
+		MessageKey::ReplayBack => "Back to the game",
+		MessageKey::ReplayPreviousTurn => "Previous turn",
+		MessageKey::ReplayNextTurn => "Next turn",
+		MessageKey::ReplayStep => "Step {0} of {1}",
+		MessageKey::ReplayViewingAs => "Viewing as: {0}",
+		MessageKey::ReplayTurns => "Turns: ",
+
+		MessageKey::RollDescriptionHero => "{0} is rolling for ",
+		MessageKey::RollDescriptionHeroAfter => "'s ability",
+		MessageKey::RollDescriptionMonster => "{0} is attacking ",
+		MessageKey::ChallengeDescriptionHero => "{0} challenged {1}'s choice to place ",
+		MessageKey::ChallengeDescriptionHeroAfter => "in their party.",
+		MessageKey::ChallengeDescriptionItem => "{0} challenged {1}'s choice to place the item ",
+		MessageKey::ChallengeDescriptionMagic => "{0} challenged {1}'s choice to play magic card",
+		MessageKey::ChallengeRoll => "{0}'s roll",
+	}
+}
@@ -0,0 +1,283 @@
+use crate::slay::i18n::keys::MessageKey;
+use crate::slay::specs::cards::card_type::SlayCardSpec;
+
+pub fn template(key: MessageKey) -> Option<&'static str> {
+	Some(match key {
+		MessageKey::You => "Vous",
+		MessageKey::PlayerNumber => "Joueur {0}",
+		MessageKey::NotApplicable => "N/A",
+		MessageKey::LanguageButton => "Langue : {0}",
+
+		MessageKey::ButtonRestart => "Recommencer",
+		MessageKey::ButtonTestNext => "Tester la suivante",
+		MessageKey::ButtonHumans => "Humains : {0}",
+		MessageKey::ButtonViewMode => "Vue : {0}",
+		MessageKey::ButtonBotDelay => "Délai des bots : {0} ms",
+		MessageKey::ButtonAutoPass => "Passer automatiquement : {0}",
+		MessageKey::ButtonReplay => "Revoir la partie",
+		MessageKey::ViewModePlayer => "Joueur",
+		MessageKey::ViewModeSpectator => "Spectateur",
+		MessageKey::ViewModeOmniscient => "Vue divine",
+		MessageKey::AutoPassNever => "Jamais",
+		MessageKey::AutoPassWhenNoResponse => "Quand je ne peux pas répondre",
+		MessageKey::AutoPassAlways => "Toujours",
+		MessageKey::PassDevice => "Passez l'appareil à {0}",
+		MessageKey::PassDeviceAccept => "Je suis {0}",
+
+		MessageKey::ActionForfeit => "Ne rien faire ce tour-ci.",
+		MessageKey::ActionPlaceHero => "Placer {0} dans votre groupe",
+		MessageKey::ActionCastMagic => "Jouer {0}",
+		MessageKey::ActionPlaceItem => "Placer {0} sur un héros.",
+		MessageKey::ActionDraw => "Piocher une carte.",
+		MessageKey::ActionReplaceHand => {
+			"Utiliser 3 points d'action pour remplacer toute votre main."
+		}
+		MessageKey::ActionAttackMonster => "Attaquer {0}",
+		MessageKey::ActionUseLeader => {
+			"Utiliser Shadow Claw pour prendre une carte dans la main d'un autre joueur."
+		}
+		MessageKey::ActionRollForAbility => "Lancer les dés pour {0}",
+		MessageKey::CompletionDoneUntilModification => {
+			"Ne pas modifier ce lancer, sauf si quelqu'un d'autre le fait."
+		}
+		MessageKey::CompletionAllDone => "Ne pas modifier ce lancer, même si quelqu'un d'autre le fait.",
+		MessageKey::ChoiceModify => "Utiliser {0} pour modifier {1} de {2}",
+		MessageKey::ChoiceChallenge => "Défier !",
+		MessageKey::ChoiceSetPlayerParam => "Choisir {1} pour : {0}",
+		MessageKey::ChoiceSetCardParam => "Choisir {1} pour : {0}",
+		MessageKey::ChoiceDiscardedCard => "{0}",
+		MessageKey::ChoiceReturnItem => "Rendre {0} de {1}",
+		MessageKey::ChoicePlayImmediately => "Jouer {0} immédiatement",
+		MessageKey::ChoiceDoNotPlayImmediately => "Ne pas jouer immédiatement",
+		MessageKey::ChoiceDiscard => "Défausser {0}",
+		MessageKey::ChoiceSacrifice => "Sacrifier {0}",
+		MessageKey::ChoiceGiveCard => "Donner {0} à {1}.",
+		MessageKey::ChoiceQuit => "Non",
+		MessageKey::ChoiceContinueDiscardingAndDestroying => "Défausser et détruire encore",
+		MessageKey::ChoiceRevealChallengeAndDestroy => {
+			"Révéler que vous avez pris une carte défi, pour détruire un héros."
+		}
+		MessageKey::ChoicePlaceHeroImmediately => "Placer {0} dans votre groupe immédiatement.",
+		MessageKey::ChoiceBullseyeKeep => "Mettre {0} dans votre main.",
+		MessageKey::ChoiceBullseyeReorder => "Changer l'ordre.",
+		MessageKey::ChoiceBullseyeDoNotReorder => "Garder l'ordre actuel.",
+		MessageKey::ChoiceWithOdds => "{0} ({1} % de chances)",
+		MessageKey::PathRoll => "le lancer",
+		MessageKey::PathChallenger => "le lancer du défieur",
+		MessageKey::PathInitiator => "le lancer défié",
+
+		MessageKey::ChoseForfeit => "{0} a choisi de ne rien faire.",
+		MessageKey::ChosePlaceHero => "{0} a choisi de placer {1} dans son groupe.",
+		MessageKey::ChoseCastMagic => "{0} a choisi de jouer la carte magie {1}",
+		MessageKey::ChosePlaceItem => "{0} a choisi de placer l'objet {1}",
+		MessageKey::ChoseDraw => "{0} a choisi de piocher une carte",
+		MessageKey::ChoseReplaceHand => "{0} a choisi de remplacer sa main par 5 nouvelles cartes",
+		MessageKey::ChoseAttackMonster => "{0} a choisi d'attaquer {1}",
+		MessageKey::ChoseUseLeader => {
+			"{0} a choisi d'utiliser le pouvoir de son chef de groupe (et de prendre une carte dans une main)."
+		}
+		MessageKey::ChoseRollForAbility => "{0} a choisi de lancer les dés pour le pouvoir de {1}.",
+		MessageKey::ChoseThinking => "{0} réfléchit encore.",
+		MessageKey::ChoseDoneUntilModification => {
+			"{0} a fini de modifier, sauf si quelqu'un d'autre modifie."
+		}
+		MessageKey::ChoseAllDone => "{0} a fini de modifier.",
+		MessageKey::ChoseModify => "{0} a choisi de modifier {1} de {2}.",
+		MessageKey::ChoseChallenge => "{0} a choisi de défier !",
+		MessageKey::ChosePlayer => "{0} a choisi un joueur.",
+		MessageKey::ChoseCard => "{0} a choisi une carte",
+		MessageKey::ChoseDiscardedCard => "{0} a choisi une carte de la défausse",
+		MessageKey::ChoseReturnItem => "{0} a choisi de rendre un objet.",
+		MessageKey::ChosePlayImmediately => "{0} a décidé s'il fallait jouer {1} immédiatement.",
+		MessageKey::ChoseDoNotPlayImmediately => "{0} ne veut pas jouer immédiatement",
+		MessageKey::ChoseDiscard => "{0} a choisi de défausser une certaine carte.",
+		MessageKey::ChoseSacrifice => "{0} a choisi de sacrifier quelque chose.",
+		MessageKey::ChoseGiveCard => "{0} a donné une carte secrète à {1}",
+		MessageKey::ChoseQuit => "{0} a choisi d'arrêter.",
+		MessageKey::ChoseContinueDiscardingAndDestroying => {
+			"{0} va continuer à défausser et à détruire."
+		}
+		MessageKey::ChoseRevealChallengeAndDestroy => {
+			"{0} a pioché une carte défi, et va maintenant détruire un héros."
+		}
+		MessageKey::ChosePlaceHeroImmediately => "{0} a placé {1} dans son groupe immédiatement",
+		MessageKey::ChoseBullseyeKeep => "{0} a gardé une des cartes.",
+		MessageKey::ChoseBullseyeReorder => "{0} a changé l'ordre des deux prochaines cartes.",
+		MessageKey::ChoseBullseyeDoNotReorder => {
+			"{0} n'a pas changé l'ordre des deux prochaines cartes."
+		}
+
+		MessageKey::InstructSpendActionPoints => "Comment voulez-vous utiliser vos points d'action ?",
+		MessageKey::InstructSearchDiscard => "Cherchez {0} dans la défausse.",
+		MessageKey::InstructModifyChallenge => "Choisissez si vous modifiez le défi.",
+		MessageKey::InstructOfferChallenges => "Choisissez si vous défiez.",
+		MessageKey::InstructPlayImmediately => {
+			"Vous avez reçu {0}, voulez-vous la jouer immédiatement ?"
+		}
+		MessageKey::InstructModifyRoll => "Choisissez si vous modifiez le lancer en cours.",
+		MessageKey::InstructDiscard => "Choisissez une carte de votre main à défausser.",
+		MessageKey::InstructReturnItem => "Rendez un objet à la main de quelqu'un.",
+		MessageKey::InstructChoosePlayer => "Choisissez un joueur",
+		MessageKey::InstructChooseCard => "Choisissez une carte.",
+		MessageKey::InstructSacrifice => "Choisissez un héros à sacrifier.",
+		MessageKey::InstructGiveCard => "Choisissez une carte à donner à {0}",
+		MessageKey::InstructContinueDiscardingAndDestroying => {
+			"Voulez-vous défausser une carte pour détruire un héros ? (n° {0})"
+		}
+		MessageKey::InstructRevealAndDestroy => "Voulez-vous révéler et détruire ?",
+		MessageKey::InstructPlaceAHero => "Quel héros voulez-vous placer dans votre groupe ?",
+		MessageKey::InstructPlayOneOfImmediately => "Quelle carte voulez-vous jouer ?",
+		MessageKey::InstructBullseyeKeep => "Quelle carte voulez-vous garder ?",
+		MessageKey::InstructBullseyeOrdering => {
+			"Les deux prochaines cartes sont {0} puis {1}. Voulez-vous inverser l'ordre ?"
+		}
+		MessageKey::FilterHero => "un héros",
+		MessageKey::FilterModifier => "un modificateur",
+		MessageKey::FilterItem => "un objet",
+		MessageKey::FilterMagic => "une carte magie",
+
+		MessageKey::PromptPullMagic => {
+			"Dans quelle main voulez-vous prendre une carte ? (Si c'est une magie, vous pouvez la jouer immédiatement.)"
+		}
+		MessageKey::PromptPullHero => {
+			"Dans quelle main voulez-vous prendre une carte ? (Si c'est un héros, vous pouvez le jouer immédiatement.)"
+		}
+		MessageKey::PromptPullChallenge => {
+			"Dans quelle main voulez-vous prendre une carte ? (Si c'est un défi, vous pouvez recommencer.)"
+		}
+		MessageKey::PromptPullFrom => "Choisissez un joueur chez qui prendre une carte.",
+		MessageKey::PromptStealFromPlayer => "À quel joueur voulez-vous voler ?",
+		MessageKey::PromptStealFrom => "Choisissez un joueur à qui voler.",
+		MessageKey::PromptStealHero => "Quel héros voulez-vous voler ?",
+		MessageKey::PromptStealCardFrom => "À qui voulez-vous voler une carte ?",
+		MessageKey::PromptStealCard => "Quelle carte voulez-vous voler ?",
+		MessageKey::PromptStealMagic => {
+			"À qui voulez-vous voler ? (Si c'est une magie, vous pouvez la jouer immédiatement.)"
+		}
+		MessageKey::PromptStealAndPull => "Choisissez un joueur à qui voler et prendre une carte.",
+		MessageKey::PromptPlayerToGiveItem => {
+			"Quel joueur a le héros sur lequel vous voulez placer cet objet ?"
+		}
+		MessageKey::PromptStackToPlaceItemOn => "Sur quel héros voulez-vous placer cet objet ?",
+		MessageKey::PromptPlayerToDestroy => "Quel joueur doit détruire un héros ?",
+		MessageKey::PromptCardToDestroy => "Quel héros voulez-vous détruire ?",
+		MessageKey::PromptDestroyFromPlayer => {
+			"À qui voulez-vous détruire une carte ? (Vous recevrez ses objets.)"
+		}
+		MessageKey::PromptDestroyForItems => {
+			"Quel héros voulez-vous détruire ? (Vous recevrez ses objets.)"
+		}
+		MessageKey::PromptSeeHand => "Quelle main voulez-vous voir ?",
+		MessageKey::PromptTakeCard => "Quelle carte voulez-vous prendre ?",
+		MessageKey::PromptSlipperyPaws => {
+			"Choisissez un joueur chez qui prendre 2 cartes, vous devrez en défausser une."
+		}
+		MessageKey::PromptDiscardTwo => "Qui doit défausser 2 cartes ?",
+		MessageKey::PromptTradeHands => "Avec qui voulez-vous échanger votre main ?",
+		MessageKey::PromptSacrificeHero => "Qui doit sacrifier un héros ?",
+		MessageKey::PromptWiggles => "Wiggles : à qui voulez-vous voler un héros ?",
+		MessageKey::PromptForcedExchange => "Choisissez un joueur avec qui échanger des héros de force.",
+		MessageKey::PromptDonateHero => "Quel héros voulez-vous mettre dans sa main ?",
+
+		MessageKey::DeckDraw => "Pioche",
+		MessageKey::DeckDiscard => "Défausse",
+		MessageKey::DeckPartyLeaders => "Chefs de groupe inutilisés",
+		MessageKey::DeckMonsters => "Monstres",
+		MessageKey::DeckNextMonsters => "Prochains monstres",
+		MessageKey::DeckHand => "Main de {0}",
+		MessageKey::DeckParty => "Groupe de {0}",
+		MessageKey::DeckSlainMonsters => "Monstres de {0}",
+
+		MessageKey::NotifyChoosing => "{0} choisit {1}",
+		MessageKey::NotifyModification => "Modification ({0}) : {1}.",
+		MessageKey::ModificationFromPlayer => "{0} par {1}",
+		MessageKey::ModificationFromBuff => "{0} par {1}",
+		MessageKey::NotifyRoll => {
+			"{0} a obtenu {1} + {2} {3} et avait besoin de {4}{5}, pour un total de {6} : {7}."
+		}
+		MessageKey::NotifyRollModifiedBy => ", modifié de {0}",
+		MessageKey::RollReasonHero => "pour utiliser {0}",
+		MessageKey::RollReasonMonster => "pour attaquer {0}",
+		MessageKey::RollReasonChallenged => "contre un défi",
+		MessageKey::RollReasonChallenging => "pour défier",
+		MessageKey::ConditionAtLeast => "{0} ou plus",
+		MessageKey::ConditionAtMost => "{0} ou moins",
+		MessageKey::OutlookSuccess => "réussite",
+		MessageKey::OutlookFailure => "échec",
+		MessageKey::OutlookNoEffect => "sans effet",
+		MessageKey::NotifyChallenged => "{0} a défié {1}.",
+		MessageKey::NotifyNotChallenged => "Personne n'a défié {0}.",
+		MessageKey::NotifyChallengeResult => {
+			"{0} a obtenu {1} contre {3} pour {2}, au sujet de {4} : {5} a gagné."
+		}
+		MessageKey::NotifyWon => "La partie est finie ! {0} a gagné !",
+		MessageKey::NotifyTurn => "C'est au tour de {0}.",
+		MessageKey::NotifyNoWhereToPlaceItem => "Il n'y avait nulle part où placer un objet.",
+		MessageKey::NotifyDrew => "{0} a pioché {1}",
+		MessageKey::NotifyCanPullAgain => "{0} peut prendre une autre carte.",
+		MessageKey::NotifyCannotPullAgain => "{0} ne peut pas prendre d'autre carte.",
+		MessageKey::NotifyCanPlayAgain => "{0} peut jouer encore.",
+		MessageKey::NotifyCannotPlayAgain => "{0} ne peut pas jouer encore.",
+		MessageKey::NotifyDestroyed => "{0} a détruit {2} de {1}.",
+		MessageKey::NotifyStole => "{0} a volé {1} à {2}.",
+		MessageKey::NotifySacrificed => "{0} a sacrifié {1}.",
+
+		MessageKey::CategoryTurns => "Tours",
+		MessageKey::CategoryChoices => "Choix",
+		MessageKey::CategoryRolls => "Lancers",
+		MessageKey::CategoryChallenges => "Défis",
+		MessageKey::CategoryCards => "Cartes",
+		MessageKey::HistoryTurn => "Tour {0}",
+		MessageKey::HistoryPlayerTurn => "Tour {0} : {1}",
+		MessageKey::HistoryEveryone => "Joueur : tout le monde",
+		MessageKey::HistoryPlayer => "Joueur : {0}",
+		MessageKey::HistoryHide => "Masquer {0}",
+		MessageKey::HistoryShow => "Afficher {0}",
+
+		MessageKey::RollOddsSummary => {
+			"Il faut {0} pour réussir, le total de {1} est pour l'instant : {2}."
+		}
+		MessageKey::RollOddsModification => "Jouer {0} ({1}) donne {2} : {3}.",
+		MessageKey::ChallengeOddsSummary => {
+			"{0} gagne pour l'instant, les égalités vont au défieur."
+		}
+		MessageKey::ChallengeOddsModification => {
+			"Jouer {0} ({1}) sur le lancer de {2} fait gagner {3}."
+		}
+
+		MessageKey::ReplayNextChoice => "Ensuite, {0} choisit : {1}",
+		MessageKey::ReplayEnd => "C'est la fin de l'enregistrement.",
+		MessageKey::ReplayShowRecord => "Afficher l'enregistrement de cette partie",
+		MessageKey::ReplayLoadRecord => "Revoir cet enregistrement",
+		MessageKey::ReplayRecordPlaceholder => {
+			"Collez ici l'enregistrement d'une partie pour la revoir."
+		}
+		MessageKey::ReplayEveryone => "Tout le monde",
+		MessageKey::ReplayBack => "Retour à la partie",
+		MessageKey::ReplayPreviousTurn => "Tour précédent",
+		MessageKey::ReplayNextTurn => "Tour suivant",
+		MessageKey::ReplayStep => "Étape {0} sur {1}",
+		MessageKey::ReplayViewingAs => "Vu par : {0}",
+		MessageKey::ReplayTurns => "Tours : ",
+
+		MessageKey::RollDescriptionHero => "{0} lance les dés pour le pouvoir de ",
+		MessageKey::RollDescriptionHeroAfter => "",
+		MessageKey::RollDescriptionMonster => "{0} attaque ",
+		MessageKey::ChallengeDescriptionHero => "{0} a défié le choix de {1} de placer ",
+		MessageKey::ChallengeDescriptionHeroAfter => "dans son groupe.",
+		MessageKey::ChallengeDescriptionItem => "{0} a défié le choix de {1} de placer l'objet ",
+		MessageKey::ChallengeDescriptionMagic => {
+			"{0} a défié le choix de {1} de jouer la carte magique"
+		}
+		MessageKey::ChallengeRoll => "Lancer de {0}",
+	})
+}
+
+// The cards keep their printed English names and texts until they have an official translation.
+pub fn card_label(_spec: &SlayCardSpec) -> Option<&'static str> {
+	None
+}
+
+pub fn card_description(_spec: &SlayCardSpec) -> Option<&'static str> {
+	None
+}
@@ -0,0 +1,230 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MessageKey {
+	You,
+	PlayerNumber,
+	NotApplicable,
+	LanguageButton,
+
+	// The app's buttons
+	ButtonRestart,
+	ButtonTestNext,
+	ButtonHumans,
+	ButtonViewMode,
+	ButtonBotDelay,
+	ButtonAutoPass,
+	ButtonReplay,
+	ViewModePlayer,
+	ViewModeSpectator,
+	ViewModeOmniscient,
+	AutoPassNever,
+	AutoPassWhenNoResponse,
+	AutoPassAlways,
+	PassDevice,
+	PassDeviceAccept,
+
+	// Choice::label
+	ActionForfeit,
+	ActionPlaceHero,
+	ActionCastMagic,
+	ActionPlaceItem,
+	ActionDraw,
+	ActionReplaceHand,
+	ActionAttackMonster,
+	ActionUseLeader,
+	ActionRollForAbility,
+	CompletionDoneUntilModification,
+	CompletionAllDone,
+	ChoiceModify,
+	ChoiceChallenge,
+	ChoiceSetPlayerParam,
+	ChoiceSetCardParam,
+	ChoiceDiscardedCard,
+	ChoiceReturnItem,
+	ChoicePlayImmediately,
+	ChoiceDoNotPlayImmediately,
+	ChoiceDiscard,
+	ChoiceSacrifice,
+	ChoiceGiveCard,
+	ChoiceQuit,
+	ChoiceContinueDiscardingAndDestroying,
+	ChoiceRevealChallengeAndDestroy,
+	ChoicePlaceHeroImmediately,
+	ChoiceBullseyeKeep,
+	ChoiceBullseyeReorder,
+	ChoiceBullseyeDoNotReorder,
+	ChoiceWithOdds,
+	PathRoll,
+	PathChallenger,
+	PathInitiator,
+
+	// Choice::get_notification
+	ChoseForfeit,
+	ChosePlaceHero,
+	ChoseCastMagic,
+	ChosePlaceItem,
+	ChoseDraw,
+	ChoseReplaceHand,
+	ChoseAttackMonster,
+	ChoseUseLeader,
+	ChoseRollForAbility,
+	ChoseThinking,
+	ChoseDoneUntilModification,
+	ChoseAllDone,
+	ChoseModify,
+	ChoseChallenge,
+	ChosePlayer,
+	ChoseCard,
+	ChoseDiscardedCard,
+	ChoseReturnItem,
+	ChosePlayImmediately,
+	ChoseDoNotPlayImmediately,
+	ChoseDiscard,
+	ChoseSacrifice,
+	ChoseGiveCard,
+	ChoseQuit,
+	ChoseContinueDiscardingAndDestroying,
+	ChoseRevealChallengeAndDestroy,
+	ChosePlaceHeroImmediately,
+	ChoseBullseyeKeep,
+	ChoseBullseyeReorder,
+	ChoseBullseyeDoNotReorder,
+
+	// ChoicesType::get_instructions
+	InstructSpendActionPoints,
+	InstructSearchDiscard,
+	InstructModifyChallenge,
+	InstructOfferChallenges,
+	InstructPlayImmediately,
+	InstructModifyRoll,
+	InstructDiscard,
+	InstructReturnItem,
+	InstructChoosePlayer,
+	InstructChooseCard,
+	InstructSacrifice,
+	InstructGiveCard,
+	InstructContinueDiscardingAndDestroying,
+	InstructRevealAndDestroy,
+	InstructPlaceAHero,
+	InstructPlayOneOfImmediately,
+	InstructBullseyeKeep,
+	InstructBullseyeOrdering,
+	FilterHero,
+	FilterModifier,
+	FilterItem,
+	FilterMagic,
+
+	// PlayerParam::prompt and CardParam::prompt
+	PromptPullMagic,
+	PromptPullHero,
+	PromptPullChallenge,
+	PromptPullFrom,
+	PromptStealFromPlayer,
+	PromptStealFrom,
+	PromptStealHero,
+	PromptStealCardFrom,
+	PromptStealCard,
+	PromptStealMagic,
+	PromptStealAndPull,
+	PromptPlayerToGiveItem,
+	PromptStackToPlaceItemOn,
+	PromptPlayerToDestroy,
+	PromptCardToDestroy,
+	PromptDestroyFromPlayer,
+	PromptDestroyForItems,
+	PromptSeeHand,
+	PromptTakeCard,
+	PromptSlipperyPaws,
+	PromptDiscardTwo,
+	PromptTradeHands,
+	PromptSacrificeHero,
+	PromptWiggles,
+	PromptForcedExchange,
+	PromptDonateHero,
+
+	// DeckPath::get_label
+	DeckDraw,
+	DeckDiscard,
+	DeckPartyLeaders,
+	DeckMonsters,
+	DeckNextMonsters,
+	DeckHand,
+	DeckParty,
+	DeckSlainMonsters,
+
+	// Notification::get_description
+	NotifyChoosing,
+	NotifyModification,
+	ModificationFromPlayer,
+	ModificationFromBuff,
+	NotifyRoll,
+	NotifyRollModifiedBy,
+	RollReasonHero,
+	RollReasonMonster,
+	RollReasonChallenged,
+	RollReasonChallenging,
+	ConditionAtLeast,
+	ConditionAtMost,
+	OutlookSuccess,
+	OutlookFailure,
+	OutlookNoEffect,
+	NotifyChallenged,
+	NotifyNotChallenged,
+	NotifyChallengeResult,
+	NotifyWon,
+	NotifyTurn,
+	NotifyNoWhereToPlaceItem,
+	NotifyDrew,
+	NotifyCanPullAgain,
+	NotifyCannotPullAgain,
+	NotifyCanPlayAgain,
+	NotifyCannotPlayAgain,
+	NotifyDestroyed,
+	NotifyStole,
+	NotifySacrificed,
+
+	// The notification history
+	CategoryTurns,
+	CategoryChoices,
+	CategoryRolls,
+	CategoryChallenges,
+	CategoryCards,
+	HistoryTurn,
+	HistoryPlayerTurn,
+	HistoryEveryone,
+	HistoryPlayer,
+	HistoryHide,
+	HistoryShow,
+
+	// Odds in the roll and challenge modals
+	RollOddsSummary,
+	RollOddsModification,
+	ChallengeOddsSummary,
+	ChallengeOddsModification,
+
+	// The replay viewer
+	ReplayNextChoice,
+	ReplayEnd,
+	ReplayShowRecord,
+	ReplayLoadRecord,
+	ReplayRecordPlaceholder,
+	ReplayEveryone,
+	ReplayBack,
+	ReplayPreviousTurn,
+	ReplayNextTurn,
+	ReplayStep,
+	ReplayViewingAs,
+	ReplayTurns,
+
+	// The showdown modals, the card is shown between the two parts
+	RollDescriptionHero,
+	RollDescriptionHeroAfter,
+	RollDescriptionMonster,
+	ChallengeDescriptionHero,
+	ChallengeDescriptionHeroAfter,
+	ChallengeDescriptionItem,
+	ChallengeDescriptionMagic,
+	ChallengeRoll,
+}
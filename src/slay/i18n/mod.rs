@@ -0,0 +1,215 @@
+pub mod english;
+pub mod french;
+pub mod keys;
+
+use crate::slay::i18n::keys::MessageKey;
+use crate::slay::ids;
+use crate::slay::specs::cards::card_type::SlayCardSpec;
+use crate::slay::state::game::GameStaticInformation;
+use serde::Deserialize;
+use serde::Serialize;
+
+/*
+	Player facing text is built as a message key with arguments, and only turned into a string
+	by a Localizer for the reader's locale.
+	Every key has an English template, other locales fall back to English for anything they are missing.
+	Templates refer to their arguments by position ("{0}", "{1}"), so translations can reorder them.
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Locale {
+	#[default]
+	English,
+	French,
+}
+
+impl Locale {
+	pub fn all() -> [Locale; 2] {
+		[Locale::English, Locale::French]
+	}
+
+	// Always in the locale itself, so that readers can find their language.
+	pub fn name(&self) -> &'static str {
+		match self {
+			Locale::English => "English",
+			Locale::French => "Français",
+		}
+	}
+
+	pub fn next(&self) -> Locale {
+		match self {
+			Locale::English => Locale::French,
+			Locale::French => Locale::English,
+		}
+	}
+
+	// French verbs agree with their subject, so "Vous" would need its own form of every sentence.
+	fn addresses_viewer(&self) -> bool {
+		match self {
+			Locale::English => true,
+			Locale::French => false,
+		}
+	}
+
+	fn template(&self, key: MessageKey) -> Option<&'static str> {
+		match self {
+			Locale::English => Some(english::template(key)),
+			Locale::French => french::template(key),
+		}
+	}
+
+	fn card_label(&self, spec: &SlayCardSpec) -> Option<&'static str> {
+		match self {
+			Locale::English => Some(spec.label()),
+			Locale::French => french::card_label(spec),
+		}
+	}
+
+	fn card_description(&self, spec: &SlayCardSpec) -> Option<&'static str> {
+		match self {
+			Locale::English => Some(spec.description()),
+			Locale::French => french::card_description(spec),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Arg {
+	// Shown as "You" to that player, in the locales that address the reader.
+	Player(ids::PlayerIndex),
+	// Always shown by name, for possessives and the like.
+	PlayerName(ids::PlayerIndex),
+	Card(SlayCardSpec),
+	Number(i32),
+	// Shown with its sign, for roll modifications.
+	Signed(i32),
+	Message(Box<Message>),
+	// Shown separated by commas.
+	List(Vec<Arg>),
+	// Text that is not translated, like debugging names.
+	Verbatim(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Message {
+	pub key: MessageKey,
+	pub args: Vec<Arg>,
+}
+
+impl Message {
+	pub fn new(key: MessageKey, args: Vec<Arg>) -> Self {
+		Self { key, args }
+	}
+
+	pub fn to_english(&self) -> String {
+		Localizer::new(Locale::English).render(self)
+	}
+}
+
+impl From<MessageKey> for Message {
+	fn from(key: MessageKey) -> Self {
+		Self::new(key, Vec::new())
+	}
+}
+
+impl From<Message> for Arg {
+	fn from(message: Message) -> Self {
+		Arg::Message(Box::new(message))
+	}
+}
+
+impl From<MessageKey> for Arg {
+	fn from(key: MessageKey) -> Self {
+		Arg::Message(Box::new(Message::from(key)))
+	}
+}
+
+pub struct Localizer<'a> {
+	pub locale: Locale,
+	statics: Option<&'a GameStaticInformation>,
+	viewer: Option<ids::PlayerIndex>,
+}
+
+impl<'a> Localizer<'a> {
+	// Without the game, players are only known by their seat.
+	pub fn new(locale: Locale) -> Self {
+		Self {
+			locale,
+			statics: None,
+			viewer: None,
+		}
+	}
+
+	pub fn for_game(
+		locale: Locale,
+		statics: &'a GameStaticInformation,
+		viewer: Option<ids::PlayerIndex>,
+	) -> Self {
+		Self {
+			locale,
+			statics: Some(statics),
+			viewer,
+		}
+	}
+
+	pub fn template(&self, key: MessageKey) -> &'static str {
+		self
+			.locale
+			.template(key)
+			.unwrap_or_else(|| english::template(key))
+	}
+
+	pub fn card_label(&self, spec: &SlayCardSpec) -> &'static str {
+		self.locale.card_label(spec).unwrap_or_else(|| spec.label())
+	}
+
+	pub fn card_description(&self, spec: &SlayCardSpec) -> &'static str {
+		self
+			.locale
+			.card_description(spec)
+			.unwrap_or_else(|| spec.description())
+	}
+
+	pub fn text(&self, key: MessageKey) -> String {
+		self.render(&Message::from(key))
+	}
+
+	pub fn render(&self, message: &Message) -> String {
+		let mut rendered = self.template(message.key).to_string();
+		for (index, arg) in message.args.iter().enumerate() {
+			rendered = rendered.replace(&format!("{{{}}}", index), &self.render_arg(arg));
+		}
+		rendered
+	}
+
+	fn player_name(&self, player_index: ids::PlayerIndex) -> String {
+		match self.statics {
+			Some(statics) => statics.player_name(player_index).to_owned(),
+			None => self.render(&Message::new(
+				MessageKey::PlayerNumber,
+				vec![Arg::Number(player_index as i32 + 1)],
+			)),
+		}
+	}
+
+	fn render_arg(&self, arg: &Arg) -> String {
+		match arg {
+			Arg::Player(player_index)
+				if self.viewer == Some(*player_index) && self.locale.addresses_viewer() =>
+			{
+				self.text(MessageKey::You)
+			}
+			Arg::Player(player_index) | Arg::PlayerName(player_index) => self.player_name(*player_index),
+			Arg::Card(spec) => self.card_label(spec).to_string(),
+			Arg::Number(number) => number.to_string(),
+			Arg::Signed(number) => format!("{:+}", number),
+			Arg::Message(message) => self.render(message),
+			Arg::List(args) => args
+				.iter()
+				.map(|arg| self.render_arg(arg))
+				.collect::<Vec<_>>()
+				.join(", "),
+			Arg::Verbatim(text) => text.to_owned(),
+		}
+	}
+}
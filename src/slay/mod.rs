@@ -2,16 +2,21 @@ pub mod abilities;
 pub mod actions;
 pub mod choices;
 pub mod choices_rewrite;
+pub mod conformance;
 pub mod deadlines;
 pub mod driver;
 pub mod errors;
+pub mod fuzz;
+pub mod golden;
 pub mod game_context;
 pub mod hero_abilities;
+pub mod i18n;
 pub mod ids;
 pub mod modifier_listeners;
 pub mod modifier_visitors;
 pub mod notification;
 pub mod old;
+pub mod replay;
 pub mod showdown;
 pub mod specification;
 pub mod specs;
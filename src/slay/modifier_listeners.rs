@@ -10,7 +10,6 @@ pub fn hero_is_destroyable() {}
 
 // DrawOnDestroy,
 // StealInsteadOfSacrifice,
-// DrawOnPlayMagic,
 // ModifierBonus,
 
 // DrawOnModify,
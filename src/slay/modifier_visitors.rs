@@ -13,12 +13,12 @@ pub fn create_roll_history(
 	if let RollReason::UseHeroAbility(hero) = reason {
 		////////////////////////////////////////////////////////////////////////////
 		game.players[player_index]
-			.player_effects()
+			.player_effects(&game.cards)
 			.flat_map(|effect| effect.create_roll_modification(reason))
 			////////////////////////////////////////////////////////////////////////////
 			.chain(
 				game.players[player_index]
-					.hero_effects()
+					.hero_effects(&game.cards)
 					.filter(|item| item.hero == hero)
 					.flat_map(|item| item.effect.create_roll_modification(reason)),
 			)
@@ -26,7 +26,7 @@ pub fn create_roll_history(
 	} else {
 		////////////////////////////////////////////////////////////////////////////
 		game.players[player_index]
-			.player_effects()
+			.player_effects(&game.cards)
 			.flat_map(|effect| effect.create_roll_modification(reason))
 			////////////////////////////////////////////////////////////////////////////
 			.collect()
@@ -110,7 +110,7 @@ pub fn create_roll_history(
 /*
 elf
 			.slain_monsters
-			.tops()
+			.tops(&game.cards)
 			.map(|card| {
 				if let Some(monster) = card.card_type.get_card_spec_creation().monster {
 					monster
@@ -1,21 +1,73 @@
 use crate::slay::choices::Choice;
 use crate::slay::choices::ChoicesType;
+use crate::slay::i18n::english;
+use crate::slay::i18n::keys::MessageKey;
+use crate::slay::i18n::Arg;
+use crate::slay::i18n::Locale;
+use crate::slay::i18n::Localizer;
+use crate::slay::i18n::Message;
 use crate::slay::ids;
+use crate::slay::showdown::challenge::ChallengeRoll;
+use crate::slay::showdown::consequences::Condition;
+use crate::slay::showdown::odds::RollOutlook;
+use crate::slay::showdown::roll::ChallengeReason;
+use crate::slay::showdown::roll::Roll;
+use crate::slay::showdown::roll_modification::ModificationOrigin;
+use crate::slay::showdown::roll_modification::ModificationPath;
+use crate::slay::showdown::roll_modification::RollModification;
+use crate::slay::showdown::roll_state::RollReason;
 use crate::slay::state::game::GameStaticInformation;
 use crate::slay::state::summarizable::Summarizable;
+use serde::Deserialize;
+use serde::Serialize;
+use std::io::Write;
 
 use super::specs::cards::card_type::SlayCardSpec;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RollOutcome {
+	pub roller_index: ids::PlayerIndex,
+	pub reason: RollReason,
+	pub initial: Roll,
+	pub modifications: Vec<RollModification>,
+	pub total: i32,
+	pub win_condition: Condition,
+	pub loss_condition: Option<Condition>,
+}
+
+impl RollOutcome {
+	pub fn outlook(&self) -> RollOutlook {
+		RollOutlook::from_total(
+			self.total,
+			&self.win_condition,
+			self.loss_condition.as_ref(),
+		)
+	}
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChallengeOutcome {
+	pub reason: ChallengeReason,
+	pub initiator: ChallengeRoll,
+	pub challenger: ChallengeRoll,
+}
+
+impl ChallengeOutcome {
+	// Ties go to the challenger.
+	pub fn challenger_won(&self) -> bool {
+		self.initiator.calculate_roll_total() <= self.challenger.calculate_roll_total()
+	}
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Notification {
 	PlayerChose(ids::PlayerIndex, Choice),
 	PlayerIsChoosing(ids::PlayerIndex, ChoicesType),
-	Modification,
-	InitialRoll(i32),
-	RollResult(bool), // show the roll value and threshold?
-	OfferResult,      // Option<ids::PlayerIndex>
-	ChallengeResult(bool),
-	// Player Complete?
+	Modification(ModificationPath, RollModification),
+	RollResult(RollOutcome),
+	// The player whose card was offered, and who challenged it if anyone did.
+	OfferResult(ids::PlayerIndex, Option<ids::PlayerIndex>),
+	ChallengeResult(ChallengeOutcome),
 	PlayerWon(ids::PlayerIndex),
 	PlayersTurn(ids::PlayerIndex),
 
@@ -23,68 +75,310 @@ pub enum Notification {
 	PlayerDrew(ids::PlayerIndex, SlayCardSpec),
 	CanPullAgain(ids::PlayerIndex, bool),
 	CanPlayImmediately(ids::PlayerIndex, bool),
+	// The player who destroyed the card, the owner, and the card.
+	CardDestroyed(ids::PlayerIndex, ids::PlayerIndex, SlayCardSpec),
+	// The thief, the previous owner, and the card.
+	CardStolen(ids::PlayerIndex, ids::PlayerIndex, SlayCardSpec),
+	CardSacrificed(ids::PlayerIndex, SlayCardSpec),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NotificationCategory {
+	Turns,
+	Choices,
+	Rolls,
+	Challenges,
+	Cards,
+}
+
+impl NotificationCategory {
+	pub fn all() -> [NotificationCategory; 5] {
+		[
+			NotificationCategory::Turns,
+			NotificationCategory::Choices,
+			NotificationCategory::Rolls,
+			NotificationCategory::Challenges,
+			NotificationCategory::Cards,
+		]
+	}
+
+	pub fn label_key(&self) -> MessageKey {
+		match self {
+			NotificationCategory::Turns => MessageKey::CategoryTurns,
+			NotificationCategory::Choices => MessageKey::CategoryChoices,
+			NotificationCategory::Rolls => MessageKey::CategoryRolls,
+			NotificationCategory::Challenges => MessageKey::CategoryChallenges,
+			NotificationCategory::Cards => MessageKey::CategoryCards,
+		}
+	}
+
+	pub fn label(&self) -> &'static str {
+		english::template(self.label_key())
+	}
+}
+
+fn roll_reason_message(reason: &RollReason) -> Message {
+	match reason {
+		RollReason::UseHeroAbility(hero) => Message::new(
+			MessageKey::RollReasonHero,
+			vec![Arg::Card(SlayCardSpec::HeroCard(*hero))],
+		),
+		RollReason::AttackMonster(monster) => Message::new(
+			MessageKey::RollReasonMonster,
+			vec![Arg::Card(SlayCardSpec::MonsterCard(*monster))],
+		),
+		RollReason::Challenged => MessageKey::RollReasonChallenged.into(),
+		RollReason::Challenging => MessageKey::RollReasonChallenging.into(),
+	}
+}
+
+fn challenged_card(reason: &ChallengeReason) -> SlayCardSpec {
+	match reason {
+		ChallengeReason::PlaceHeroCard(spec)
+		| ChallengeReason::PlaceItem(spec)
+		| ChallengeReason::CastMagic(spec) => *spec,
+	}
 }
 
 impl Notification {
-	pub fn get_description(
-		&self,
-		statics: &GameStaticInformation,
-		viewer: ids::PlayerIndex,
-	) -> String {
+	// Some notifications reveal hidden cards, so they are only sent to the players involved.
+	// Whether watchers who are not playing may see it.
+	pub fn is_public(&self) -> bool {
+		!matches!(
+			self,
+			Notification::PlayerDrew(..) | Notification::PlayerChose(_, Choice::ChooseCardToGive(..))
+		)
+	}
+
+	pub fn is_visible_to(&self, viewer: ids::PlayerIndex) -> bool {
 		match self {
-			Notification::PlayerChose(viewed, choice) => choice.get_notification(statics, *viewed),
-			// format!(
-			// 	"{} chose {}",
-			// 	statics.players_name_from_perspective(viewer, *viewed),
-			// 	choice.get_notification(statics, *viewed),
-			// ),
-			Notification::PlayerIsChoosing(viewed, choices_type) => format!(
-				"{} is choosing {:?}",
-				statics.players_name_from_perspective(viewer, *viewed),
-				choices_type,
+			Notification::PlayerDrew(player_index, _) => *player_index == viewer,
+			Notification::PlayerChose(player_index, Choice::ChooseCardToGive(_, recipient)) => {
+				*player_index == viewer || *recipient == viewer
+			}
+			_ => true,
+		}
+	}
+
+	pub fn message(&self) -> Message {
+		match self {
+			Notification::PlayerChose(viewed, choice) => choice.notification_message(*viewed),
+			Notification::PlayerIsChoosing(viewed, choices_type) => Message::new(
+				MessageKey::NotifyChoosing,
+				vec![
+					Arg::Player(*viewed),
+					Arg::Verbatim(format!("{:?}", choices_type)),
+				],
 			),
-			Notification::Modification => format!("There has been a modification {}", "sir",),
-			Notification::InitialRoll(roll_amount) => format!("Someone rolled a {}", roll_amount,),
-			Notification::RollResult(success) => format!("The result of the roll was {}", success),
-			Notification::OfferResult => String::from("Challenges are no longer accepted."),
-			Notification::ChallengeResult(success) => {
-				format!("The result of the challenge was {:?}", success,)
+			Notification::Modification(path, modification) => Message::new(
+				MessageKey::NotifyModification,
+				vec![path.label_key().into(), modification.message().into()],
+			),
+			Notification::RollResult(outcome) => {
+				let modifications = if outcome.modifications.is_empty() {
+					Arg::Verbatim(String::new())
+				} else {
+					Message::new(
+						MessageKey::NotifyRollModifiedBy,
+						vec![Arg::List(
+							outcome
+								.modifications
+								.iter()
+								.map(|modification| modification.message().into())
+								.collect(),
+						)],
+					)
+					.into()
+				};
+				Message::new(
+					MessageKey::NotifyRoll,
+					vec![
+						Arg::Player(outcome.roller_index),
+						Arg::Number(outcome.initial.die1 as i32),
+						Arg::Number(outcome.initial.die2 as i32),
+						roll_reason_message(&outcome.reason).into(),
+						outcome.win_condition.label_message().into(),
+						modifications,
+						Arg::Number(outcome.total),
+						outcome.outlook().label_key().into(),
+					],
+				)
+			}
+			Notification::OfferResult(player_index, challenger) => match challenger {
+				Some(challenger) => Message::new(
+					MessageKey::NotifyChallenged,
+					vec![Arg::Player(*challenger), Arg::Player(*player_index)],
+				),
+				None => Message::new(
+					MessageKey::NotifyNotChallenged,
+					vec![Arg::Player(*player_index)],
+				),
+			},
+			Notification::ChallengeResult(outcome) => Message::new(
+				MessageKey::NotifyChallengeResult,
+				vec![
+					Arg::Player(outcome.challenger.player_index),
+					Arg::Number(outcome.challenger.calculate_roll_total()),
+					Arg::PlayerName(outcome.initiator.player_index),
+					Arg::Number(outcome.initiator.calculate_roll_total()),
+					Arg::Card(challenged_card(&outcome.reason)),
+					Arg::Player(if outcome.challenger_won() {
+						outcome.challenger.player_index
+					} else {
+						outcome.initiator.player_index
+					}),
+				],
+			),
+			Notification::PlayerWon(viewed) => {
+				Message::new(MessageKey::NotifyWon, vec![Arg::Player(*viewed)])
+			}
+			Notification::PlayersTurn(viewed) => {
+				Message::new(MessageKey::NotifyTurn, vec![Arg::PlayerName(*viewed)])
 			}
-			Notification::PlayerWon(viewed) => format!(
-				"The game is over! {} won!",
-				statics.players_name_from_perspective(viewer, *viewed),
+			Notification::NoWhereToPlaceItem => MessageKey::NotifyNoWhereToPlaceItem.into(),
+			Notification::PlayerDrew(player_index, spec) => Message::new(
+				MessageKey::NotifyDrew,
+				vec![Arg::PlayerName(*player_index), Arg::Card(*spec)],
 			),
-			Notification::PlayersTurn(viewed) => format!(
-				"It is now {}'s turn.",
-				statics.players_name_from_perspective(viewer, *viewed),
+			Notification::CanPullAgain(player_index, able) => Message::new(
+				if *able {
+					MessageKey::NotifyCanPullAgain
+				} else {
+					MessageKey::NotifyCannotPullAgain
+				},
+				vec![Arg::PlayerName(*player_index)],
 			),
-			Notification::NoWhereToPlaceItem => String::from("There was no where to place an item card."),
-			Notification::PlayerDrew(player_index, spec) => format!(
-				"{} drew {}",
-				statics.player_name(*player_index),
-				spec.label(),
+			Notification::CanPlayImmediately(player_index, able) => Message::new(
+				if *able {
+					MessageKey::NotifyCanPlayAgain
+				} else {
+					MessageKey::NotifyCannotPlayAgain
+				},
+				vec![Arg::PlayerName(*player_index)],
 			),
-			Notification::CanPullAgain(player_index, able) => format!(
-				"{} {} pull again.",
-				statics.player_name(*player_index),
-				if *able { "can" } else { "cannot" },
+			Notification::CardDestroyed(destroyer, victim, spec) => Message::new(
+				MessageKey::NotifyDestroyed,
+				vec![
+					Arg::Player(*destroyer),
+					Arg::PlayerName(*victim),
+					Arg::Card(*spec),
+				],
 			),
-			Notification::CanPlayImmediately(player_index, able) => format!(
-				"{} {} play again.",
-				statics.player_name(*player_index),
-				if *able { "can" } else { "cannot" },
+			Notification::CardStolen(thief, victim, spec) => Message::new(
+				MessageKey::NotifyStole,
+				vec![
+					Arg::Player(*thief),
+					Arg::Card(*spec),
+					Arg::PlayerName(*victim),
+				],
+			),
+			Notification::CardSacrificed(player_index, spec) => Message::new(
+				MessageKey::NotifySacrificed,
+				vec![Arg::Player(*player_index), Arg::Card(*spec)],
 			),
 		}
 	}
+
+	pub fn get_description(
+		&self,
+		statics: &GameStaticInformation,
+		viewer: ids::PlayerIndex,
+	) -> String {
+		Localizer::for_game(Locale::English, statics, Some(viewer)).render(&self.message())
+	}
+
+	pub fn category(&self) -> NotificationCategory {
+		match self {
+			Notification::PlayersTurn(_) | Notification::PlayerWon(_) => NotificationCategory::Turns,
+			Notification::PlayerChose(..)
+			| Notification::PlayerIsChoosing(..)
+			| Notification::CanPullAgain(..)
+			| Notification::CanPlayImmediately(..) => NotificationCategory::Choices,
+			Notification::Modification(..) | Notification::RollResult(_) => NotificationCategory::Rolls,
+			Notification::OfferResult(..) | Notification::ChallengeResult(_) => {
+				NotificationCategory::Challenges
+			}
+			Notification::NoWhereToPlaceItem
+			| Notification::PlayerDrew(..)
+			| Notification::CardDestroyed(..)
+			| Notification::CardStolen(..)
+			| Notification::CardSacrificed(..) => NotificationCategory::Cards,
+		}
+	}
+
+	pub fn involved_players(&self) -> Vec<ids::PlayerIndex> {
+		match self {
+			Notification::PlayerChose(player_index, Choice::ChooseCardToGive(_, recipient)) => {
+				vec![*player_index, *recipient]
+			}
+			Notification::PlayerChose(player_index, _)
+			| Notification::PlayerIsChoosing(player_index, _)
+			| Notification::PlayerWon(player_index)
+			| Notification::PlayersTurn(player_index)
+			| Notification::PlayerDrew(player_index, _)
+			| Notification::CanPullAgain(player_index, _)
+			| Notification::CanPlayImmediately(player_index, _)
+			| Notification::CardSacrificed(player_index, _) => vec![*player_index],
+			Notification::Modification(_, modification) => match modification.origin {
+				ModificationOrigin::FromPlayer(player_index, _) => vec![player_index],
+				ModificationOrigin::FromBuff(_) => Vec::new(),
+			},
+			Notification::RollResult(outcome) => vec![outcome.roller_index],
+			Notification::OfferResult(player_index, challenger) => {
+				let mut players = vec![*player_index];
+				players.extend(challenger.iter());
+				players
+			}
+			Notification::ChallengeResult(outcome) => vec![
+				outcome.initiator.player_index,
+				outcome.challenger.player_index,
+			],
+			Notification::CardDestroyed(player_index, victim, _)
+			| Notification::CardStolen(player_index, victim, _) => vec![*player_index, *victim],
+			Notification::NoWhereToPlaceItem => Vec::new(),
+		}
+	}
+
+	// The cards a notification is about, so that they can be looked at.
+	pub fn mentioned_cards(&self) -> Vec<SlayCardSpec> {
+		match self {
+			Notification::PlayerChose(_, choice) => match choice {
+				Choice::ChooseDiscardedCard(spec)
+				| Choice::SetCardParameter(_, spec)
+				| Choice::PlayImmediately(spec)
+				| Choice::Discard(spec)
+				| Choice::ChooseCardToGive(spec, _)
+				| Choice::BullseyeKeep(spec) => vec![*spec],
+				Choice::Sacrifice(hero) | Choice::PlaceHeroImmediately(hero) => {
+					vec![SlayCardSpec::HeroCard(*hero)]
+				}
+				_ => Vec::new(),
+			},
+			Notification::RollResult(outcome) => match outcome.reason {
+				RollReason::UseHeroAbility(hero) => vec![SlayCardSpec::HeroCard(hero)],
+				RollReason::AttackMonster(monster) => vec![SlayCardSpec::MonsterCard(monster)],
+				RollReason::Challenged | RollReason::Challenging => Vec::new(),
+			},
+			Notification::ChallengeResult(outcome) => vec![challenged_card(&outcome.reason)],
+			Notification::PlayerDrew(_, spec)
+			| Notification::CardDestroyed(_, _, spec)
+			| Notification::CardStolen(_, _, spec)
+			| Notification::CardSacrificed(_, spec) => vec![*spec],
+			_ => Vec::new(),
+		}
+	}
 }
 
 impl Summarizable for Notification {
 	fn summarize<W: std::io::Write>(
 		&self,
-		_f: &mut std::io::BufWriter<W>,
-		_indentation_level: u32,
+		f: &mut std::io::BufWriter<W>,
+		indentation_level: u32,
 	) -> Result<(), std::io::Error> {
-		todo!()
+		for _ in 0..indentation_level {
+			write!(f, "  ")?;
+		}
+		writeln!(f, "{}", self.message().to_english())
 	}
 }
 
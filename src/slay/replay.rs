@@ -0,0 +1,297 @@
+use crate::slay::choices::Choice;
+use crate::slay::deadlines::ManualClock;
+use crate::slay::driver;
+use crate::slay::game_context::GameBookKeeping;
+use crate::slay::ids;
+use crate::slay::showdown::completion::AutoPass;
+use crate::slay::state::game::Game;
+use crate::slay::state::initialize;
+use crate::slay::state::initialize::GameSetup;
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+use std::fmt;
+
+/*
+	The rng is seeded, so a game is fully described by its seed, its setup and the choices made.
+	The time of every choice is kept too, so that the replay's clock reads what the game's did and
+	timelines run out at the same choices.
+	A deadline can pass between two choices, so the game is advanced to the time of each choice
+	before it is made, by the game that records it and by its replay alike.
+*/
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChoiceRecord {
+	pub player_index: ids::PlayerIndex,
+	pub choice_id: ids::ChoiceId,
+	// Only used to notice when a replay no longer matches what was played.
+	pub choice: Choice,
+	#[serde(default)]
+	pub time: DateTime<Utc>,
+	// Each player's auto pass setting when the choice was made, it decides what the next offers are.
+	#[serde(default)]
+	pub auto_pass: Vec<AutoPass>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameRecord {
+	pub seed: u64,
+	pub setup: GameSetup,
+	// When the game was set up.
+	#[serde(default)]
+	pub start_time: DateTime<Utc>,
+	pub choices: Vec<ChoiceRecord>,
+}
+
+impl GameRecord {
+	pub fn new(seed: u64, setup: GameSetup, start_time: DateTime<Utc>) -> Self {
+		Self {
+			seed,
+			setup,
+			start_time,
+			choices: Vec::new(),
+		}
+	}
+
+	// So that a game can be kept, and watched again later.
+	pub fn to_json(&self) -> serde_json::Result<String> {
+		serde_json::to_string(self)
+	}
+
+	pub fn from_json(json: &str) -> Result<Self, ReplayError> {
+		serde_json::from_str(json).map_err(|error| ReplayError::Unreadable(error.to_string()))
+	}
+
+	pub fn record(
+		&mut self,
+		game: &Game,
+		player_index: ids::PlayerIndex,
+		choice_id: ids::ChoiceId,
+		time: DateTime<Utc>,
+	) {
+		let choice = game.players[player_index]
+			.choices_
+			.as_ref()
+			.and_then(|choices| choices.options.iter().find(|option| option.id == choice_id))
+			.map(|option| option.choice.to_owned());
+		if let Some(choice) = choice {
+			self.choices.push(ChoiceRecord {
+				player_index,
+				choice_id,
+				choice,
+				time,
+				auto_pass: game.players.iter().map(|player| player.auto_pass).collect(),
+			});
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplayError {
+	Unreadable(String),
+	Diverged(usize),
+	Engine(usize, String),
+}
+
+impl fmt::Display for ReplayError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Unreadable(reason) => write!(f, "The record could not be read: {}", reason),
+			Self::Diverged(step) => write!(f, "The recorded choice {} was not offered.", step),
+			Self::Engine(step, reason) => write!(f, "Replaying choice {} failed: {}", step, reason),
+		}
+	}
+}
+
+// Every state of a recorded game, so that it can be viewed in any order.
+pub struct Replay {
+	pub record: GameRecord,
+	snapshots: Vec<Game>,
+}
+
+impl Replay {
+	pub fn new(record: GameRecord) -> Result<Self, ReplayError> {
+		let clock = ManualClock::new(record.start_time);
+		let mut context = GameBookKeeping::with_seed(record.seed);
+		context.clock = Box::new(clock.clone());
+		let mut game = Game::new();
+		initialize::initialize_game(&mut context, &mut game, &record.setup);
+
+		let mut snapshots = Vec::new();
+		for (step, entry) in record.choices.iter().enumerate() {
+			clock.set(entry.time);
+			for (player, auto_pass) in game.players.iter_mut().zip(entry.auto_pass.iter()) {
+				player.auto_pass = *auto_pass;
+			}
+			driver::advance_game(&mut context, &mut game)
+				.map_err(|error| ReplayError::Engine(step, error.to_string()))?;
+			snapshots.push(game.clone());
+
+			let offered = game.players[entry.player_index]
+				.choices_
+				.as_ref()
+				.map(|choices| {
+					choices
+						.options
+						.iter()
+						.any(|option| option.id == entry.choice_id && option.choice == entry.choice)
+				})
+				.unwrap_or(false);
+			if !offered {
+				return Err(ReplayError::Diverged(step));
+			}
+			driver::make_selection(&mut game, entry.player_index, entry.choice_id, &mut |_| {})
+				.map_err(|error| ReplayError::Engine(step, error.to_string()))?;
+			driver::advance_game(&mut context, &mut game)
+				.map_err(|error| ReplayError::Engine(step, error.to_string()))?;
+		}
+		snapshots.push(game);
+		Ok(Self { record, snapshots })
+	}
+
+	// One more than the number of choices: the state before each choice, and the final state.
+	pub fn number_of_steps(&self) -> usize {
+		self.snapshots.len()
+	}
+
+	pub fn game_at(&self, step: usize) -> &Game {
+		&self.snapshots[step.min(self.snapshots.len() - 1)]
+	}
+
+	// The choice that was made from the given step, if any.
+	pub fn choice_at(&self, step: usize) -> Option<&ChoiceRecord> {
+		self.record.choices.get(step)
+	}
+
+	// The first step of each turn.
+	pub fn turn_starts(&self) -> Vec<usize> {
+		(0..self.snapshots.len())
+			.filter(|step| {
+				*step == 0 || self.snapshots[*step].get_turn() != self.snapshots[*step - 1].get_turn()
+			})
+			.collect()
+	}
+}
+
+impl PartialEq for Replay {
+	fn eq(&self, other: &Self) -> bool {
+		self.record == other.record
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::Duration;
+
+	use crate::slay::deadlines;
+	use crate::slay::deadlines::Clock;
+	use crate::slay::deadlines::ManualClock;
+	use crate::slay::driver;
+	use crate::slay::game_context::GameBookKeeping;
+	use crate::slay::ids;
+	use crate::slay::replay::GameRecord;
+	use crate::slay::replay::Replay;
+	use crate::slay::replay::ReplayError;
+	use crate::slay::state::game::Game;
+	use crate::slay::state::initialize;
+	use crate::slay::state::initialize::GameSetup;
+	use crate::slay::strategy;
+
+	struct Recording {
+		clock: ManualClock,
+		context: GameBookKeeping,
+		game: Game,
+		record: GameRecord,
+	}
+
+	impl Recording {
+		fn start(seed: u64) -> Self {
+			let clock = ManualClock::new(deadlines::current_time());
+			let mut context = GameBookKeeping::with_seed(seed);
+			context.clock = Box::new(clock.clone());
+			let mut game = Game::new();
+			let setup = GameSetup::default();
+			initialize::initialize_game(&mut context, &mut game, &setup);
+			let record = GameRecord::new(seed, setup, clock.now());
+			Self {
+				clock,
+				context,
+				game,
+				record,
+			}
+		}
+
+		// Catches up to the clock first, the way the app does.
+		fn choose_randomly(&mut self) -> ids::ChoiceId {
+			driver::advance_game(&mut self.context, &mut self.game).unwrap();
+			let (player_index, choice_id) =
+				strategy::pick_a_random_choice(&mut self.context, &mut self.game).unwrap();
+			self
+				.record
+				.record(&self.game, player_index, choice_id, self.clock.now());
+			driver::make_selection(&mut self.game, player_index, choice_id, &mut |_| {}).unwrap();
+			driver::advance_game(&mut self.context, &mut self.game).unwrap();
+			choice_id
+		}
+
+		fn offered(&self) -> Vec<ids::ChoiceId> {
+			self
+				.game
+				.players
+				.iter()
+				.flat_map(|player| player.choices_.iter())
+				.flat_map(|choices| choices.options.iter().map(|option| option.id))
+				.collect()
+		}
+	}
+
+	#[test]
+	fn a_record_reads_back_from_json() {
+		let mut recording = Recording::start(11);
+		for _ in 0..30 {
+			recording.clock.advance(Duration::seconds(1));
+			recording.choose_randomly();
+		}
+
+		let json = recording.record.to_json().unwrap();
+		let record = GameRecord::from_json(&json).unwrap();
+		assert_eq!(record, recording.record);
+		let replay = Replay::new(record).unwrap();
+		assert_eq!(
+			replay.game_at(30).to_omniscient_perspective(),
+			recording.game.to_omniscient_perspective()
+		);
+
+		assert!(matches!(
+			GameRecord::from_json("{\"seed\": 11}"),
+			Err(ReplayError::Unreadable(_))
+		));
+	}
+
+	#[test]
+	fn a_showdown_that_timed_out_replays_the_same() {
+		let mut recording = Recording::start(5);
+		while recording.game.showdown.is_empty() {
+			recording.clock.advance(Duration::seconds(1));
+			recording.choose_randomly();
+		}
+		// Nobody answers until the showdown is over, the next choice is one it had not offered.
+		let offered_during_showdown = recording.offered();
+		recording.clock.advance(Duration::seconds(301));
+		let choice_id = recording.choose_randomly();
+		assert!(!offered_during_showdown.contains(&choice_id));
+		for _ in 0..10 {
+			recording.clock.advance(Duration::seconds(1));
+			recording.choose_randomly();
+		}
+
+		let steps = recording.record.choices.len();
+		let replay = Replay::new(recording.record.to_owned()).unwrap();
+		assert_eq!(
+			replay.game_at(steps).to_omniscient_perspective(),
+			recording.game.to_omniscient_perspective()
+		);
+	}
+}
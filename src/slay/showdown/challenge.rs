@@ -1,15 +1,18 @@
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
 use std::rc::Rc;
 
-use rand::rngs::ThreadRng;
-
 use crate::slay::choices::ChoicePerspective;
 use crate::slay::choices::Choices;
 use crate::slay::choices::ChoicesPerspective;
 use crate::slay::choices::ChoicesType;
-use crate::slay::deadlines;
 use crate::slay::game_context::GameBookKeeping;
 use crate::slay::ids;
 use crate::slay::modifier_visitors;
+use crate::slay::notification::ChallengeOutcome;
+use crate::slay::notification::Notification;
 use crate::slay::showdown::completion::CompletionTracker;
 use crate::slay::showdown::consequences::RollConsequences;
 use crate::slay::showdown::current_showdown::ShowDown;
@@ -22,7 +25,7 @@ use crate::slay::showdown::roll_state::RollReason;
 use crate::slay::state::game::Game;
 use crate::slay::state::game::GameStaticInformation;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChallengeRoll {
 	pub player_index: ids::PlayerIndex,
 	pub initial: Roll,
@@ -41,7 +44,7 @@ pub struct ChallengeState {
 	consequences: RollConsequences,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct ChallengePerspective {
 	pub initiator: ChallengeRoll,
 	pub challenger: ChallengeRoll,
@@ -53,13 +56,13 @@ pub struct ChallengePerspective {
 
 impl ChallengeRoll {
 	pub fn new(
-		rng: &mut ThreadRng,
+		context: &mut GameBookKeeping,
 		game: &Game,
 		player_index: ids::PlayerIndex,
 		path: ModificationPath,
 	) -> Self {
 		Self {
-			initial: Roll::create_from(rng),
+			initial: context.roll_dice(),
 			history: modifier_visitors::create_roll_history(
 				game,
 				player_index,
@@ -113,7 +116,7 @@ impl ChallengeState {
 	}
 
 	pub fn new(
-		rng: &mut rand::rngs::ThreadRng,
+		context: &mut GameBookKeeping,
 		game: &Game,
 		player_index: ids::PlayerIndex,
 		challenger_index: ids::PlayerIndex,
@@ -121,8 +124,13 @@ impl ChallengeState {
 		reason: ChallengeReason,
 	) -> Self {
 		Self {
-			initiator: ChallengeRoll::new(rng, game, player_index, ModificationPath::Initiator),
-			challenger: ChallengeRoll::new(rng, game, challenger_index, ModificationPath::Challenger),
+			initiator: ChallengeRoll::new(context, game, player_index, ModificationPath::Initiator),
+			challenger: ChallengeRoll::new(
+				context,
+				game,
+				challenger_index,
+				ModificationPath::Challenger,
+			),
 			completion_tracker: None,
 			consequences,
 			reason,
@@ -133,8 +141,9 @@ impl ChallengeState {
 		&mut self,
 		modification_path: ModificationPath,
 		modification: RollModification,
+		now: DateTime<Utc>,
 	) {
-		self.tracker_mut().timeline = deadlines::get_challenge_deadline();
+		self.tracker_mut().reset_timeline(now);
 		match modification_path {
 			ModificationPath::Roll => panic!(),
 			// Err(SlayError::new(
@@ -180,12 +189,21 @@ impl ShowDown for ChallengeState {
 		}
 	}
 
-	fn finish(&mut self, _context: &mut GameBookKeeping, game: &mut Game) {
+	fn finish(&mut self, context: &mut GameBookKeeping, game: &mut Game) {
 		let roll_sum = self.calculate_roll_total();
+		context.emit(&Notification::ChallengeResult(ChallengeOutcome {
+			reason: self.reason.to_owned(),
+			initiator: self.initiator.to_owned(),
+			challenger: self.challenger.to_owned(),
+		}));
 		self
 			.consequences
 			.apply_roll_sum(game, roll_sum, self.initiator.player_index);
 	}
+
+	fn can_respond(&self, game: &Game, player_index: ids::PlayerIndex) -> bool {
+		game.players[player_index].has_modifier_card(&game.cards)
+	}
 }
 
 // impl ChallengeRoll {
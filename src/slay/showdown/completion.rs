@@ -1,5 +1,10 @@
 use crate::slay::deadlines::Timeline;
+use crate::slay::i18n::keys::MessageKey;
 use crate::slay::ids;
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
 
 // #[derive(Debug, Clone, PartialEq, Eq, Copy)]
 // pub enum CompletionPath {
@@ -9,7 +14,7 @@ use crate::slay::ids;
 // }
 
 // Rename this to ShowdownCompletion
-#[derive(Debug, Clone, PartialEq, Eq, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq, Copy, Serialize, Deserialize)]
 pub enum Completion {
 	Thinking,
 	DoneUntilModification,
@@ -33,7 +38,42 @@ impl Completion {
 	}
 }
 
-#[derive(Debug, Clone, PartialEq)]
+// Whether a player should be prompted during showdowns they cannot affect.
+#[derive(Debug, Clone, PartialEq, Eq, Copy, Default, Serialize, Deserialize)]
+pub enum AutoPass {
+	Never,
+	#[default]
+	WhenNoResponse,
+	Always,
+}
+
+impl AutoPass {
+	pub fn should_pass(&self, can_respond: bool) -> bool {
+		match self {
+			Self::Never => false,
+			Self::WhenNoResponse => !can_respond,
+			Self::Always => true,
+		}
+	}
+
+	pub fn next(&self) -> Self {
+		match self {
+			Self::Never => Self::WhenNoResponse,
+			Self::WhenNoResponse => Self::Always,
+			Self::Always => Self::Never,
+		}
+	}
+
+	pub fn label_key(&self) -> MessageKey {
+		match self {
+			Self::Never => MessageKey::AutoPassNever,
+			Self::WhenNoResponse => MessageKey::AutoPassWhenNoResponse,
+			Self::Always => MessageKey::AutoPassAlways,
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CompletionTracker {
 	pub completions: Vec<Completion>,
 	pub timeline: Timeline,
@@ -51,8 +91,8 @@ impl CompletionTracker {
 		self.timeline = new_timeline;
 	}
 
-	pub fn is_complete(&self) -> bool {
-		let ret = self.completions.iter().all(|rc| rc.done()) || self.timeline.is_complete();
+	pub fn is_complete(&self, now: DateTime<Utc>) -> bool {
+		let ret = self.completions.iter().all(|rc| rc.done()) || self.timeline.is_complete(now);
 		// Check the deadline!?
 		log::info!("showdown completion: {}", ret);
 		ret
@@ -66,8 +106,8 @@ impl CompletionTracker {
 		self.completions[player_index].offer_on_modify()
 	}
 
-	pub(crate) fn reset_timeline(&mut self) {
-		self.timeline.reset();
+	pub(crate) fn reset_timeline(&mut self, now: DateTime<Utc>) {
+		self.timeline.reset(now);
 	}
 }
 
@@ -1,15 +1,21 @@
 use crate::slay::game_context::GameBookKeeping;
+use crate::slay::i18n::keys::MessageKey;
+use crate::slay::i18n::Arg;
+use crate::slay::i18n::Message;
 use crate::slay::ids;
+use crate::slay::showdown::current_showdown;
 use crate::slay::state::game::Game;
 use crate::slay::tasks::player_tasks::PlayerTask;
+use serde::Deserialize;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Comparison {
 	LE,
 	GE,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Condition {
 	pub cmp: Comparison,
 	pub threshold: i32,
@@ -63,6 +69,18 @@ impl Condition {
 			threshold,
 		}
 	}
+
+	pub fn label_message(&self) -> Message {
+		let key = match self.cmp {
+			Comparison::LE => MessageKey::ConditionAtMost,
+			Comparison::GE => MessageKey::ConditionAtLeast,
+		};
+		Message::new(key, vec![Arg::Number(self.threshold)])
+	}
+
+	pub fn label(&self) -> String {
+		self.label_message().to_english()
+	}
 }
 
 #[derive(Debug, Clone)]
@@ -113,9 +131,10 @@ impl RollConsequences {
 		roll_sum: i32,
 		player_index: ids::PlayerIndex,
 	) {
-		game.players.iter_mut().for_each(|p| p.clear_choices());
+		current_showdown::clear_showdown_choices(game);
 		// TODO: An extra copy here...
+		// They run before anything queued after the action that started the showdown.
 		let mut tasks = self.take_tasks_for(roll_sum);
-		game.players[player_index].tasks.take_from(&mut tasks);
+		game.players[player_index].tasks.prepend_from(&mut tasks);
 	}
 }
@@ -1,7 +1,11 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
 
+use chrono::DateTime;
+use chrono::Utc;
+
 use crate::slay::choices::Choices;
+use crate::slay::choices::ChoicesType;
 use crate::slay::errors::SlayError;
 use crate::slay::errors::SlayResult;
 use crate::slay::game_context::GameBookKeeping;
@@ -17,228 +21,306 @@ use crate::slay::state::game::Game;
 
 dyn_clone::clone_trait_object!(ShowDown);
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-enum ShowDownType {
-	None,
-	Roll,
-	OfferChallenges,
-	Challenge,
+/*
+	Showdowns nest. A card played while another showdown is being resolved, like an item played
+	during someone's roll, can be challenged on its own. The showdown it starts goes on top, and
+	the one underneath is suspended: its players' choices are taken away, but it keeps track of
+	who was already done. Once the showdown on top is finished, and everything it started has
+	run, the suspended one offers its choices again and carries on.
+*/
+
+#[derive(Clone, Debug)]
+pub enum ShowDownLevel {
+	Roll(RollState),
+	OfferChallenges(OfferChallengesState),
+	Challenge(ChallengeState),
+}
+
+impl ShowDownLevel {
+	fn label(&self) -> &'static str {
+		match self {
+			Self::Roll(_) => "roll",
+			Self::OfferChallenges(_) => "offer",
+			Self::Challenge(_) => "challenge",
+		}
+	}
+
+	fn showdown(&self) -> &dyn ShowDown {
+		match self {
+			Self::Roll(roll) => roll,
+			Self::OfferChallenges(offer) => offer,
+			Self::Challenge(challenge) => challenge,
+		}
+	}
+
+	fn showdown_mut(&mut self) -> &mut dyn ShowDown {
+		match self {
+			Self::Roll(roll) => roll,
+			Self::OfferChallenges(offer) => offer,
+			Self::Challenge(challenge) => challenge,
+		}
+	}
+
+	fn into_showdown(self) -> Box<dyn ShowDown> {
+		match self {
+			Self::Roll(roll) => Box::new(roll),
+			Self::OfferChallenges(offer) => Box::new(offer),
+			Self::Challenge(challenge) => Box::new(challenge),
+		}
+	}
 }
 
 #[derive(Clone, Debug)]
+struct Level {
+	showdown: ShowDownLevel,
+	// Waiting for a showdown started on top of it.
+	suspended: bool,
+}
+
+#[derive(Clone, Debug, Default)]
 pub struct CurrentShowdown {
-	show_down_type: ShowDownType,
+	// The innermost showdown is last.
+	levels: Vec<Level>,
+}
+
+fn is_showdown_choice(choices_type: &ChoicesType) -> bool {
+	matches!(
+		choices_type,
+		ChoicesType::ModifyRoll | ChoicesType::OfferChallenges | ChoicesType::ModifyChallenge
+	)
+}
 
-	roll: Option<RollState>,
-	offer: Option<OfferChallengesState>,
-	challenge: Option<ChallengeState>,
+// Choosing something other than how to respond to the showdown.
+fn is_busy(game: &Game, player_index: ids::PlayerIndex) -> bool {
+	game.players[player_index]
+		.choices_
+		.as_ref()
+		.map(|choices| !is_showdown_choice(&choices.choices_type))
+		.unwrap_or(false)
 }
 
-impl Default for CurrentShowdown {
-	fn default() -> Self {
-		Self {
-			show_down_type: ShowDownType::None,
-			roll: Default::default(),
-			offer: Default::default(),
-			challenge: Default::default(),
+// Leaves the choices of anyone busy with something else, like placing an item during a roll.
+pub(crate) fn clear_showdown_choices(game: &mut Game) {
+	for player in game.players.iter_mut() {
+		if player
+			.choices_
+			.as_ref()
+			.map(|choices| is_showdown_choice(&choices.choices_type))
+			.unwrap_or(false)
+		{
+			player.clear_choices();
 		}
 	}
 }
 
+// Offers the showdown's choices, suspending the one in flight until it is finished.
+pub(crate) fn begin(context: &mut GameBookKeeping, game: &mut Game, mut showdown: ShowDownLevel) {
+	if let Some(current) = game.showdown.levels.last_mut() {
+		if !current.suspended {
+			log::info!("Suspending the {}", current.showdown.label());
+			current.suspended = true;
+			clear_showdown_choices(game);
+		}
+	}
+	showdown.showdown_mut().assign_all_choices(context, game);
+	game.showdown.levels.push(Level {
+		showdown,
+		suspended: false,
+	});
+}
+
+// Picks a suspended showdown back up, offering its choices to the players that were not done yet.
+pub(crate) fn resume(context: &mut GameBookKeeping, game: &mut Game) -> bool {
+	let Some(mut level) = game.showdown.levels.pop() else {
+		return false;
+	};
+	if level.suspended {
+		log::info!("Resuming the {}", level.showdown.label());
+		level.suspended = false;
+		let showdown = level.showdown.showdown_mut();
+		showdown.tracker_mut().reset_timeline(context.now());
+		for player_index in 0..game.number_of_players() {
+			// They are offered the roll again once they are done with what they were doing.
+			if showdown.tracker().completions[player_index].done() || is_busy(game, player_index) {
+				continue;
+			}
+			if showdown.should_auto_pass(game, player_index) {
+				showdown
+					.tracker_mut()
+					.set_player_completion(player_index, Completion::AllDone);
+				continue;
+			}
+			let choices = showdown.create_choice_for(context, game, player_index);
+			game.players[player_index].choose(choices);
+		}
+		game.showdown.levels.push(level);
+		return true;
+	}
+	game.showdown.levels.push(level);
+	false
+}
+
 pub struct ModificationTask {
 	choices_to_assign: HashMap<ids::PlayerIndex, Choices>,
+	auto_passed: Vec<ids::PlayerIndex>,
 }
 
 impl ModificationTask {
-	pub fn apply(&self, _context: &mut GameBookKeeping, game: &mut Game) {
+	pub fn apply(&self, _context: &mut GameBookKeeping, game: &mut Game) -> SlayResult<()> {
 		// Extra copy...
 		for (player_index, choices) in self.choices_to_assign.iter() {
+			// Like placing an item during a roll, they go back to the roll once they are done.
+			if is_busy(game, *player_index) {
+				continue;
+			}
 			game.players[*player_index].choose(choices.to_owned());
 		}
+		for player_index in self.auto_passed.iter() {
+			if !is_busy(game, *player_index) {
+				game.players[*player_index].clear_choices();
+			}
+			game
+				.showdown
+				.set_player_completion(*player_index, Completion::AllDone)?;
+		}
+		Ok(())
 	}
 }
 
 impl CurrentShowdown {
-	// pub fn set_showdown(
-	//     &mut self,
-	//     context: &mut GameBookKeeping,
-	//     showdown: &mut Option<Box<dyn ShowDown>>,
-	// ) {
-	//     showdown
-	//         .as_ref()
-	//         .map(|s| s.assign_all_choices(context, self));
-	//     self.showdown = showdown.take();
-	// }
-
-	pub fn reset_timer(&mut self) {
+	pub fn reset_timer(&mut self, now: DateTime<Utc>) {
 		if let Some(r) = self.current_mut() {
-			r.tracker_mut().reset_timeline()
+			r.tracker_mut().reset_timeline(now)
 		}
 	}
 
 	pub fn is_empty(&self) -> bool {
-		self.show_down_type == ShowDownType::None
+		self.levels.is_empty()
 	}
 
-	pub fn get_roll(&self) -> Option<&RollState> {
-		self.roll.as_ref()
+	// How many showdowns are in flight, including the suspended ones.
+	pub(crate) fn depth(&self) -> usize {
+		self.levels.len()
 	}
 
-	pub fn get_offer(&self) -> Option<&OfferChallengesState> {
-		self.offer.as_ref()
+	// The showdowns in flight, outermost first.
+	pub(crate) fn active_parts(&self) -> Vec<&'static str> {
+		self
+			.levels
+			.iter()
+			.map(|level| level.showdown.label())
+			.collect()
 	}
 
-	pub fn get_challenge(&self) -> Option<&ChallengeState> {
-		self.challenge.as_ref()
+	// Only the innermost showdown can be running, every one underneath waits for it.
+	pub(crate) fn is_consistent(&self) -> bool {
+		self
+			.levels
+			.iter()
+			.rev()
+			.skip(1)
+			.all(|level| level.suspended)
 	}
 
-	pub fn take_current_offer(&mut self) -> SlayResult<OfferChallengesState> {
-		if self.show_down_type != ShowDownType::OfferChallenges {
-			return Err(SlayError::new(
-				"Needed to be offering challenges, instead there was no roll event.",
-			));
-		}
-		self.show_down_type = ShowDownType::None;
+	fn running(&self) -> Option<&ShowDownLevel> {
 		self
-			.offer
-			.take()
-			.ok_or_else(|| SlayError::new("No eyes shall see this"))
+			.levels
+			.last()
+			.filter(|level| !level.suspended)
+			.map(|level| &level.showdown)
 	}
 
-	pub fn roll(&mut self, roll: RollState) {
-		if self.show_down_type != ShowDownType::None {
-			panic!();
+	fn running_mut(&mut self) -> Option<&mut ShowDownLevel> {
+		self
+			.levels
+			.last_mut()
+			.filter(|level| !level.suspended)
+			.map(|level| &mut level.showdown)
+	}
+
+	// These only return the showdown that is running, not the ones it suspended.
+	pub fn get_roll(&self) -> Option<&RollState> {
+		match self.running() {
+			Some(ShowDownLevel::Roll(roll)) => Some(roll),
+			_ => None,
 		}
-		self.show_down_type = ShowDownType::Roll;
-		self.roll = Some(roll);
-		self.challenge = None;
-		self.offer = None;
 	}
 
-	pub fn challenge(&mut self, challenge: ChallengeState) {
-		if self.show_down_type != ShowDownType::None {
-			panic!();
+	pub fn get_offer(&self) -> Option<&OfferChallengesState> {
+		match self.running() {
+			Some(ShowDownLevel::OfferChallenges(offer)) => Some(offer),
+			_ => None,
 		}
-		self.show_down_type = ShowDownType::Challenge;
-		self.roll = None;
-		self.challenge = Some(challenge);
-		self.offer = None;
 	}
 
-	pub fn offer(&mut self, offer: OfferChallengesState) {
-		if self.show_down_type != ShowDownType::None {
-			panic!();
+	pub fn get_challenge(&self) -> Option<&ChallengeState> {
+		match self.running() {
+			Some(ShowDownLevel::Challenge(challenge)) => Some(challenge),
+			_ => None,
 		}
-		self.show_down_type = ShowDownType::OfferChallenges;
-		self.roll = None;
-		self.challenge = None;
-		self.offer = Some(offer);
-	}
-	pub fn clear(&mut self) {
-		if self.show_down_type == ShowDownType::None {
-			panic!();
+	}
+
+	pub fn take_current_offer(&mut self) -> SlayResult<OfferChallengesState> {
+		if self.get_offer().is_none() {
+			return Err(SlayError::new(
+				"Needed to be offering challenges, instead there was no roll event.",
+			));
+		}
+		match self.levels.pop().map(|level| level.showdown) {
+			Some(ShowDownLevel::OfferChallenges(offer)) => Ok(offer),
+			_ => Err(SlayError::new("No eyes shall see this")),
 		}
-		log::info!("showdown cleared");
-		self.roll = None;
-		self.challenge = None;
-		self.offer = None;
 	}
 
 	pub fn current(&self) -> Option<&dyn ShowDown> {
-		match self.show_down_type {
-			ShowDownType::None => None,
-			ShowDownType::Roll => self.roll.as_ref().map(|x| x as &dyn ShowDown),
-			ShowDownType::OfferChallenges => self.offer.as_ref().map(|x| x as &dyn ShowDown),
-			ShowDownType::Challenge => self.challenge.as_ref().map(|x| x as &dyn ShowDown),
-		}
+		self.running().map(|showdown| showdown.showdown())
 	}
 	pub fn current_mut(&mut self) -> Option<&mut dyn ShowDown> {
-		match self.show_down_type {
-			ShowDownType::None => None,
-			ShowDownType::Roll => self.roll.as_mut().map(|x| x as &mut dyn ShowDown),
-			ShowDownType::OfferChallenges => self.offer.as_mut().map(|x| x as &mut dyn ShowDown),
-			ShowDownType::Challenge => self.challenge.as_mut().map(|x| x as &mut dyn ShowDown),
-		}
+		self.running_mut().map(|showdown| showdown.showdown_mut())
 	}
-	// pub fn current_mut(&mut self) -> Option<Box<dyn ShowDown>> {
-	//     match self.show_down_type {
-	//         ShowDownType::None => None,
-	//         ShowDownType::Roll => self.roll.map(|x| Box::new(x) as Box::<dyn ShowDown>),
-	//         ShowDownType::OfferChallenges => self.offer.map(|x| Box::new(x) as Box::<dyn ShowDown>),
-	//         ShowDownType::Challenge => self.challenge.map(|x| Box::new(x) as Box::<dyn ShowDown>),
-	//     }
-	// }
-
-	// fn assign_all_choices(
-	//     &mut self,
-	//     context: &mut GameBookKeeping,
-	//     game: &mut Game,
-	// ) {
-	//     self.current_mut().map(
-	//         |s| s.assign_all_choices(context, game);
-	//     );
-	// }
 
-	pub fn take_complete(&mut self) -> Option<Box<dyn ShowDown>> {
+	pub fn take_complete(&mut self, now: DateTime<Utc>) -> Option<Box<dyn ShowDown>> {
 		if self.current().is_none() {
 			log::debug!("There is no current showdown.");
 			return None;
 		}
-		if !self.current().as_ref().unwrap().tracker().is_complete() {
+		if !self.current().as_ref().unwrap().tracker().is_complete(now) {
 			log::debug!("The current showdown is not complete.");
 			return None;
 		}
 		log::info!("The current showdown is complete!");
-		let current_type = self.show_down_type.to_owned();
-		self.show_down_type = ShowDownType::None;
-		match current_type {
-			ShowDownType::None => panic!(),
-			ShowDownType::Roll => self.roll.take().map(|x| Box::new(x) as Box<dyn ShowDown>),
-			ShowDownType::OfferChallenges => self.offer.take().map(|x| Box::new(x) as Box<dyn ShowDown>),
-			ShowDownType::Challenge => self
-				.challenge
-				.take()
-				.map(|x| Box::new(x) as Box<dyn ShowDown>),
-		}
+		self
+			.levels
+			.pop()
+			.map(|level| level.showdown.into_showdown())
 	}
 
 	pub(crate) fn add_modification(
 		&mut self,
 		modification_path: ModificationPath,
 		modification: RollModification,
+		now: DateTime<Utc>,
 	) -> SlayResult<()> {
-		match modification_path {
-			ModificationPath::Roll => match self.show_down_type {
-				ShowDownType::None => return Err(SlayError::new("ASFDGAJKLRIYU")),
-				ShowDownType::Roll => {}
-				ShowDownType::OfferChallenges => return Err(SlayError::new("ADSHAF")),
-				ShowDownType::Challenge => return Err(SlayError::new("adsfbHJJJQ")),
-			},
-			ModificationPath::Challenger => match self.show_down_type {
-				ShowDownType::None => return Err(SlayError::new("asdfgaq4hadf")),
-				ShowDownType::Roll => return Err(SlayError::new("aevq34yq")),
-				ShowDownType::OfferChallenges => return Err(SlayError::new("3vaesdghar")),
-				ShowDownType::Challenge => {}
-			},
-			ModificationPath::Initiator => match self.show_down_type {
-				ShowDownType::None => return Err(SlayError::new("gadsgadg")),
-				ShowDownType::Roll => return Err(SlayError::new("mp[aqgjsy")),
-				ShowDownType::OfferChallenges => return Err(SlayError::new("")),
-				ShowDownType::Challenge => {}
-			},
+		match (modification_path, self.running_mut()) {
+			(ModificationPath::Roll, Some(ShowDownLevel::Roll(roll))) => {
+				roll.add_modification(modification, now);
+				Ok(())
+			}
+			(
+				ModificationPath::Challenger | ModificationPath::Initiator,
+				Some(ShowDownLevel::Challenge(challenge)),
+			) => {
+				challenge.add_modification(modification_path, modification, now);
+				Ok(())
+			}
+			(_, None) => Err(SlayError::new("There is no showdown to modify.")),
+			(ModificationPath::Roll, Some(_)) => Err(SlayError::new(
+				"Modifying a roll, but the current showdown is not a roll.",
+			)),
+			(_, Some(_)) => Err(SlayError::new(
+				"Modifying a challenge, but the current showdown is not a challenge.",
+			)),
 		}
-
-		match self.show_down_type {
-			ShowDownType::None => panic!(),
-			ShowDownType::OfferChallenges => panic!(),
-			ShowDownType::Roll => self.roll.as_mut().unwrap().add_modification(modification),
-			ShowDownType::Challenge => self
-				.challenge
-				.as_mut()
-				.unwrap()
-				.add_modification(modification_path, modification),
-		};
-		Ok(())
 	}
 
 	pub(crate) fn get_modification_task(
@@ -247,13 +329,17 @@ impl CurrentShowdown {
 		game: &Game,
 	) -> ModificationTask {
 		let current = self.current().unwrap();
+		let (auto_passed, to_offer): (Vec<ids::PlayerIndex>, Vec<ids::PlayerIndex>) = (0..game
+			.number_of_players())
+			.filter(|player_index| {
+				current
+					.tracker()
+					.should_offer_modifications_again(*player_index)
+			})
+			.partition(|player_index| current.should_auto_pass(game, *player_index));
 		ModificationTask {
-			choices_to_assign: (0..game.number_of_players())
-				.filter(|player_index| {
-					current
-						.tracker()
-						.should_offer_modifications_again(*player_index)
-				})
+			choices_to_assign: to_offer
+				.into_iter()
 				.map(|player_index| {
 					(
 						player_index,
@@ -261,6 +347,7 @@ impl CurrentShowdown {
 					)
 				})
 				.collect(),
+			auto_passed,
 		}
 	}
 
@@ -274,21 +361,11 @@ impl CurrentShowdown {
 			player_index,
 			persist
 		);
-		match self.show_down_type {
-			ShowDownType::None => return Err(SlayError::new("alskjdf;alksjdf;")),
-			ShowDownType::Roll => self
-				.roll
-				.as_mut()
-				.map(|x| x.tracker_mut().set_player_completion(player_index, persist)),
-			ShowDownType::OfferChallenges => self
-				.offer
-				.as_mut()
-				.map(|x| x.tracker_mut().set_player_completion(player_index, persist)),
-			ShowDownType::Challenge => self
-				.challenge
-				.as_mut()
-				.map(|x| x.tracker_mut().set_player_completion(player_index, persist)),
-		};
+		self
+			.current_mut()
+			.ok_or_else(|| SlayError::new("alskjdf;alksjdf;"))?
+			.tracker_mut()
+			.set_player_completion(player_index, persist);
 		Ok(())
 	}
 }
@@ -311,9 +388,31 @@ pub trait ShowDown: Debug + dyn_clone::DynClone {
 
 	fn finish(&mut self, _context: &mut GameBookKeeping, game: &mut Game);
 
-	fn assign_all_choices(&self, context: &mut GameBookKeeping, game: &mut Game) {
+	// Whether the player holds anything that would let them affect this showdown.
+	fn can_respond(&self, game: &Game, player_index: ids::PlayerIndex) -> bool;
+
+	fn should_auto_pass(&self, game: &Game, player_index: ids::PlayerIndex) -> bool {
+		game.players[player_index]
+			.auto_pass
+			.should_pass(self.can_respond(game, player_index))
+	}
+
+	fn assign_all_choices(&mut self, context: &mut GameBookKeeping, game: &mut Game) {
 		let nb_players = game.number_of_players();
 		for player_index in 0..nb_players {
+			if !self
+				.tracker()
+				.should_offer_modifications_again(player_index)
+			{
+				continue;
+			}
+			// Someone placing an item during a roll cannot respond to it being challenged as well.
+			if self.should_auto_pass(game, player_index) || is_busy(game, player_index) {
+				self
+					.tracker_mut()
+					.set_player_completion(player_index, Completion::AllDone);
+				continue;
+			}
 			let choices = self.create_choice_for(context, game, player_index);
 			game.players[player_index].choose(choices);
 		}
@@ -329,3 +428,248 @@ pub trait ShowDown: Debug + dyn_clone::DynClone {
 	//         .for_each(|player| self.assign_choices(context, player));
 	// }
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::slay::choices::Action;
+	use crate::slay::choices::Choice;
+	use crate::slay::choices::ChoicesType;
+	use crate::slay::driver;
+	use crate::slay::game_context::GameBookKeeping;
+	use crate::slay::ids;
+	use crate::slay::showdown::completion::AutoPass;
+	use crate::slay::showdown::completion::Completion;
+	use crate::slay::showdown::current_showdown::ShowDown;
+	use crate::slay::showdown::roll::Roll;
+	use crate::slay::specification::HeroType;
+	use crate::slay::specs::cards::card_type::SlayCardSpec;
+	use crate::slay::specs::hero::HeroAbilityType;
+	use crate::slay::specs::items::AnotherItemType;
+	use crate::slay::specs::items::Item;
+	use crate::slay::specs::modifier::ModifierKinds;
+	use crate::slay::state::builder::GameBuilder;
+	use crate::slay::state::builder::StackBuilder;
+	use crate::slay::state::game::Game;
+
+	const PEANUT: SlayCardSpec = SlayCardSpec::HeroCard(HeroAbilityType::Peanut);
+	const RING: SlayCardSpec = SlayCardSpec::Item(AnotherItemType::NotMask(Item::ReallyBigRing));
+	const MODIFIER: SlayCardSpec = SlayCardSpec::ModifierCard(ModifierKinds::Plus1Minus3);
+
+	fn choose_where(
+		context: &mut GameBookKeeping,
+		game: &mut Game,
+		player_index: ids::PlayerIndex,
+		matches: &dyn Fn(&Choice) -> bool,
+	) {
+		let choice_id = game.players[player_index]
+			.choices_
+			.as_ref()
+			.and_then(|choices| {
+				choices
+					.options
+					.iter()
+					.find(|option| matches(&option.choice))
+			})
+			.map(|option| option.id)
+			.unwrap_or_else(|| panic!("Player {} was not offered the choice", player_index));
+		driver::make_selection(game, player_index, choice_id, &mut |_| {}).unwrap();
+		driver::advance_game(context, game).unwrap();
+	}
+
+	fn choose(
+		context: &mut GameBookKeeping,
+		game: &mut Game,
+		player_index: ids::PlayerIndex,
+		choice: Choice,
+	) {
+		choose_where(context, game, player_index, &|offered| *offered == choice);
+	}
+
+	fn choices_type(game: &Game, player_index: ids::PlayerIndex) -> Option<ChoicesType> {
+		game.players[player_index]
+			.choices_
+			.as_ref()
+			.map(|choices| choices.choices_type.to_owned())
+	}
+
+	// Player 0 rolls for Peanut, player 1 plays an item during the roll and player 2 challenges it.
+	fn challenge_an_item_played_during_a_roll(
+		context: &mut GameBookKeeping,
+		initiator: Roll,
+		challenger: Roll,
+	) -> Game {
+		let mut game = GameBuilder::new(3)
+			.leader(0, HeroType::Thief)
+			.leader(1, HeroType::Thief)
+			.leader(2, HeroType::Thief)
+			.party(0, vec![StackBuilder::new(PEANUT)])
+			.hand(0, vec![MODIFIER])
+			.hand(1, vec![RING, MODIFIER])
+			.hand(2, vec![SlayCardSpec::Challenge, MODIFIER])
+			.draw_pile(vec![MODIFIER; 4])
+			.build(context);
+		context.script_dice(vec![Roll::new(3, 3), initiator, challenger]);
+
+		choose(
+			context,
+			&mut game,
+			0,
+			Choice::UseActionPoints(Action::RollForAbility(HeroAbilityType::Peanut)),
+		);
+		choose(
+			context,
+			&mut game,
+			0,
+			Choice::SetCompletion(Completion::AllDone),
+		);
+		choose(context, &mut game, 1, Choice::PlayImmediately(RING));
+		assert_eq!(game.showdown.active_parts(), vec!["roll", "offer"]);
+
+		choose(context, &mut game, 2, Choice::Challenge);
+		assert_eq!(game.showdown.active_parts(), vec!["roll", "challenge"]);
+		for player_index in 0..3 {
+			assert_eq!(
+				choices_type(&game, player_index),
+				Some(ChoicesType::ModifyChallenge)
+			);
+			choose(
+				context,
+				&mut game,
+				player_index,
+				Choice::SetCompletion(Completion::AllDone),
+			);
+		}
+		game
+	}
+
+	fn assert_roll_resumed(game: &Game) {
+		assert_eq!(game.showdown.active_parts(), vec!["roll"]);
+		let roll = game.showdown.get_roll().unwrap();
+		assert_eq!(roll.tracker().completions[0], Completion::AllDone);
+		assert_eq!(choices_type(game, 0), None);
+		for player_index in [1, 2] {
+			assert_eq!(
+				roll.tracker().completions[player_index],
+				Completion::Thinking
+			);
+			assert_eq!(
+				choices_type(game, player_index),
+				Some(ChoicesType::ModifyRoll)
+			);
+		}
+	}
+
+	#[test]
+	fn a_sustained_challenge_during_a_roll_goes_back_to_the_roll() {
+		let context = &mut GameBookKeeping::new();
+		let game = challenge_an_item_played_during_a_roll(context, Roll::new(1, 1), Roll::new(6, 6));
+		assert_roll_resumed(&game);
+		assert!(game
+			.discard
+			.tops(&game.cards)
+			.any(|card| card.card_type == RING));
+	}
+
+	#[test]
+	fn a_denied_challenge_places_the_item_before_going_back_to_the_roll() {
+		let context = &mut GameBookKeeping::new();
+		let mut game =
+			challenge_an_item_played_during_a_roll(context, Roll::new(6, 6), Roll::new(1, 1));
+		// The roll waits for the item to be placed.
+		assert_eq!(game.showdown.active_parts(), vec!["roll"]);
+		assert_eq!(choices_type(&game, 2), None);
+		choose_where(context, &mut game, 1, &|choice| {
+			matches!(choice, Choice::SetPlayerParam(_, 0))
+		});
+		choose_where(context, &mut game, 1, &|choice| {
+			matches!(choice, Choice::SetCardParameter(_, PEANUT))
+		});
+		assert_roll_resumed(&game);
+		let peanut = game.players[0].party.stacks().next().unwrap();
+		assert_eq!(peanut.modifiers.len(), 1);
+	}
+
+	// Player 0 rolls for Peanut while player 1 holds a modifier and player 2 holds nothing to respond with.
+	fn roll_for_peanut(context: &mut GameBookKeeping, auto_pass: AutoPass) -> Game {
+		let mut game = GameBuilder::new(3)
+			.leader(0, HeroType::Thief)
+			.leader(1, HeroType::Thief)
+			.leader(2, HeroType::Thief)
+			.party(0, vec![StackBuilder::new(PEANUT)])
+			.hand(1, vec![MODIFIER])
+			.draw_pile(vec![MODIFIER; 4])
+			.build(context);
+		game.players[0].auto_pass = AutoPass::Never;
+		game.players[1].auto_pass = auto_pass;
+		game.players[2].auto_pass = auto_pass;
+		context.script_dice(vec![Roll::new(3, 3)]);
+		choose(
+			context,
+			&mut game,
+			0,
+			Choice::UseActionPoints(Action::RollForAbility(HeroAbilityType::Peanut)),
+		);
+		game
+	}
+
+	fn offered_the_roll(game: &Game) -> Vec<ids::PlayerIndex> {
+		(0..game.number_of_players())
+			.filter(|player_index| choices_type(game, *player_index) == Some(ChoicesType::ModifyRoll))
+			.collect()
+	}
+
+	fn passed_the_roll(game: &Game) -> Vec<ids::PlayerIndex> {
+		let roll = game.showdown.get_roll().unwrap();
+		(0..game.number_of_players())
+			.filter(|player_index| roll.tracker().completions[*player_index] == Completion::AllDone)
+			.collect()
+	}
+
+	#[test]
+	fn never_auto_passing_offers_the_roll_without_a_response() {
+		let context = &mut GameBookKeeping::new();
+		let game = roll_for_peanut(context, AutoPass::Never);
+		assert_eq!(offered_the_roll(&game), vec![0, 1, 2]);
+		assert_eq!(passed_the_roll(&game), Vec::<ids::PlayerIndex>::new());
+	}
+
+	#[test]
+	fn auto_passing_without_a_response_offers_the_roll_to_those_holding_one() {
+		let context = &mut GameBookKeeping::new();
+		let game = roll_for_peanut(context, AutoPass::WhenNoResponse);
+		assert_eq!(offered_the_roll(&game), vec![0, 1]);
+		assert_eq!(passed_the_roll(&game), vec![2]);
+	}
+
+	#[test]
+	fn always_auto_passing_skips_the_roll_despite_a_response() {
+		let context = &mut GameBookKeeping::new();
+		let game = roll_for_peanut(context, AutoPass::Always);
+		assert_eq!(offered_the_roll(&game), vec![0]);
+		assert_eq!(passed_the_roll(&game), vec![1, 2]);
+	}
+
+	#[test]
+	fn a_modification_reoffers_the_roll_according_to_the_current_setting() {
+		for (auto_pass, offered, passed) in [
+			(AutoPass::Never, vec![0, 1, 2], vec![]),
+			(AutoPass::WhenNoResponse, vec![0, 1], vec![2]),
+			(AutoPass::Always, vec![0], vec![1, 2]),
+		] {
+			let context = &mut GameBookKeeping::new();
+			let mut game = roll_for_peanut(context, AutoPass::Never);
+			game.players[1].auto_pass = auto_pass;
+			game.players[2].auto_pass = auto_pass;
+
+			let task = game.showdown.get_modification_task(context, &game);
+			let mut to_offer = task.choices_to_assign.keys().copied().collect::<Vec<_>>();
+			to_offer.sort();
+			assert_eq!(to_offer, offered, "{:?}", auto_pass);
+			assert_eq!(task.auto_passed, passed, "{:?}", auto_pass);
+
+			task.apply(context, &mut game).unwrap();
+			assert_eq!(offered_the_roll(&game), offered, "{:?}", auto_pass);
+			assert_eq!(passed_the_roll(&game), passed, "{:?}", auto_pass);
+		}
+	}
+}
@@ -0,0 +1,163 @@
+use std::collections::VecDeque;
+use std::fmt::Debug;
+
+use rand::rngs::StdRng;
+
+use crate::slay::showdown::roll::Roll;
+
+dyn_clone::clone_trait_object!(DiceSource);
+
+// Where every roll of the dice comes from, so that tests can decide how a roll turns out.
+pub trait DiceSource: Debug + dyn_clone::DynClone {
+	fn roll(&mut self, rng: &mut StdRng) -> Roll;
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RandomDice {}
+
+impl DiceSource for RandomDice {
+	fn roll(&mut self, rng: &mut StdRng) -> Roll {
+		Roll::create_from(rng)
+	}
+}
+
+// Rolls the queued rolls in order, then goes back to random rolls once they run out.
+#[derive(Debug, Clone, Default)]
+pub struct ScriptedDice {
+	rolls: VecDeque<Roll>,
+}
+
+impl ScriptedDice {
+	pub fn new(rolls: Vec<Roll>) -> Self {
+		Self {
+			rolls: VecDeque::from(rolls),
+		}
+	}
+
+	pub fn queue(&mut self, roll: Roll) {
+		self.rolls.push_back(roll);
+	}
+
+	pub fn remaining(&self) -> usize {
+		self.rolls.len()
+	}
+}
+
+impl DiceSource for ScriptedDice {
+	fn roll(&mut self, rng: &mut StdRng) -> Roll {
+		self
+			.rolls
+			.pop_front()
+			.unwrap_or_else(|| Roll::create_from(rng))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::slay::choices::Action;
+	use crate::slay::choices::Choice;
+	use crate::slay::choices::ChoicesType;
+	use crate::slay::driver;
+	use crate::slay::game_context::GameBookKeeping;
+	use crate::slay::ids;
+	use crate::slay::showdown::completion::Completion;
+	use crate::slay::showdown::dice::DiceSource;
+	use crate::slay::showdown::dice::ScriptedDice;
+	use crate::slay::showdown::roll::Roll;
+	use crate::slay::specs::cards::card_type::SlayCardSpec;
+	use crate::slay::specs::hero::HeroAbilityType;
+	use crate::slay::specs::modifier::ModifierKinds;
+	use crate::slay::state::builder::GameBuilder;
+	use crate::slay::state::game::Game;
+
+	const PEANUT: SlayCardSpec = SlayCardSpec::HeroCard(HeroAbilityType::Peanut);
+	const MODIFIER: SlayCardSpec = SlayCardSpec::ModifierCard(ModifierKinds::Plus1Minus3);
+
+	fn choose(
+		context: &mut GameBookKeeping,
+		game: &mut Game,
+		player_index: ids::PlayerIndex,
+		choice: Choice,
+	) {
+		let choice_id = game.players[player_index]
+			.choices_
+			.as_ref()
+			.and_then(|choices| {
+				choices
+					.options
+					.iter()
+					.find(|option| option.choice == choice)
+			})
+			.map(|option| option.id)
+			.unwrap_or_else(|| panic!("Player {} was not offered {:?}", player_index, choice));
+		driver::make_selection(game, player_index, choice_id, &mut |_| {}).unwrap();
+		driver::advance_game(context, game).unwrap();
+	}
+
+	// Player 0 places Peanut, player 1 challenges it and the dice decide who wins.
+	fn challenge_a_hero(initiator: Roll, challenger: Roll) -> Game {
+		let context = &mut GameBookKeeping::new();
+		let mut game = GameBuilder::new(2)
+			.hand(0, vec![PEANUT, MODIFIER])
+			.hand(1, vec![SlayCardSpec::Challenge, MODIFIER])
+			.draw_pile(vec![MODIFIER; 4])
+			.build(context);
+		context.script_dice(vec![initiator, challenger]);
+
+		choose(
+			context,
+			&mut game,
+			0,
+			Choice::UseActionPoints(Action::PlaceHeroInParty(HeroAbilityType::Peanut)),
+		);
+		choose(context, &mut game, 1, Choice::Challenge);
+		for player_index in 0..2 {
+			let choices_type = game.players[player_index]
+				.choices_
+				.as_ref()
+				.map(|choices| choices.choices_type.to_owned());
+			assert_eq!(choices_type, Some(ChoicesType::ModifyChallenge));
+			choose(
+				context,
+				&mut game,
+				player_index,
+				Choice::SetCompletion(Completion::AllDone),
+			);
+		}
+		// A placed hero can go on to roll for its ability straight away.
+		assert!(game.showdown.get_challenge().is_none());
+		game
+	}
+
+	fn has_peanut(game: &Game, card_ids: impl Iterator<Item = ids::CardId>) -> bool {
+		card_ids
+			.map(|card_id| game.cards.card_type(card_id))
+			.any(|spec| spec == PEANUT)
+	}
+
+	#[test]
+	fn a_sustained_challenge_discards_the_hero() {
+		let game = challenge_a_hero(Roll::new(1, 1), Roll::new(6, 6));
+		assert!(has_peanut(&game, game.discard.top_ids()));
+		assert!(!has_peanut(&game, game.players[0].party.top_ids()));
+	}
+
+	#[test]
+	fn a_denied_challenge_places_the_hero() {
+		let game = challenge_a_hero(Roll::new(6, 6), Roll::new(1, 1));
+		assert!(has_peanut(&game, game.players[0].party.top_ids()));
+		assert!(!has_peanut(&game, game.discard.top_ids()));
+	}
+
+	#[test]
+	fn scripted_dice_roll_randomly_once_they_run_out() {
+		let context = &mut GameBookKeeping::new();
+		let mut dice = ScriptedDice::new(vec![Roll::new(2, 5)]);
+		dice.queue(Roll::new(6, 6));
+		assert_eq!(dice.roll(&mut context.rng), Roll::new(2, 5));
+		assert_eq!(dice.roll(&mut context.rng), Roll::new(6, 6));
+		assert_eq!(dice.remaining(), 0);
+		let roll = dice.roll(&mut context.rng);
+		assert!((1..=6).contains(&roll.die1) && (1..=6).contains(&roll.die2));
+	}
+}
@@ -2,6 +2,8 @@ pub mod challenge;
 pub mod completion;
 pub mod consequences;
 pub mod current_showdown;
+pub mod dice;
+pub mod odds;
 pub mod offer;
 pub mod roll;
 pub mod roll_choices;
@@ -0,0 +1,232 @@
+use crate::slay::choices::Action;
+use crate::slay::choices::Choice;
+use crate::slay::i18n::keys::MessageKey;
+use crate::slay::ids;
+use crate::slay::modifier_visitors;
+use crate::slay::showdown::challenge::ChallengePerspective;
+use crate::slay::showdown::consequences::Condition;
+use crate::slay::showdown::roll_modification::ModificationPath;
+use crate::slay::showdown::roll_state::RollPerspective;
+use crate::slay::showdown::roll_state::RollReason;
+use crate::slay::state::game::Game;
+use serde::Deserialize;
+use serde::Serialize;
+
+/*
+	Answers "what are my odds?".
+	Before a roll, the chance of success is taken over the 36 outcomes of the two dice,
+	including the buffs the roller already has.
+	Once the dice are rolled the total is known,
+	so the outlook only changes when someone plays a modifier.
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RollOutlook {
+	Success,
+	Failure,
+	// Some monsters have a gap between the win and loss conditions.
+	NoEffect,
+}
+
+impl RollOutlook {
+	pub fn from_total(total: i32, win: &Condition, loss: Option<&Condition>) -> Self {
+		if win.applies_to(total) {
+			RollOutlook::Success
+		} else if loss.map(|loss| loss.applies_to(total)).unwrap_or(false) {
+			RollOutlook::Failure
+		} else {
+			RollOutlook::NoEffect
+		}
+	}
+
+	pub fn label_key(&self) -> MessageKey {
+		match self {
+			RollOutlook::Success => MessageKey::OutlookSuccess,
+			RollOutlook::Failure => MessageKey::OutlookFailure,
+			RollOutlook::NoEffect => MessageKey::OutlookNoEffect,
+		}
+	}
+}
+
+pub fn success_probability(condition: &Condition, modifier: i32) -> f32 {
+	let successes = (1..=6)
+		.flat_map(|die1| (1..=6).map(move |die2| die1 + die2 + modifier))
+		.filter(|total| condition.applies_to(*total))
+		.count();
+	successes as f32 / 36.0
+}
+
+fn known_modifier(game: &Game, player_index: ids::PlayerIndex, reason: RollReason) -> i32 {
+	modifier_visitors::create_roll_history(game, player_index, reason)
+		.iter()
+		.map(|modification| modification.amount)
+		.sum()
+}
+
+// Only actions that roll right away have odds.
+pub fn action_success_probability(
+	game: &Game,
+	player_index: ids::PlayerIndex,
+	choice: &Choice,
+) -> Option<f32> {
+	match choice {
+		Choice::UseActionPoints(Action::AttackMonster(monster)) => Some(success_probability(
+			&monster.create_spec().consequences.success.condition,
+			known_modifier(game, player_index, RollReason::AttackMonster(*monster)),
+		)),
+		Choice::UseActionPoints(Action::RollForAbility(hero)) => Some(success_probability(
+			&hero.condition(),
+			known_modifier(game, player_index, RollReason::UseHeroAbility(*hero)),
+		)),
+		_ => None,
+	}
+}
+
+impl RollPerspective {
+	pub fn outlook(&self) -> RollOutlook {
+		self.outlook_with(0)
+	}
+
+	pub fn outlook_with(&self, amount: i32) -> RollOutlook {
+		RollOutlook::from_total(
+			self.calculate_roll_total() + amount,
+			&self.win_condition,
+			self.loss_condition.as_ref(),
+		)
+	}
+}
+
+impl ChallengePerspective {
+	// Ties go to the challenger.
+	pub fn challenger_wins_with(&self, path: ModificationPath, amount: i32) -> bool {
+		let amount = match path {
+			ModificationPath::Initiator => amount,
+			ModificationPath::Challenger => -amount,
+			ModificationPath::Roll => 0,
+		};
+		self.calculate_roll_total() + amount <= 0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::slay::choices::Action;
+	use crate::slay::choices::Choice;
+	use crate::slay::game_context::GameBookKeeping;
+	use crate::slay::showdown::consequences::Condition;
+	use crate::slay::showdown::odds;
+	use crate::slay::showdown::odds::RollOutlook;
+	use crate::slay::specification::HeroType;
+	use crate::slay::specs::hero::HeroAbilityType;
+	use crate::slay::state::builder::GameBuilder;
+
+	// Out of the 36 outcomes, how many totals are 2, 3, ..., 12.
+	const WAYS: [usize; 11] = [1, 2, 3, 4, 5, 6, 5, 4, 3, 2, 1];
+
+	fn ways_at_least(total: i32) -> usize {
+		(2..=12)
+			.zip(WAYS)
+			.filter(|(sum, _)| *sum >= total)
+			.map(|(_, ways)| ways)
+			.sum()
+	}
+
+	#[test]
+	fn a_seven_or_more_succeeds_in_21_of_36_rolls() {
+		assert_eq!(odds::success_probability(&Condition::ge(7), 0), 21.0 / 36.0);
+	}
+
+	#[test]
+	fn the_chances_are_exact_at_every_threshold() {
+		for threshold in 2..=12 {
+			assert_eq!(
+				odds::success_probability(&Condition::ge(threshold), 0),
+				ways_at_least(threshold) as f32 / 36.0,
+				"{}",
+				threshold
+			);
+		}
+	}
+
+	#[test]
+	fn the_chances_reach_certainty_and_impossibility_at_the_edges() {
+		assert_eq!(odds::success_probability(&Condition::ge(2), 0), 1.0);
+		assert_eq!(odds::success_probability(&Condition::ge(12), 0), 1.0 / 36.0);
+		assert_eq!(odds::success_probability(&Condition::ge(13), 0), 0.0);
+		assert_eq!(odds::success_probability(&Condition::le(2), 0), 1.0 / 36.0);
+		assert_eq!(odds::success_probability(&Condition::le(12), 0), 1.0);
+	}
+
+	#[test]
+	fn a_modifier_shifts_the_threshold() {
+		assert_eq!(odds::success_probability(&Condition::ge(7), 2), 30.0 / 36.0);
+		assert_eq!(
+			odds::success_probability(&Condition::ge(7), -2),
+			10.0 / 36.0
+		);
+		assert_eq!(odds::success_probability(&Condition::ge(12), 1), 3.0 / 36.0);
+		assert_eq!(odds::success_probability(&Condition::ge(13), 1), 1.0 / 36.0);
+		assert_eq!(
+			odds::success_probability(&Condition::ge(2), -1),
+			35.0 / 36.0
+		);
+		assert_eq!(odds::success_probability(&Condition::ge(7), 5), 1.0);
+	}
+
+	#[test]
+	fn the_outlook_follows_the_conditions_at_their_thresholds() {
+		let win = Condition::ge(8);
+		let loss = Condition::le(4);
+		assert_eq!(
+			RollOutlook::from_total(8, &win, Some(&loss)),
+			RollOutlook::Success
+		);
+		assert_eq!(
+			RollOutlook::from_total(7, &win, Some(&loss)),
+			RollOutlook::NoEffect
+		);
+		assert_eq!(
+			RollOutlook::from_total(5, &win, Some(&loss)),
+			RollOutlook::NoEffect
+		);
+		assert_eq!(
+			RollOutlook::from_total(4, &win, Some(&loss)),
+			RollOutlook::Failure
+		);
+		assert_eq!(
+			RollOutlook::from_total(7, &win, None),
+			RollOutlook::NoEffect
+		);
+		assert_eq!(
+			RollOutlook::from_total(-3, &win, None),
+			RollOutlook::NoEffect
+		);
+	}
+
+	#[test]
+	fn only_actions_that_roll_have_odds() {
+		let game = GameBuilder::new(2)
+			.leader(0, HeroType::Thief)
+			.build(&mut GameBookKeeping::new());
+		let roll = Choice::UseActionPoints(Action::RollForAbility(HeroAbilityType::SmoothMimimeow));
+		assert_eq!(
+			odds::action_success_probability(&game, 0, &roll),
+			Some(21.0 / 36.0)
+		);
+		let forfeit = Choice::UseActionPoints(Action::Forfeit);
+		assert_eq!(odds::action_success_probability(&game, 0, &forfeit), None);
+	}
+
+	#[test]
+	fn the_odds_of_an_action_include_the_buffs_the_roller_has() {
+		// The Bard adds one to every roll for a hero's ability.
+		let game = GameBuilder::new(2)
+			.leader(0, HeroType::Bard)
+			.build(&mut GameBookKeeping::new());
+		let roll = Choice::UseActionPoints(Action::RollForAbility(HeroAbilityType::SmoothMimimeow));
+		assert_eq!(
+			odds::action_success_probability(&game, 0, &roll),
+			Some(26.0 / 36.0)
+		);
+	}
+}
@@ -6,6 +6,7 @@ use crate::slay::choices::TasksChoice;
 use crate::slay::errors::SlayResult;
 use crate::slay::game_context::GameBookKeeping;
 use crate::slay::ids;
+use crate::slay::notification::Notification;
 use crate::slay::showdown::challenge::ChallengeState;
 use crate::slay::showdown::completion::CompletionTracker;
 use crate::slay::showdown::consequences::RollConsequences;
@@ -14,6 +15,8 @@ use crate::slay::showdown::roll::ChallengeReason;
 use crate::slay::showdown::roll_choices;
 use crate::slay::state::game::Game;
 use crate::slay::tasks::tasks::set_complete;
+use serde::Deserialize;
+use serde::Serialize;
 
 #[derive(Debug, Clone)]
 pub struct OfferChallengesState {
@@ -23,7 +26,7 @@ pub struct OfferChallengesState {
 	consequences: RollConsequences,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct OfferChallengesPerspective {
 	pub player_index: ids::PlayerIndex,
 	pub reason: ChallengeReason,
@@ -79,7 +82,7 @@ impl OfferChallengesState {
 
 		if let Some(card) = game.players[challenging_player_index]
 			.hand
-			.tops()
+			.tops(&game.cards)
 			.find(|card| card.is_challenge())
 		{
 			ret.push(roll_choices::create_challenge_choice(
@@ -93,12 +96,12 @@ impl OfferChallengesState {
 
 	pub fn to_challenge(
 		&self,
-		rng: &mut rand::rngs::ThreadRng,
+		context: &mut GameBookKeeping,
 		game: &Game,
 		challenger_index: ids::PlayerIndex,
 	) -> SlayResult<ChallengeState> {
 		Ok(ChallengeState::new(
-			rng,
+			context,
 			game,
 			self.player_index,
 			challenger_index,
@@ -133,6 +136,11 @@ impl ShowDown for OfferChallengesState {
 	}
 
 	fn finish(&mut self, context: &mut GameBookKeeping, game: &mut Game) {
+		context.emit(&Notification::OfferResult(self.player_index, None));
 		self.consequences.proceed(context, game, self.player_index);
 	}
+
+	fn can_respond(&self, game: &Game, player_index: ids::PlayerIndex) -> bool {
+		player_index != self.player_index && game.players[player_index].has_challenge_card(&game.cards)
+	}
 }
@@ -1,4 +1,6 @@
 use crate::slay::specs::cards::card_type::SlayCardSpec;
+use serde::Deserialize;
+use serde::Serialize;
 
 use rand::Rng;
 
@@ -6,13 +8,13 @@ use crate::slay::showdown::roll_modification::RollModification;
 
 // Only the party needs stacks...
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Roll {
 	pub die1: u32,
 	pub die2: u32,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum ChallengeReason {
 	PlaceHeroCard(SlayCardSpec),
 	PlaceItem(SlayCardSpec),
@@ -20,7 +22,11 @@ pub enum ChallengeReason {
 }
 
 impl Roll {
-	pub fn create_from(rng: &mut rand::rngs::ThreadRng) -> Self {
+	pub fn new(die1: u32, die2: u32) -> Self {
+		Roll { die1, die2 }
+	}
+
+	pub fn create_from(rng: &mut rand::rngs::StdRng) -> Self {
 		Roll {
 			die1: rng.gen_range(1..=6),
 			die2: rng.gen_range(1..=6),
@@ -1,3 +1,4 @@
+use crate::slay::actions::place_item;
 use crate::slay::choices::CardPath;
 use crate::slay::choices::Choice;
 use crate::slay::choices::ChoiceDisplayType;
@@ -7,8 +8,12 @@ use crate::slay::deadlines;
 use crate::slay::errors::SlayResult;
 use crate::slay::game_context::GameBookKeeping;
 use crate::slay::ids;
+use crate::slay::notification::Notification;
+use crate::slay::showdown::completion::Completion;
 use crate::slay::showdown::completion::CompletionTracker;
+use crate::slay::showdown::current_showdown;
 use crate::slay::showdown::current_showdown::ShowDown;
+use crate::slay::showdown::current_showdown::ShowDownLevel;
 use crate::slay::showdown::roll_modification::ModificationOrigin;
 use crate::slay::showdown::roll_modification::ModificationPath;
 use crate::slay::showdown::roll_modification::RollModification;
@@ -21,6 +26,7 @@ use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
 use crate::slay::tasks::tasks::modify_roll::ModifyRollTask;
 use crate::slay::tasks::tasks::move_card::MoveCardTask;
+use crate::slay::tasks::tasks::set_complete::SetCompleteTask;
 
 pub fn create_modify_roll_choice(
 	context: &mut GameBookKeeping,
@@ -33,7 +39,7 @@ pub fn create_modify_roll_choice(
 	let choice_id = context.id_generator.generate();
 	let _display_path =
 		DisplayPath::CardAt(CardPath::TopCardIn(DeckPath::Hand(player_index), card_id));
-	TasksChoice::new(
+	TasksChoice::prepend(
 		choice_id,
 		Choice::Modify(*modification_path, *modifier_kind, modification_amount),
 		ChoiceDisplayType::Modify(RollModificationChoiceType::from_card(
@@ -130,12 +136,79 @@ let move_card =  ;
 //     }
 // }
 
+// Items can be played while a roll is open. A challenge against one suspends the roll, and the
+// player goes back to the roll once the item is placed.
+pub fn create_play_item_choices(
+	context: &mut GameBookKeeping,
+	game: &Game,
+	player_index: ids::PlayerIndex,
+) -> Vec<TasksChoice> {
+	let mut choices = Vec::new();
+	if game.players_with_stacks().is_empty() {
+		return choices;
+	}
+	for card in game.players[player_index].hand.tops(&game.cards) {
+		if !card.is_item() {
+			continue;
+		}
+		let return_to_roll = vec![Box::new(ReturnToRollTask {}) as Box<dyn PlayerTask>];
+		if let Some(place_item) = place_item::create_place_item_challenge_offer_then(
+			context,
+			game,
+			player_index,
+			card,
+			return_to_roll,
+		) {
+			// Still thinking, so the roll cannot finish while the item is being placed.
+			choices.push(TasksChoice::prepend(
+				context.id_generator.generate(),
+				Choice::PlayImmediately(card.card_type),
+				card.as_choice(),
+				vec![
+					Box::new(SetCompleteTask::new(Completion::Thinking)) as Box<dyn PlayerTask>,
+					place_item,
+				],
+			));
+		}
+	}
+	choices
+}
+
+#[derive(Debug, Clone)]
+struct ReturnToRollTask {}
+
+impl PlayerTask for ReturnToRollTask {
+	fn make_progress(
+		&mut self,
+		context: &mut GameBookKeeping,
+		game: &mut Game,
+		player_index: ids::PlayerIndex,
+	) -> SlayResult<TaskProgressResult> {
+		// A roll that was suspended by a challenge offers its choices again when it is resumed.
+		let choices = match game.showdown.get_roll() {
+			Some(roll)
+				if roll
+					.tracker()
+					.should_offer_modifications_again(player_index) =>
+			{
+				roll.create_choice_for(context, game, player_index)
+			}
+			_ => return Ok(TaskProgressResult::TaskComplete),
+		};
+		game.players[player_index].choose(choices);
+		Ok(TaskProgressResult::TaskComplete)
+	}
+	fn label(&self) -> String {
+		"Going back to the roll.".to_string()
+	}
+}
+
 pub fn create_challenge_choice(
 	player_index: ids::PlayerIndex,
 	id: ids::ChoiceId,
 	challenge_card: &Card,
 ) -> TasksChoice {
-	TasksChoice::new(
+	TasksChoice::prepend(
 		id,
 		Choice::Challenge,
 		ChoiceDisplayType::Challenge(challenge_card.card_type),
@@ -165,13 +238,18 @@ impl PlayerTask for ChallengeTask {
 		challenging_player_index: ids::PlayerIndex,
 	) -> SlayResult<TaskProgressResult> {
 		let offer = game.showdown.take_current_offer()?;
-		let mut challenge = offer.to_challenge(&mut context.rng, game, challenging_player_index)?;
+		context.emit(&Notification::OfferResult(
+			offer.player_index,
+			Some(challenging_player_index),
+		));
+		let mut challenge = offer.to_challenge(context, game, challenging_player_index)?;
 		challenge.completion_tracker = Some(CompletionTracker::new(
 			game.number_of_players(),
-			deadlines::get_challenge_deadline(),
+			deadlines::get_challenge_deadline(context),
 		));
-		challenge.assign_all_choices(context, game);
-		game.showdown.challenge(challenge);
+		// The other players' chances to challenge are gone, the challenge offers its own choices.
+		current_showdown::clear_showdown_choices(game);
+		current_showdown::begin(context, game, ShowDownLevel::Challenge(challenge));
 		Ok(TaskProgressResult::TaskComplete)
 	}
 	fn label(&self) -> String {
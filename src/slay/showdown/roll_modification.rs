@@ -1,28 +1,61 @@
+use crate::slay::i18n::keys::MessageKey;
+use crate::slay::i18n::Arg;
+use crate::slay::i18n::Message;
 use crate::slay::ids;
 use crate::slay::specs::modifier::ModifierKinds;
 use crate::slay::status_effects::effect_entry::EffectOrigin;
+use serde::Deserialize;
+use serde::Serialize;
 
 // TODO: rename to Roll....
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ModificationPath {
 	Roll,
 	Challenger,
 	Initiator,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+impl ModificationPath {
+	pub fn label_key(&self) -> MessageKey {
+		match self {
+			ModificationPath::Roll => MessageKey::PathRoll,
+			ModificationPath::Challenger => MessageKey::PathChallenger,
+			ModificationPath::Initiator => MessageKey::PathInitiator,
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ModificationOrigin {
 	FromPlayer(ids::PlayerIndex, ModifierKinds),
 	FromBuff(EffectOrigin),
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RollModification {
 	pub origin: ModificationOrigin,
 	pub amount: i32,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+impl RollModification {
+	pub fn message(&self) -> Message {
+		match &self.origin {
+			ModificationOrigin::FromPlayer(player_index, _) => Message::new(
+				MessageKey::ModificationFromPlayer,
+				vec![Arg::Signed(self.amount), Arg::PlayerName(*player_index)],
+			),
+			ModificationOrigin::FromBuff(origin) => Message::new(
+				MessageKey::ModificationFromBuff,
+				vec![
+					Arg::Signed(self.amount),
+					Arg::Verbatim(format!("{:?}", origin)),
+				],
+			),
+		}
+	}
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum RollModificationChoiceType {
 	AddToRoll(ModifierKinds, i32, ModificationPath),
 	RemoveFromRoll(ModifierKinds, i32, ModificationPath),
@@ -1,3 +1,7 @@
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
 use std::rc::Rc;
 use std::vec;
 
@@ -6,10 +10,11 @@ use crate::slay::choices::Choices;
 use crate::slay::choices::ChoicesPerspective;
 use crate::slay::choices::ChoicesType;
 use crate::slay::choices::TasksChoice;
-use crate::slay::deadlines;
 use crate::slay::game_context::GameBookKeeping;
 use crate::slay::ids;
 use crate::slay::modifier_visitors;
+use crate::slay::notification::Notification;
+use crate::slay::notification::RollOutcome;
 use crate::slay::showdown::completion::CompletionTracker;
 use crate::slay::showdown::consequences::Condition;
 use crate::slay::showdown::consequences::RollConsequences;
@@ -27,7 +32,7 @@ use crate::slay::tasks::tasks::set_complete;
 
 // Only the party needs stacks...
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub enum RollReason {
 	UseHeroAbility(HeroAbilityType),
 	AttackMonster(Monster),
@@ -45,7 +50,7 @@ pub struct RollState {
 	pub completion_tracker: Option<CompletionTracker>,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct RollPerspective {
 	pub roller_index: ids::PlayerIndex,
 	pub reason: RollReason,
@@ -68,7 +73,7 @@ impl RollState {
 	) -> Self {
 		Self {
 			roller_index,
-			initial: Roll::create_from(&mut context.rng),
+			initial: context.roll_dice(),
 			history: modifier_visitors::create_roll_history(game, roller_index, reason),
 			consequences,
 			completion_tracker: None,
@@ -92,8 +97,8 @@ impl RollState {
 	// 	}
 	// }
 
-	pub fn add_modification(&mut self, modification: RollModification) {
-		self.tracker_mut().timeline = deadlines::get_roll_deadline();
+	pub fn add_modification(&mut self, modification: RollModification, now: DateTime<Utc>) {
+		self.tracker_mut().reset_timeline(now);
 		self.history.push(modification);
 	}
 
@@ -120,27 +125,51 @@ impl ShowDown for RollState {
 		player_index: ids::PlayerIndex,
 	) -> Choices {
 		let default_choice = context.id_generator.generate();
+		let mut options = list_modification_choices(
+			context,
+			game,
+			player_index,
+			default_choice,
+			vec![ModificationPath::Roll],
+		);
+		options.extend(roll_choices::create_play_item_choices(
+			context,
+			game,
+			player_index,
+		));
 		Choices {
 			choices_type: ChoicesType::ModifyRoll,
 			default_choice: Some(default_choice),
 			timeline: self.tracker().timeline.to_owned(),
-			options: list_modification_choices(
-				context,
-				game,
-				player_index,
-				default_choice,
-				vec![ModificationPath::Roll],
-			),
+			options,
 		}
 	}
 
-	fn finish(&mut self, _context: &mut GameBookKeeping, game: &mut Game) {
+	fn finish(&mut self, context: &mut GameBookKeeping, game: &mut Game) {
 		let roll_sum = self.calculate_roll_total();
+		context.emit(&Notification::RollResult(RollOutcome {
+			roller_index: self.roller_index,
+			reason: self.reason,
+			initial: self.initial.to_owned(),
+			modifications: self.history.to_vec(),
+			total: roll_sum,
+			win_condition: self.consequences.success.condition.to_owned(),
+			loss_condition: self
+				.consequences
+				.loss
+				.as_ref()
+				.map(|c| c.condition.to_owned()),
+		}));
 		self
 			.consequences
 			.apply_roll_sum(game, roll_sum, self.roller_index);
 		// game.players[roll.roller_index].tasks = Some(roll.consequences.take_tasks(roll_sum));
 	}
+
+	fn can_respond(&self, game: &Game, player_index: ids::PlayerIndex) -> bool {
+		let player = &game.players[player_index];
+		player.has_modifier_card(&game.cards) || player.has_item_card(&game.cards)
+	}
 }
 
 pub fn list_modification_choices(
@@ -155,7 +184,7 @@ pub fn list_modification_choices(
 		set_complete::create_set_completion_until_modification(context.id_generator.generate()),
 	];
 
-	for card in game.players[player_index].hand.tops() {
+	for card in game.players[player_index].hand.tops(&game.cards) {
 		if let SlayCardSpec::ModifierCard(kind) = card.card_type {
 			for modification_path in rolls.iter() {
 				for modification_amount in kind.list_amounts() {
@@ -2,6 +2,8 @@ use std::collections::HashSet;
 use std::vec;
 
 use enum_iterator::Sequence;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::slay::ids;
 use crate::slay::showdown::consequences::Condition;
@@ -136,7 +138,7 @@ impl CardType {
 	}
 }
 
-#[derive(Debug, Clone, PartialEq, Copy, Sequence)]
+#[derive(Debug, Clone, PartialEq, Eq, Copy, Sequence, Serialize, Deserialize)]
 pub enum GameVersion {
 	Original,
 	WarriorsAndDruids,
@@ -144,7 +146,7 @@ pub enum GameVersion {
 	HereToSleigh,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy, Sequence)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy, Sequence, Serialize, Deserialize)]
 pub enum HeroType {
 	Bard,
 	Wizard,
@@ -198,7 +200,7 @@ impl HeroType {
 			HeroType::Necromancer => "imgs/icons/hero_icons/necromancer.png",
 			HeroType::Druid => "imgs/icons/hero_icons/druid.png",
 			HeroType::Warrior => "imgs/icons/hero_icons/warrior.png",
-			HeroType::Sorcerer => "imgs/icons/hero_icons/sourcerer.png",
+			HeroType::Sorcerer => "imgs/icons/hero_icons/sorcerer.png",
 		}
 	}
 	pub fn label(&self) -> &'static str {
@@ -1,4 +1,6 @@
 use enum_iterator::Sequence;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::slay::specification::HeroType;
 use crate::slay::specs::items::AnotherItemType;
@@ -15,7 +17,7 @@ pub enum ChallengeType {
 }
 
 // Rename this: remove the Slay
-#[derive(Debug, Clone, Sequence, PartialEq, Copy)]
+#[derive(Debug, Clone, Sequence, PartialEq, Copy, Serialize, Deserialize)]
 pub enum SlayCardSpec {
 	HeroCard(HeroAbilityType),
 	PartyLeader(HeroType),
@@ -1,3 +1,7 @@
+use enum_iterator::all;
+use std::fmt;
+use std::path::Path;
+
 use crate::slay::specification::HeroType;
 use crate::slay::specs::cards::card_type::SlayCardSpec;
 use crate::slay::specs::hero::HeroAbilityType;
@@ -7,8 +11,9 @@ use crate::slay::specs::modifier::ModifierKinds;
 use crate::slay::specs::monster::Monster;
 
 impl SlayCardSpec {
-	pub fn image_path(&self) -> &'static str {
-		match self {
+	// None for the cards that do not have art yet, the frontend draws a placeholder for them.
+	pub fn image_path(&self) -> Option<&'static str> {
+		Some(match self {
 			SlayCardSpec::HeroCard(hero_card) => match hero_card {
 				HeroAbilityType::PlunderingPuma => "imgs/cards/heros/thief/plundering_puma.jpg",
 				HeroAbilityType::SlipperyPaws => "imgs/cards/heros/thief/slippery_paws.jpg",
@@ -67,11 +72,11 @@ impl SlayCardSpec {
 				HeroType::Ranger => "imgs/cards/party_leaders/divine_arrow.jpg",
 				HeroType::Thief => "imgs/cards/party_leaders/shadow_claw.jpg",
 
-				HeroType::Necromancer => todo!(),
-				HeroType::Druid => todo!(),
-				HeroType::Warrior => todo!(),
-				HeroType::Sorcerer => todo!(),
-				HeroType::Beserker => todo!(),
+				HeroType::Necromancer
+				| HeroType::Druid
+				| HeroType::Warrior
+				| HeroType::Sorcerer
+				| HeroType::Beserker => return None,
 			},
 			SlayCardSpec::MonsterCard(monster) => match monster {
 				Monster::AnuranCauldron => "imgs/cards/monsters/anuran_cauldron.jpg",
@@ -115,11 +120,11 @@ impl SlayCardSpec {
 					HeroType::Gaurdian => "imgs/cards/items/guardian_mask.jpg",
 					HeroType::Ranger => "imgs/cards/items/ranger_mask.jpg",
 					HeroType::Thief => "imgs/cards/items/thief_mask.jpg",
-					HeroType::Sorcerer => todo!(),
-					HeroType::Beserker => todo!(),
-					HeroType::Necromancer => todo!(),
-					HeroType::Druid => todo!(),
-					HeroType::Warrior => todo!(),
+					HeroType::Sorcerer
+					| HeroType::Beserker
+					| HeroType::Necromancer
+					| HeroType::Druid
+					| HeroType::Warrior => return None,
 				},
 				AnotherItemType::NotMask(item) => match item {
 					Item::DecoyDoll => "imgs/cards/items/decoy_doll.jpg",
@@ -131,6 +136,79 @@ impl SlayCardSpec {
 				},
 			},
 			SlayCardSpec::Challenge => "imgs/cards/challenge/challenge.jpg",
+		})
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AssetProblem {
+	MissingCardImage(SlayCardSpec, &'static str),
+	MissingHeroIcon(HeroType, &'static str),
+}
+
+impl fmt::Display for AssetProblem {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			AssetProblem::MissingCardImage(spec, path) => {
+				write!(
+					f,
+					"{} ({:?}) points to {}, which does not exist",
+					spec.label(),
+					spec,
+					path
+				)
+			}
+			AssetProblem::MissingHeroIcon(hero_type, path) => {
+				write!(
+					f,
+					"The {} icon points to {}, which does not exist",
+					hero_type.label(),
+					path
+				)
+			}
 		}
 	}
 }
+
+// Every card and the image it is drawn with.
+pub fn card_image_manifest() -> Vec<(SlayCardSpec, Option<&'static str>)> {
+	all::<SlayCardSpec>()
+		.map(|spec| (spec, spec.image_path()))
+		.collect()
+}
+
+pub fn cards_without_art() -> Vec<SlayCardSpec> {
+	card_image_manifest()
+		.into_iter()
+		.filter(|(_, path)| path.is_none())
+		.map(|(spec, _)| spec)
+		.collect()
+}
+
+// The paths are relative to the directory the frontend is served from, which holds imgs/.
+pub fn check_assets(root: &Path) -> Vec<AssetProblem> {
+	let card_images = card_image_manifest()
+		.into_iter()
+		.filter_map(|(spec, path)| path.map(|path| (spec, path)))
+		.filter(|(_, path)| !root.join(path).is_file())
+		.map(|(spec, path)| AssetProblem::MissingCardImage(spec, path));
+	let hero_icons = all::<HeroType>()
+		.map(|hero_type| (hero_type, hero_type.icon()))
+		.filter(|(_, path)| !root.join(path).is_file())
+		.map(|(hero_type, path)| AssetProblem::MissingHeroIcon(hero_type, path));
+	card_images.chain(hero_icons).collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use std::path::Path;
+
+	use crate::slay::specs::cards::images;
+
+	#[test]
+	fn every_image_is_in_the_repository() {
+		let problems = images::check_assets(Path::new(env!("CARGO_MANIFEST_DIR")));
+		let problems: Vec<String> = problems.iter().map(|problem| problem.to_string()).collect();
+		assert!(problems.is_empty(), "{}", problems.join("\n"));
+	}
+}
@@ -1,11 +1,15 @@
 use enum_iterator::Sequence;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::slay::abilities::heros::VictimDraws;
 use crate::slay::actions::roll_for_ability::RollForAbilityEffects;
+use crate::slay::i18n::keys::MessageKey;
 use crate::slay::showdown::consequences::Condition;
 use crate::slay::showdown::consequences::RollConsequence;
 use crate::slay::showdown::consequences::RollConsequences;
 use crate::slay::specification::HeroType;
+use crate::slay::state::deck::PartialDeckPath;
 use crate::slay::status_effects::effect::PlayerStatusEffect;
 use crate::slay::status_effects::effect_entry::EffectOrigin;
 use crate::slay::tasks::core::destroy::DestroyCardTask;
@@ -16,6 +20,7 @@ use crate::slay::tasks::core::discard::DiscardFromParam;
 use crate::slay::tasks::core::discard::PlayersWithHeroTypeDiscard;
 use crate::slay::tasks::core::draw::DrawTask;
 use crate::slay::tasks::core::pull::PullFromTask;
+use crate::slay::tasks::core::pull::TakeFromHandTask;
 use crate::slay::tasks::core::sacrifice::ChooseSacrifice;
 use crate::slay::tasks::core::steal::StealCardFromTask;
 use crate::slay::tasks::core::steal::StealTask;
@@ -30,13 +35,12 @@ use crate::slay::tasks::heros::quick_draw_style::QuickDrawStyle;
 use crate::slay::tasks::heros::slippery_paws::SlipperyPaws;
 use crate::slay::tasks::heros::spooky::Spooky;
 use crate::slay::tasks::player_tasks::PlayerTask;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::task_params::ParamScope;
 use crate::slay::tasks::tasks::immediate::OfferPlayImmediately;
 use crate::slay::tasks::tasks::immediate::PlayImmediatelyFilter;
 
 use crate::slay::tasks::tasks::params::ChooseCardFromPlayerParameterTask;
 use crate::slay::tasks::tasks::params::ChoosePlayerParameterTask;
-use crate::slay::tasks::tasks::params::ClearParamsTask;
 use crate::slay::tasks::tasks::place_hero::PlaceHero;
 use crate::slay::tasks::tasks::pull_again::PullAgain;
 use crate::slay::tasks::tasks::receive_modifier::ReceiveModifier;
@@ -68,7 +72,7 @@ pub struct HeroAbility {
 
 // Some renaming is appropriate...
 // Call this HeroCard
-#[derive(Clone, Debug, Sequence, PartialEq, Copy)]
+#[derive(Clone, Debug, Sequence, PartialEq, Copy, Serialize, Deserialize)]
 pub enum HeroAbilityType {
 	PlunderingPuma,
 	SlipperyPaws,
@@ -206,73 +210,72 @@ impl HeroAbilityType {
 
 	pub fn create_tasks(&self) -> Vec<Box<dyn PlayerTask>> {
 		match self {
-			HeroAbilityType::PlunderingPuma => vec![
-				ChoosePlayerParameterTask::exclude_self(TaskParamName::PlayerToPullFrom),
-				PullFromTask::create(TaskParamName::PlayerToPullFrom),
-				PullFromTask::create(TaskParamName::PlayerToPullFrom),
-				VictimDraws::create(TaskParamName::PlayerToPullFrom),
-				ClearParamsTask::create(),
-			],
-			HeroAbilityType::SlipperyPaws => vec![
-				ChoosePlayerParameterTask::exclude_self(TaskParamName::SlipperyPawsVictim),
-				PullFromTask::record_pulled(
-					TaskParamName::SlipperyPawsVictim,
-					TaskParamName::SlipperyPawsVictimPulledCard1,
-				),
-				PullFromTask::record_pulled(
-					TaskParamName::SlipperyPawsVictim,
-					TaskParamName::SlipperyPawsVictimPulledCard2,
-				),
-				SlipperyPaws::create(),
-				ClearParamsTask::create(),
-			],
-			HeroAbilityType::SmoothMimimeow => vec![Mimimeow::create(), ClearParamsTask::create()],
-			HeroAbilityType::Meowzio => vec![
-				ChoosePlayerParameterTask::exclude_self(TaskParamName::MeowzioVictim),
-				PullFromTask::create(TaskParamName::MeowzioVictim),
-				ChooseCardFromPlayerParameterTask::from_party(
-					TaskParamName::MeowzioVictim,
-					TaskParamName::MeowzioCard,
-				),
-				StealCardFromTask::create(TaskParamName::MeowzioVictim, TaskParamName::MeowzioCard),
-				ClearParamsTask::create(),
-			],
-			HeroAbilityType::Shurikitty => vec![
-				ChoosePlayerParameterTask::exclude_self(TaskParamName::ShurikittyVictim),
-				ChooseCardFromPlayerParameterTask::from_party(
-					TaskParamName::ShurikittyVictim,
-					TaskParamName::ShurikittyCard,
-				),
-				DestroyCardTask::create(
-					TaskParamName::ShurikittyVictim,
-					TaskParamName::ShurikittyCard,
-					DestroyModifiersDestination::Myself,
-				),
-				ClearParamsTask::create(),
-			],
+			HeroAbilityType::PlunderingPuma => {
+				let mut params = ParamScope::new();
+				let victim = params.player(MessageKey::PromptPullFrom);
+				params.chain(vec![
+					ChoosePlayerParameterTask::exclude_self(victim),
+					PullFromTask::create(victim),
+					PullFromTask::create(victim),
+					VictimDraws::create(victim),
+				])
+			}
+			HeroAbilityType::SlipperyPaws => {
+				let mut params = ParamScope::new();
+				let victim = params.player(MessageKey::PromptSlipperyPaws);
+				let first_card = params.card(MessageKey::NotApplicable);
+				let second_card = params.card(MessageKey::NotApplicable);
+				params.chain(vec![
+					ChoosePlayerParameterTask::exclude_self(victim),
+					PullFromTask::record_pulled(victim, first_card),
+					PullFromTask::record_pulled(victim, second_card),
+					SlipperyPaws::create(first_card, second_card),
+				])
+			}
+			HeroAbilityType::SmoothMimimeow => vec![Mimimeow::create()],
+			HeroAbilityType::Meowzio => {
+				let mut params = ParamScope::new();
+				let victim = params.player(MessageKey::PromptStealAndPull);
+				let card = params.card(MessageKey::PromptStealHero);
+				params.chain(vec![
+					ChoosePlayerParameterTask::exclude_self(victim),
+					PullFromTask::create(victim),
+					ChooseCardFromPlayerParameterTask::from_party(victim, card),
+					StealCardFromTask::create(victim, card),
+				])
+			}
+			HeroAbilityType::Shurikitty => {
+				let mut params = ParamScope::new();
+				let victim = params.player(MessageKey::PromptDestroyFromPlayer);
+				let card = params.card(MessageKey::PromptDestroyForItems);
+				params.chain(vec![
+					ChoosePlayerParameterTask::exclude_self(victim),
+					ChooseCardFromPlayerParameterTask::from_party(victim, card),
+					DestroyCardTask::create(victim, card, DestroyModifiersDestination::Myself),
+				])
+			}
 			HeroAbilityType::KitNapper => vec![StealTask::create()],
-			HeroAbilityType::SilentShadow => vec![
-				ChoosePlayerParameterTask::exclude_self(TaskParamName::SilentShadowVictim),
-				ChooseCardFromPlayerParameterTask::from_party(
-					TaskParamName::SilentShadowVictim,
-					TaskParamName::SilentShadowCard,
-				),
-				// TODO
-				// "Steal a Hero card."
-				ClearParamsTask::create(),
-			],
-			HeroAbilityType::SlyPickings => vec![
-				ChoosePlayerParameterTask::exclude_self(TaskParamName::SlyPickinsVictim),
-				PullFromTask::record_pulled(
-					TaskParamName::SlyPickinsVictim,
-					TaskParamName::SlyPickinsCard,
-				),
-				OfferPlayImmediately::create(
-					TaskParamName::SlyPickinsCard,
-					PlayImmediatelyFilter::IsMagic,
-				),
-				ClearParamsTask::create(),
-			],
+			HeroAbilityType::SilentShadow => {
+				let mut params = ParamScope::new();
+				let victim = params.player(MessageKey::PromptSeeHand);
+				let card = params.card(MessageKey::PromptTakeCard);
+				params.chain(vec![
+					ChoosePlayerParameterTask::exclude_self(victim),
+					ViewHand::create(victim),
+					ChooseCardFromPlayerParameterTask::create(victim, card, PartialDeckPath::Hand, ""),
+					TakeFromHandTask::create(victim, card),
+				])
+			}
+			HeroAbilityType::SlyPickings => {
+				let mut params = ParamScope::new();
+				let victim = params.player(MessageKey::PromptStealMagic);
+				let card = params.card(MessageKey::NotApplicable);
+				params.chain(vec![
+					ChoosePlayerParameterTask::exclude_self(victim),
+					PullFromTask::record_pulled(victim, card),
+					OfferPlayImmediately::create(card, PlayImmediatelyFilter::IsItem),
+				])
+			}
 			HeroAbilityType::HolyCurselifter => vec![ReturnModifierTask::return_everyones()],
 			HeroAbilityType::IronResolve => vec![ReceiveModifier::for_this_turn(
 				PlayerStatusEffect::NoCardsCanBeChallenged,
@@ -297,132 +300,158 @@ impl HeroAbilityType {
 				EffectOrigin::FromHeroAbility,
 			)],
 			HeroAbilityType::QiBear => vec![QiBear::create()],
-			HeroAbilityType::PanChucks => vec![
-				DrawTask::into_param(TaskParamName::PanChuckFirstCard),
-				DrawTask::into_param(TaskParamName::PanChuckSecondCard),
-				PanChucksDestroy::create(),
-				ClearParamsTask::create(),
-			],
-			HeroAbilityType::HeavyBear => vec![
-				ChoosePlayerParameterTask::exclude_self(TaskParamName::HeavyBearVictim),
-				DiscardFromParam::create(2, TaskParamName::HeavyBearVictim),
-				ClearParamsTask::create(),
-			],
+			HeroAbilityType::PanChucks => {
+				let mut params = ParamScope::new();
+				let first_card = params.card(MessageKey::NotApplicable);
+				let second_card = params.card(MessageKey::NotApplicable);
+				params.chain(vec![
+					DrawTask::into_param(first_card),
+					DrawTask::into_param(second_card),
+					PanChucksDestroy::create(first_card, second_card),
+				])
+			}
+			HeroAbilityType::HeavyBear => {
+				let mut params = ParamScope::new();
+				let victim = params.player(MessageKey::PromptDiscardTwo);
+				params.chain(vec![
+					ChoosePlayerParameterTask::exclude_self(victim),
+					DiscardFromParam::create(2, victim),
+				])
+			}
 			HeroAbilityType::BadAxe => vec![DestroyTask::create()],
 			HeroAbilityType::ToughTeddy => vec![PlayersWithHeroTypeDiscard::create(HeroType::Fighter)],
-			HeroAbilityType::BearClaw => vec![
-				ChoosePlayerParameterTask::exclude_self(TaskParamName::BearClawVictim),
-				PullFromTask::record_pulled(TaskParamName::BearClawVictim, TaskParamName::BearClawCard),
-				PullAgain::create(
-					TaskParamName::BearClawVictim,
-					TaskParamName::BearClawCard,
-					PlayImmediatelyFilter::IsHero,
-				),
-				ClearParamsTask::create(),
-			],
-			HeroAbilityType::FuryKnuckle => vec![
-				ChoosePlayerParameterTask::exclude_self(TaskParamName::FuryKnuckleVictim),
-				PullFromTask::record_pulled(
-					TaskParamName::FuryKnuckleVictim,
-					TaskParamName::FuryKnuckleCard,
-				),
-				PullAgain::create(
-					TaskParamName::FuryKnuckleVictim,
-					TaskParamName::FuryKnuckleCard,
-					PlayImmediatelyFilter::IsChallenge,
-				),
-				ClearParamsTask::create(),
-			],
+			HeroAbilityType::BearClaw => {
+				let mut params = ParamScope::new();
+				let victim = params.player(MessageKey::PromptPullHero);
+				let card = params.card(MessageKey::NotApplicable);
+				params.chain(vec![
+					ChoosePlayerParameterTask::exclude_self(victim),
+					PullFromTask::record_pulled(victim, card),
+					PullAgain::create(victim, card, PlayImmediatelyFilter::IsHero),
+				])
+			}
+			HeroAbilityType::FuryKnuckle => {
+				let mut params = ParamScope::new();
+				let victim = params.player(MessageKey::PromptPullChallenge);
+				let card = params.card(MessageKey::NotApplicable);
+				params.chain(vec![
+					ChoosePlayerParameterTask::exclude_self(victim),
+					PullFromTask::record_pulled(victim, card),
+					PullAgain::create(victim, card, PlayImmediatelyFilter::IsChallenge),
+				])
+			}
 			HeroAbilityType::BearyWise => vec![BearyWise::create()],
-			HeroAbilityType::Hook => vec![Hook::create(PlayImmediatelyFilter::IsMagic)],
+			HeroAbilityType::Hook => vec![Hook::create(PlayImmediatelyFilter::IsItem)],
 			HeroAbilityType::Wildshot => vec![DrawTask::create(3), Discard::create(1)],
 			HeroAbilityType::SeriousGrey => vec![DestroyTask::create(), DrawTask::create(1)],
 			HeroAbilityType::WilyRed => vec![DrawTask::until(7)],
-			HeroAbilityType::QuickDraw => vec![
-				DrawTask::into_param(TaskParamName::QuickDrawCard1),
-				DrawTask::into_param(TaskParamName::QuickDrawCard2),
-				QuickDrawStyle::create(
-					TaskParamName::QuickDrawCard1,
-					TaskParamName::QuickDrawCard2,
-					PlayImmediatelyFilter::IsItem,
-				),
-				ClearParamsTask::create(),
-			],
+			HeroAbilityType::QuickDraw => {
+				let mut params = ParamScope::new();
+				let first_card = params.card(MessageKey::NotApplicable);
+				let second_card = params.card(MessageKey::NotApplicable);
+				params.chain(vec![
+					DrawTask::into_param(first_card),
+					DrawTask::into_param(second_card),
+					QuickDrawStyle::create(first_card, second_card, PlayImmediatelyFilter::IsItem),
+				])
+			}
 			HeroAbilityType::LookieRookie => vec![SearchDiscard::for_item()],
 			HeroAbilityType::Bullseye => vec![Bullseye::create()],
-			HeroAbilityType::SharpFox => vec![
-				ChoosePlayerParameterTask::exclude_self(TaskParamName::SharpFoxVictim),
-				ViewHand::create(TaskParamName::SharpFoxVictim),
-				ClearParamsTask::create(),
-			],
+			HeroAbilityType::SharpFox => {
+				let mut params = ParamScope::new();
+				let victim = params.player(MessageKey::PromptSeeHand);
+				params.chain(vec![
+					ChoosePlayerParameterTask::exclude_self(victim),
+					ViewHand::create(victim),
+				])
+			}
 			HeroAbilityType::FuzzyCheeks => vec![DrawTask::create(1), PlaceHero::create()],
 			HeroAbilityType::Peanut => vec![DrawTask::create(2)],
 			HeroAbilityType::NappingNibbles => vec![/* This one actually is empty. */],
-			HeroAbilityType::TipsyTootie => vec![
-				ChoosePlayerParameterTask::exclude_self(TaskParamName::TipsyTootieVictim),
-				ChooseCardFromPlayerParameterTask::from_party(
-					TaskParamName::TipsyTootieVictim,
-					TaskParamName::TipsyTootieCard,
-				),
-				StealCardFromTask::create(
-					TaskParamName::TipsyTootieVictim,
-					TaskParamName::TipsyTootieCard,
-				),
-				UnstealTo::create(),
-				ClearParamsTask::create(),
-				// Choose a player. STEAL a Hero card from that player's Party and
-				//  move this card to that player's Party.
-			], //////////////////////////////////
-			HeroAbilityType::MellowDee => vec![
-				DrawTask::into_param(TaskParamName::MellowDeeCard),
-				OfferPlayImmediately::create(TaskParamName::MellowDeeCard, PlayImmediatelyFilter::IsHero),
-				ClearParamsTask::create(),
-			],
-			HeroAbilityType::LuckBucky => vec![
-				ChoosePlayerParameterTask::exclude_self(TaskParamName::LuckBuckyVictim),
-				PullFromTask::record_pulled(TaskParamName::LuckBuckyVictim, TaskParamName::LuckBuckyCard),
-				OfferPlayImmediately::create(TaskParamName::LuckBuckyCard, PlayImmediatelyFilter::IsHero),
-				ClearParamsTask::create(),
-			],
-			HeroAbilityType::DodgyDealer => vec![
-				ChoosePlayerParameterTask::exclude_self(TaskParamName::DodgyDealerVictim),
-				TradeHands::create(TaskParamName::DodgyDealerVictim),
-				ClearParamsTask::create(),
-			],
+			HeroAbilityType::TipsyTootie => {
+				let mut params = ParamScope::new();
+				let victim = params.player(MessageKey::PromptStealCardFrom);
+				let card = params.card(MessageKey::PromptStealCard);
+				params.chain(vec![
+					ChoosePlayerParameterTask::exclude_self(victim),
+					ChooseCardFromPlayerParameterTask::from_party(victim, card),
+					StealCardFromTask::create(victim, card),
+					UnstealTo::create(victim),
+					// Choose a player. STEAL a Hero card from that player's Party and
+					//  move this card to that player's Party.
+				])
+			}
+			HeroAbilityType::MellowDee => {
+				let mut params = ParamScope::new();
+				let card = params.card(MessageKey::NotApplicable);
+				params.chain(vec![
+					DrawTask::into_param(card),
+					OfferPlayImmediately::create(card, PlayImmediatelyFilter::IsHero),
+				])
+			}
+			HeroAbilityType::LuckBucky => {
+				let mut params = ParamScope::new();
+				let victim = params.player(MessageKey::PromptPullHero);
+				let card = params.card(MessageKey::NotApplicable);
+				params.chain(vec![
+					ChoosePlayerParameterTask::exclude_self(victim),
+					PullFromTask::record_pulled(victim, card),
+					OfferPlayImmediately::create(card, PlayImmediatelyFilter::IsHero),
+				])
+			}
+			HeroAbilityType::DodgyDealer => {
+				let mut params = ParamScope::new();
+				let victim = params.player(MessageKey::PromptTradeHands);
+				params.chain(vec![
+					ChoosePlayerParameterTask::exclude_self(victim),
+					TradeHands::create(victim),
+				])
+			}
 			HeroAbilityType::GreedyCheeks => vec![GreedyCheeks::create()],
 			HeroAbilityType::Fluffy => vec![DestroyTask::create(), DestroyTask::create()],
-			HeroAbilityType::Wiggles => vec![
-				ChoosePlayerParameterTask::exclude_self(TaskParamName::WigglesVictim),
-				ChooseCardFromPlayerParameterTask::from_party(
-					TaskParamName::WigglesVictim,
-					TaskParamName::WigglesCard,
-				),
-				StealCardFromTask::create(TaskParamName::WigglesVictim, TaskParamName::WigglesCard),
-				OfferPlayImmediately::create(TaskParamName::WigglesCard, PlayImmediatelyFilter::None),
-				ClearParamsTask::create(),
-			],
+			HeroAbilityType::Wiggles => {
+				let mut params = ParamScope::new();
+				let victim = params.player(MessageKey::PromptWiggles);
+				let card = params.card(MessageKey::PromptStealHero);
+				params.chain(vec![
+					ChoosePlayerParameterTask::exclude_self(victim),
+					ChooseCardFromPlayerParameterTask::from_party(victim, card),
+					StealCardFromTask::create(victim, card),
+					OfferPlayImmediately::create(card, PlayImmediatelyFilter::None),
+				])
+			}
 			HeroAbilityType::Spooky => vec![Spooky::create()],
-			HeroAbilityType::Snowball => vec![
-				DrawTask::into_param(TaskParamName::SnowballCard),
-				OfferPlayImmediately::with_an_extra_task(
-					TaskParamName::SnowballCard,
-					PlayImmediatelyFilter::IsMagic,
-					DrawTask::create(1),
-				),
-				ClearParamsTask::create(),
-			],
-			HeroAbilityType::Buttons => vec![
-				ChoosePlayerParameterTask::exclude_self(TaskParamName::ButtonsVictim),
-				PullFromTask::record_pulled(TaskParamName::ButtonsVictim, TaskParamName::ButtonsCard),
-				OfferPlayImmediately::create(TaskParamName::ButtonsCard, PlayImmediatelyFilter::IsMagic),
-				ClearParamsTask::create(),
-			],
+			HeroAbilityType::Snowball => {
+				let mut params = ParamScope::new();
+				let card = params.card(MessageKey::NotApplicable);
+				params.chain(vec![
+					DrawTask::into_param(card),
+					OfferPlayImmediately::with_an_extra_task(
+						card,
+						PlayImmediatelyFilter::IsMagic,
+						DrawTask::create(1),
+					),
+				])
+			}
+			HeroAbilityType::Buttons => {
+				let mut params = ParamScope::new();
+				let victim = params.player(MessageKey::PromptPullMagic);
+				let card = params.card(MessageKey::NotApplicable);
+				params.chain(vec![
+					ChoosePlayerParameterTask::exclude_self(victim),
+					PullFromTask::record_pulled(victim, card),
+					OfferPlayImmediately::create(card, PlayImmediatelyFilter::IsMagic),
+				])
+			}
 			HeroAbilityType::BunBun => vec![SearchDiscard::for_magic()],
-			HeroAbilityType::Hopper => vec![
-				ChoosePlayerParameterTask::exclude_self(TaskParamName::HopperVictim),
-				ChooseSacrifice::from_param(TaskParamName::HopperVictim),
-				ClearParamsTask::create(),
-			],
+			HeroAbilityType::Hopper => {
+				let mut params = ParamScope::new();
+				let victim = params.player(MessageKey::PromptSacrificeHero);
+				params.chain(vec![
+					ChoosePlayerParameterTask::exclude_self(victim),
+					ChooseSacrifice::from_param(victim),
+				])
+			}
 			HeroAbilityType::Whiskers => vec![StealTask::create(), DestroyTask::create()],
 		}
 	}
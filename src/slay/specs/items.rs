@@ -1,4 +1,6 @@
 use enum_iterator::Sequence;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::slay::specification::{HeroType, ItemType};
 
@@ -6,7 +8,7 @@ use crate::slay::specs::cards::card_type::SlayCardSpec;
 use crate::slay::status_effects::effect::HeroStatusEffect;
 use crate::slay::status_effects::effect_entry::{EffectOrigin, HeroStatusEffectEntry};
 
-#[derive(Debug, Clone, Sequence, PartialEq, Copy)]
+#[derive(Debug, Clone, Sequence, PartialEq, Copy, Serialize, Deserialize)]
 pub enum Item {
 	DecoyDoll,
 	ReallyBigRing,
@@ -27,7 +29,7 @@ impl Item {
 	}
 }
 
-#[derive(Debug, Clone, Sequence, PartialEq, Copy)]
+#[derive(Debug, Clone, Sequence, PartialEq, Copy, Serialize, Deserialize)]
 pub enum AnotherItemType {
 	MaskCard(HeroType),
 	NotMask(Item),
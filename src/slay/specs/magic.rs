@@ -1,10 +1,13 @@
 use enum_iterator::Sequence;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::slay::errors::SlayResult;
 use crate::slay::game_context::GameBookKeeping;
+use crate::slay::i18n::keys::MessageKey;
 use crate::slay::ids;
+use crate::slay::state::deck::DeckPath;
 use crate::slay::state::game::Game;
-use crate::slay::state::stack::Stack;
 use crate::slay::status_effects::effect::PlayerStatusEffect;
 use crate::slay::status_effects::effect_entry::EffectOrigin;
 use crate::slay::tasks::core::destroy::DestroyTask;
@@ -14,10 +17,9 @@ use crate::slay::tasks::core::steal::StealCardFromTask;
 use crate::slay::tasks::core::steal::StealTask;
 use crate::slay::tasks::core::steal::UnStealCardFromTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::task_params::ParamScope;
 use crate::slay::tasks::tasks::params::ChooseCardFromPlayerParameterTask;
 use crate::slay::tasks::tasks::params::ChoosePlayerParameterTask;
-use crate::slay::tasks::tasks::params::ClearParamsTask;
 use crate::slay::tasks::tasks::params::SetParameterToMyself;
 use crate::slay::tasks::tasks::return_modifiers::ReturnModifierTask;
 use crate::slay::tasks::tasks::search_discard::create_search_discard_choices;
@@ -26,7 +28,7 @@ use crate::slay::tasks::tasks::search_discard::SearchDiscardFilters;
 use super::cards::card_type::SlayCardSpec;
 
 // Rename this to magic card.
-#[derive(Debug, Clone, Copy, Sequence, PartialEq)]
+#[derive(Debug, Clone, Copy, Sequence, PartialEq, Serialize, Deserialize)]
 pub enum MagicSpell {
 	EnganglingTrap,
 	CriticalBoost,
@@ -81,40 +83,27 @@ impl MagicSpell {
 				Ok(TaskProgressResult::TaskComplete)
 			}
 			MagicSpell::ForcedExchange => {
-				let tasks = &mut vec![
-					ChoosePlayerParameterTask::exclude_self(TaskParamName::ForcedExchangeVictim),
-					ChooseCardFromPlayerParameterTask::from_party(
-						TaskParamName::ForcedExchangeVictim,
-						TaskParamName::ForcedExchangeVictimCard,
-					),
-					StealCardFromTask::create(
-						TaskParamName::ForcedExchangeVictim,
-						TaskParamName::ForcedExchangeVictimCard,
-					),
+				let mut params = ParamScope::new();
+				let victim = params.player(MessageKey::PromptForcedExchange);
+				let victim_card = params.card(MessageKey::PromptStealHero);
+				let myself = params.player(MessageKey::NotApplicable);
+				let donation_card = params.card(MessageKey::PromptDonateHero);
+				let tasks = &mut params.chain(vec![
+					ChoosePlayerParameterTask::exclude_self(victim),
+					ChooseCardFromPlayerParameterTask::from_party(victim, victim_card),
+					StealCardFromTask::create(victim, victim_card),
 					// TODO: Should we check if we win here?!?!?!
-					SetParameterToMyself::create(TaskParamName::ForcedExchangeSelf),
-					ChooseCardFromPlayerParameterTask::from_party(
-						TaskParamName::ForcedExchangeSelf,
-						TaskParamName::ForcedExchangeVictimDonationCard,
-					),
-					UnStealCardFromTask::create(
-						TaskParamName::ForcedExchangeSelf,
-						TaskParamName::ForcedExchangeVictimDonationCard,
-					),
-					ClearParamsTask::create(),
-				];
+					SetParameterToMyself::create(myself),
+					ChooseCardFromPlayerParameterTask::from_party(myself, donation_card),
+					UnStealCardFromTask::create(victim, donation_card),
+				]);
 				game.players[player_index].tasks.prepend_from(tasks);
 				Ok(TaskProgressResult::TaskComplete)
 			}
 			MagicSpell::ForcefulWinds => {
-				let mut cards_to_move = Vec::new();
 				for player_index in 0..game.number_of_players() {
-					for stack in game.players[player_index].party.stacks_mut() {
-						cards_to_move.append(&mut stack.modifiers);
-					}
-					game.players[player_index]
-						.hand
-						.extend(cards_to_move.drain(..).map(Stack::new));
+					let cards_to_move = game.take_modifiers(DeckPath::Party(player_index));
+					game.add_stacks(DeckPath::Hand(player_index), cards_to_move);
 				}
 				Ok(TaskProgressResult::TaskComplete)
 			}
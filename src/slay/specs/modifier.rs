@@ -1,6 +1,8 @@
 use enum_iterator::Sequence;
+use serde::Deserialize;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Sequence, PartialEq, Copy)]
+#[derive(Debug, Clone, Sequence, PartialEq, Copy, Serialize, Deserialize)]
 pub enum ModifierKinds {
 	Plus4,
 	Plus3Minus1,
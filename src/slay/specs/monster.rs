@@ -1,4 +1,6 @@
 use enum_iterator::Sequence;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::slay::ids;
 use crate::slay::showdown::consequences::Condition;
@@ -56,7 +58,7 @@ pub fn player_satisfies_requirements(
 	requirements.len() < hero_type_counts.sum()
 }
 
-#[derive(Debug, Clone, Sequence, PartialEq, Copy)]
+#[derive(Debug, Clone, Sequence, PartialEq, Copy, Serialize, Deserialize)]
 pub enum Monster {
 	AnuranCauldron,
 	TitanWyvern,
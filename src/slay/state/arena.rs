@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::slay::choices::CardPath;
+use crate::slay::ids;
+use crate::slay::specs::cards::card_type::SlayCardSpec;
+use crate::slay::state::stack::Card;
+
+/*
+	Every card in the game, by id.
+	Decks only hold the ids of their cards, in order. The arena remembers where each card was put,
+	so finding a card does not mean searching every deck.
+	A card that has been taken out of a deck and not put back yet, like the stack being moved,
+	has no location.
+*/
+
+#[derive(Debug, Clone, Default)]
+pub struct CardArena {
+	// Cards are only created while the game is being set up, so copies of the game share them.
+	cards: Arc<HashMap<ids::CardId, Card>>,
+	locations: HashMap<ids::CardId, CardPath>,
+}
+
+impl CardArena {
+	pub fn create(
+		&mut self,
+		id_generator: &mut ids::IdGenerator,
+		card_type: SlayCardSpec,
+	) -> ids::CardId {
+		let card = Card::new(id_generator.generate(), card_type);
+		let card_id = card.id;
+		Arc::make_mut(&mut self.cards).insert(card_id, card);
+		card_id
+	}
+
+	pub fn get(&self, card_id: ids::CardId) -> Option<&Card> {
+		self.cards.get(&card_id)
+	}
+
+	pub fn card(&self, card_id: ids::CardId) -> &Card {
+		if let Some(card) = self.get(card_id) {
+			card
+		} else {
+			log::info!("Unable to find card {}", card_id);
+			unreachable!()
+		}
+	}
+
+	pub fn card_type(&self, card_id: ids::CardId) -> SlayCardSpec {
+		self.card(card_id).card_type
+	}
+
+	pub fn location(&self, card_id: ids::CardId) -> Option<CardPath> {
+		self.locations.get(&card_id).copied()
+	}
+
+	pub(crate) fn place(&mut self, path: CardPath) {
+		self.locations.insert(path.get_card_id(), path);
+	}
+
+	pub(crate) fn remove(&mut self, card_id: ids::CardId) {
+		self.locations.remove(&card_id);
+	}
+}
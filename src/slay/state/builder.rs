@@ -0,0 +1,369 @@
+use enum_iterator::all;
+
+use crate::slay::actions::list_actions;
+use crate::slay::game_context::GameBookKeeping;
+use crate::slay::ids;
+use crate::slay::specification::GameVersion;
+use crate::slay::specification::HeroType;
+use crate::slay::specs::cards::card_type::SlayCardSpec;
+use crate::slay::specs::monster::Monster;
+use crate::slay::state::deck::DeckPath;
+use crate::slay::state::game::Game;
+use crate::slay::state::initialize::bot_name;
+use crate::slay::state::player::Player;
+use crate::slay::state::stack::Stack;
+use crate::slay::tasks::player_tasks::continue_tasks;
+
+/*
+	Sets up a game exactly, for testing a single interaction:
+
+		let game = GameBuilder::new(2)
+			.leader(0, HeroType::Thief)
+			.party(0, vec![StackBuilder::new(hero).with(item)])
+			.slain_monsters(1, vec![Monster::MegaSlime])
+			.draw_pile(vec![spell, challenge])
+			.active_player(1)
+			.build(context);
+
+	Every pile holds only what it was given, in the order it was given, so a test that draws
+	should give the draw pile enough cards.
+	Slain monsters grant their effects just by being slain, like in a dealt game.
+*/
+
+// A card with the items and modifiers on it, bottom first.
+#[derive(Debug, Clone)]
+pub struct StackBuilder {
+	top: SlayCardSpec,
+	modifiers: Vec<SlayCardSpec>,
+}
+
+impl StackBuilder {
+	pub fn new(top: SlayCardSpec) -> Self {
+		Self {
+			top,
+			modifiers: Vec::new(),
+		}
+	}
+
+	pub fn with(mut self, modifier: SlayCardSpec) -> Self {
+		self.modifiers.push(modifier);
+		self
+	}
+
+	fn build(&self, context: &mut GameBookKeeping, game: &mut Game) -> Stack {
+		let mut stack = Stack::new(create_card(context, game, self.top));
+		for modifier in self.modifiers.iter() {
+			stack.modifiers.push(create_card(context, game, *modifier));
+		}
+		stack
+	}
+}
+
+impl From<SlayCardSpec> for StackBuilder {
+	fn from(top: SlayCardSpec) -> Self {
+		Self::new(top)
+	}
+}
+
+#[derive(Debug, Clone)]
+struct PlayerSetup {
+	name: String,
+	leader: HeroType,
+	hand: Vec<SlayCardSpec>,
+	party: Vec<StackBuilder>,
+	slain_monsters: Vec<Monster>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GameBuilder {
+	players: Vec<PlayerSetup>,
+	draw: Vec<SlayCardSpec>,
+	discard: Vec<SlayCardSpec>,
+	monsters: Vec<Monster>,
+	next_monsters: Vec<Monster>,
+	active_player: ids::PlayerIndex,
+	action_points: Option<u32>,
+}
+
+fn create_card(context: &mut GameBookKeeping, game: &mut Game, spec: SlayCardSpec) -> ids::CardId {
+	game.cards.create(&mut context.id_generator, spec)
+}
+
+fn fill(
+	context: &mut GameBookKeeping,
+	game: &mut Game,
+	deck_path: DeckPath,
+	specs: &[SlayCardSpec],
+) {
+	for spec in specs.iter() {
+		let card_id = create_card(context, game, *spec);
+		game.add_stack(deck_path, Stack::new(card_id));
+	}
+}
+
+fn monster_cards(monsters: &[Monster]) -> Vec<SlayCardSpec> {
+	monsters
+		.iter()
+		.map(|monster| SlayCardSpec::MonsterCard(*monster))
+		.collect()
+}
+
+impl GameBuilder {
+	// Players lead the original party leaders in order, with empty hands and parties.
+	pub fn new(number_of_players: usize) -> Self {
+		let leaders: Vec<HeroType> = all::<HeroType>()
+			.filter(|hero_type| hero_type.game_version() == GameVersion::Original)
+			.collect();
+		Self {
+			players: (0..number_of_players)
+				.map(|player_index| PlayerSetup {
+					name: format!("{} (Player {})", bot_name(player_index), player_index + 1),
+					leader: leaders[player_index % leaders.len()],
+					hand: Vec::new(),
+					party: Vec::new(),
+					slain_monsters: Vec::new(),
+				})
+				.collect(),
+			draw: Vec::new(),
+			discard: Vec::new(),
+			monsters: Vec::new(),
+			next_monsters: Vec::new(),
+			active_player: 0,
+			action_points: None,
+		}
+	}
+
+	pub fn name(mut self, player_index: ids::PlayerIndex, name: &str) -> Self {
+		self.players[player_index].name = name.to_owned();
+		self
+	}
+
+	pub fn leader(mut self, player_index: ids::PlayerIndex, leader: HeroType) -> Self {
+		self.players[player_index].leader = leader;
+		self
+	}
+
+	pub fn hand(mut self, player_index: ids::PlayerIndex, hand: Vec<SlayCardSpec>) -> Self {
+		self.players[player_index].hand = hand;
+		self
+	}
+
+	pub fn party(mut self, player_index: ids::PlayerIndex, party: Vec<StackBuilder>) -> Self {
+		self.players[player_index].party = party;
+		self
+	}
+
+	pub fn slain_monsters(mut self, player_index: ids::PlayerIndex, monsters: Vec<Monster>) -> Self {
+		self.players[player_index].slain_monsters = monsters;
+		self
+	}
+
+	// The first card is the first one drawn.
+	pub fn draw_pile(mut self, draw: Vec<SlayCardSpec>) -> Self {
+		self.draw = draw;
+		self
+	}
+
+	pub fn discard_pile(mut self, discard: Vec<SlayCardSpec>) -> Self {
+		self.discard = discard;
+		self
+	}
+
+	pub fn monsters(mut self, monsters: Vec<Monster>) -> Self {
+		self.monsters = monsters;
+		self
+	}
+
+	// The first monster is the next one to be revealed.
+	pub fn next_monsters(mut self, next_monsters: Vec<Monster>) -> Self {
+		self.next_monsters = next_monsters;
+		self
+	}
+
+	pub fn active_player(mut self, player_index: ids::PlayerIndex) -> Self {
+		self.active_player = player_index;
+		self
+	}
+
+	// Otherwise the active player starts their turn with all of their action points.
+	pub fn action_points(mut self, remaining: u32) -> Self {
+		self.action_points = Some(remaining);
+		self
+	}
+
+	// Without any choices assigned, for looking at the board before the turn starts.
+	pub fn build_board(&self, context: &mut GameBookKeeping) -> Game {
+		let mut game = Game::new();
+		for (player_index, setup) in self.players.iter().enumerate() {
+			let leader = create_card(context, &mut game, SlayCardSpec::PartyLeader(setup.leader));
+			game.add_player(Player::new(setup.name.to_owned(), player_index, leader));
+			fill(
+				context,
+				&mut game,
+				DeckPath::Hand(player_index),
+				&setup.hand,
+			);
+			for stack in setup.party.iter() {
+				let stack = stack.build(context, &mut game);
+				game.add_stack(DeckPath::Party(player_index), stack);
+			}
+			fill(
+				context,
+				&mut game,
+				DeckPath::SlainMonsters(player_index),
+				&monster_cards(&setup.slain_monsters),
+			);
+		}
+		fill(context, &mut game, DeckPath::Draw, &self.draw);
+		fill(context, &mut game, DeckPath::Discard, &self.discard);
+		fill(
+			context,
+			&mut game,
+			DeckPath::ActiveMonsters,
+			&monster_cards(&self.monsters),
+		);
+		fill(
+			context,
+			&mut game,
+			DeckPath::NextMonsters,
+			&monster_cards(&self.next_monsters),
+		);
+
+		game.set_active_player(self.active_player);
+		game.begin_turn();
+		if let Some(remaining) = self.action_points {
+			game
+				.current_player_mut()
+				.set_remaining_action_points(remaining);
+		}
+		game.take_card_census();
+		game
+	}
+
+	// Ready for the active player's first choice, like a freshly dealt game.
+	pub fn build(&self, context: &mut GameBookKeeping) -> Game {
+		let mut game = self.build_board(context);
+		list_actions::assign_action_choices(context, &mut game);
+		continue_tasks(context, &mut game, self.active_player).expect("uh oh");
+		game
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::slay::choices::Action;
+	use crate::slay::choices::Choice;
+	use crate::slay::choices::ChoicesType;
+	use crate::slay::driver;
+	use crate::slay::game_context::GameBookKeeping;
+	use crate::slay::specification::HeroType;
+	use crate::slay::specs::cards::card_type::SlayCardSpec;
+	use crate::slay::specs::hero::HeroAbilityType;
+	use crate::slay::specs::items::AnotherItemType;
+	use crate::slay::specs::items::Item;
+	use crate::slay::specs::modifier::ModifierKinds;
+	use crate::slay::specs::monster::Monster;
+	use crate::slay::state::builder::GameBuilder;
+	use crate::slay::state::builder::StackBuilder;
+	use crate::slay::state::deck::Deck;
+	use crate::slay::state::game::Game;
+
+	const PEANUT: SlayCardSpec = SlayCardSpec::HeroCard(HeroAbilityType::Peanut);
+	const RING: SlayCardSpec = SlayCardSpec::Item(AnotherItemType::NotMask(Item::ReallyBigRing));
+	const PLUS_4: SlayCardSpec = SlayCardSpec::ModifierCard(ModifierKinds::Plus4);
+
+	fn specs(game: &Game, deck: &Deck) -> Vec<SlayCardSpec> {
+		deck
+			.top_ids()
+			.map(|card_id| game.cards.card_type(card_id))
+			.collect()
+	}
+
+	#[test]
+	fn every_pile_holds_exactly_what_it_was_given() {
+		let context = &mut GameBookKeeping::new();
+		let game = GameBuilder::new(2)
+			.leader(1, HeroType::Wizard)
+			.hand(0, vec![PLUS_4, SlayCardSpec::Challenge])
+			.party(1, vec![StackBuilder::new(PEANUT).with(RING)])
+			.monsters(vec![Monster::Dracos])
+			.discard_pile(vec![PLUS_4])
+			.build(context);
+
+		assert_eq!(
+			specs(&game, &game.players[0].hand),
+			vec![PLUS_4, SlayCardSpec::Challenge]
+		);
+		assert_eq!(specs(&game, &game.players[1].hand), vec![]);
+		assert_eq!(
+			game.cards.card_type(game.players[1].leader),
+			SlayCardSpec::PartyLeader(HeroType::Wizard)
+		);
+		let stack = game.players[1].party.stacks().next().unwrap();
+		assert_eq!(game.cards.card_type(stack.top), PEANUT);
+		assert_eq!(
+			stack
+				.modifiers
+				.iter()
+				.map(|card_id| game.cards.card_type(*card_id))
+				.collect::<Vec<_>>(),
+			vec![RING]
+		);
+		assert_eq!(
+			specs(&game, &game.monsters),
+			vec![SlayCardSpec::MonsterCard(Monster::Dracos)]
+		);
+		assert_eq!(specs(&game, &game.discard), vec![PLUS_4]);
+		assert_eq!(specs(&game, &game.draw), vec![]);
+		assert_eq!(game.check_invariants(), vec![]);
+	}
+
+	#[test]
+	fn the_active_player_starts_with_the_given_action_points_and_draws_in_order() {
+		let context = &mut GameBookKeeping::new();
+		let mut game = GameBuilder::new(2)
+			.draw_pile(vec![SlayCardSpec::Challenge, PLUS_4])
+			.active_player(1)
+			.action_points(2)
+			.build(context);
+		assert!(game.players[0].choices_.is_none());
+		assert_eq!(
+			game.players[1]
+				.choices_
+				.as_ref()
+				.map(|choices| choices.choices_type.to_owned()),
+			Some(ChoicesType::SpendActionPoints)
+		);
+		assert_eq!(game.players[1].get_remaining_action_points(), 2);
+
+		let choice_id = game.players[1]
+			.choices_
+			.as_ref()
+			.and_then(|choices| {
+				choices
+					.options
+					.iter()
+					.find(|option| option.choice == Choice::UseActionPoints(Action::Draw))
+			})
+			.unwrap()
+			.id;
+		driver::make_selection(&mut game, 1, choice_id, &mut |_| {}).unwrap();
+		driver::advance_game(context, &mut game).unwrap();
+		assert_eq!(
+			specs(&game, &game.players[1].hand),
+			vec![SlayCardSpec::Challenge]
+		);
+		assert_eq!(specs(&game, &game.draw), vec![PLUS_4]);
+		assert_eq!(game.players[1].get_remaining_action_points(), 1);
+	}
+
+	#[test]
+	fn slain_monsters_grant_their_effects() {
+		let context = &mut GameBookKeeping::new();
+		let game = GameBuilder::new(2)
+			.slain_monsters(0, vec![Monster::MegaSlime])
+			.build(context);
+		assert_eq!(game.players[0].get_remaining_action_points(), 4);
+		assert_eq!(game.check_invariants(), vec![]);
+	}
+}
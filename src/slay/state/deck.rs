@@ -1,20 +1,26 @@
 // use super::ids::{CardId, ChallengeId, ChoiceId, DeckId, ElementId, IdGenerator, PlayerId, RollId};
 
+use serde::Deserialize;
+use serde::Serialize;
+
 use crate::slay::choices::CardPath;
 use crate::slay::choices::ChoicePerspective;
 use crate::slay::choices::ChoicesPerspective;
 use crate::slay::choices::DisplayPath;
 use crate::slay::errors;
 use crate::slay::errors::SlayResult;
+use crate::slay::i18n::keys::MessageKey;
+use crate::slay::i18n::Arg;
+use crate::slay::i18n::Message;
 use crate::slay::ids;
 use crate::slay::specification::HeroType;
 use crate::slay::specs::visibility::Perspective;
 use crate::slay::specs::visibility::Visibility;
 use crate::slay::specs::visibility::VisibilitySpec;
+use crate::slay::state::arena::CardArena;
 use crate::slay::state::game::Game;
 use crate::slay::state::stack::Card;
 use crate::slay::state::stack::Stack;
-use crate::slay::state::summarizable::Summarizable;
 
 use std::collections::HashSet;
 use std::collections::VecDeque;
@@ -35,7 +41,7 @@ pub struct DeckSpec {
 }
 
 // Lol, tried of looking for the deck by id...
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Serialize, Deserialize)]
 pub enum DeckPath {
 	Draw,
 	Discard,
@@ -65,18 +71,29 @@ impl DeckPath {
 		}
 	}
 
-	pub fn get_label(&self) -> String {
+	pub fn label_message(&self) -> Message {
 		match self {
-			DeckPath::Draw => "Draw pile".to_string(),
-			DeckPath::Discard => "Discard pile".to_string(),
-			DeckPath::PartyLeaders => "Unused Party leaders".to_string(),
-			DeckPath::ActiveMonsters => "Monsters".to_string(),
-			DeckPath::NextMonsters => "Next monsters".to_string(),
-			DeckPath::Hand(player_index) => format!("Player {}'s hand", player_index),
-			DeckPath::Party(player_index) => format!("Player {}'s party", player_index),
-			DeckPath::SlainMonsters(player_index) => format!("Player {}'s monsters", player_index),
+			DeckPath::Draw => MessageKey::DeckDraw.into(),
+			DeckPath::Discard => MessageKey::DeckDiscard.into(),
+			DeckPath::PartyLeaders => MessageKey::DeckPartyLeaders.into(),
+			DeckPath::ActiveMonsters => MessageKey::DeckMonsters.into(),
+			DeckPath::NextMonsters => MessageKey::DeckNextMonsters.into(),
+			DeckPath::Hand(player_index) => {
+				Message::new(MessageKey::DeckHand, vec![Arg::PlayerName(*player_index)])
+			}
+			DeckPath::Party(player_index) => {
+				Message::new(MessageKey::DeckParty, vec![Arg::PlayerName(*player_index)])
+			}
+			DeckPath::SlainMonsters(player_index) => Message::new(
+				MessageKey::DeckSlainMonsters,
+				vec![Arg::PlayerName(*player_index)],
+			),
 		}
 	}
+
+	pub fn get_label(&self) -> String {
+		self.label_message().to_english()
+	}
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -117,17 +134,17 @@ impl PartialDeckPath {
 //     pub fn monsters(player_index: ids::PlayerIndex) -> DeckPath { DeckPath::PlayerDeck(player_index, PlayerDeckName::SlainMonsters)}
 // }
 
+// Only the game adds or takes stacks, so that the card arena always knows where each card is.
 #[derive(Debug, Clone)]
 pub struct Deck {
 	// TODO: hide internals...
 	// TODO: remove the id...
 	// TODO: make stacks optional...
-	// TODO: Make the stacks just have a card id
 	stacks: VecDeque<Stack>,
 	pub spec: DeckSpec,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct DeckPerspective {
 	pub count: usize,
 	pub path: DeckPath,
@@ -193,37 +210,54 @@ impl Deck {
 	// }
 
 	pub fn stack(&self, card_id: ids::CardId) -> Option<&Stack> {
-		self.stacks.iter().find(|stack| stack.top.id == card_id)
+		self.stacks.iter().find(|stack| stack.top == card_id)
 	}
 	pub fn stacks(&self) -> impl Iterator<Item = &Stack> {
 		self.stacks.iter()
 	}
-	pub fn stacks_mut(&mut self) -> impl Iterator<Item = &mut Stack> {
-		self.stacks.iter_mut()
-	}
 	// pub fn iter(&self) -> impl Iterator<Item = &Stack> {
 	// 	self.stacks.iter()
 	// }
-	pub fn tops(&self) -> impl Iterator<Item = &Card> {
-		self.stacks.iter().map(|stack| &stack.top)
+	pub fn top_ids(&self) -> impl Iterator<Item = ids::CardId> + '_ {
+		self.stacks.iter().map(|stack| stack.top)
+	}
+	pub fn tops<'a>(&'a self, cards: &'a CardArena) -> impl Iterator<Item = &'a Card> {
+		self.top_ids().map(|card_id| cards.card(card_id))
 	}
 	// TODO: understand the whole '_ thing...
 	pub fn top_paths(&self) -> impl Iterator<Item = CardPath> + '_ {
 		self
-			.tops()
-			.map(|card| CardPath::TopCardIn(self.spec.path, card.id))
-	}
-
-	pub fn extend<D: IntoIterator<Item = Stack>>(&mut self, drained: D) {
-		self.stacks.extend(drained);
+			.top_ids()
+			.map(|card_id| CardPath::TopCardIn(self.spec.path, card_id))
+	}
+
+	// Where each card in the deck is.
+	pub fn card_paths(&self) -> impl Iterator<Item = CardPath> + '_ {
+		let deck_path = self.spec.path;
+		self.stacks.iter().flat_map(move |stack| {
+			std::iter::once(CardPath::TopCardIn(deck_path, stack.top)).chain(
+				stack
+					.modifiers
+					.iter()
+					.map(move |modifier| CardPath::ModifyingCardIn(deck_path, stack.top, *modifier)),
+			)
+		})
 	}
 
-	// Wish the return type didn't have to expose the inner data type...
-	pub fn drain<R>(&mut self, range: R) -> std::collections::vec_deque::Drain<Stack>
+	pub(crate) fn drain<R>(&mut self, range: R) -> Vec<Stack>
 	where
 		R: RangeBounds<usize>,
 	{
-		self.stacks.drain(range)
+		self.stacks.drain(range).collect()
+	}
+
+	// The items and modifiers on every stack, each in a stack of its own.
+	pub(crate) fn drain_modifiers(&mut self) -> Vec<Stack> {
+		self
+			.stacks
+			.iter_mut()
+			.flat_map(|stack| stack.modifiers.drain(..).map(Stack::new))
+			.collect()
 	}
 
 	pub fn is_visible(&self, perspective: &Perspective) -> bool {
@@ -234,40 +268,41 @@ impl Deck {
 		self.is_visible(&Perspective::Spectator)
 	}
 
-	pub fn add(&mut self, stack: Stack) {
+	pub(crate) fn add(&mut self, stack: Stack) {
 		self.stacks.push_back(stack);
 	}
 
-	pub fn add_card(&mut self, c: Card) {
-		self.stacks.push_back(Stack {
-			top: c,
-			modifiers: vec![],
-		});
-	}
-
-	pub fn count_hero_types(&self, hero_types: &mut HeroTypeCounter) {
+	pub fn count_hero_types(&self, cards: &CardArena, hero_types: &mut HeroTypeCounter) {
 		for stack in self.stacks.iter() {
-			hero_types.maybe_add_hero_type(stack.get_hero_type());
+			hero_types.maybe_add_hero_type(stack.get_hero_type(cards));
 		}
 	}
-	pub fn collect_hero_types(&self, hero_types: &mut HashSet<HeroType>) {
-		hero_types.extend(self.stacks.iter().flat_map(|stack| stack.get_hero_type()))
+	pub fn collect_hero_types(&self, cards: &CardArena, hero_types: &mut HashSet<HeroType>) {
+		hero_types.extend(
+			self
+				.stacks
+				.iter()
+				.flat_map(|stack| stack.get_hero_type(cards)),
+		)
 	}
-	pub(crate) fn contains_hero_type(&self, hero_type: &HeroType) -> bool {
-		self.stacks.iter().any(|stack| match stack.get_hero_type() {
-			Some(ht) => ht == *hero_type,
-			None => false,
-		})
+	pub(crate) fn contains_hero_type(&self, cards: &CardArena, hero_type: &HeroType) -> bool {
+		self
+			.stacks
+			.iter()
+			.any(|stack| match stack.get_hero_type(cards) {
+				Some(ht) => ht == *hero_type,
+				None => false,
+			})
 	}
 
-	pub fn take(&mut self, card_id: ids::CardId) -> Option<Stack> {
-		if let Some(position) = self.stacks.iter().position(|s| s.top.id == card_id) {
+	pub(crate) fn take(&mut self, card_id: ids::CardId) -> Option<Stack> {
+		if let Some(position) = self.stacks.iter().position(|s| s.top == card_id) {
 			// Take the whole stack
 			return self.stacks.remove(position);
 		}
 
 		for stack in self.stacks.iter_mut() {
-			if let Some(position) = stack.modifiers.iter().position(|c| c.id == card_id) {
+			if let Some(position) = stack.modifiers.iter().position(|c| *c == card_id) {
 				// Just the modifier
 				return Some(Stack::new(stack.modifiers.remove(position)));
 			}
@@ -288,45 +323,31 @@ impl Deck {
 		// 	.and_then(|i| self.stacks.remove(i))
 	}
 
-	pub fn take_at_index(&mut self, index: usize) -> Stack {
+	// Puts the cards under the stack with the given top, as its items or modifiers.
+	pub(crate) fn attach(&mut self, top: ids::CardId, card_ids: Vec<ids::CardId>) -> SlayResult<()> {
+		let stack = self
+			.stacks
+			.iter_mut()
+			.find(|stack| stack.top == top)
+			.ok_or_else(|| errors::SlayError::new("Unable to find the stack to attach to."))?;
+		stack.modifiers.extend(card_ids);
+		Ok(())
+	}
+
+	pub(crate) fn take_at_index(&mut self, index: usize) -> Stack {
 		self.stacks.remove(index).unwrap()
 	}
 
-	pub fn take_card(&mut self, card_id: ids::CardId) -> SlayResult<Stack> {
+	pub(crate) fn take_card(&mut self, card_id: ids::CardId) -> SlayResult<Stack> {
 		self
 			.take(card_id)
 			.ok_or_else(|| errors::SlayError::new("Unable to find card in deck."))
 	}
 
-	pub fn maybe_deal(&mut self) -> Option<Stack> {
+	pub(crate) fn maybe_deal(&mut self) -> Option<Stack> {
 		self.stacks.pop_front()
 	}
 
-	pub fn deal(&mut self) -> Stack {
-		self.stacks.pop_front().unwrap()
-		// // TODO:
-		// let mut stack = self.stacks.pop().unwrap();
-		// if stack.cards.len() != 1 {
-		//     println!("Losing cards.");
-		// }
-		// return stack.cards.pop().unwrap();
-
-		// let mut cards = &mut self.stacks.pop()?.cards;
-		// if cards.len() != 1 {
-		//     println!("Losing cards.");
-		//     return None;
-		// }
-		// return Some(&mut cards[0]);
-	}
-
-	pub(crate) fn card(&self, card_id: u32) -> Option<&Card> {
-		self
-			.stacks
-			.iter()
-			.find(|s| s.top.id == card_id)
-			.map(|s| &s.top)
-	}
-
 	// pub(crate) fn other_cards(&self, exclude: &HashSet<ids::CardId>) -> HashSet<ids::CardId> {
 	// 	self
 	// 		.stacks
@@ -336,47 +357,34 @@ impl Deck {
 	// 		.collect()
 	// }
 
-	pub(crate) fn modifier(&self, top_card_id: u32, modifier_card_id: u32) -> Option<&Card> {
-		for stack in self.stacks.iter() {
-			if stack.top.id != top_card_id {
-				continue;
-			}
-			for modifier in stack.modifiers.iter() {
-				if modifier.id == modifier_card_id {
-					return Some(modifier);
-				}
-			}
-		}
-		None
-	}
-
-	pub fn swap_first_cards(&mut self) {
+	pub(crate) fn swap_first_cards(&mut self) {
 		self.stacks.swap(0, 1);
 	}
 }
 
-impl Summarizable for Deck {
-	fn summarize<W: Write>(
+impl Deck {
+	pub fn summarize_with<W: Write>(
 		&self,
+		cards: &CardArena,
 		f: &mut BufWriter<W>,
 		indentation_level: u32,
 	) -> Result<(), std::io::Error> {
 		for _ in 0..indentation_level {
 			write!(f, "  ")?;
 		}
-		write!(f, "{}: ", self.spec.path.get_label())?;
 		let num_stacks = self.stacks.len();
+		write!(f, "{} ({}): ", self.spec.path.get_label(), num_stacks)?;
 		if num_stacks > 8 {
 			for stack in self.stacks.range(0..4) {
-				stack.summarize(f, indentation_level + 1)?;
+				stack.summarize_with(cards, f, indentation_level + 1)?;
 			}
 			write!(f, "...  ")?;
 			for stack in self.stacks.range((num_stacks - 4)..num_stacks) {
-				stack.summarize(f, indentation_level + 1)?;
+				stack.summarize_with(cards, f, indentation_level + 1)?;
 			}
 		} else {
 			for stack in self.stacks.iter() {
-				stack.summarize(f, indentation_level + 1)?;
+				stack.summarize_with(cards, f, indentation_level + 1)?;
 			}
 		}
 		writeln!(f)?;
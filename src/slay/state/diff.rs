@@ -0,0 +1,453 @@
+use crate::slay::choices::ChoicesPerspective;
+use crate::slay::ids;
+use crate::slay::showdown::challenge::ChallengePerspective;
+use crate::slay::showdown::completion::CompletionTracker;
+use crate::slay::showdown::offer::OfferChallengesPerspective;
+use crate::slay::showdown::roll_modification::RollModification;
+use crate::slay::showdown::roll_state::RollPerspective;
+use crate::slay::state::deck::DeckPath;
+use crate::slay::state::deck::DeckPerspective;
+use crate::slay::state::game::GamePerspective;
+use crate::slay::state::player::PlayerPerspective;
+use crate::slay::state::player::RepresentedHeroType;
+use crate::slay::state::stack::CardPerspective;
+use crate::slay::state::stack::StackPerspective;
+use crate::slay::state::turn::Turn;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/*
+	The changes between two perspectives of the same viewer.
+	Fields that did not change are left empty, so the size of a patch follows the size of the change.
+	Applying the diff of (a, b) to a results in b.
+*/
+
+// Stacks are identified by the id of their top card.
+// Stacks that are gone are removed first, then each upsert is placed at its index in order.
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct StacksPatch {
+	pub removed: Vec<ids::CardId>,
+	pub upserted: Vec<(usize, StackPerspective)>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub enum StacksChange {
+	Hidden,
+	Revealed(Vec<StackPerspective>),
+	Patched(StacksPatch),
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct DeckPatch {
+	pub path: DeckPath,
+	pub count: Option<usize>,
+	pub stacks: Option<StacksChange>,
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct PlayerPatch {
+	pub remaining_action_points: Option<u32>,
+	pub total_action_points: Option<u32>,
+	pub decks: Vec<DeckPatch>,
+	pub represented_hero_types: Option<Vec<RepresentedHeroType>>,
+	pub leader: Option<CardPerspective>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub enum RollPatch {
+	Replaced(Option<RollPerspective>),
+	Continued {
+		appended_history: Vec<RollModification>,
+		completion_tracker: Option<CompletionTracker>,
+	},
+}
+
+// Option<Option<T>> would lose Some(None) on the wire, so optional fields say so explicitly.
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub enum Change<T> {
+	#[default]
+	Unchanged,
+	Set(T),
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct PerspectiveDiff {
+	pub players: Vec<(ids::PlayerIndex, PlayerPatch)>,
+	pub decks: Vec<DeckPatch>,
+	pub choices: Change<Option<ChoicesPerspective>>,
+	pub turn: Option<Turn>,
+	pub roll: Option<RollPatch>,
+	pub offer: Change<Option<OfferChallengesPerspective>>,
+	pub challenge: Change<Option<ChallengePerspective>>,
+}
+
+// The list of players and decks never changes during a game,
+// if it somehow does the whole perspective is sent instead.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub enum PerspectivePatch {
+	Replace(Box<GamePerspective>),
+	Diff(Box<PerspectiveDiff>),
+}
+
+fn changed<T: PartialEq + Clone>(previous: &T, next: &T) -> Option<T> {
+	if previous == next {
+		None
+	} else {
+		Some(next.to_owned())
+	}
+}
+
+impl<T: PartialEq + Clone> Change<T> {
+	pub fn between(previous: &T, next: &T) -> Self {
+		match changed(previous, next) {
+			Some(value) => Self::Set(value),
+			None => Self::Unchanged,
+		}
+	}
+
+	pub fn apply(self, value: &mut T) {
+		if let Self::Set(replacement) = self {
+			*value = replacement;
+		}
+	}
+}
+
+fn same_paths(previous: &[DeckPerspective], next: &[DeckPerspective]) -> bool {
+	previous.len() == next.len()
+		&& previous
+			.iter()
+			.zip(next.iter())
+			.all(|(p, n)| p.path == n.path)
+}
+
+fn place_stack(stacks: &mut Vec<StackPerspective>, index: usize, stack: StackPerspective) {
+	if let Some(existing) = stacks.iter().position(|s| s.top.id == stack.top.id) {
+		stacks.remove(existing);
+	}
+	stacks.insert(index.min(stacks.len()), stack);
+}
+
+impl StacksPatch {
+	pub fn between(previous: &[StackPerspective], next: &[StackPerspective]) -> Self {
+		let removed: Vec<ids::CardId> = previous
+			.iter()
+			.map(|stack| stack.top.id)
+			.filter(|id| !next.iter().any(|stack| stack.top.id == *id))
+			.collect();
+		let mut current: Vec<StackPerspective> = previous
+			.iter()
+			.filter(|stack| !removed.contains(&stack.top.id))
+			.cloned()
+			.collect();
+		let mut upserted = Vec::new();
+		for (index, stack) in next.iter().enumerate() {
+			if current.get(index) == Some(stack) {
+				continue;
+			}
+			place_stack(&mut current, index, stack.to_owned());
+			upserted.push((index, stack.to_owned()));
+		}
+		Self { removed, upserted }
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.removed.is_empty() && self.upserted.is_empty()
+	}
+
+	pub fn apply(self, stacks: &mut Vec<StackPerspective>) {
+		stacks.retain(|stack| !self.removed.contains(&stack.top.id));
+		for (index, stack) in self.upserted {
+			place_stack(stacks, index, stack);
+		}
+	}
+}
+
+impl DeckPatch {
+	pub fn between(previous: &DeckPerspective, next: &DeckPerspective) -> Option<Self> {
+		let stacks = match (&previous.stacks, &next.stacks) {
+			(None, None) => None,
+			(Some(_), None) => Some(StacksChange::Hidden),
+			(None, Some(stacks)) => Some(StacksChange::Revealed(stacks.to_owned())),
+			(Some(previous), Some(next)) => {
+				let patch = StacksPatch::between(previous, next);
+				if patch.is_empty() {
+					None
+				} else {
+					Some(StacksChange::Patched(patch))
+				}
+			}
+		};
+		let count = changed(&previous.count, &next.count);
+		if count.is_none() && stacks.is_none() {
+			return None;
+		}
+		Some(Self {
+			path: next.path,
+			count,
+			stacks,
+		})
+	}
+
+	pub fn apply(self, deck: &mut DeckPerspective) {
+		if let Some(count) = self.count {
+			deck.count = count;
+		}
+		match self.stacks {
+			None => {}
+			Some(StacksChange::Hidden) => deck.stacks = None,
+			Some(StacksChange::Revealed(stacks)) => deck.stacks = Some(stacks),
+			Some(StacksChange::Patched(patch)) => patch.apply(deck.stacks.get_or_insert_with(Vec::new)),
+		}
+	}
+}
+
+fn deck_patches(previous: &[DeckPerspective], next: &[DeckPerspective]) -> Vec<DeckPatch> {
+	previous
+		.iter()
+		.zip(next.iter())
+		.filter_map(|(p, n)| DeckPatch::between(p, n))
+		.collect()
+}
+
+fn apply_deck_patches(decks: &mut [DeckPerspective], patches: Vec<DeckPatch>) {
+	for patch in patches {
+		if let Some(deck) = decks.iter_mut().find(|deck| deck.path == patch.path) {
+			patch.apply(deck);
+		}
+	}
+}
+
+impl PlayerPatch {
+	pub fn between(previous: &PlayerPerspective, next: &PlayerPerspective) -> Option<Self> {
+		let patch = Self {
+			remaining_action_points: changed(
+				&previous.remaining_action_points,
+				&next.remaining_action_points,
+			),
+			total_action_points: changed(&previous.total_action_points, &next.total_action_points),
+			decks: deck_patches(&previous.decks, &next.decks),
+			represented_hero_types: changed(
+				&previous.represented_hero_types,
+				&next.represented_hero_types,
+			),
+			leader: changed(&previous.leader, &next.leader),
+		};
+		if patch == Self::default() {
+			None
+		} else {
+			Some(patch)
+		}
+	}
+
+	pub fn apply(self, player: &mut PlayerPerspective) {
+		if let Some(points) = self.remaining_action_points {
+			player.remaining_action_points = points;
+		}
+		if let Some(points) = self.total_action_points {
+			player.total_action_points = points;
+		}
+		apply_deck_patches(&mut player.decks, self.decks);
+		if let Some(hero_types) = self.represented_hero_types {
+			player.represented_hero_types = hero_types;
+		}
+		if let Some(leader) = self.leader {
+			player.leader = leader;
+		}
+	}
+}
+
+impl RollPatch {
+	pub fn between(
+		previous: &Option<RollPerspective>,
+		next: &Option<RollPerspective>,
+	) -> Option<Self> {
+		match (previous, next) {
+			(Some(p), Some(n))
+				if p.roller_index == n.roller_index
+					&& p.reason == n.reason
+					&& p.win_condition == n.win_condition
+					&& p.loss_condition == n.loss_condition
+					&& p.initial == n.initial
+					&& n.history.starts_with(&p.history) =>
+			{
+				let appended_history = n.history[p.history.len()..].to_vec();
+				let completion_tracker = changed(&p.completion_tracker, &n.completion_tracker);
+				if appended_history.is_empty() && completion_tracker.is_none() {
+					None
+				} else {
+					Some(Self::Continued {
+						appended_history,
+						completion_tracker,
+					})
+				}
+			}
+			_ => changed(previous, next).map(Self::Replaced),
+		}
+	}
+
+	pub fn apply(self, roll: &mut Option<RollPerspective>) {
+		match self {
+			Self::Replaced(replacement) => *roll = replacement,
+			Self::Continued {
+				appended_history,
+				completion_tracker,
+			} => {
+				if let Some(roll) = roll {
+					roll.history.extend(appended_history);
+					if let Some(tracker) = completion_tracker {
+						roll.completion_tracker = tracker;
+					}
+				}
+			}
+		}
+	}
+}
+
+impl PerspectiveDiff {
+	pub fn is_empty(&self) -> bool {
+		*self == Self::default()
+	}
+}
+
+impl GamePerspective {
+	pub fn diff(&self, next: &GamePerspective) -> PerspectivePatch {
+		let same_shape = self.players.len() == next.players.len()
+			&& self
+				.players
+				.iter()
+				.zip(next.players.iter())
+				.all(|(p, n)| same_paths(&p.decks, &n.decks))
+			&& same_paths(&self.decks, &next.decks);
+		if !same_shape {
+			return PerspectivePatch::Replace(Box::new(next.to_owned()));
+		}
+		PerspectivePatch::Diff(Box::new(PerspectiveDiff {
+			players: self
+				.players
+				.iter()
+				.zip(next.players.iter())
+				.enumerate()
+				.filter_map(|(index, (p, n))| PlayerPatch::between(p, n).map(|patch| (index, patch)))
+				.collect(),
+			decks: deck_patches(&self.decks, &next.decks),
+			choices: Change::between(&self.choices, &next.choices),
+			turn: changed(&self.turn, &next.turn),
+			roll: RollPatch::between(&self.roll, &next.roll),
+			offer: Change::between(&self.offer, &next.offer),
+			challenge: Change::between(&self.challenge, &next.challenge),
+		}))
+	}
+
+	pub fn apply(&mut self, patch: PerspectivePatch) {
+		let diff = match patch {
+			PerspectivePatch::Replace(perspective) => {
+				*self = *perspective;
+				return;
+			}
+			PerspectivePatch::Diff(diff) => *diff,
+		};
+		for (player_index, patch) in diff.players {
+			if let Some(player) = self.players.get_mut(player_index) {
+				patch.apply(player);
+			}
+		}
+		apply_deck_patches(&mut self.decks, diff.decks);
+		diff.choices.apply(&mut self.choices);
+		if let Some(turn) = diff.turn {
+			self.turn = turn;
+		}
+		if let Some(roll) = diff.roll {
+			roll.apply(&mut self.roll);
+		}
+		diff.offer.apply(&mut self.offer);
+		diff.challenge.apply(&mut self.challenge);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::slay::driver;
+	use crate::slay::driver::AdvanceGameResult;
+	use crate::slay::game_context::GameBookKeeping;
+	use crate::slay::state::diff::PerspectivePatch;
+	use crate::slay::state::game::Game;
+	use crate::slay::state::game::GamePerspective;
+	use crate::slay::state::initialize;
+	use crate::slay::state::initialize::GameSetup;
+	use crate::slay::strategy;
+
+	const SEEDS: u64 = 4;
+	const MAX_CHOICES: usize = 150;
+
+	// What each seat, a spectator and the omniscient view see.
+	fn perspectives(game: &Game) -> Vec<GamePerspective> {
+		(0..game.number_of_players())
+			.map(|player_index| game.to_player_perspective(Some(player_index)))
+			.chain([
+				game.to_player_perspective(None),
+				game.to_omniscient_perspective(),
+			])
+			.collect()
+	}
+
+	// Every perspective after each random choice of the game.
+	fn play(seed: u64) -> Vec<Vec<GamePerspective>> {
+		let context = &mut GameBookKeeping::with_seed(seed);
+		let game = &mut Game::new();
+		initialize::initialize_game(context, game, &GameSetup::default());
+		let mut steps = Vec::new();
+		for _ in 0..MAX_CHOICES {
+			if !matches!(
+				driver::advance_game(context, game).unwrap(),
+				AdvanceGameResult::WaitingForPlayers
+			) {
+				break;
+			}
+			steps.push(perspectives(game));
+			let Some((player_index, choice_id)) =
+				strategy::pick_a_random_choice_among(context, game, &|_| true)
+			else {
+				break;
+			};
+			driver::make_selection(game, player_index, choice_id, &mut |_| {}).unwrap();
+		}
+		steps
+	}
+
+	// The patch goes over the wire, so it has to survive serialization as well.
+	fn assert_round_trip(previous: &GamePerspective, next: &GamePerspective) {
+		let patch = previous.diff(next);
+		let text = serde_json::to_string(&patch).unwrap();
+		let mut patched = previous.to_owned();
+		patched.apply(serde_json::from_str(&text).unwrap());
+		assert_eq!(&patched, next);
+	}
+
+	#[test]
+	fn applying_the_diff_of_two_perspectives_results_in_the_second() {
+		for seed in 0..SEEDS {
+			let steps = play(seed);
+			let first = &steps[0];
+			for (previous, next) in steps.iter().zip(steps.iter().skip(1)) {
+				for viewer in 0..next.len() {
+					assert_round_trip(&previous[viewer], &next[viewer]);
+					// Patches also hold across several choices and between viewers.
+					assert_round_trip(&first[viewer], &next[viewer]);
+					assert_round_trip(&previous[viewer], &next[(viewer + 1) % next.len()]);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn a_perspective_that_did_not_change_has_an_empty_diff() {
+		let steps = play(0);
+		for perspective in steps.last().unwrap() {
+			match perspective.diff(perspective) {
+				PerspectivePatch::Diff(diff) => assert!(diff.is_empty()),
+				PerspectivePatch::Replace(_) => panic!("The whole perspective was sent again."),
+			}
+		}
+	}
+}
@@ -5,32 +5,42 @@ use crate::slay::errors::SlayResult;
 use crate::slay::ids;
 use crate::slay::showdown::challenge::ChallengePerspective;
 use crate::slay::showdown::current_showdown::CurrentShowdown;
+use crate::slay::showdown::odds;
 use crate::slay::showdown::offer::OfferChallengesPerspective;
 use crate::slay::showdown::roll_state::RollPerspective;
 use crate::slay::specs::visibility::Perspective;
 use crate::slay::specs::visibility::VisibilitySpec;
+use crate::slay::state::arena::CardArena;
 use crate::slay::state::deck::Deck;
 use crate::slay::state::deck::DeckPath;
 use crate::slay::state::deck::DeckPerspective;
 use crate::slay::state::deck::DeckSpec;
+use crate::slay::state::invariants::CardCensus;
 use crate::slay::state::player::Player;
 use crate::slay::state::player::PlayerPerspective;
 use crate::slay::state::stack::Card;
+use crate::slay::state::stack::Stack;
 use crate::slay::state::summarizable::Summarizable;
 use crate::slay::state::turn::Turn;
 use crate::slay::status_effects::effect::PlayerStatusEffect;
 use crate::slay::tasks::player_tasks::PlayerTask;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::player_tasks::TasksPerspective;
+use crate::slay::tasks::task_params::CardParam;
+use crate::slay::tasks::task_params::PlayerParam;
+use serde::Deserialize;
+use serde::Serialize;
 
 use std::fmt::Debug;
 use std::io::BufWriter;
 use std::io::Write;
 use std::iter::Iterator;
+use std::ops::RangeBounds;
 
 #[derive(Clone, Debug)]
 pub struct Game {
 	pub players: Vec<Player>,
 	pub showdown: CurrentShowdown,
+	pub cards: CardArena,
 	turn: Turn,
 	// decks should reduce visibility and use deckpath...
 	pub draw: Deck,
@@ -38,6 +48,8 @@ pub struct Game {
 	pub monsters: Deck,
 	pub leaders: Deck,
 	pub next_monsters: Deck,
+	// Taken once the cards are dealt, so that lost cards can be found.
+	card_census: Option<CardCensus>,
 }
 /*
 	Game <- domain state stored in db
@@ -48,19 +60,19 @@ pub struct Game {
 
 */
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct PlayerStaticInformation {
 	pub name: String,
 	pub leader: Card, // <-- This is not currently visible...
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct GameStaticInformation {
 	pub players: Vec<PlayerStaticInformation>,
 	pub player_index: usize,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct GamePerspective {
 	pub players: Vec<PlayerPerspective>,
 	pub decks: Vec<DeckPerspective>,
@@ -88,7 +100,7 @@ impl Game {
 		player_index: ids::PlayerIndex,
 		effect: PlayerStatusEffect,
 	) -> bool {
-		self.players[player_index].has_player_effect(effect)
+		self.players[player_index].has_player_effect(&self.cards, effect)
 	}
 
 	pub fn clear_expired_modifiers(&mut self) {
@@ -131,6 +143,7 @@ impl Game {
 			// card_specs: specification::get_card_specs(),
 			players: Default::default(),
 			showdown: Default::default(),
+			cards: Default::default(),
 			draw: Deck::new(DeckSpec {
 				visibility: VisibilitySpec::summary(),
 				path: DeckPath::Draw,
@@ -152,21 +165,19 @@ impl Game {
 				path: DeckPath::NextMonsters,
 			}),
 			turn: Default::default(),
+			card_census: None,
 		}
 	}
 
-	// Maybe cards should have been a top level field on game?
-	// The individual decks could just have card ids in them...
 	pub fn maybe_card(&self, card_path: CardPath) -> Option<&Card> {
-		match card_path {
-			CardPath::TopCardIn(deck_path, card_id) => self.deck(deck_path).card(card_id),
-			CardPath::ModifyingCardIn(deck_path, top_card_id, modifier_card_id) => {
-				self.deck(deck_path).modifier(top_card_id, modifier_card_id)
-			}
-			CardPath::Leader(player_index, _) => Some(&self.players[player_index].leader),
+		let card_id = card_path.get_card_id();
+		if self.cards.location(card_id) == Some(card_path) {
+			self.cards.get(card_id)
+		} else {
+			None
 		}
-		// self.deck(card_path.deck_path()).stack(card_path.top_id())
 	}
+	#[track_caller]
 	pub fn card(&self, card_path: CardPath) -> &Card {
 		if let Some(card) = self.maybe_card(card_path) {
 			card
@@ -176,25 +187,16 @@ impl Game {
 		}
 	}
 
-	// Maybe cards should have been a top level field on game?
-	// The individual decks could just have card ids in them...
+	// Only the cards that are in a deck or leading a party.
 	pub fn find_card(&self, card_id: ids::CardId) -> Option<&Card> {
-		for deck in self.decks().iter() {
-			if let Some(card) = deck.card(card_id) {
-				return Some(card);
-			}
-		}
-		for player in self.players.iter() {
-			if player.leader.id == card_id {
-				return Some(&player.leader);
-			}
-			for deck in player.decks().iter() {
-				if let Some(card) = deck.card(card_id) {
-					return Some(card);
-				}
-			}
-		}
-		None
+		self
+			.cards
+			.location(card_id)
+			.and_then(|_| self.cards.get(card_id))
+	}
+
+	pub fn locate_card(&self, card_id: ids::CardId) -> Option<CardPath> {
+		self.cards.location(card_id)
 	}
 
 	pub fn current_player(&self) -> &Player {
@@ -205,6 +207,11 @@ impl Game {
 		&mut self.players[self.turn.active_player_index()]
 	}
 
+	pub fn begin_turn(&mut self) {
+		let player_index = self.turn.active_player_index();
+		self.players[player_index].turn_begin(&self.cards);
+	}
+
 	pub fn take_current_task(
 		&mut self,
 		player_index: ids::PlayerIndex,
@@ -225,7 +232,7 @@ impl Game {
 			DeckPath::SlainMonsters(index) => &self.players[index].slain_monsters,
 		}
 	}
-	pub fn deck_mut(&mut self, deck_path: DeckPath) -> &mut Deck {
+	fn deck_mut(&mut self, deck_path: DeckPath) -> &mut Deck {
 		match deck_path {
 			DeckPath::Draw => &mut self.draw,
 			DeckPath::Discard => &mut self.discard,
@@ -238,17 +245,130 @@ impl Game {
 		}
 	}
 
+	/*
+		Cards only move through the following, which keep the card arena up to date.
+		A stack that has been taken has no location until it is added somewhere again.
+	*/
+
+	pub fn add_stack(&mut self, destination: DeckPath, stack: Stack) {
+		self
+			.cards
+			.place(CardPath::TopCardIn(destination, stack.top));
+		for modifier in stack.modifiers.iter() {
+			self
+				.cards
+				.place(CardPath::ModifyingCardIn(destination, stack.top, *modifier));
+		}
+		self.deck_mut(destination).add(stack);
+	}
+
+	// Every card of the stack becomes an item or modifier on the stack with the given top.
+	pub fn attach_stack(
+		&mut self,
+		destination: DeckPath,
+		top: ids::CardId,
+		stack: Stack,
+	) -> SlayResult<()> {
+		let card_ids: Vec<ids::CardId> = stack.card_ids().collect();
+		self
+			.deck_mut(destination)
+			.attach(top, card_ids.to_owned())?;
+		for card_id in card_ids {
+			self
+				.cards
+				.place(CardPath::ModifyingCardIn(destination, top, card_id));
+		}
+		Ok(())
+	}
+
+	pub fn add_stacks<S: IntoIterator<Item = Stack>>(&mut self, destination: DeckPath, stacks: S) {
+		for stack in stacks {
+			self.add_stack(destination, stack);
+		}
+	}
+
+	fn lift(&mut self, stack: &Stack) {
+		for card_id in stack.card_ids() {
+			self.cards.remove(card_id);
+		}
+	}
+
+	// The whole stack if the card is on top, otherwise only the modifier.
+	pub fn take_stack(&mut self, source: DeckPath, card_id: ids::CardId) -> SlayResult<Stack> {
+		let stack = self.deck_mut(source).take_card(card_id)?;
+		self.lift(&stack);
+		Ok(stack)
+	}
+
+	pub fn take_stack_at(&mut self, source: DeckPath, index: usize) -> Stack {
+		let stack = self.deck_mut(source).take_at_index(index);
+		self.lift(&stack);
+		stack
+	}
+
+	pub fn take_stacks<R: RangeBounds<usize>>(&mut self, source: DeckPath, range: R) -> Vec<Stack> {
+		let stacks = self.deck_mut(source).drain(range);
+		for stack in stacks.iter() {
+			self.lift(stack);
+		}
+		stacks
+	}
+
+	pub fn deal(&mut self, source: DeckPath) -> Option<Stack> {
+		let stack = self.deck_mut(source).maybe_deal();
+		if let Some(stack) = stack.as_ref() {
+			self.lift(stack);
+		}
+		stack
+	}
+
+	// Leaves the tops of the stacks where they are.
+	pub fn take_modifiers(&mut self, source: DeckPath) -> Vec<Stack> {
+		let stacks = self.deck_mut(source).drain_modifiers();
+		for stack in stacks.iter() {
+			self.lift(stack);
+		}
+		stacks
+	}
+
 	pub fn move_card(
 		&mut self,
 		source: DeckPath,
 		destination: DeckPath,
 		card_id: ids::CardId,
 	) -> SlayResult<()> {
-		let stack = self.deck_mut(source).take_card(card_id)?;
-		self.deck_mut(destination).add(stack);
+		let stack = self.take_stack(source, card_id)?;
+		self.add_stack(destination, stack);
 		Ok(())
 	}
 
+	pub fn move_stacks<R: RangeBounds<usize>>(
+		&mut self,
+		source: DeckPath,
+		destination: DeckPath,
+		range: R,
+	) {
+		let stacks = self.take_stacks(source, range);
+		self.add_stacks(destination, stacks);
+	}
+
+	pub(crate) fn swap_first_cards(&mut self, deck_path: DeckPath) {
+		self.deck_mut(deck_path).swap_first_cards();
+	}
+
+	pub(crate) fn add_player(&mut self, player: Player) {
+		self
+			.cards
+			.place(CardPath::Leader(player.player_index, player.leader));
+		self.players.push(player);
+	}
+
+	pub(crate) fn replace_leader(&mut self, player_index: ids::PlayerIndex, leader: ids::CardId) {
+		self.cards.remove(self.players[player_index].leader);
+		self.cards.place(CardPath::Leader(player_index, leader));
+		self.players[player_index].leader = leader;
+	}
+
 	// pub(crate) fn get_player_name(&self, player_index: ids::PlayerIndex) -> String {
 	// 	self.players[player_index].name.to_owned()
 	// }
@@ -257,7 +377,7 @@ impl Game {
 		self.turn.set_active_player(player_index);
 	}
 
-	pub(crate) fn get_turn(&self) -> &Turn {
+	pub fn get_turn(&self) -> &Turn {
 		&self.turn
 	}
 
@@ -265,13 +385,13 @@ impl Game {
 		if self.draw.num_top_cards() >= number_to_draw {
 			return;
 		}
-		self.draw.extend(self.discard.drain(..));
+		self.move_stacks(DeckPath::Discard, DeckPath::Draw, ..);
 	}
 
 	pub(crate) fn player_param(
 		&self,
 		player_index: ids::PlayerIndex,
-		param: &TaskParamName,
+		param: &PlayerParam,
 	) -> SlayResult<ids::PlayerIndex> {
 		self.players[player_index]
 			.tasks
@@ -281,7 +401,7 @@ impl Game {
 	pub(crate) fn card_param(
 		&self,
 		player_index: ids::PlayerIndex,
-		param: &TaskParamName,
+		param: &CardParam,
 	) -> SlayResult<Option<ids::CardId>> {
 		self.players[player_index]
 			.tasks
@@ -306,10 +426,12 @@ impl Game {
 		}
 	}
 
-	pub(crate) fn clear_choices(&mut self) {
-		for player in self.players.iter_mut() {
-			player.choices_ = None;
-		}
+	pub(crate) fn take_card_census(&mut self) {
+		self.card_census = Some(CardCensus::take(self));
+	}
+
+	pub(crate) fn card_census(&self) -> Option<&CardCensus> {
+		self.card_census.as_ref()
 	}
 }
 
@@ -326,17 +448,33 @@ impl Summarizable for Game {
 		f: &mut BufWriter<W>,
 		indentation_level: u32,
 	) -> Result<(), std::io::Error> {
+		self.turn.summarize(f, indentation_level)?;
+		let showdown = self.showdown.active_parts();
+		if !showdown.is_empty() {
+			for _ in 0..indentation_level {
+				write!(f, "  ")?;
+			}
+			writeln!(f, "showdown: {}", showdown.join(", "))?;
+		}
 		for _ in 0..indentation_level {
 			write!(f, "  ")?;
 		}
 		writeln!(f, "players:")?;
 		for player in self.players.iter() {
-			player.summarize(f, indentation_level + 1)?;
+			player.summarize_with(&self.cards, f, indentation_level + 1)?;
 		}
-		self.discard.summarize(f, indentation_level + 1)?;
-		self.monsters.summarize(f, indentation_level + 1)?;
-		self.draw.summarize(f, indentation_level + 1)?;
-		self.next_monsters.summarize(f, indentation_level + 1)?;
+		self
+			.discard
+			.summarize_with(&self.cards, f, indentation_level + 1)?;
+		self
+			.monsters
+			.summarize_with(&self.cards, f, indentation_level + 1)?;
+		self
+			.draw
+			.summarize_with(&self.cards, f, indentation_level + 1)?;
+		self
+			.next_monsters
+			.summarize_with(&self.cards, f, indentation_level + 1)?;
 
 		Ok(())
 	}
@@ -367,31 +505,62 @@ impl Game {
 				// TODO: Visible hands!!!!
 				.map(|p| PlayerStaticInformation {
 					name: p.name.to_owned(),
-					leader: p.leader.to_owned(),
+					leader: self.cards.card(p.leader).to_owned(),
 				})
 				.collect(),
 		}
 	}
 	pub fn to_player_perspective(&self, viewing_player: Option<ids::PlayerIndex>) -> GamePerspective {
-		let choices = &if let Some(player_index) = viewing_player {
-			self.players[player_index].choices_.as_ref()
-		} else {
-			None
-		};
+		self.to_perspective(
+			viewing_player,
+			&|owner| get_perspective(owner, viewing_player),
+			&Perspective::Spectator,
+		)
+	}
+
+	// Every hand and the draw pile are visible, for hunting down engine bugs.
+	pub fn to_omniscient_perspective(&self) -> GamePerspective {
+		self.to_perspective(None, &|_| &Perspective::Jesus, &Perspective::Jesus)
+	}
+
+	pub fn to_tasks_perspective(&self) -> Vec<TasksPerspective> {
+		self
+			.players
+			.iter()
+			.map(|player| player.tasks.to_perspective())
+			.collect()
+	}
+
+	fn to_perspective(
+		&self,
+		viewing_player: Option<ids::PlayerIndex>,
+		perspective_of: &dyn Fn(ids::PlayerIndex) -> &'static Perspective,
+		deck_perspective: &Perspective,
+	) -> GamePerspective {
+		let choices = viewing_player.and_then(|player_index| {
+			self.players[player_index].choices_.as_ref().map(|choices| {
+				let mut perspective = choices.to_perspective();
+				for option in perspective.options.iter_mut() {
+					option.success_probability =
+						odds::action_success_probability(self, player_index, &option.choice);
+				}
+				perspective
+			})
+		});
 		GamePerspective {
 			players: self
 				.players
 				.iter()
-				.map(|p| p.to_perspective(self, get_perspective(p.player_index, viewing_player)))
+				.map(|p| p.to_perspective(self, perspective_of(p.player_index)))
 				.collect(),
 			decks: self
 				.decks()
 				.iter()
-				.filter(|d| d.visible_to_spectator())
-				.map(|d| d.to_spectator_perspective(self, None))
+				.filter(|d| d.is_visible(deck_perspective))
+				.map(|d| d.to_perspective(self, None, deck_perspective))
 				.collect(),
 			turn: self.get_turn().to_owned(),
-			choices: choices.map(|c| c.to_perspective()),
+			choices,
 			roll: self.showdown.get_roll().map(|r| r.to_perspective()),
 			offer: self.showdown.get_offer().map(|o| o.to_perspective()),
 			challenge: self.showdown.get_challenge().map(|o| o.to_perspective()),
@@ -3,21 +3,23 @@ use std::collections::HashSet;
 use enum_iterator::all;
 use rand::prelude::SliceRandom;
 use rand::Rng;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::slay::actions::list_actions;
 use crate::slay::game_context::GameBookKeeping;
 use crate::slay::ids;
+use crate::slay::specification::GameVersion;
 use crate::slay::specs::cards::card_type::SlayCardSpec;
 use crate::slay::specs::hero::HeroAbilityType;
 use crate::slay::state::game::Game;
 
 use crate::slay::state::deck::DeckPath;
 use crate::slay::state::player::Player;
-use crate::slay::state::stack::Card;
 use crate::slay::state::stack::Stack;
 use crate::slay::tasks::player_tasks::continue_tasks;
 
-fn bot_name(player_index: usize) -> &'static str {
+pub fn bot_name(player_index: usize) -> &'static str {
 	match player_index {
 		0 => "Henry",
 		1 => "Ralph",
@@ -29,22 +31,47 @@ fn bot_name(player_index: usize) -> &'static str {
 	}
 }
 
-fn initialize_global_decks(context: &mut GameBookKeeping, game: &mut Game) {
-	let (draw_capacity, leaders_capacity, monsters_capacity) = (101, 10, 20);
+// Who is sitting at the table and which boxes the cards come from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameSetup {
+	pub player_names: Vec<String>,
+	pub versions: Vec<GameVersion>,
+}
+
+impl Default for GameSetup {
+	fn default() -> Self {
+		Self {
+			player_names: (0..4)
+				.map(|player_index| format!("{} (Player {})", bot_name(player_index), player_index + 1))
+				.collect(),
+			versions: vec![GameVersion::Original],
+		}
+	}
+}
+
+impl GameSetup {
+	fn includes(&self, spec_type: &SlayCardSpec) -> bool {
+		self.versions.contains(&spec_type.game_version())
+	}
+}
+
+fn initialize_global_decks(context: &mut GameBookKeeping, game: &mut Game, setup: &GameSetup) {
+	let (draw_capacity, leaders_capacity, monsters_capacity) = (109, 6, 15);
 	let mut draw = Vec::with_capacity(draw_capacity);
 	let mut leaders = Vec::with_capacity(leaders_capacity);
 	let mut monsters = Vec::with_capacity(monsters_capacity);
 	all::<SlayCardSpec>().for_each(|spec_type| {
 		let spec = spec_type.get_card_spec_creation();
-		if spec.ignore {
+		if spec.ignore || !setup.includes(&spec_type) {
 			return;
 		}
 
 		for _ in 0..spec_type.repeat() {
-			let stack = Stack::new(Card::new(
-				context.id_generator.generate(),
-				spec_type.to_owned(),
-			));
+			let stack = Stack::new(
+				game
+					.cards
+					.create(&mut context.id_generator, spec_type.to_owned()),
+			);
 
 			match spec.get_initial_deck() {
 				DeckPath::Draw => draw.push(stack),
@@ -55,48 +82,44 @@ fn initialize_global_decks(context: &mut GameBookKeeping, game: &mut Game) {
 		}
 	});
 	if draw_capacity != draw.len() {
-		println!("Draw's capacity should be {}", draw.len())
+		log::info!("Draw's capacity should be {}", draw.len())
 	}
-	if leaders_capacity != draw.len() {
-		println!("Leader's capacity should be {}", leaders.len())
+	if leaders_capacity != leaders.len() {
+		log::info!("Leader's capacity should be {}", leaders.len())
 	}
-	if monsters_capacity != draw.len() {
-		println!("Monster's capacity should be {}", monsters.len())
+	if monsters_capacity != monsters.len() {
+		log::info!("Monster's capacity should be {}", monsters.len())
 	}
 
 	[&mut draw, &mut leaders, &mut monsters]
 		.iter_mut()
 		.for_each(|deck| deck.shuffle(&mut context.rng));
 
-	game.draw.extend(draw.drain(..));
-	game.next_monsters.extend(monsters.drain(..));
-	game.leaders.extend(leaders.drain(..));
+	game.add_stacks(DeckPath::Draw, draw);
+	game.add_stacks(DeckPath::NextMonsters, monsters);
+	game.add_stacks(DeckPath::PartyLeaders, leaders);
 }
 
-fn initialize_players(_context: &mut GameBookKeeping, game: &mut Game) {
-	for player_index in 0..4 {
-		let player = Player::new(
-			format!("{} (Player {})", bot_name(player_index), player_index + 1),
-			player_index,
-			game.leaders.deal().top,
-		);
-		game.players.push(player);
+fn initialize_players(_context: &mut GameBookKeeping, game: &mut Game, setup: &GameSetup) {
+	for (player_index, name) in setup.player_names.iter().enumerate() {
+		let leader = game.deal(DeckPath::PartyLeaders).unwrap().top;
+		game.add_player(Player::new(name.to_owned(), player_index, leader));
 	}
 }
 
-pub fn initialize_game(context: &mut GameBookKeeping, game: &mut Game) {
-	initialize_global_decks(context, game);
-	initialize_players(context, game);
-	game.monsters.extend(game.next_monsters.drain(0..3));
+pub fn initialize_game(context: &mut GameBookKeeping, game: &mut Game, setup: &GameSetup) {
+	initialize_global_decks(context, game, setup);
+	initialize_players(context, game, setup);
+	game.move_stacks(DeckPath::NextMonsters, DeckPath::ActiveMonsters, 0..3);
 
 	for player_index in 0..game.number_of_players() {
-		let drain = game.draw.drain(0..5);
-		game.players[player_index].hand.extend(drain);
+		game.move_stacks(DeckPath::Draw, DeckPath::Hand(player_index), 0..5);
 	}
 
 	// initialize the first first random player
 	game.set_active_player(context.rng.gen_range(0..game.number_of_players()));
-	game.current_player_mut().turn_begin();
+	game.begin_turn();
+	game.take_card_census();
 	list_actions::assign_action_choices(context, game);
 	continue_tasks(context, game, game.active_player_index()).expect("uh oh");
 }
@@ -107,8 +130,11 @@ fn randomly_initialize_hand(
 	player_index: ids::PlayerIndex,
 ) {
 	let number_of_hand_cards = context.rng.gen_range(0..10);
-	let drain = game.draw.drain(0..number_of_hand_cards);
-	game.players[player_index].hand.extend(drain);
+	game.move_stacks(
+		DeckPath::Draw,
+		DeckPath::Hand(player_index),
+		0..number_of_hand_cards,
+	);
 }
 
 fn randomly_initialize_monsters(
@@ -117,8 +143,11 @@ fn randomly_initialize_monsters(
 	player_index: ids::PlayerIndex,
 ) {
 	let number_of_monsters = context.rng.gen_range(0..3);
-	let drain = game.next_monsters.drain(0..number_of_monsters);
-	game.players[player_index].slain_monsters.extend(drain);
+	game.move_stacks(
+		DeckPath::NextMonsters,
+		DeckPath::SlainMonsters(player_index),
+		0..number_of_monsters,
+	);
 
 	// Need to add the buffs...
 }
@@ -136,9 +165,9 @@ fn adding_card_would_mean_player_wins(
 	player_index: ids::PlayerIndex,
 	stack: &Stack,
 ) -> bool {
-	if let Some(hero_type) = stack.get_hero_type() {
+	if let Some(hero_type) = stack.get_hero_type(&game.cards) {
 		let hero_types = &mut HashSet::new();
-		game.players[player_index].collect_hero_types(hero_types);
+		game.players[player_index].collect_hero_types(&game.cards, hero_types);
 		hero_types.insert(hero_type);
 		hero_types.len() >= 6
 	} else {
@@ -153,11 +182,11 @@ fn randomly_initialize_party(
 ) {
 	let number_of_party_cards = context.rng.gen_range(0..10);
 	for _ in 0..number_of_party_cards {
-		if let Some(stack) = game.draw.maybe_deal() {
+		if let Some(stack) = game.deal(DeckPath::Draw) {
 			if adding_card_would_mean_player_wins(game, player_index, &stack) {
-				game.draw.add(stack);
+				game.add_stack(DeckPath::Draw, stack);
 			} else {
-				game.players[player_index].party.add(stack);
+				game.add_stack(DeckPath::Party(player_index), stack);
 			}
 		}
 	}
@@ -167,9 +196,10 @@ pub fn initialize_game_to_random_state_without_assigning_player(
 	context: &mut GameBookKeeping,
 	game: &mut Game,
 ) {
-	initialize_global_decks(context, game);
-	initialize_players(context, game);
-	game.monsters.extend(game.next_monsters.drain(0..3));
+	let setup = &GameSetup::default();
+	initialize_global_decks(context, game, setup);
+	initialize_players(context, game, setup);
+	game.move_stacks(DeckPath::NextMonsters, DeckPath::ActiveMonsters, 0..3);
 
 	for player_index in 0..game.number_of_players() {
 		randomly_initialize_hand(context, game, player_index);
@@ -178,19 +208,24 @@ pub fn initialize_game_to_random_state_without_assigning_player(
 		randomly_initialize_monsters(context, game, player_index);
 		// randomly initialize temporary modifiers
 	}
+	game.take_card_census();
 }
 
 pub fn initialize_game_to_random_state(context: &mut GameBookKeeping, game: &mut Game) {
 	initialize_game_to_random_state_without_assigning_player(context, game);
 	// initialize the first first random player
 	game.set_active_player(context.rng.gen_range(0..game.number_of_players()));
-	game.current_player_mut().turn_begin();
+	game.begin_turn();
 	list_actions::assign_action_choices(context, game);
 	continue_tasks(context, game, game.active_player_index()).expect("uh oh");
 }
 
-fn stack_from(context: &mut GameBookKeeping, card: &SlayCardSpec) -> Stack {
-	Stack::new(Card::new(context.id_generator.generate(), card.to_owned()))
+fn stack_from(context: &mut GameBookKeeping, game: &mut Game, card: &SlayCardSpec) -> Stack {
+	Stack::new(
+		game
+			.cards
+			.create(&mut context.id_generator, card.to_owned()),
+	)
 }
 
 pub fn create_state_to_test(context: &mut GameBookKeeping, game: &mut Game, card: &SlayCardSpec) {
@@ -198,33 +233,43 @@ pub fn create_state_to_test(context: &mut GameBookKeeping, game: &mut Game, card
 
 	match card {
 		SlayCardSpec::HeroCard(_) => {
-			game.players[0].hand.add(stack_from(context, card));
-			game.players[0].party.add(stack_from(context, card));
+			let stack = stack_from(context, game, card);
+			game.add_stack(DeckPath::Hand(0), stack);
+			let stack = stack_from(context, game, card);
+			game.add_stack(DeckPath::Party(0), stack);
+		}
+		SlayCardSpec::PartyLeader(_) => {
+			let leader = stack_from(context, game, card).top;
+			game.replace_leader(0, leader);
 		}
-		SlayCardSpec::PartyLeader(_) => game.players[0].leader = stack_from(context, card).top,
 		SlayCardSpec::MonsterCard(_) => {
 			// Fill in the requirements...
-			game.players[0]
-				.slain_monsters
-				.add(stack_from(context, card));
-			game.monsters.add(stack_from(context, card));
+			let stack = stack_from(context, game, card);
+			game.add_stack(DeckPath::SlainMonsters(0), stack);
+			let stack = stack_from(context, game, card);
+			game.add_stack(DeckPath::ActiveMonsters, stack);
 		}
-		SlayCardSpec::MagicCard(_) => game.players[0].hand.add(stack_from(context, card)),
 		SlayCardSpec::ModifierCard(_) => {
 			let mut stack = stack_from(
 				context,
+				game,
 				&SlayCardSpec::HeroCard(HeroAbilityType::PlunderingPuma),
 			);
-			stack.modifiers.push(stack_from(context, card).top);
-			game.players[0].party.add(stack);
-			game.players[0].hand.add(stack_from(context, card));
+			stack.modifiers.push(stack_from(context, game, card).top);
+			game.add_stack(DeckPath::Party(0), stack);
+			let stack = stack_from(context, game, card);
+			game.add_stack(DeckPath::Hand(0), stack);
+		}
+		SlayCardSpec::MagicCard(_) | SlayCardSpec::Item(_) | SlayCardSpec::Challenge => {
+			let stack = stack_from(context, game, card);
+			game.add_stack(DeckPath::Hand(0), stack);
 		}
-		SlayCardSpec::Item(_) => game.players[0].hand.add(stack_from(context, card)),
-		SlayCardSpec::Challenge => game.players[0].hand.add(stack_from(context, card)),
 	}
 
 	game.set_active_player(0);
-	game.current_player_mut().turn_begin();
+	game.begin_turn();
+	// The cards under test were added after the deal.
+	game.take_card_census();
 	list_actions::assign_action_choices(context, game);
 	continue_tasks(context, game, game.active_player_index()).expect("uh oh");
 }
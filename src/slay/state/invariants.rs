@@ -0,0 +1,777 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::slay::choices::CardPath;
+use crate::slay::choices::Choice;
+use crate::slay::choices::Choices;
+use crate::slay::choices::ChoicesType;
+use crate::slay::ids;
+use crate::slay::showdown::completion::Completion;
+use crate::slay::showdown::completion::CompletionTracker;
+use crate::slay::showdown::current_showdown::ShowDown;
+use crate::slay::specs::cards::card_type::SlayCardSpec;
+use crate::slay::specs::hero::HeroAbilityType;
+use crate::slay::specs::items::AnotherItemType;
+use crate::slay::state::deck::DeckPath;
+use crate::slay::state::game::Game;
+
+/*
+	Checks for states the engine should never be able to reach.
+	Cards are only ever moved, never created or destroyed, so once the game has been dealt
+	every card should be found in exactly one place until the game is over.
+	The card arena should also agree with the decks about where each card is.
+	The driver runs these after every step in debug builds, so a lost card is reported
+	where it was lost instead of several turns later.
+*/
+
+// Every card that was dealt, to find the ones that go missing.
+#[derive(Debug, Clone, Default)]
+pub struct CardCensus {
+	cards: BTreeMap<ids::CardId, SlayCardSpec>,
+}
+
+impl CardCensus {
+	pub fn take(game: &Game) -> Self {
+		Self {
+			cards: card_locations(game)
+				.into_iter()
+				.map(|(_, card_id, spec)| (card_id, spec))
+				.collect(),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InvariantViolation {
+	DuplicateCard(ids::CardId, Vec<CardPath>),
+	LostCard(ids::CardId, SlayCardSpec),
+	UnexpectedCard(CardPath, SlayCardSpec),
+	MisplacedCard(CardPath, Option<CardPath>),
+	ConflictingShowdowns(Vec<&'static str>),
+	UnexpectedChoices(ids::PlayerIndex, ChoicesType),
+	OverspentActionPoints(ids::PlayerIndex, u32),
+}
+
+impl fmt::Display for InvariantViolation {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::DuplicateCard(card_id, paths) => {
+				write!(f, "Card {} is in more than one place: {:?}", card_id, paths)
+			}
+			Self::LostCard(card_id, spec) => {
+				write!(f, "Card {} ({:?}) was dealt, but is gone.", card_id, spec)
+			}
+			Self::UnexpectedCard(path, spec) => {
+				write!(f, "{:?} at {:?} was never dealt.", spec, path)
+			}
+			Self::MisplacedCard(path, location) => {
+				write!(f, "The card at {:?} is recorded at {:?}.", path, location)
+			}
+			Self::ConflictingShowdowns(parts) => {
+				write!(
+					f,
+					"More than one of these showdowns is running: {:?}",
+					parts
+				)
+			}
+			Self::UnexpectedChoices(player_index, choices_type) => write!(
+				f,
+				"Player {} has {:?} choices that nothing offered them.",
+				player_index, choices_type
+			),
+			Self::OverspentActionPoints(player_index, amount) => write!(
+				f,
+				"Player {} spent {} more action points than they had.",
+				player_index, amount
+			),
+		}
+	}
+}
+
+// Found by walking every deck, not from the card arena, so that the two can be compared.
+fn card_locations(game: &Game) -> Vec<(CardPath, ids::CardId, SlayCardSpec)> {
+	let mut paths: Vec<CardPath> = Vec::new();
+	for deck in game.decks() {
+		paths.extend(deck.card_paths());
+	}
+	for player in game.players.iter() {
+		paths.push(CardPath::Leader(player.player_index, player.leader));
+		for deck in player.decks() {
+			paths.extend(deck.card_paths());
+		}
+	}
+	paths
+		.into_iter()
+		.map(|path| {
+			let card_id = path.get_card_id();
+			(path, card_id, game.cards.card_type(card_id))
+		})
+		.collect()
+}
+
+fn check_cards(game: &Game, violations: &mut Vec<InvariantViolation>) {
+	let mut found: BTreeMap<ids::CardId, Vec<CardPath>> = BTreeMap::new();
+	for (path, card_id, spec) in card_locations(game) {
+		if let Some(census) = game.card_census() {
+			if census.cards.get(&card_id) != Some(&spec) {
+				violations.push(InvariantViolation::UnexpectedCard(path, spec));
+			}
+		}
+		let location = game.locate_card(card_id);
+		if location != Some(path) {
+			violations.push(InvariantViolation::MisplacedCard(path, location));
+		}
+		found.entry(card_id).or_default().push(path);
+	}
+	for (card_id, paths) in found.iter() {
+		if paths.len() > 1 {
+			violations.push(InvariantViolation::DuplicateCard(
+				*card_id,
+				paths.to_owned(),
+			));
+		}
+	}
+	if let Some(census) = game.card_census() {
+		for (card_id, spec) in census.cards.iter() {
+			if !found.contains_key(card_id) {
+				violations.push(InvariantViolation::LostCard(*card_id, *spec));
+			}
+		}
+	}
+}
+
+fn check_showdown(game: &Game, violations: &mut Vec<InvariantViolation>) {
+	if !game.showdown.is_consistent() {
+		violations.push(InvariantViolation::ConflictingShowdowns(
+			game.showdown.active_parts(),
+		));
+	}
+}
+
+fn holds(game: &Game, deck_path: DeckPath, spec: SlayCardSpec) -> bool {
+	game
+		.deck(deck_path)
+		.tops(&game.cards)
+		.any(|card| card.card_type == spec)
+}
+
+fn carries_item(game: &Game, item: AnotherItemType, hero: HeroAbilityType) -> bool {
+	game.players.iter().any(|player| {
+		player.party.stacks().any(|stack| {
+			game.cards.card_type(stack.top) == SlayCardSpec::HeroCard(hero)
+				&& stack
+					.modifiers
+					.iter()
+					.any(|modifier| game.cards.card_type(*modifier) == SlayCardSpec::Item(item))
+		})
+	})
+}
+
+fn still_responding(tracker: Option<&CompletionTracker>, player_index: ids::PlayerIndex) -> bool {
+	tracker
+		.and_then(|tracker| tracker.completions.get(player_index))
+		.map(|completion| *completion != Completion::AllDone)
+		.unwrap_or(false)
+}
+
+// Whether the player can still act on what a task offered them, like discarding a card they still hold.
+fn option_is_expected(
+	game: &Game,
+	player_index: ids::PlayerIndex,
+	choices_type: &ChoicesType,
+	choice: &Choice,
+) -> bool {
+	let hand = DeckPath::Hand(player_index);
+	let party = DeckPath::Party(player_index);
+	match (choices_type, choice) {
+		(ChoicesType::Discard, Choice::Discard(spec)) => holds(game, hand, *spec),
+		(ChoicesType::Sacrifice, Choice::Sacrifice(hero)) => {
+			holds(game, party, SlayCardSpec::HeroCard(*hero))
+		}
+		(ChoicesType::ReturnAnItemCard, Choice::ReturnItem(item, hero)) => {
+			carries_item(game, *item, *hero)
+		}
+		(ChoicesType::SearchDiscard(_), Choice::ChooseDiscardedCard(spec)) => {
+			holds(game, DeckPath::Discard, *spec)
+		}
+		(ChoicesType::PlaceAHeroCard, Choice::PlaceHeroImmediately(hero)) => {
+			holds(game, hand, SlayCardSpec::HeroCard(*hero))
+		}
+		(ChoicesType::ChooseCardToGive(recipient), Choice::ChooseCardToGive(spec, to)) => {
+			recipient == to && holds(game, hand, *spec)
+		}
+		// A hero that was already placed is played by rolling for its ability.
+		(ChoicesType::PlayImmediately(offered), Choice::PlayImmediately(spec)) => {
+			offered == spec && (holds(game, hand, *spec) || holds(game, party, *spec))
+		}
+		(ChoicesType::PlayOneOfImmediately, Choice::PlayImmediately(spec)) => {
+			holds(game, hand, *spec) || holds(game, party, *spec)
+		}
+		(
+			ChoicesType::PlayImmediately(_)
+			| ChoicesType::PlayOneOfImmediately
+			| ChoicesType::RevealAndDestroy,
+			Choice::DoNotPlayImmediately,
+		) => true,
+		(ChoicesType::ChoosePlayerParam(param), Choice::SetPlayerParam(chosen, victim_index)) => {
+			param == chosen && *victim_index < game.number_of_players()
+		}
+		(ChoicesType::ChooseCardParam(param), Choice::SetCardParameter(chosen, _)) => param == chosen,
+		(ChoicesType::ContinueDiscardingAndDestroying(_), Choice::ContinueDiscardingAndDestroying)
+		| (ChoicesType::ContinueDiscardingAndDestroying(_), Choice::QuitAction) => true,
+		(ChoicesType::RevealAndDestroy, Choice::RevealChallengeAndDestroy) => {
+			holds(game, hand, SlayCardSpec::Challenge)
+		}
+		// Bullseye looks at the top three cards of the draw pile.
+		(ChoicesType::BullseyeKeep, Choice::BullseyeKeep(spec)) => game
+			.deck(DeckPath::Draw)
+			.tops(&game.cards)
+			.take(3)
+			.any(|card| card.card_type == *spec),
+		(
+			ChoicesType::BullseyeOrdering(_, _),
+			Choice::BullseyeReorder | Choice::BullseyeDoNotReorder,
+		) => true,
+		_ => false,
+	}
+}
+
+// Action choices belong to the active player between showdowns, showdown choices to the showdown
+// they respond to, and every other choice to the task that asked about what the player still has.
+fn choices_are_expected(game: &Game, player_index: ids::PlayerIndex, choices: &Choices) -> bool {
+	match &choices.choices_type {
+		ChoicesType::SpendActionPoints => {
+			player_index == game.active_player_index() && game.showdown.is_empty()
+		}
+		ChoicesType::OfferChallenges => game
+			.showdown
+			.get_offer()
+			.map(|offer| {
+				offer.player_index != player_index
+					&& still_responding(offer.completion_tracker.as_ref(), player_index)
+			})
+			.unwrap_or(false),
+		ChoicesType::ModifyRoll => game
+			.showdown
+			.get_roll()
+			.map(|roll| still_responding(Some(roll.tracker()), player_index))
+			.unwrap_or(false),
+		ChoicesType::ModifyChallenge => game
+			.showdown
+			.get_challenge()
+			.map(|challenge| still_responding(Some(challenge.tracker()), player_index))
+			.unwrap_or(false),
+		ChoicesType::ChooseCardToGive(recipient) if *recipient == player_index => false,
+		ChoicesType::ContinueDiscardingAndDestroying(0) => false,
+		ChoicesType::BullseyeOrdering(first, second) => {
+			let mut tops = game.deck(DeckPath::Draw).tops(&game.cards);
+			tops.next().map(|card| card.card_type) == Some(*first)
+				&& tops.next().map(|card| card.card_type) == Some(*second)
+		}
+		choices_type => choices
+			.options
+			.iter()
+			.all(|option| option_is_expected(game, player_index, choices_type, &option.choice)),
+	}
+}
+
+fn check_choices(game: &Game, violations: &mut Vec<InvariantViolation>) {
+	for player in game.players.iter() {
+		if let Some(choices) = player.choices_.as_ref() {
+			if !choices_are_expected(game, player.player_index, choices) {
+				violations.push(InvariantViolation::UnexpectedChoices(
+					player.player_index,
+					choices.choices_type.to_owned(),
+				));
+			}
+		}
+	}
+}
+
+fn check_action_points(game: &Game, violations: &mut Vec<InvariantViolation>) {
+	for player in game.players.iter() {
+		let overspent = player.get_overspent_action_points();
+		if overspent > 0 {
+			violations.push(InvariantViolation::OverspentActionPoints(
+				player.player_index,
+				overspent,
+			));
+		}
+	}
+}
+
+impl Game {
+	// Empty when the game is in a state that can be reached.
+	pub fn check_invariants(&self) -> Vec<InvariantViolation> {
+		let mut violations = Vec::new();
+		check_cards(self, &mut violations);
+		check_showdown(self, &mut violations);
+		check_choices(self, &mut violations);
+		check_action_points(self, &mut violations);
+		violations
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::slay::choices::Action;
+	use crate::slay::choices::CardPath;
+	use crate::slay::choices::Choice;
+	use crate::slay::choices::ChoiceDisplayType;
+	use crate::slay::choices::Choices;
+	use crate::slay::choices::ChoicesType;
+	use crate::slay::choices::TasksChoice;
+	use crate::slay::deadlines;
+	use crate::slay::driver;
+	use crate::slay::game_context::GameBookKeeping;
+	use crate::slay::i18n::keys::MessageKey;
+	use crate::slay::ids;
+	use crate::slay::showdown::completion::AutoPass;
+	use crate::slay::showdown::completion::Completion;
+	use crate::slay::specs::cards::card_type::SlayCardSpec;
+	use crate::slay::specs::hero::HeroAbilityType;
+	use crate::slay::specs::items::AnotherItemType;
+	use crate::slay::specs::items::Item;
+	use crate::slay::specs::modifier::ModifierKinds;
+	use crate::slay::state::builder::GameBuilder;
+	use crate::slay::state::builder::StackBuilder;
+	use crate::slay::state::deck::DeckPath;
+	use crate::slay::state::game::Game;
+	use crate::slay::state::invariants::InvariantViolation;
+	use crate::slay::state::stack::Stack;
+	use crate::slay::tasks::task_params::ParamScope;
+	use crate::slay::tasks::tasks::search_discard::SearchDiscardFilters;
+
+	const MODIFIER: SlayCardSpec = SlayCardSpec::ModifierCard(ModifierKinds::Plus4);
+	const PEANUT: HeroAbilityType = HeroAbilityType::Peanut;
+	const MIMIMEOW: HeroAbilityType = HeroAbilityType::SmoothMimimeow;
+	const RING: AnotherItemType = AnotherItemType::NotMask(Item::ReallyBigRing);
+
+	fn build(context: &mut GameBookKeeping) -> Game {
+		GameBuilder::new(2)
+			.hand(0, vec![SlayCardSpec::Challenge, MODIFIER])
+			.hand(1, vec![MODIFIER])
+			.draw_pile(vec![MODIFIER; 2])
+			.action_points(1)
+			.build(context)
+	}
+
+	#[test]
+	fn a_dealt_game_has_no_violations() {
+		let context = &mut GameBookKeeping::new();
+		let game = build(context);
+		assert_eq!(game.check_invariants(), vec![]);
+	}
+
+	#[test]
+	fn a_card_that_is_taken_and_never_put_back_is_lost() {
+		let context = &mut GameBookKeeping::new();
+		let mut game = build(context);
+		let card_id = game.players[0].hand.top_ids().next().unwrap();
+		game.take_stack(DeckPath::Hand(0), card_id).unwrap();
+		assert_eq!(
+			game.check_invariants(),
+			vec![InvariantViolation::LostCard(
+				card_id,
+				SlayCardSpec::Challenge
+			)]
+		);
+	}
+
+	#[test]
+	fn a_card_put_in_two_places_is_duplicated() {
+		let context = &mut GameBookKeeping::new();
+		let mut game = build(context);
+		let card_id = game.players[0].hand.top_ids().next().unwrap();
+		let stack = game.take_stack(DeckPath::Hand(0), card_id).unwrap();
+		game.add_stack(DeckPath::Discard, stack.to_owned());
+		game.add_stack(DeckPath::Hand(1), stack);
+		let violations = game.check_invariants();
+		assert!(violations.iter().any(|violation| matches!(
+			violation,
+			InvariantViolation::DuplicateCard(duplicate, paths)
+				if *duplicate == card_id && paths.len() == 2
+		)));
+		// The arena only remembers the last place it was put.
+		assert!(violations.contains(&InvariantViolation::MisplacedCard(
+			CardPath::TopCardIn(DeckPath::Discard, card_id),
+			Some(CardPath::TopCardIn(DeckPath::Hand(1), card_id)),
+		)));
+	}
+
+	#[test]
+	fn a_card_created_after_dealing_is_unexpected() {
+		let context = &mut GameBookKeeping::new();
+		let mut game = build(context);
+		let card_id = game.cards.create(&mut context.id_generator, MODIFIER);
+		game.add_stack(DeckPath::Discard, Stack::new(card_id));
+		assert_eq!(
+			game.check_invariants(),
+			vec![InvariantViolation::UnexpectedCard(
+				CardPath::TopCardIn(DeckPath::Discard, card_id),
+				MODIFIER
+			)]
+		);
+	}
+
+	#[test]
+	fn actions_offered_to_someone_else_are_unexpected() {
+		let context = &mut GameBookKeeping::new();
+		let mut game = build(context);
+		game.players[1].choices_ = game.players[0].choices_.take();
+		assert_eq!(
+			game.check_invariants(),
+			vec![InvariantViolation::UnexpectedChoices(
+				1,
+				ChoicesType::SpendActionPoints
+			)]
+		);
+	}
+
+	#[test]
+	fn spending_more_action_points_than_remain_is_overspending() {
+		let context = &mut GameBookKeeping::new();
+		let mut game = build(context);
+		game.players[0].action_points_used(3);
+		assert_eq!(
+			game.check_invariants(),
+			vec![InvariantViolation::OverspentActionPoints(0, 2)]
+		);
+	}
+
+	// Player 0 holds a challenge, a modifier and Peanut, and has Mimimeow with a ring in their party.
+	fn build_table(context: &mut GameBookKeeping) -> Game {
+		GameBuilder::new(2)
+			.hand(
+				0,
+				vec![
+					SlayCardSpec::Challenge,
+					MODIFIER,
+					SlayCardSpec::HeroCard(PEANUT),
+				],
+			)
+			.hand(1, vec![MODIFIER])
+			.party(
+				0,
+				vec![StackBuilder::new(SlayCardSpec::HeroCard(MIMIMEOW)).with(SlayCardSpec::Item(RING))],
+			)
+			.discard_pile(vec![SlayCardSpec::Challenge])
+			.draw_pile(vec![MODIFIER; 4])
+			.action_points(1)
+			.build(context)
+	}
+
+	fn offer(
+		context: &mut GameBookKeeping,
+		game: &mut Game,
+		player_index: ids::PlayerIndex,
+		choices_type: ChoicesType,
+		choice: Choice,
+	) {
+		let option = TasksChoice::prepend(
+			context.id_generator.generate(),
+			choice,
+			ChoiceDisplayType::Yes,
+			vec![],
+		);
+		game.players[player_index].choose(Choices::new(
+			vec![option],
+			None,
+			deadlines::get_refactor_me_deadline(context),
+			choices_type,
+		));
+	}
+
+	fn is_unexpected(
+		player_index: ids::PlayerIndex,
+		choices_type: ChoicesType,
+		choice: Choice,
+	) -> bool {
+		let context = &mut GameBookKeeping::new();
+		let mut game = build_table(context);
+		offer(
+			context,
+			&mut game,
+			player_index,
+			choices_type.to_owned(),
+			choice,
+		);
+		game
+			.check_invariants()
+			.contains(&InvariantViolation::UnexpectedChoices(
+				player_index,
+				choices_type,
+			))
+	}
+
+	// A player, the kind of prompt they are given and one option in it.
+	type Case = (ids::PlayerIndex, ChoicesType, Choice);
+
+	// The choices that are expected at the table, and the ones that are not.
+	fn cases() -> (Vec<Case>, Vec<Case>) {
+		let params = &mut ParamScope::new();
+		let victim = params.player(MessageKey::PromptStealFrom);
+		let recipient = params.player(MessageKey::PromptPlayerToGiveItem);
+		let hero = params.card(MessageKey::PromptStackToPlaceItemOn);
+		let other_card = params.card(MessageKey::PromptStackToPlaceItemOn);
+		let peanut = SlayCardSpec::HeroCard(PEANUT);
+		let mimimeow = SlayCardSpec::HeroCard(MIMIMEOW);
+		let ring = SlayCardSpec::Item(RING);
+		let expected = vec![
+			(0, ChoicesType::Discard, Choice::Discard(MODIFIER)),
+			(0, ChoicesType::Sacrifice, Choice::Sacrifice(MIMIMEOW)),
+			(
+				0,
+				ChoicesType::ReturnAnItemCard,
+				Choice::ReturnItem(RING, MIMIMEOW),
+			),
+			(
+				0,
+				ChoicesType::SearchDiscard(SearchDiscardFilters::IsMagic),
+				Choice::ChooseDiscardedCard(SlayCardSpec::Challenge),
+			),
+			(
+				0,
+				ChoicesType::PlaceAHeroCard,
+				Choice::PlaceHeroImmediately(PEANUT),
+			),
+			(
+				0,
+				ChoicesType::ChooseCardToGive(1),
+				Choice::ChooseCardToGive(MODIFIER, 1),
+			),
+			(
+				0,
+				ChoicesType::PlayImmediately(peanut),
+				Choice::PlayImmediately(peanut),
+			),
+			(
+				0,
+				ChoicesType::PlayImmediately(mimimeow),
+				Choice::PlayImmediately(mimimeow),
+			),
+			(
+				0,
+				ChoicesType::PlayImmediately(peanut),
+				Choice::DoNotPlayImmediately,
+			),
+			(
+				0,
+				ChoicesType::PlayOneOfImmediately,
+				Choice::PlayImmediately(peanut),
+			),
+			(
+				0,
+				ChoicesType::ChoosePlayerParam(victim),
+				Choice::SetPlayerParam(victim, 1),
+			),
+			(
+				0,
+				ChoicesType::ChooseCardParam(hero),
+				Choice::SetCardParameter(hero, mimimeow),
+			),
+			(
+				0,
+				ChoicesType::ContinueDiscardingAndDestroying(2),
+				Choice::ContinueDiscardingAndDestroying,
+			),
+			(
+				0,
+				ChoicesType::ContinueDiscardingAndDestroying(2),
+				Choice::QuitAction,
+			),
+			(
+				0,
+				ChoicesType::RevealAndDestroy,
+				Choice::RevealChallengeAndDestroy,
+			),
+			(0, ChoicesType::BullseyeKeep, Choice::BullseyeKeep(MODIFIER)),
+			(
+				0,
+				ChoicesType::BullseyeOrdering(MODIFIER, MODIFIER),
+				Choice::BullseyeReorder,
+			),
+		];
+		let unexpected = vec![
+			(0, ChoicesType::Discard, Choice::Discard(ring)),
+			(0, ChoicesType::Sacrifice, Choice::Sacrifice(PEANUT)),
+			(
+				0,
+				ChoicesType::ReturnAnItemCard,
+				Choice::ReturnItem(RING, PEANUT),
+			),
+			(
+				0,
+				ChoicesType::SearchDiscard(SearchDiscardFilters::IsModifier),
+				Choice::ChooseDiscardedCard(MODIFIER),
+			),
+			(
+				0,
+				ChoicesType::PlaceAHeroCard,
+				Choice::PlaceHeroImmediately(MIMIMEOW),
+			),
+			(
+				0,
+				ChoicesType::ChooseCardToGive(1),
+				Choice::ChooseCardToGive(ring, 1),
+			),
+			(
+				0,
+				ChoicesType::ChooseCardToGive(1),
+				Choice::ChooseCardToGive(MODIFIER, 0),
+			),
+			(
+				0,
+				ChoicesType::ChooseCardToGive(0),
+				Choice::ChooseCardToGive(MODIFIER, 0),
+			),
+			(
+				0,
+				ChoicesType::PlayImmediately(peanut),
+				Choice::PlayImmediately(MODIFIER),
+			),
+			(
+				0,
+				ChoicesType::PlayImmediately(ring),
+				Choice::PlayImmediately(ring),
+			),
+			(
+				0,
+				ChoicesType::PlayOneOfImmediately,
+				Choice::PlayImmediately(ring),
+			),
+			(
+				0,
+				ChoicesType::ChoosePlayerParam(victim),
+				Choice::SetPlayerParam(victim, 2),
+			),
+			(
+				0,
+				ChoicesType::ChoosePlayerParam(victim),
+				Choice::SetPlayerParam(recipient, 1),
+			),
+			(
+				0,
+				ChoicesType::ChooseCardParam(hero),
+				Choice::SetCardParameter(other_card, mimimeow),
+			),
+			(
+				0,
+				ChoicesType::ContinueDiscardingAndDestroying(0),
+				Choice::ContinueDiscardingAndDestroying,
+			),
+			(
+				1,
+				ChoicesType::RevealAndDestroy,
+				Choice::RevealChallengeAndDestroy,
+			),
+			(
+				0,
+				ChoicesType::BullseyeKeep,
+				Choice::BullseyeKeep(SlayCardSpec::Challenge),
+			),
+			(
+				0,
+				ChoicesType::BullseyeOrdering(SlayCardSpec::Challenge, MODIFIER),
+				Choice::BullseyeReorder,
+			),
+			// Nothing offers these together.
+			(0, ChoicesType::Discard, Choice::BullseyeReorder),
+		];
+		(expected, unexpected)
+	}
+
+	#[test]
+	fn choices_about_what_the_player_still_has_are_expected() {
+		for (player_index, choices_type, choice) in cases().0 {
+			assert!(
+				!is_unexpected(player_index, choices_type.to_owned(), choice.to_owned()),
+				"{:?} {:?}",
+				choices_type,
+				choice
+			);
+		}
+	}
+
+	#[test]
+	fn choices_about_what_the_player_no_longer_has_are_unexpected() {
+		for (player_index, choices_type, choice) in cases().1 {
+			assert!(
+				is_unexpected(player_index, choices_type.to_owned(), choice.to_owned()),
+				"{:?} {:?}",
+				choices_type,
+				choice
+			);
+		}
+	}
+
+	fn choose(
+		context: &mut GameBookKeeping,
+		game: &mut Game,
+		player_index: ids::PlayerIndex,
+		choice: Choice,
+	) {
+		let choice_id = game.players[player_index]
+			.choices_
+			.as_ref()
+			.and_then(|choices| {
+				choices
+					.options
+					.iter()
+					.find(|option| option.choice == choice)
+			})
+			.map(|option| option.id)
+			.unwrap();
+		driver::make_selection(game, player_index, choice_id, &mut |_| {}).unwrap();
+		driver::advance_game(context, game).unwrap();
+	}
+
+	#[test]
+	fn modifying_a_roll_after_being_done_with_it_is_unexpected() {
+		let context = &mut GameBookKeeping::new();
+		let mut game = build_table(context);
+		choose(
+			context,
+			&mut game,
+			0,
+			Choice::UseActionPoints(Action::RollForAbility(MIMIMEOW)),
+		);
+		assert_eq!(game.check_invariants(), vec![]);
+
+		// Being done with the roll takes the choices away.
+		game
+			.showdown
+			.set_player_completion(0, Completion::AllDone)
+			.unwrap();
+		assert_eq!(
+			game.check_invariants(),
+			vec![InvariantViolation::UnexpectedChoices(
+				0,
+				ChoicesType::ModifyRoll
+			)]
+		);
+	}
+
+	#[test]
+	fn challenging_your_own_card_is_unexpected() {
+		let context = &mut GameBookKeeping::new();
+		let mut game = build_table(context);
+		game.players[1].auto_pass = AutoPass::Never;
+		choose(
+			context,
+			&mut game,
+			0,
+			Choice::UseActionPoints(Action::PlaceHeroInParty(PEANUT)),
+		);
+		assert_eq!(game.check_invariants(), vec![]);
+
+		game.players[0].choices_ = game.players[1].choices_.to_owned();
+		assert_eq!(
+			game.check_invariants(),
+			vec![InvariantViolation::UnexpectedChoices(
+				0,
+				ChoicesType::OfferChallenges
+			)]
+		);
+	}
+}
@@ -1,6 +1,10 @@
+pub mod arena;
+pub mod builder;
 pub mod deck;
+pub mod diff;
 pub mod game;
 pub mod initialize;
+pub mod invariants;
 pub mod player;
 pub mod stack;
 pub mod stacks;
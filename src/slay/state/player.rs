@@ -4,10 +4,12 @@ use crate::slay::choices::ChoicesPerspective;
 use crate::slay::choices::DisplayPath;
 use crate::slay::errors;
 use crate::slay::ids;
+use crate::slay::showdown::completion::AutoPass;
 use crate::slay::specification::HeroType;
 use crate::slay::specs::cards::card_type::SlayCardSpec;
 use crate::slay::specs::visibility::Perspective;
 use crate::slay::specs::visibility::VisibilitySpec;
+use crate::slay::state::arena::CardArena;
 use crate::slay::state::deck::Deck;
 use crate::slay::state::deck::DeckPath;
 use crate::slay::state::deck::DeckPerspective;
@@ -15,13 +17,14 @@ use crate::slay::state::deck::DeckSpec;
 use crate::slay::state::game::Game;
 use crate::slay::state::game::GamePerspective;
 use crate::slay::state::game::GameStaticInformation;
-use crate::slay::state::stack::Card;
 use crate::slay::state::summarizable::Summarizable;
 use crate::slay::state::turn::Turn;
 use crate::slay::status_effects::effect::PlayerStatusEffect;
 use crate::slay::status_effects::effect_entry::PlayerStatusEffectEntry;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::PlayerTasks;
+use serde::Deserialize;
+use serde::Serialize;
 
 use enum_iterator::all;
 use std::collections::HashMap;
@@ -104,16 +107,19 @@ pub struct Player {
 	pub choices_: Option<Choices>,
 	pub tasks: PlayerTasks,
 
-	pub leader: Card,
+	pub leader: ids::CardId,
 
 	pub hand: Deck,
 	pub party: Deck,
 	pub slain_monsters: Deck,
 
 	pub visible_hands: HashSet<ids::PlayerIndex>,
+	pub auto_pass: AutoPass,
 
 	played_this_turn: HashSet<ids::CardId>,
 	remaining_action_points: u32,
+	// Only ever non zero because of a bug, kept so that the invariant checker can report it.
+	overspent_action_points: u32,
 }
 
 impl Player {
@@ -138,13 +144,14 @@ impl Player {
 	pub fn decks_mut(&mut self) -> [&mut Deck; 3] {
 		[&mut self.hand, &mut self.party, &mut self.slain_monsters]
 	}
-	pub fn new(name: String, player_index: ids::PlayerIndex, leader: Card) -> Self {
+	pub fn new(name: String, player_index: ids::PlayerIndex, leader: ids::CardId) -> Self {
 		Player {
 			player_index,
 			name,
 			choices_: None,
 			tasks: Default::default(),
 			remaining_action_points: 0,
+			overspent_action_points: 0,
 			leader,
 			temporary_buffs: Default::default(),
 			hand: Deck::new(DeckSpec {
@@ -161,15 +168,19 @@ impl Player {
 			}),
 			played_this_turn: Default::default(),
 			visible_hands: Default::default(),
+			auto_pass: Default::default(),
 		}
 	}
 
-	pub fn turn_begin(&mut self) {
-		self.remaining_action_points = self.calculate_total_action_points();
+	pub fn turn_begin(&mut self, cards: &CardArena) {
+		self.remaining_action_points = self.calculate_total_action_points(cards);
 	}
 
 	pub fn action_points_used(&mut self, amount: u32) {
-		self.remaining_action_points -= amount;
+		if amount > self.remaining_action_points {
+			self.overspent_action_points += amount - self.remaining_action_points;
+		}
+		self.remaining_action_points = self.remaining_action_points.saturating_sub(amount);
 	}
 
 	pub fn set_card_played(&mut self, card_id: ids::CardId) {
@@ -185,19 +196,19 @@ impl Player {
 		self.visible_hands.clear();
 	}
 
-	pub fn count_hero_types(&self, hero_types: &mut HeroTypeCounter) {
+	pub fn count_hero_types(&self, cards: &CardArena, hero_types: &mut HeroTypeCounter) {
 		// Could this be a one liner?
-		self.party.count_hero_types(hero_types);
-		hero_types.leader_type = self.leader.get_unmodified_hero_type();
+		self.party.count_hero_types(cards, hero_types);
+		hero_types.leader_type = cards.card(self.leader).get_unmodified_hero_type();
 	}
-	pub fn collect_hero_types(&self, hero_types: &mut HashSet<HeroType>) {
+	pub fn collect_hero_types(&self, cards: &CardArena, hero_types: &mut HashSet<HeroType>) {
 		// Could this be a one liner?
-		self.party.collect_hero_types(hero_types);
-		hero_types.insert(self.leader.get_unmodified_hero_type().unwrap());
+		self.party.collect_hero_types(cards, hero_types);
+		hero_types.insert(cards.card(self.leader).get_unmodified_hero_type().unwrap());
 	}
-	pub fn has_hero_type(&self, hero_type: &HeroType) -> bool {
-		self.leader.get_unmodified_hero_type().unwrap() == *hero_type
-			|| self.party.contains_hero_type(hero_type)
+	pub fn has_hero_type(&self, cards: &CardArena, hero_type: &HeroType) -> bool {
+		cards.card(self.leader).get_unmodified_hero_type().unwrap() == *hero_type
+			|| self.party.contains_hero_type(cards, hero_type)
 	}
 
 	pub fn take_current_task(&mut self) -> Option<Box<dyn PlayerTask>> {
@@ -211,8 +222,14 @@ impl Player {
 	pub(crate) fn get_remaining_action_points(&self) -> u32 {
 		self.remaining_action_points
 	}
-	pub(crate) fn calculate_total_action_points(&self) -> u32 {
-		if self.has_player_effect(PlayerStatusEffect::ExtraActionPoint) {
+	pub(crate) fn set_remaining_action_points(&mut self, remaining: u32) {
+		self.remaining_action_points = remaining;
+	}
+	pub(crate) fn get_overspent_action_points(&self) -> u32 {
+		self.overspent_action_points
+	}
+	pub(crate) fn calculate_total_action_points(&self, cards: &CardArena) -> u32 {
+		if self.has_player_effect(cards, PlayerStatusEffect::ExtraActionPoint) {
 			4
 		} else {
 			3
@@ -223,17 +240,19 @@ impl Player {
 	// status effects
 	//////////////////////////////////////////////////////////////////////////////
 
-	pub fn has_player_effect(&self, effect: PlayerStatusEffect) -> bool {
-		self.temporary_buffs.has_player_effect(effect);
+	pub fn has_player_effect(&self, cards: &CardArena, effect: PlayerStatusEffect) -> bool {
+		if self.temporary_buffs.has_player_effect(effect) {
+			return true;
+		}
 		guard_unwrap!(
-			let SlayCardSpec::PartyLeader(hero_type) = self.leader.card_type
+			let SlayCardSpec::PartyLeader(hero_type) = cards.card_type(self.leader)
 		);
 		if let Some(status_effect) = hero_type.get_leader_effect() {
 			if status_effect == effect {
 				return true;
 			}
 		}
-		for top in self.slain_monsters.tops() {
+		for top in self.slain_monsters.tops(cards) {
 			guard_unwrap!(
 				let SlayCardSpec::MonsterCard(monster) = top.card_type
 			);
@@ -244,14 +263,17 @@ impl Player {
 		false
 	}
 
-	pub(crate) fn player_effects(&self) -> impl Iterator<Item = PlayerStatusEffectEntry> + '_ {
+	pub(crate) fn player_effects<'a>(
+		&'a self,
+		cards: &'a CardArena,
+	) -> impl Iterator<Item = PlayerStatusEffectEntry> + 'a {
 		guard_unwrap!(
-			let SlayCardSpec::PartyLeader(hero_type) = self.leader.card_type
+			let SlayCardSpec::PartyLeader(hero_type) = cards.card_type(self.leader)
 		);
 		self
 			.temporary_buffs
 			.player_effects()
-			.chain(self.slain_monsters.tops().map(|top| {
+			.chain(self.slain_monsters.tops(cards).map(|top| {
 				guard_unwrap!(
 					let SlayCardSpec::MonsterCard(monster) = top.card_type
 				);
@@ -288,8 +310,14 @@ impl Player {
 		// }
 	}
 
-	pub fn hero_effects(&self) -> impl Iterator<Item = ActiveHeroItem> + '_ {
-		self.party.stacks().flat_map(|stack| stack.hero_effects())
+	pub fn hero_effects<'a>(
+		&'a self,
+		cards: &'a CardArena,
+	) -> impl Iterator<Item = ActiveHeroItem> + 'a {
+		self
+			.party
+			.stacks()
+			.flat_map(|stack| stack.hero_effects(cards))
 
 		// let mut ret = iter::empty::<(ids::CardId, HeroStatusEffectEntry)>();
 		// for stack in self.party.stacks() {
@@ -330,12 +358,13 @@ impl Player {
 	pub fn to_perspective(&self, game: &Game, perspective: &Perspective) -> PlayerPerspective {
 		PlayerPerspective {
 			player_index: self.player_index,
-			leader: self
-				.leader
-				.to_perspective(game.was_card_played(Some(self.player_index), self.leader.id)),
+			leader: game
+				.cards
+				.card(self.leader)
+				.to_perspective(game.was_card_played(Some(self.player_index), self.leader)),
 			remaining_action_points: self.get_remaining_action_points(),
 			// Could be calculated...
-			total_action_points: self.calculate_total_action_points(),
+			total_action_points: self.calculate_total_action_points(&game.cards),
 			decks: self
 				.decks()
 				.iter()
@@ -345,12 +374,24 @@ impl Player {
 			represented_hero_types: all::<HeroType>()
 				.map(|hero_type| RepresentedHeroType {
 					hero_type,
-					represented: self.has_hero_type(&hero_type),
+					represented: self.has_hero_type(&game.cards, &hero_type),
 				})
 				.collect(),
 		}
 	}
 
+	pub(crate) fn has_modifier_card(&self, cards: &CardArena) -> bool {
+		self.hand.tops(cards).any(|card| card.is_modifier())
+	}
+
+	pub(crate) fn has_item_card(&self, cards: &CardArena) -> bool {
+		self.hand.tops(cards).any(|card| card.is_item())
+	}
+
+	pub(crate) fn has_challenge_card(&self, cards: &CardArena) -> bool {
+		self.hand.tops(cards).any(|card| card.is_challenge())
+	}
+
 	pub(crate) fn has_choices(&self) -> bool {
 		self.choices_.is_some()
 	}
@@ -360,19 +401,28 @@ impl Player {
 	}
 }
 
-impl Summarizable for Player {
-	fn summarize<W: Write>(
+impl Player {
+	pub fn summarize_with<W: Write>(
 		&self,
+		cards: &CardArena,
 		f: &mut BufWriter<W>,
 		indentation_level: u32,
 	) -> Result<(), std::io::Error> {
 		for _ in 0..indentation_level {
 			write!(f, "  ")?;
 		}
-		writeln!(f, "player {}", self.player_index)?;
-		self.hand.summarize(f, indentation_level + 1)?;
-		self.party.summarize(f, indentation_level + 1)?;
-		self.slain_monsters.summarize(f, indentation_level + 1)?;
+		writeln!(
+			f,
+			"player {} ({}), {} action points",
+			self.player_index,
+			cards.card(self.leader).label(),
+			self.get_remaining_action_points()
+		)?;
+		self.hand.summarize_with(cards, f, indentation_level + 1)?;
+		self.party.summarize_with(cards, f, indentation_level + 1)?;
+		self
+			.slain_monsters
+			.summarize_with(cards, f, indentation_level + 1)?;
 
 		if let Some(choices) = self.choices_.as_ref() {
 			choices.summarize(f, indentation_level + 1)?;
@@ -383,13 +433,13 @@ impl Summarizable for Player {
 	}
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct RepresentedHeroType {
 	pub hero_type: HeroType,
 	pub represented: bool,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct PlayerPerspective {
 	pub remaining_action_points: u32,
 	pub total_action_points: u32,
@@ -7,23 +7,26 @@ use crate::slay::specs::cards::card_type::SlayCardSpec;
 use crate::slay::specs::hero::HeroAbility;
 use crate::slay::specs::hero::HeroAbilityType;
 use crate::slay::specs::items::AnotherItemType;
+use crate::slay::state::arena::CardArena;
 use crate::slay::state::game::Game;
 use crate::slay::state::summarizable::Summarizable;
 use crate::slay::status_effects::effect::HeroStatusEffect;
 use crate::slay::status_effects::effect_entry::HeroStatusEffectEntry;
+use serde::Deserialize;
+use serde::Serialize;
 
 use std::fmt::Debug;
 use std::io::BufWriter;
 use std::io::Write;
 use std::iter::Iterator;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Card {
 	pub id: ids::CardId,
 	pub card_type: SlayCardSpec,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct CardPerspective {
 	pub id: ids::CardId,
 	pub spec: SlayCardSpec,
@@ -33,11 +36,11 @@ pub struct CardPerspective {
 #[derive(Debug, Clone)]
 pub struct Stack {
 	// pub id: ElementId,
-	pub top: Card,
-	pub modifiers: Vec<Card>,
+	pub top: ids::CardId,
+	pub modifiers: Vec<ids::CardId>,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct StackPerspective {
 	pub top: CardPerspective,
 	pub modifiers: Vec<CardPerspective>,
@@ -116,28 +119,36 @@ pub struct ActiveHeroItem {
 }
 
 impl Stack {
-	pub fn new(top: Card) -> Self {
+	pub fn new(top: ids::CardId) -> Self {
 		Self {
 			top,
 			modifiers: Vec::new(),
 		}
 	}
 
-	pub fn hero_effects(&self) -> impl Iterator<Item = ActiveHeroItem> + '_ {
+	// The top first, then the modifiers.
+	pub fn card_ids(&self) -> impl Iterator<Item = ids::CardId> + '_ {
+		std::iter::once(self.top).chain(self.modifiers.iter().copied())
+	}
+
+	pub fn hero_effects<'a>(
+		&'a self,
+		cards: &'a CardArena,
+	) -> impl Iterator<Item = ActiveHeroItem> + 'a {
 		// guard_unwrap!(
 		// 	let SlayCardSpec::HeroCard(top_hero) = self.top.card_type
 		// );
 
-		self.modifiers.iter().map(|item_card| {
+		self.modifiers.iter().map(|item_id| {
 			guard_unwrap!(
-				let SlayCardSpec::Item(item) = item_card.card_type
+				let SlayCardSpec::Item(item) = cards.card_type(*item_id)
 			);
 			guard_unwrap!(
-				let SlayCardSpec::HeroCard(hero_card) = self.top.card_type
+				let SlayCardSpec::HeroCard(hero_card) = cards.card_type(self.top)
 			);
 			ActiveHeroItem {
 				hero: hero_card,
-				item_id: item_card.id,
+				item_id: *item_id,
 				effect: item.hero_effect_entry(),
 			}
 		})
@@ -150,18 +161,18 @@ impl Stack {
 	// }
 
 	pub fn contains(&self, card_id: ids::CardId) -> bool {
-		self.top.id == card_id || self.modifiers.iter().any(|c| c.id == card_id)
+		self.top == card_id || self.modifiers.contains(&card_id)
 	}
 
-	pub(crate) fn get_hero_type(&self) -> Option<HeroType> {
+	pub(crate) fn get_hero_type(&self, cards: &CardArena) -> Option<HeroType> {
 		guard!(
-			let SlayCardSpec::HeroCard(hero_card) = self.top.card_type
+			let SlayCardSpec::HeroCard(hero_card) = cards.card_type(self.top)
 			else { return None; }
 		);
 		let mut return_value = hero_card.hero_type();
-		for item_card in self.modifiers.iter() {
+		for item_id in self.modifiers.iter() {
 			guard_unwrap!(
-				let SlayCardSpec::Item(item) = item_card.card_type
+				let SlayCardSpec::Item(item) = cards.card_type(*item_id)
 			);
 			if let AnotherItemType::MaskCard(hero_type) = item {
 				return_value = hero_type;
@@ -177,34 +188,38 @@ impl Summarizable for Card {
 		f: &mut BufWriter<W>,
 		_indentation_level: u32,
 	) -> Result<(), std::io::Error> {
+		// Not the id, those change whenever anything else asks for one.
 		write!(
 			f,
-			"({}) {}",
-			self.id,
+			"{}",
 			self.label(),
 			//  if self.played_this_turn { "X" } else { "" }
 		)
 	}
 }
-impl Summarizable for Stack {
-	fn summarize<W: Write>(
+impl Stack {
+	// Stacks only know the ids of their cards, so they are summarized with the cards they name.
+	pub fn summarize_with<W: Write>(
 		&self,
+		cards: &CardArena,
 		f: &mut BufWriter<W>,
 		indentation_level: u32,
 	) -> Result<(), std::io::Error> {
 		write!(f, "[")?;
-		self.top.summarize(f, indentation_level + 1)?;
-		write!(f, " {}], ", self.modifiers.len())
+		cards.card(self.top).summarize(f, indentation_level + 1)?;
+		for modifier in self.modifiers.iter() {
+			write!(f, " + ")?;
+			cards.card(*modifier).summarize(f, indentation_level + 1)?;
+		}
+		write!(f, "], ")
 	}
-}
 
-impl Stack {
-	pub fn get_id_to_sacrifice_or_destroy(&self) -> ids::CardId {
+	pub fn get_id_to_sacrifice_or_destroy(&self, cards: &CardArena) -> ids::CardId {
 		self
-			.hero_effects()
+			.hero_effects(cards)
 			.find(|item| item.effect.effect == HeroStatusEffect::SacrificeMeInstead)
 			.map(|item| item.item_id)
-			.unwrap_or(self.top.id)
+			.unwrap_or(self.top)
 	}
 	pub fn to_perspective(
 		&self,
@@ -212,13 +227,13 @@ impl Stack {
 		player_index: Option<ids::PlayerIndex>,
 	) -> StackPerspective {
 		StackPerspective {
-			top: self.top.to_perspective(
-				game.was_card_played(player_index, self.top.id), // DisplayPath::CardAt(CardPath::TopCardIn(deck_path, self.top.id)),
+			top: game.cards.card(self.top).to_perspective(
+				game.was_card_played(player_index, self.top), // DisplayPath::CardAt(CardPath::TopCardIn(deck_path, self.top.id)),
 			),
 			modifiers: self
 				.modifiers
 				.iter()
-				.map(|s| s.to_perspective(false))
+				.map(|modifier| game.cards.card(*modifier).to_perspective(false))
 				.collect(),
 		}
 	}
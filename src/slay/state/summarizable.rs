@@ -8,3 +8,13 @@ pub trait Summarizable {
 		indentation_level: u32,
 	) -> Result<(), std::io::Error>;
 }
+
+// Summaries only name cards, never their ids, so the same game always summarizes the same way.
+pub fn summary<S: Summarizable>(item: &S) -> String {
+	let mut writer = BufWriter::new(Vec::new());
+	item
+		.summarize(&mut writer, 0)
+		.expect("Writing to memory failed");
+	let bytes = writer.into_inner().expect("Writing to memory failed");
+	String::from_utf8(bytes).expect("Summaries are utf8")
+}
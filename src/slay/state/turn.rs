@@ -1,10 +1,16 @@
 use crate::slay::ids;
 use crate::slay::specification;
 use crate::slay::status_effects::temp_effect::EffectDuration;
+use serde::Deserialize;
+use serde::Serialize;
 
 use std::fmt::Debug;
+use std::io::BufWriter;
+use std::io::Write;
 
-#[derive(Debug, Default, Clone, PartialEq)]
+use crate::slay::state::summarizable::Summarizable;
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Turn {
 	turn_number: u32,
 	round_number: u32,
@@ -53,3 +59,20 @@ impl Turn {
 		self.player_index as ids::PlayerIndex
 	}
 }
+
+impl Summarizable for Turn {
+	fn summarize<W: Write>(
+		&self,
+		f: &mut BufWriter<W>,
+		indentation_level: u32,
+	) -> Result<(), std::io::Error> {
+		for _ in 0..indentation_level {
+			write!(f, "  ")?;
+		}
+		writeln!(
+			f,
+			"turn {}, round {}, player {} is active",
+			self.turn_number, self.round_number, self.player_index
+		)
+	}
+}
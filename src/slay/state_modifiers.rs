@@ -1,26 +1,28 @@
-use crate::slay::state::deck::Deck;
+use crate::slay::state::deck::DeckPath;
+use crate::slay::state::game::Game;
 
 use std::cmp;
 
-pub fn deal(source: &mut Deck, destination: &mut Deck, num: usize) {
-	destination.extend(source.drain(0..num));
+pub fn deal(game: &mut Game, source: DeckPath, destination: DeckPath, num: usize) {
+	game.move_stacks(source, destination, 0..num);
 }
 
 pub fn replentish(
-	source: &mut Deck,
-	destination: &mut Deck,
-	sources_source: &mut Deck,
+	game: &mut Game,
+	source: DeckPath,
+	destination: DeckPath,
+	sources_source: DeckPath,
 	num: usize,
 ) {
-	let mut amount_to_drain = cmp::min(num, source.num_top_cards());
-	destination.extend(source.drain(0..amount_to_drain));
+	let mut amount_to_drain = cmp::min(num, game.deck(source).num_top_cards());
+	game.move_stacks(source, destination, 0..amount_to_drain);
 	let remaining_amount = num - amount_to_drain;
 	if remaining_amount == 0 {
 		return;
 	}
-	source.extend(sources_source.drain(..));
-	amount_to_drain = cmp::min(remaining_amount, source.num_top_cards());
-	destination.extend(source.drain(0..amount_to_drain));
+	game.move_stacks(sources_source, source, ..);
+	amount_to_drain = cmp::min(remaining_amount, game.deck(source).num_top_cards());
+	game.move_stacks(source, destination, 0..amount_to_drain);
 
 	if amount_to_drain != remaining_amount {
 		// This is theoretically possible, but bad: everybody has all their cards in their hand!
@@ -1,12 +1,14 @@
 use crate::slay::specification::HeroType;
 use crate::slay::specs::magic::MagicSpell;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::slay::showdown::roll_modification::{ModificationOrigin, RollModification};
 use crate::slay::showdown::roll_state::RollReason;
 
 use super::effect::{HeroStatusEffect, PlayerStatusEffect};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum EffectOrigin {
 	FromMagicCard(MagicSpell),
 	FromHeroAbility,
@@ -75,6 +77,13 @@ impl PlayerStatusEffectEntry {
 				}),
 				_ => None,
 			},
+			PlayerStatusEffect::AddToRollForAttack(amount) => match reason {
+				RollReason::AttackMonster(_) => Some(RollModification {
+					origin: ModificationOrigin::FromBuff(self.origin),
+					amount,
+				}),
+				_ => None,
+			},
 			_ => None,
 		}
 	}
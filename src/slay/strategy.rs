@@ -12,20 +12,33 @@ pub fn pick_a_random_choice(
 	context: &mut GameBookKeeping,
 	game: &mut Game,
 ) -> SlayResult<(ids::PlayerIndex, ids::ChoiceId)> {
+	pick_a_random_choice_among(context, game, &|_| true)
+		.ok_or_else(|| SlayError::new("No choices found."))
+}
+
+// Only considers the players the filter accepts, for example the seats played by bots.
+pub fn pick_a_random_choice_among(
+	context: &mut GameBookKeeping,
+	game: &mut Game,
+	is_candidate: &dyn Fn(ids::PlayerIndex) -> bool,
+) -> Option<(ids::PlayerIndex, ids::ChoiceId)> {
 	// reservoir sampling
 	let mut count = 0;
 	let mut ret = None;
 	for player in game.players.iter_mut() {
+		if !is_candidate(player.player_index) {
+			continue;
+		}
 		if let Some(choices) = player.choices_.as_mut() {
 			for choice in choices.options.iter_mut() {
 				count += 1;
-				if context.rng.gen::<f32>() < 1f32 / (count as f32) {
+				if context.bot_rng.gen::<f32>() < 1f32 / (count as f32) {
 					ret = Some((player.player_index, choice.id));
 				}
 			}
 		}
 	}
-	ret.ok_or_else(|| SlayError::new("No choices found."))
+	ret
 }
 
 // pub fn pick_a_random_choice(
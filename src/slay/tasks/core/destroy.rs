@@ -1,16 +1,19 @@
 use crate::slay::errors::SlayError;
 use crate::slay::errors::SlayResult;
 use crate::slay::game_context::GameBookKeeping;
+use crate::slay::i18n::keys::MessageKey;
 use crate::slay::ids;
+use crate::slay::notification::Notification;
 use crate::slay::state::deck::DeckPath;
 use crate::slay::state::game::Game;
 use crate::slay::state::stack::Stack;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::task_params::CardParam;
+use crate::slay::tasks::task_params::ParamScope;
+use crate::slay::tasks::task_params::PlayerParam;
 use crate::slay::tasks::tasks::params::ChooseCardFromPlayerParameterTask;
 use crate::slay::tasks::tasks::params::ChoosePlayerParameterTask;
-use crate::slay::tasks::tasks::params::ClearParamsTask;
 
 #[derive(Clone, Debug)]
 pub struct DestroyTask {}
@@ -28,19 +31,16 @@ impl PlayerTask for DestroyTask {
 		game: &mut Game,
 		thief_index: ids::PlayerIndex,
 	) -> SlayResult<TaskProgressResult> {
-		game.players[thief_index].tasks.prepend_from(&mut vec![
-			ChoosePlayerParameterTask::exclude_self(TaskParamName::PlayerToDestroy),
-			ChooseCardFromPlayerParameterTask::from_party(
-				TaskParamName::PlayerToDestroy,
-				TaskParamName::CardToDestroy,
-			),
-			DestroyCardTask::create(
-				TaskParamName::PlayerToDestroy,
-				TaskParamName::CardToDestroy,
-				DestroyModifiersDestination::Discard,
-			),
-			ClearParamsTask::create(),
-		]);
+		let mut params = ParamScope::new();
+		let victim = params.player(MessageKey::PromptPlayerToDestroy);
+		let card = params.card(MessageKey::PromptCardToDestroy);
+		game.players[thief_index]
+			.tasks
+			.prepend_from(&mut params.chain(vec![
+				ChoosePlayerParameterTask::exclude_self(victim),
+				ChooseCardFromPlayerParameterTask::from_party(victim, card),
+				DestroyCardTask::create(victim, card, DestroyModifiersDestination::Discard),
+			]));
 		Ok(TaskProgressResult::TaskComplete)
 	}
 
@@ -57,15 +57,15 @@ pub enum DestroyModifiersDestination {
 
 #[derive(Clone, Debug)]
 pub struct DestroyCardTask {
-	victim_param: TaskParamName,
-	card_param: TaskParamName,
+	victim_param: PlayerParam,
+	card_param: CardParam,
 	destination: DestroyModifiersDestination,
 }
 
 impl DestroyCardTask {
 	pub fn create(
-		victim_param: TaskParamName,
-		card_param: TaskParamName,
+		victim_param: PlayerParam,
+		card_param: CardParam,
 		destination: DestroyModifiersDestination,
 	) -> Box<dyn PlayerTask> {
 		Box::new(Self {
@@ -86,7 +86,7 @@ impl DestroyCardTask {
 impl PlayerTask for DestroyCardTask {
 	fn make_progress(
 		&mut self,
-		_context: &mut GameBookKeeping,
+		context: &mut GameBookKeeping,
 		game: &mut Game,
 		stealer_index: ids::PlayerIndex,
 	) -> SlayResult<TaskProgressResult> {
@@ -99,15 +99,19 @@ impl PlayerTask for DestroyCardTask {
 			.party
 			.stack(hero_card_id)
 			.ok_or_else(|| SlayError::new("Unable to find card to destroy"))?
-			.get_id_to_sacrifice_or_destroy();
+			.get_id_to_sacrifice_or_destroy(&game.cards);
 
-		let mut stack = game.players[victim_player_index]
-			.party
-			.take_card(card_to_destroy)?;
-		game
-			.deck_mut(self.get_destination(stealer_index))
-			.extend(stack.modifiers.drain(..).map(Stack::new));
-		game.deck_mut(DeckPath::Discard).add(stack);
+		let mut stack = game.take_stack(DeckPath::Party(victim_player_index), card_to_destroy)?;
+		context.emit(&Notification::CardDestroyed(
+			stealer_index,
+			victim_player_index,
+			game.cards.card_type(stack.top),
+		));
+		game.add_stacks(
+			self.get_destination(stealer_index),
+			stack.modifiers.drain(..).map(Stack::new),
+		);
+		game.add_stack(DeckPath::Discard, stack);
 		Ok(TaskProgressResult::TaskComplete)
 	}
 
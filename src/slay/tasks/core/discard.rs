@@ -13,13 +13,13 @@ use crate::slay::state::deck::DeckPath;
 use crate::slay::state::game::Game;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::task_params::PlayerParam;
 use crate::slay::tasks::tasks::move_card::MoveCardTask;
 
 // #[derive(Debug, Clone)]
 // pub enum DiscardVictimSpec {
 // 	Myself,
-// 	FromParam(TaskParamName),
+// 	FromParam(PlayerParam),
 // 	PlayersWith(HeroType),
 // }
 
@@ -67,7 +67,7 @@ impl PlayerTask for Discard {
 
 		let options: Vec<TasksChoice> = game.players[player_index]
 			.hand
-			.tops()
+			.tops(&game.cards)
 			.filter(|card| self.should_include(card.id))
 			.map(|card| {
 				TasksChoice::prepend(
@@ -89,10 +89,15 @@ impl PlayerTask for Discard {
 		game.players[player_index].choose(Choices::new(
 			options,
 			None,
-			deadlines::get_discard_deadline(),
+			deadlines::get_discard_deadline(context),
 			ChoicesType::Discard,
 		));
-		Ok(TaskProgressResult::TaskComplete)
+		// The rest are discarded once this one has been chosen.
+		if self.num == 0 {
+			Ok(TaskProgressResult::TaskComplete)
+		} else {
+			Ok(TaskProgressResult::ProgressMade)
+		}
 	}
 	fn label(&self) -> String {
 		format!("Player must discard {} cards", self.num)
@@ -102,10 +107,10 @@ impl PlayerTask for Discard {
 #[derive(Debug, Clone)]
 pub struct DiscardFromParam {
 	num: u32,
-	victim_param: TaskParamName,
+	victim_param: PlayerParam,
 }
 impl DiscardFromParam {
-	pub fn create(num: u32, param: TaskParamName) -> Box<dyn PlayerTask> {
+	pub fn create(num: u32, param: PlayerParam) -> Box<dyn PlayerTask> {
 		Box::new(Self {
 			num,
 			victim_param: param,
@@ -151,7 +156,7 @@ impl PlayerTask for PlayersWithHeroTypeDiscard {
 		_player_index: ids::PlayerIndex,
 	) -> SlayResult<TaskProgressResult> {
 		for player in game.players.iter_mut() {
-			if !player.has_hero_type(&self.hero_type) {
+			if !player.has_hero_type(&game.cards, &self.hero_type) {
 				continue;
 			}
 			player.tasks.prepend(Discard::create(self.num));
@@ -169,11 +174,11 @@ impl PlayerTask for PlayersWithHeroTypeDiscard {
 
 // #[derive(Debug, Clone)]
 // pub struct DiscardToSet {
-//   parameter: TaskParamName,
+//   parameter: PlayerParam,
 // }
 
 // impl DiscardToSet {
-// 	pub fn create(parameter: TaskParamName,) -> Box<dyn PlayerTask> {
+// 	pub fn create(parameter: PlayerParam,) -> Box<dyn PlayerTask> {
 // 		Box::new(Self {parameter})
 // 	}
 // }
@@ -1,13 +1,15 @@
+use crate::slay::errors::SlayError;
 use crate::slay::errors::SlayResult;
 use crate::slay::game_context::GameBookKeeping;
 use crate::slay::ids;
 use crate::slay::specs::cards::card_type::SlayCardSpec;
+use crate::slay::state::deck::DeckPath;
 use crate::slay::state::game::Game;
 use crate::slay::state::stack::Card;
 use crate::slay::status_effects::effect::PlayerStatusEffect;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::task_params::CardParam;
 use crate::slay::tasks::tasks::choose::ChooseTask;
 use crate::slay::tasks::tasks::immediate;
 
@@ -48,7 +50,7 @@ enum DrawAmount {
 #[derive(Debug, Clone)]
 pub struct DrawTask {
 	amount: DrawAmount,
-	param: Option<TaskParamName>,
+	param: Option<CardParam>,
 }
 
 impl DrawTask {
@@ -67,7 +69,7 @@ impl DrawTask {
 			param: None,
 		})
 	}
-	pub fn into_param(param: TaskParamName) -> Box<dyn PlayerTask> {
+	pub fn into_param(param: CardParam) -> Box<dyn PlayerTask> {
 		Box::new(Self {
 			amount: DrawAmount::Fixed(1),
 			param: Some(param),
@@ -77,7 +79,7 @@ impl DrawTask {
 		match self.amount {
 			// TODO: Double check this...
 			DrawAmount::Fixed(_) => false, // amount <= 0,
-			DrawAmount::Until(amount) => amount >= hand_size,
+			DrawAmount::Until(amount) => hand_size >= amount,
 		}
 	}
 	fn decrement_and_check_if_is_last_draw(&mut self, hand_size: usize) -> bool {
@@ -103,9 +105,11 @@ impl PlayerTask for DrawTask {
 		}
 		let is_last = self.decrement_and_check_if_is_last_draw(hand_size);
 		game.replentish_for(1);
-		let stack = game.draw.deal();
-		let card = &stack.top.to_owned();
-		game.players[player_index].hand.add(stack);
+		let stack = game
+			.deal(DeckPath::Draw)
+			.ok_or_else(|| SlayError::new("Unable to draw from an empty deck."))?;
+		let card = &game.cards.card(stack.top).to_owned();
+		game.add_stack(DeckPath::Hand(player_index), stack);
 
 		player_has_drawn(context, game, player_index, card);
 
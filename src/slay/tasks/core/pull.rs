@@ -8,25 +8,23 @@ use crate::slay::state::deck::DeckPath;
 use crate::slay::state::game::Game;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::task_params::CardParam;
+use crate::slay::tasks::task_params::PlayerParam;
 
 #[derive(Clone, Debug)]
 pub struct PullFromTask {
-	pub victim_param: TaskParamName,
-	pub output_param: Option<TaskParamName>,
+	pub victim_param: PlayerParam,
+	pub output_param: Option<CardParam>,
 }
 
 impl PullFromTask {
-	pub fn create(victim_param: TaskParamName) -> Box<dyn PlayerTask> {
+	pub fn create(victim_param: PlayerParam) -> Box<dyn PlayerTask> {
 		Box::new(Self {
 			victim_param,
 			output_param: None,
 		}) as Box<dyn PlayerTask>
 	}
-	pub fn record_pulled(
-		victim_param: TaskParamName,
-		output_param: TaskParamName,
-	) -> Box<dyn PlayerTask> {
+	pub fn record_pulled(victim_param: PlayerParam, output_param: CardParam) -> Box<dyn PlayerTask> {
 		Box::new(Self {
 			victim_param,
 			output_param: Some(output_param),
@@ -47,9 +45,9 @@ pub fn pull_a_random_card(
 		return None;
 	}
 	let card_index = context.rng.gen_range(0..number_of_cards);
-	let stack = game.deck_mut(source).take_at_index(card_index);
-	let ret = stack.top.id;
-	game.deck_mut(destination).add(stack);
+	let stack = game.take_stack_at(source, card_index);
+	let ret = stack.top;
+	game.add_stack(destination, stack);
 	Some(ret)
 }
 
@@ -77,3 +75,40 @@ impl PlayerTask for PullFromTask {
 		"Pulling from a player".to_string()
 	}
 }
+
+// Takes the card the puller chose after looking at the victim's hand.
+#[derive(Clone, Debug)]
+pub struct TakeFromHandTask {
+	victim_param: PlayerParam,
+	card_param: CardParam,
+}
+
+impl TakeFromHandTask {
+	pub fn create(victim_param: PlayerParam, card_param: CardParam) -> Box<dyn PlayerTask> {
+		Box::new(Self {
+			victim_param,
+			card_param,
+		}) as Box<dyn PlayerTask>
+	}
+}
+
+impl PlayerTask for TakeFromHandTask {
+	fn make_progress(
+		&mut self,
+		_context: &mut GameBookKeeping,
+		game: &mut Game,
+		puller_index: ids::PlayerIndex,
+	) -> SlayResult<TaskProgressResult> {
+		let victim_index = game.player_param(puller_index, &self.victim_param)?;
+		let Some(card_id) = game.card_param(puller_index, &self.card_param)? else {
+			return Ok(TaskProgressResult::TaskComplete);
+		};
+		let stack = game.take_stack(DeckPath::Hand(victim_index), card_id)?;
+		game.add_stack(DeckPath::Hand(puller_index), stack);
+		Ok(TaskProgressResult::TaskComplete)
+	}
+
+	fn label(&self) -> String {
+		"Taking a card from a player's hand".to_string()
+	}
+}
@@ -7,12 +7,13 @@ use crate::slay::errors::SlayError;
 use crate::slay::errors::SlayResult;
 use crate::slay::game_context::GameBookKeeping;
 use crate::slay::ids;
+use crate::slay::notification::Notification;
 use crate::slay::specs::cards::card_type::SlayCardSpec;
 use crate::slay::state::deck::DeckPath;
 use crate::slay::state::game::Game;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::task_params::PlayerParam;
 
 #[derive(Debug, Clone)]
 pub struct SacrificeTask {
@@ -28,16 +29,18 @@ impl SacrificeTask {
 impl PlayerTask for SacrificeTask {
 	fn make_progress(
 		&mut self,
-		_context: &mut GameBookKeeping,
+		context: &mut GameBookKeeping,
 		game: &mut Game,
 		player_index: ids::PlayerIndex,
 	) -> SlayResult<TaskProgressResult> {
-		let card_id = game.players[player_index]
+		let stack = game.players[player_index]
 			.party
 			.stack(self.card_id)
-			.ok_or_else(|| SlayError::new("Unable to find card to sacrifice"))?
-			.get_id_to_sacrifice_or_destroy();
-		// make a notification?
+			.ok_or_else(|| SlayError::new("Unable to find card to sacrifice"))?;
+		// An item can be sacrificed in place of the hero it is attached to.
+		let card_id = stack.get_id_to_sacrifice_or_destroy(&game.cards);
+		let spec = game.cards.card_type(card_id);
+		context.emit(&Notification::CardSacrificed(player_index, spec));
 
 		game.move_card(DeckPath::Party(player_index), DeckPath::Discard, card_id)?;
 		Ok(TaskProgressResult::TaskComplete)
@@ -51,7 +54,7 @@ impl PlayerTask for SacrificeTask {
 #[derive(Debug, Clone)]
 pub enum SacrificeVictim {
 	Myself,
-	FromParam(TaskParamName),
+	FromParam(PlayerParam),
 }
 
 #[derive(Debug, Clone)]
@@ -70,7 +73,7 @@ impl ChooseSacrifice {
 			victim: SacrificeVictim::Myself,
 		}
 	}
-	pub fn from_param(param: TaskParamName) -> Box<dyn PlayerTask> {
+	pub fn from_param(param: PlayerParam) -> Box<dyn PlayerTask> {
 		Box::new(Self {
 			num: 1,
 			victim: SacrificeVictim::FromParam(param),
@@ -105,11 +108,11 @@ impl PlayerTask for ChooseSacrifice {
 		let victim_index = self.get_victim(game, player_index)?;
 		let party = &game.players[victim_index].party;
 		let mut options: Vec<TasksChoice> = party
-			.tops()
+			.tops(&game.cards)
 			// .filter(card_is_sacrificable)
 			.map(|card| {
 				if let SlayCardSpec::HeroCard(hero_card) = card.card_type {
-					TasksChoice::new(
+					TasksChoice::prepend(
 						context.id_generator.generate(),
 						Choice::Sacrifice(hero_card),
 						card.as_choice(),
@@ -136,7 +139,7 @@ impl PlayerTask for ChooseSacrifice {
 			choices_type: ChoicesType::Sacrifice,
 			options,
 			default_choice: None,
-			timeline: deadlines::get_sacrifice_deadline(),
+			timeline: deadlines::get_sacrifice_deadline(context),
 		});
 
 		self.num -= 1;
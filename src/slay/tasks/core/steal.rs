@@ -1,13 +1,17 @@
 use crate::slay::errors::SlayResult;
 use crate::slay::game_context::GameBookKeeping;
+use crate::slay::i18n::keys::MessageKey;
 use crate::slay::ids;
+use crate::slay::notification::Notification;
+use crate::slay::state::deck::DeckPath;
 use crate::slay::state::game::Game;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::task_params::CardParam;
+use crate::slay::tasks::task_params::ParamScope;
+use crate::slay::tasks::task_params::PlayerParam;
 use crate::slay::tasks::tasks::params::ChooseCardFromPlayerParameterTask;
 use crate::slay::tasks::tasks::params::ChoosePlayerParameterTask;
-use crate::slay::tasks::tasks::params::ClearParamsTask;
 
 // Could be: AddTasks
 #[derive(Clone, Debug, Default)]
@@ -26,19 +30,20 @@ impl PlayerTask for StealTask {
 		game: &mut Game,
 		thief_index: ids::PlayerIndex,
 	) -> SlayResult<TaskProgressResult> {
-		game.players[thief_index].tasks.prepend_from(&mut vec![
-			ChoosePlayerParameterTask::exclude_self(TaskParamName::PlayerToStealFrom),
-			// This one coulds just be a method call, all it does is assign a new task...
-			// (Kinda like the steal action...)
-			// I guess it should just be renamed to 'choose card from player's party' or smh...
-			// Could be from DeckPath...
-			ChooseCardFromPlayerParameterTask::from_party(
-				TaskParamName::PlayerToStealFrom,
-				TaskParamName::CardToSteal,
-			),
-			StealCardFromTask::create(TaskParamName::PlayerToStealFrom, TaskParamName::CardToSteal),
-			ClearParamsTask::create(),
-		]);
+		let mut params = ParamScope::new();
+		let victim = params.player(MessageKey::PromptStealFromPlayer);
+		let card = params.card(MessageKey::PromptStealHero);
+		game.players[thief_index]
+			.tasks
+			.prepend_from(&mut params.chain(vec![
+				ChoosePlayerParameterTask::exclude_self(victim),
+				// This one coulds just be a method call, all it does is assign a new task...
+				// (Kinda like the steal action...)
+				// I guess it should just be renamed to 'choose card from player's party' or smh...
+				// Could be from DeckPath...
+				ChooseCardFromPlayerParameterTask::from_party(victim, card),
+				StealCardFromTask::create(victim, card),
+			]));
 		Ok(TaskProgressResult::TaskComplete)
 	}
 
@@ -49,12 +54,12 @@ impl PlayerTask for StealTask {
 
 #[derive(Clone, Debug)]
 pub struct StealCardFromTask {
-	victim_param: TaskParamName,
-	card_param: TaskParamName,
+	victim_param: PlayerParam,
+	card_param: CardParam,
 }
 
 impl StealCardFromTask {
-	pub fn create(victim_param: TaskParamName, card_param: TaskParamName) -> Box<dyn PlayerTask> {
+	pub fn create(victim_param: PlayerParam, card_param: CardParam) -> Box<dyn PlayerTask> {
 		Box::new(Self {
 			victim_param,
 			card_param,
@@ -65,7 +70,7 @@ impl StealCardFromTask {
 impl PlayerTask for StealCardFromTask {
 	fn make_progress(
 		&mut self,
-		_context: &mut GameBookKeeping,
+		context: &mut GameBookKeeping,
 		game: &mut Game,
 		stealer_index: ids::PlayerIndex,
 	) -> SlayResult<TaskProgressResult> {
@@ -75,8 +80,13 @@ impl PlayerTask for StealCardFromTask {
 			return Ok(TaskProgressResult::TaskComplete);
 		}
 		let card_id = card_id.unwrap();
-		let stack = game.players[victim_player_index].party.take_card(card_id)?;
-		game.players[stealer_index].party.add(stack);
+		let stack = game.take_stack(DeckPath::Party(victim_player_index), card_id)?;
+		context.emit(&Notification::CardStolen(
+			stealer_index,
+			victim_player_index,
+			game.cards.card_type(stack.top),
+		));
+		game.add_stack(DeckPath::Party(stealer_index), stack);
 		Ok(TaskProgressResult::TaskComplete)
 	}
 
@@ -87,12 +97,12 @@ impl PlayerTask for StealCardFromTask {
 
 #[derive(Clone, Debug)]
 pub struct UnStealCardFromTask {
-	victim_param: TaskParamName,
-	card_param: TaskParamName,
+	victim_param: PlayerParam,
+	card_param: CardParam,
 }
 
 impl UnStealCardFromTask {
-	pub fn create(victim_param: TaskParamName, card_param: TaskParamName) -> Box<dyn PlayerTask> {
+	pub fn create(victim_param: PlayerParam, card_param: CardParam) -> Box<dyn PlayerTask> {
 		Box::new(Self {
 			victim_param,
 			card_param,
@@ -113,9 +123,9 @@ impl PlayerTask for UnStealCardFromTask {
 			return Ok(TaskProgressResult::TaskComplete);
 		}
 		let card_id = card_id.unwrap();
-		let stolen_stack = game.players[stealer_index].party.take_card(card_id)?;
+		let stolen_stack = game.take_stack(DeckPath::Party(stealer_index), card_id)?;
 		// TODO: Check if we are actually supposed to do something else due to buffs...
-		game.players[victim_player_index].party.add(stolen_stack);
+		game.add_stack(DeckPath::Party(victim_player_index), stolen_stack);
 		Ok(TaskProgressResult::TaskComplete)
 	}
 
@@ -31,7 +31,7 @@ impl PlayerTask for Bullseye {
 		game.replentish_for(3);
 		let mut card_ids = Vec::default();
 		{
-			let iter = &mut game.deck(DeckPath::Draw).tops();
+			let iter = &mut game.deck(DeckPath::Draw).tops(&game.cards);
 			for _ in 0..3 {
 				if let Some(card) = iter.next() {
 					card_ids.push(card.to_owned());
@@ -47,7 +47,7 @@ impl PlayerTask for Bullseye {
 			options: card_ids
 				.into_iter()
 				.map(|card| {
-					TasksChoice::new(
+					TasksChoice::prepend(
 						context.id_generator.generate(),
 						Choice::BullseyeKeep(card.card_type),
 						ChoiceDisplayType::Card_(card.card_type),
@@ -56,7 +56,7 @@ impl PlayerTask for Bullseye {
 				})
 				.collect(),
 			default_choice: None,
-			timeline: deadlines::get_refactor_me_deadline(),
+			timeline: deadlines::get_refactor_me_deadline(context),
 		});
 
 		// TODO: Not implemented...
@@ -93,7 +93,7 @@ impl PlayerTask for BullseyeReorderChoice {
 		}
 		let next_tops = &mut Vec::default();
 		{
-			let iter = &mut game.deck(DeckPath::Draw).tops();
+			let iter = &mut game.deck(DeckPath::Draw).tops(&game.cards);
 			if let Some(card) = iter.next() {
 				next_tops.push(card.to_owned());
 			}
@@ -107,13 +107,13 @@ impl PlayerTask for BullseyeReorderChoice {
 				game.players[player_index].choose(Choices {
 					choices_type: ChoicesType::BullseyeOrdering(first_card.card_type, second_card.card_type),
 					options: vec![
-						TasksChoice::new(
+						TasksChoice::prepend(
 							context.id_generator.generate(),
 							Choice::BullseyeReorder,
 							ChoiceDisplayType::Yes,
 							vec![BullseyeReorder::create()],
 						),
-						TasksChoice::new(
+						TasksChoice::prepend(
 							context.id_generator.generate(),
 							Choice::BullseyeDoNotReorder,
 							ChoiceDisplayType::No,
@@ -121,7 +121,7 @@ impl PlayerTask for BullseyeReorderChoice {
 						),
 					],
 					default_choice: None,
-					timeline: deadlines::get_refactor_me_deadline(),
+					timeline: deadlines::get_refactor_me_deadline(context),
 				})
 			}
 		}
@@ -149,7 +149,7 @@ impl PlayerTask for BullseyeReorder {
 		game: &mut Game,
 		_player_index: ids::PlayerIndex,
 	) -> SlayResult<TaskProgressResult> {
-		game.deck_mut(DeckPath::Draw).swap_first_cards();
+		game.swap_first_cards(DeckPath::Draw);
 		Ok(TaskProgressResult::TaskComplete)
 	}
 
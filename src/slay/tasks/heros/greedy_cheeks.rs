@@ -38,9 +38,9 @@ impl PlayerTask for GreedyCheeks {
 
 			let options = game.players[victim_index]
 				.hand
-				.tops()
+				.tops(&game.cards)
 				.map(|card| {
-					TasksChoice::new(
+					TasksChoice::prepend(
 						context.id_generator.generate(),
 						Choice::ChooseCardToGive(card.card_type, player_index),
 						ChoiceDisplayType::HighlightPath(DisplayPath::CardAt(CardPath::TopCardIn(
@@ -62,7 +62,7 @@ impl PlayerTask for GreedyCheeks {
 			game.players[victim_index].choose(Choices {
 				choices_type: ChoicesType::ChooseCardToGive(player_index),
 				default_choice: None,
-				timeline: deadlines::get_refactor_me_deadline(),
+				timeline: deadlines::get_refactor_me_deadline(context),
 				options,
 			});
 		}
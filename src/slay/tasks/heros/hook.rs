@@ -42,7 +42,7 @@ impl PlayerTask for Hook {
 	) -> SlayResult<TaskProgressResult> {
 		// let cards = game.players[player_index]
 		// 	.hand
-		// 	.tops()
+		// 	.tops(&game.cards)
 		// 	.filter(|card| self.filter.can_play_immediately(card))
 		// 	.map(|card| card.to_owned())
 		// 	.collect::<Vec<_>>();
@@ -52,14 +52,14 @@ impl PlayerTask for Hook {
 
 		let mut options = game.players[player_index]
 			.hand
-			.tops()
+			.tops(&game.cards)
 			.filter(|card| self.filter.can_play_immediately(card))
 			// .map(|card| card.to_owned())
 			.filter_map(|card| {
 				create_play_card_immediately_task(context, game, player_index, card).map(
 					// |TasksChoice { id, choice, display, .. }| {
 					|tasks| {
-						TasksChoice::new(
+						TasksChoice::prepend(
 							context.id_generator.generate(),
 							Choice::PlayImmediately(card.card_type),
 							ChoiceDisplayType::HighlightPath(DisplayPath::CardAt(CardPath::TopCardIn(
@@ -80,7 +80,7 @@ impl PlayerTask for Hook {
 		}
 
 		let default_choice = context.id_generator.generate();
-		options.push(TasksChoice::new(
+		options.push(TasksChoice::prepend(
 			default_choice,
 			Choice::DoNotPlayImmediately,
 			ChoiceDisplayType::No,
@@ -91,7 +91,7 @@ impl PlayerTask for Hook {
 			choices_type: ChoicesType::PlayOneOfImmediately,
 			options,
 			default_choice: Some(default_choice),
-			timeline: deadlines::get_refactor_me_deadline(),
+			timeline: deadlines::get_refactor_me_deadline(context),
 		});
 		Ok(TaskProgressResult::TaskComplete)
 	}
@@ -27,7 +27,7 @@ impl PlayerTask for Mimimeow {
 			if player_index == victim_index {
 				continue;
 			}
-			if !game.players[player_index].has_hero_type(&HeroType::Thief) {
+			if !game.players[victim_index].has_hero_type(&game.cards, &HeroType::Thief) {
 				continue;
 			}
 			pull::pull_a_random_card(context, game, player_index, victim_index);
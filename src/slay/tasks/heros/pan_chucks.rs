@@ -12,15 +12,21 @@ use crate::slay::state::game::Game;
 use crate::slay::tasks::core::destroy::DestroyTask;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::task_params::CardParam;
 use crate::slay::tasks::tasks::reveal::Reveal;
 
 #[derive(Clone, Debug)]
-pub struct PanChucksDestroy {}
+pub struct PanChucksDestroy {
+	first_card: CardParam,
+	second_card: CardParam,
+}
 
 impl PanChucksDestroy {
-	pub fn create() -> Box<dyn PlayerTask> {
-		Box::new(Self {}) as Box<dyn PlayerTask>
+	pub fn create(first_card: CardParam, second_card: CardParam) -> Box<dyn PlayerTask> {
+		Box::new(Self {
+			first_card,
+			second_card,
+		}) as Box<dyn PlayerTask>
 	}
 }
 
@@ -32,8 +38,8 @@ impl PlayerTask for PanChucksDestroy {
 		player_index: ids::PlayerIndex,
 	) -> SlayResult<TaskProgressResult> {
 		let drew_challenge = vec![
-			game.card_param(player_index, &TaskParamName::PanChuckFirstCard)?,
-			game.card_param(player_index, &TaskParamName::PanChuckSecondCard)?,
+			game.card_param(player_index, &self.first_card)?,
+			game.card_param(player_index, &self.second_card)?,
 		]
 		.iter()
 		.flatten()
@@ -44,13 +50,13 @@ impl PlayerTask for PanChucksDestroy {
 				false
 			}
 		});
-		if drew_challenge {
+		if !drew_challenge {
 			return Ok(TaskProgressResult::TaskComplete);
 		}
 		let default_choice = context.id_generator.generate();
 		game.players[player_index].choose(Choices {
 			choices_type: ChoicesType::RevealAndDestroy,
-			timeline: deadlines::get_refactor_me_deadline(),
+			timeline: deadlines::get_refactor_me_deadline(context),
 			default_choice: Some(default_choice),
 			options: vec![
 				TasksChoice::prepend(
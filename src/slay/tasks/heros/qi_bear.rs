@@ -12,7 +12,6 @@ use crate::slay::tasks::core::destroy::DestroyTask;
 use crate::slay::tasks::core::discard::Discard;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
-use crate::slay::tasks::tasks::params::ClearParamsTask;
 
 #[derive(Clone, Debug)]
 pub struct QiBear {
@@ -40,18 +39,14 @@ impl PlayerTask for QiBear {
 		game.players[player_index].choose(Choices {
 			choices_type: ChoicesType::ContinueDiscardingAndDestroying(self.num_remaining),
 			default_choice: None,
-			timeline: deadlines::get_refactor_me_deadline(),
+			timeline: deadlines::get_refactor_me_deadline(context),
 			options: vec![
 				TasksChoice::prepend(
 					context.id_generator.generate(),
 					// The argument here is just so the user knows why they are being asked again...
 					Choice::ContinueDiscardingAndDestroying,
 					ChoiceDisplayType::Yes,
-					vec![
-						Discard::create(1),
-						DestroyTask::create(),
-						ClearParamsTask::create(),
-					],
+					vec![Discard::create(1), DestroyTask::create()],
 				),
 				TasksChoice::prepend(
 					default_choice,
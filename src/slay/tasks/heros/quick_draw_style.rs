@@ -14,7 +14,7 @@ use crate::slay::state::deck::DeckPath;
 use crate::slay::state::game::Game;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::task_params::CardParam;
 use crate::slay::tasks::tasks::immediate::create_play_card_immediately_task;
 use crate::slay::tasks::tasks::immediate::PlayImmediatelyFilter;
 
@@ -27,9 +27,11 @@ fn add_play_immediately_choice(
 ) {
 	if let Some(card_id) = card_option {
 		let card_path = CardPath::TopCardIn(DeckPath::Hand(player_index), card_id);
-		let card = game.card(card_path);
+		let Some(card) = game.maybe_card(card_path) else {
+			return;
+		};
 		if let Some(tasks) = create_play_card_immediately_task(context, game, player_index, card) {
-			options.push(TasksChoice::new(
+			options.push(TasksChoice::prepend(
 				context.id_generator.generate(),
 				Choice::PlayImmediately(card.card_type),
 				ChoiceDisplayType::HighlightPath(DisplayPath::CardAt(card_path)),
@@ -41,15 +43,15 @@ fn add_play_immediately_choice(
 
 #[derive(Clone, Debug)]
 pub struct QuickDrawStyle {
-	card_1_param: TaskParamName,
-	card_2_param: TaskParamName,
+	card_1_param: CardParam,
+	card_2_param: CardParam,
 	filter: PlayImmediatelyFilter,
 }
 
 impl QuickDrawStyle {
 	pub fn create(
-		card_1_param: TaskParamName,
-		card_2_param: TaskParamName,
+		card_1_param: CardParam,
+		card_2_param: CardParam,
 		filter: PlayImmediatelyFilter,
 	) -> Box<dyn PlayerTask> {
 		Box::new(Self {
@@ -60,13 +62,13 @@ impl QuickDrawStyle {
 	}
 
 	fn applies(&self, game: &Game, player_index: ids::PlayerIndex, card: Option<u32>) -> bool {
-		if let Some(card_id) = card {
-			self
-				.filter
-				.can_play_immediately(game.card(CardPath::TopCardIn(DeckPath::Hand(player_index), card_id)))
-		} else {
-			false
-		}
+		// A drawn card may already have been played, like an item placed as soon as it was drawn.
+		card
+			.and_then(|card_id| {
+				game.maybe_card(CardPath::TopCardIn(DeckPath::Hand(player_index), card_id))
+			})
+			.map(|card| self.filter.can_play_immediately(card))
+			.unwrap_or(false)
 	}
 }
 
@@ -87,7 +89,7 @@ impl PlayerTask for QuickDrawStyle {
 			return Ok(TaskProgressResult::TaskComplete);
 		}
 		let default_choice = context.id_generator.generate();
-		let mut options = vec![TasksChoice::new(
+		let mut options = vec![TasksChoice::prepend(
 			default_choice,
 			Choice::DoNotPlayImmediately,
 			ChoiceDisplayType::No,
@@ -100,7 +102,7 @@ impl PlayerTask for QuickDrawStyle {
 			choices_type: ChoicesType::PlayOneOfImmediately,
 			options,
 			default_choice: Some(default_choice),
-			timeline: deadlines::get_refactor_me_deadline(),
+			timeline: deadlines::get_refactor_me_deadline(context),
 		});
 		Ok(TaskProgressResult::TaskComplete)
 	}
@@ -7,14 +7,20 @@ use crate::slay::state::game::Game;
 use crate::slay::tasks::core::discard::Discard;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::task_params::CardParam;
 
 #[derive(Clone, Debug)]
-pub struct SlipperyPaws {}
+pub struct SlipperyPaws {
+	first_card: CardParam,
+	second_card: CardParam,
+}
 
 impl SlipperyPaws {
-	pub fn create() -> Box<dyn PlayerTask> {
-		Box::new(Self {}) as Box<dyn PlayerTask>
+	pub fn create(first_card: CardParam, second_card: CardParam) -> Box<dyn PlayerTask> {
+		Box::new(Self {
+			first_card,
+			second_card,
+		}) as Box<dyn PlayerTask>
 	}
 }
 
@@ -25,10 +31,8 @@ impl PlayerTask for SlipperyPaws {
 		game: &mut Game,
 		player_index: ids::PlayerIndex,
 	) -> SlayResult<TaskProgressResult> {
-		let first_card =
-			game.card_param(player_index, &TaskParamName::SlipperyPawsVictimPulledCard1)?;
-		let second_card =
-			game.card_param(player_index, &TaskParamName::SlipperyPawsVictimPulledCard2)?;
+		let first_card = game.card_param(player_index, &self.first_card)?;
+		let second_card = game.card_param(player_index, &self.second_card)?;
 		if first_card.is_none() || second_card.is_none() {
 			return Ok(TaskProgressResult::TaskComplete);
 		}
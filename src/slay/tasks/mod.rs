@@ -3,3 +3,4 @@ pub mod heros;
 pub mod player_tasks;
 pub mod task_params;
 pub mod tasks;
+pub mod trace;
@@ -1,12 +1,17 @@
 use crate::slay::errors;
-use crate::slay::errors::SlayError;
 use crate::slay::errors::SlayResult;
 use crate::slay::game_context::GameBookKeeping;
 use crate::slay::ids;
 use crate::slay::state::game::Game;
+use crate::slay::state::summarizable;
 use crate::slay::state::summarizable::Summarizable;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::task_params::CardParam;
+use crate::slay::tasks::task_params::PlayerParam;
 use crate::slay::tasks::task_params::TaskParams;
+use crate::slay::tasks::trace::TraceResult;
+use crate::slay::tasks::trace::TraceStep;
+use serde::Deserialize;
+use serde::Serialize;
 
 use core::fmt::Debug;
 use std::collections::VecDeque;
@@ -45,6 +50,13 @@ pub trait PlayerTask: Debug + dyn_clone::DynClone {
 	fn label(&self) -> String;
 }
 
+// What a player is about to do, only shown in the debugging view.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct TasksPerspective {
+	pub labels: Vec<String>,
+	pub params: String,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct PlayerTasks {
 	// Maybe this should be a stack?
@@ -100,6 +112,19 @@ impl Summarizable for PlayerTasks {
 }
 
 impl PlayerTasks {
+	pub fn to_perspective(&self) -> TasksPerspective {
+		TasksPerspective {
+			labels: self
+				.prepend
+				.iter()
+				.chain(self.current.iter())
+				.chain(self.upcoming.iter())
+				.map(|task| task.label())
+				.collect(),
+			params: format!("{:?}", self.params),
+		}
+	}
+
 	pub fn new(tasks: Vec<Box<dyn PlayerTask>>) -> Self {
 		Self {
 			upcoming: VecDeque::from(tasks),
@@ -121,6 +146,11 @@ impl PlayerTasks {
 		// 	self.upcoming.push_front(task);
 		// }
 	}
+	// A choice's tasks answer what was asked before anything prepended while it was on offer
+	// (say, by a roll that finished meanwhile), so they go first.
+	pub fn prepend_answer_from(&mut self, to_take: &mut Vec<Box<dyn PlayerTask>>) {
+		self.prepend.splice(0..0, to_take.drain(..));
+	}
 	pub fn prepend(&mut self, next_task: Box<dyn PlayerTask>) {
 		self.prepend.push(next_task);
 	}
@@ -163,51 +193,80 @@ impl PlayerTasks {
 
 	pub(crate) fn set_player_value(
 		&mut self,
-		param_name: TaskParamName,
+		param: PlayerParam,
 		player_index: ids::PlayerIndex,
 	) -> SlayResult<()> {
-		if self
-			.params
-			.players
-			.insert(param_name, player_index)
-			.is_some()
-		{
-			Err(SlayError::new("Overwriting a parameter value."))
-		} else {
-			Ok(())
-		}
+		self.params.set_player(param, player_index)
 	}
 
 	pub(crate) fn set_card_value(
 		&mut self,
-		param_name: TaskParamName,
+		param: CardParam,
 		card_id: Option<ids::CardId>,
 	) -> SlayResult<()> {
-		if let Some(previous) = self.params.cards.insert(param_name, card_id) {
-			log::error!(
-				"Trying to set parameter {:?} to {:?}, but it was already set to {:?}",
-				param_name,
-				card_id,
-				previous
-			);
-			Err(SlayError::new("Overwriting a parameter value."))
-		} else {
-			Ok(())
-		}
+		self.params.set_card(param, card_id)
+	}
+	pub(crate) fn get_card_value(&self, param: &CardParam) -> Option<Option<ids::CardId>> {
+		self.params.card(param)
+	}
+
+	pub(crate) fn get_player_value(&self, param: &PlayerParam) -> Option<ids::PlayerIndex> {
+		self.params.player(param)
+	}
+
+	// The tasks prepended since the current one was taken.
+	pub(crate) fn prepended_labels(&self) -> Vec<String> {
+		self.prepend.iter().map(|task| task.label()).collect()
 	}
-	pub(crate) fn get_card_value(&self, param_name: &TaskParamName) -> Option<Option<ids::CardId>> {
-		self.params.cards.get(param_name).copied()
+
+	// One line, with each chain's frame separated by a bar.
+	pub(crate) fn describe_params(&self) -> String {
+		summarizable::summary(&self.params)
+			.lines()
+			.map(|line| {
+				line
+					.trim()
+					.trim_start_matches("params: ")
+					.trim_end_matches(',')
+			})
+			.collect::<Vec<_>>()
+			.join(" | ")
 	}
 
-	pub(crate) fn get_player_value(&self, param_name: &TaskParamName) -> Option<ids::PlayerIndex> {
-		self.params.players.get(param_name).copied()
+	pub fn open_params(&mut self) {
+		self.params.open();
 	}
 
-	pub fn clear_params(&mut self) {
-		self.params.clear();
+	pub fn close_params(&mut self) -> SlayResult<()> {
+		self.params.close()
 	}
 }
 
+fn trace_step(
+	context: &mut GameBookKeeping,
+	game: &Game,
+	player_index: ids::PlayerIndex,
+	label: &str,
+	progress: &SlayResult<TaskProgressResult>,
+	params_before: String,
+) {
+	let tasks = &game.players[player_index].tasks;
+	context.trace_task(TraceStep {
+		player_index,
+		label: label.to_owned(),
+		result: match progress {
+			Ok(TaskProgressResult::NothingDone) => TraceResult::NothingDone,
+			Ok(TaskProgressResult::ProgressMade) => TraceResult::ProgressMade,
+			Ok(TaskProgressResult::TaskComplete) => TraceResult::TaskComplete,
+			Err(error) => TraceResult::Failed(error.to_string()),
+		},
+		params_before,
+		params_after: tasks.describe_params(),
+		prepended: tasks.prepended_labels(),
+		parent: None,
+	});
+}
+
 pub(crate) fn continue_tasks(
 	context: &mut GameBookKeeping,
 	game: &mut Game,
@@ -221,14 +280,29 @@ pub(crate) fn continue_tasks(
 	*/
 	let mut result = TaskProgressResult::NothingDone;
 	loop {
+		// A task that just ran can have given them choices, that still counts as progress.
 		if game.players[player_index].has_choices() {
 			log::debug!("Player {} already has choices", player_index);
-			return Ok(TaskProgressResult::NothingDone);
+			return Ok(result);
 		}
 		if let Some(mut task) = game.take_current_task(player_index) {
 			let label = task.as_ref().label();
 			log::info!("Took task '{}'", label);
-			match task.make_progress(context, game, player_index)? {
+			let params_before = context
+				.is_tracing_tasks()
+				.then(|| game.players[player_index].tasks.describe_params());
+			let progress = task.make_progress(context, game, player_index);
+			if let Some(params_before) = params_before {
+				trace_step(
+					context,
+					game,
+					player_index,
+					&label,
+					&progress,
+					params_before,
+				);
+			}
+			match progress? {
 				TaskProgressResult::TaskComplete => {
 					result = TaskProgressResult::ProgressMade;
 					log::info!("Task '{}' complete", label);
@@ -249,3 +323,29 @@ pub(crate) fn continue_tasks(
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::slay::tasks::player_tasks::PlayerTask;
+	use crate::slay::tasks::player_tasks::PlayerTasks;
+	use crate::slay::tasks::tasks::remove_action_points::RemoveActionPointsTask;
+
+	fn task(amount: u32) -> Box<dyn PlayerTask> {
+		Box::new(RemoveActionPointsTask::new(amount))
+	}
+
+	fn next_label(tasks: &mut PlayerTasks) -> String {
+		tasks.take_current_task().unwrap().label()
+	}
+
+	#[test]
+	fn a_choice_is_answered_before_the_tasks_prepended_while_it_was_offered() {
+		let mut tasks = PlayerTasks::new(vec![task(3)]);
+		tasks.prepend(task(2));
+		tasks.prepend_answer_from(&mut vec![task(1)]);
+
+		assert_eq!(next_label(&mut tasks), task(1).label());
+		assert_eq!(next_label(&mut tasks), task(2).label());
+		assert_eq!(next_label(&mut tasks), task(3).label());
+	}
+}
@@ -1,161 +1,188 @@
+use serde::Deserialize;
+use serde::Serialize;
 use std::collections::HashMap;
-use std::collections::HashSet;
+use std::fmt;
 use std::io::BufWriter;
 use std::io::Write;
 
+use crate::slay::errors::SlayError;
+use crate::slay::errors::SlayResult;
+use crate::slay::i18n::english;
+use crate::slay::i18n::keys::MessageKey;
 use crate::slay::ids;
-use crate::slay::specs::cards::card_type::SlayCardSpec;
 use crate::slay::state::summarizable::Summarizable;
+use crate::slay::tasks::player_tasks::PlayerTask;
+use crate::slay::tasks::tasks::params::CloseParamsTask;
+use crate::slay::tasks::tasks::params::OpenParamsTask;
 
 /*
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-pub enum TaskPlayerParameterName {
+	Parameters pass values between the tasks of one chain, like the player chosen as the victim
+	and the card then taken from them.
+	A chain allocates its parameters from a ParamScope, which also wraps the chain's tasks so that
+	they run in a frame of their own. A chain started from inside another one, such as a hero
+	played immediately after it was pulled, gets a new frame and cannot overwrite or clear the
+	params of the chain it interrupted.
+*/
 
+// The slot within its chain, along with what the player is asked when choosing it.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub struct PlayerParam {
+	slot: u32,
+	prompt: MessageKey,
 }
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-pub enum TaskCardParameterName {
 
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub struct CardParam {
+	slot: u32,
+	prompt: MessageKey,
 }
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-pub enum TaskSetParameterName {
-
-}*/
-
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-pub enum TaskParamName {
-	TipsyTootieVictim,
-	TipsyTootieCard,
-	ButtonsVictim,
-	ButtonsCard,
-	PanChuckFirstCard,
-	PanChuckSecondCard,
-	LuckBuckyVictim,
-	LuckBuckyCard,
-	PlayerToStealFrom,
-	CardToSteal,
-	PlayerToPullFrom,
-	PlayerToGiveItem,
-	StackToPlaceItemOn,
-	MeowzioVictim,
-	MeowzioCard,
-	PlayerToDestroy,
-	CardToDestroy,
-	SilentShadowVictim,
-	SilentShadowCard,
-	SlipperyPawsVictim,
-	SlipperyPawsVictimPulledCard1,
-	SlipperyPawsVictimPulledCard2,
-	SlyPickinsVictim,
-	SlyPickinsCard,
-	ForcedExchangeVictim,
-	ForcedExchangeVictimCard,
-	ForcedExchangeSelf,
-	ForcedExchangeVictimDonationCard,
-	ShadowClawVictim,
-	HeavyBearVictim,
-	MellowDeeCard,
-	DodgyDealerVictim,
-	HopperVictim,
-	WigglesVictim,
-	WigglesCard,
-	SnowballCard,
-	BearClawVictim,
-	BearClawCard,
-	FuryKnuckleVictim,
-	FuryKnuckleCard,
-	QuickDrawCard1,
-	QuickDrawCard2,
-	ShurikittyVictim,
-	ShurikittyCard,
-	SharpFoxVictim,
+
+impl PlayerParam {
+	pub fn prompt_key(&self) -> MessageKey {
+		self.prompt
+	}
+
+	pub fn prompt(&self) -> &'static str {
+		english::template(self.prompt)
+	}
 }
 
-impl TaskParamName {
+impl CardParam {
+	pub fn prompt_key(&self) -> MessageKey {
+		self.prompt
+	}
+
 	pub fn prompt(&self) -> &'static str {
-		match self {
-			TaskParamName::ButtonsVictim => {
-				"Who would you like to pull from? (If it is magic, you can play it immediately.)"
-			}
-			TaskParamName::ButtonsCard => "N/A",
-			TaskParamName::PanChuckFirstCard => "N/A",
-			TaskParamName::PanChuckSecondCard => "N/A",
-			TaskParamName::LuckBuckyVictim => {
-				"Who would you like to pull from? (If it is a hero card, you can play it immediately.)"
-			}
-			TaskParamName::LuckBuckyCard => "N/A",
-			TaskParamName::PlayerToStealFrom => "Which player would you like to steal from?",
-			TaskParamName::CardToSteal => "Which hero card would you like to steal?",
-			TaskParamName::PlayerToPullFrom => "Choose a player to pull from.",
-			TaskParamName::PlayerToGiveItem => {
-				"Which player has the hero card you would like to place this item on?"
-			}
-			TaskParamName::StackToPlaceItemOn => "Which hero card would you like to place this item on?",
-			TaskParamName::MeowzioVictim => "Choose a player to steal and pull from.",
-			TaskParamName::MeowzioCard => "Which hero card would you like to steal?",
-			TaskParamName::PlayerToDestroy => "Which player should destroy a hero card?",
-			TaskParamName::CardToDestroy => "Which hero card would you like to destroy?",
-			TaskParamName::SilentShadowVictim => "Who's hand do you want to see?",
-			TaskParamName::SilentShadowCard => "Which hero card would you like to take?",
-			TaskParamName::SlipperyPawsVictim => {
-				"Choose a player to pull 2 cards from, you will have to discard one of them."
-			}
-			TaskParamName::SlipperyPawsVictimPulledCard1 => "N/A",
-			TaskParamName::SlipperyPawsVictimPulledCard2 => "N/A",
-			TaskParamName::SlyPickinsVictim => {
-				"Who do you want to steal from? (If it is magic, you can play it immediately.)"
-			}
-			TaskParamName::SlyPickinsCard => "N/A",
-			TaskParamName::ShadowClawVictim => "Choose a player to steal from.",
-			TaskParamName::HeavyBearVictim => "Who should discard 2 cards?",
-			TaskParamName::MellowDeeCard => "N/A",
-			TaskParamName::DodgyDealerVictim => "Who do you want to trade hands with?",
-			TaskParamName::HopperVictim => "Who should sacrifice a hero card?",
-			TaskParamName::WigglesVictim => "Wiggles: Who do you want to steal a hero card from?",
-			TaskParamName::WigglesCard => "Which hero card would you like to steal?",
-			TaskParamName::SnowballCard => "N/A",
-			TaskParamName::BearClawVictim => {
-				"Who would you like to pull from? (If you pull a hero, you can play it immediately.)"
-			}
-			TaskParamName::BearClawCard => "N/A",
-			TaskParamName::FuryKnuckleVictim => {
-				"Who would you like to pull from? (If it is a challenge card, you can pull again.)"
-			}
-			TaskParamName::FuryKnuckleCard => "N/A",
-			TaskParamName::QuickDrawCard1 => "N/A",
-			TaskParamName::QuickDrawCard2 => "N/A",
-			TaskParamName::ShurikittyVictim => {
-				"Who would you like to destroy a card from? (You will receive that card's items.)"
-			}
-			TaskParamName::ShurikittyCard => {
-				"Which hero card would you like to destroy? (You will receive that card's items.)"
-			}
-			TaskParamName::ForcedExchangeVictim => "Choose a player to forcefully exchange heros with.",
-			TaskParamName::ForcedExchangeVictimCard => "Which hero card would you like to steal?",
-			TaskParamName::ForcedExchangeSelf => "N/A",
-			TaskParamName::ForcedExchangeVictimDonationCard => {
-				"Which hero card would you like to move to their hand?"
-			}
-			TaskParamName::TipsyTootieVictim => "Who would you like to steal a card from?",
-			TaskParamName::TipsyTootieCard => "Which card would you like to steal?",
-			TaskParamName::SharpFoxVictim => "Whose hand would you like to see?",
-		}
+		english::template(self.prompt)
+	}
+}
+
+// Task labels are only read by developers, so the prompt is always in English.
+impl fmt::Display for PlayerParam {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "#{} ({})", self.slot, self.prompt())
+	}
+}
+
+impl fmt::Display for CardParam {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "#{} ({})", self.slot, self.prompt())
+	}
+}
+
+#[derive(Debug, Default)]
+pub struct ParamScope {
+	next_slot: u32,
+}
+
+impl ParamScope {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn player(&mut self, prompt: MessageKey) -> PlayerParam {
+		let slot = self.next_slot;
+		self.next_slot += 1;
+		PlayerParam { slot, prompt }
+	}
+
+	pub fn card(&mut self, prompt: MessageKey) -> CardParam {
+		let slot = self.next_slot;
+		self.next_slot += 1;
+		CardParam { slot, prompt }
+	}
+
+	// The tasks, run in a new frame that is dropped once they are done.
+	pub fn chain(self, tasks: Vec<Box<dyn PlayerTask>>) -> Vec<Box<dyn PlayerTask>> {
+		let mut chain = Vec::with_capacity(tasks.len() + 2);
+		chain.push(OpenParamsTask::create());
+		chain.extend(tasks);
+		chain.push(CloseParamsTask::create());
+		chain
 	}
 }
 
 #[derive(Debug, Default, Clone)]
-pub struct TaskParams {
+struct ParamFrame {
 	// These should probably be card paths, right?
-	pub players: HashMap<TaskParamName, ids::PlayerIndex>,
-	// None of the player did not choose a card.
-	pub cards: HashMap<TaskParamName, Option<ids::CardId>>,
-	pub sets: HashMap<TaskParamName, HashSet<SlayCardSpec>>,
+	players: HashMap<PlayerParam, ids::PlayerIndex>,
+	// None if the player did not choose a card.
+	cards: HashMap<CardParam, Option<ids::CardId>>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct TaskParams {
+	// The innermost chain's frame is last.
+	frames: Vec<ParamFrame>,
 }
 
 impl TaskParams {
-	pub fn clear(&mut self) {
-		self.players.clear();
-		self.cards.clear();
-		self.sets.clear();
+	pub fn open(&mut self) {
+		self.frames.push(Default::default());
+	}
+
+	pub fn close(&mut self) -> SlayResult<()> {
+		self
+			.frames
+			.pop()
+			.map(|_| ())
+			.ok_or_else(|| SlayError::new("Closing parameters that were never opened."))
+	}
+
+	fn current(&mut self) -> SlayResult<&mut ParamFrame> {
+		self
+			.frames
+			.last_mut()
+			.ok_or_else(|| SlayError::new("Setting a parameter outside of a task chain."))
+	}
+
+	pub(crate) fn set_player(
+		&mut self,
+		param: PlayerParam,
+		player_index: ids::PlayerIndex,
+	) -> SlayResult<()> {
+		if self
+			.current()?
+			.players
+			.insert(param, player_index)
+			.is_some()
+		{
+			Err(SlayError::new("Overwriting a parameter value."))
+		} else {
+			Ok(())
+		}
+	}
+
+	pub(crate) fn set_card(
+		&mut self,
+		param: CardParam,
+		card_id: Option<ids::CardId>,
+	) -> SlayResult<()> {
+		if let Some(previous) = self.current()?.cards.insert(param, card_id) {
+			log::error!(
+				"Trying to set parameter {:?} to {:?}, but it was already set to {:?}",
+				param,
+				card_id,
+				previous
+			);
+			Err(SlayError::new("Overwriting a parameter value."))
+		} else {
+			Ok(())
+		}
+	}
+
+	pub(crate) fn player(&self, param: &PlayerParam) -> Option<ids::PlayerIndex> {
+		self
+			.frames
+			.last()
+			.and_then(|frame| frame.players.get(param).copied())
+	}
+
+	pub(crate) fn card(&self, param: &CardParam) -> Option<Option<ids::CardId>> {
+		self
+			.frames
+			.last()
+			.and_then(|frame| frame.cards.get(param).copied())
 	}
 }
 
@@ -165,33 +192,34 @@ impl Summarizable for TaskParams {
 		f: &mut BufWriter<W>,
 		indentation_level: u32,
 	) -> Result<(), std::io::Error> {
-		if !self.cards.is_empty() {
-			for _ in 0..indentation_level {
-				write!(f, "  ")?;
-			}
-			write!(f, "card params: ")?;
-			for (name, value) in self.cards.iter() {
-				write!(f, "{:?}->{:?}, ", name, value)?;
-			}
-			writeln!(f)?;
-		}
-		if !self.players.is_empty() {
-			for _ in 0..indentation_level {
-				write!(f, "  ")?;
-			}
-			write!(f, "player params: ")?;
-			for (name, value) in self.players.iter() {
-				write!(f, "{:?}->{:?}, ", name, value)?;
-			}
-			writeln!(f)?;
-		}
-		if !self.sets.is_empty() {
+		for frame in self.frames.iter() {
+			// Hash maps iterate in a different order every run, so the values are listed by slot.
+			// Card ids are left out, only whether a card was chosen.
+			let mut entries: Vec<(u32, String)> = frame
+				.players
+				.iter()
+				.map(|(param, value)| (param.slot, format!("{:?}->player {}", param.prompt, value)))
+				.chain(frame.cards.iter().map(|(param, value)| {
+					(
+						param.slot,
+						format!(
+							"{:?}->{}",
+							param.prompt,
+							if value.is_some() { "chosen" } else { "none" }
+						),
+					)
+				}))
+				.collect();
+			if entries.is_empty() {
+				continue;
+			}
+			entries.sort();
 			for _ in 0..indentation_level {
 				write!(f, "  ")?;
 			}
-			write!(f, "sets params: ")?;
-			for (name, value) in self.sets.iter() {
-				write!(f, "{:?}->{:?}, ", name, value)?;
+			write!(f, "params: ")?;
+			for (_, entry) in entries.iter() {
+				write!(f, "{}, ", entry)?;
 			}
 			writeln!(f)?;
 		}
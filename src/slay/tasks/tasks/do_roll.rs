@@ -4,22 +4,38 @@ use crate::slay::errors::SlayResult;
 use crate::slay::game_context::GameBookKeeping;
 use crate::slay::ids;
 use crate::slay::showdown::completion::CompletionTracker;
-use crate::slay::showdown::current_showdown::ShowDown;
+use crate::slay::showdown::consequences::RollConsequences;
+use crate::slay::showdown::current_showdown;
+use crate::slay::showdown::current_showdown::ShowDownLevel;
+use crate::slay::showdown::roll_state::RollReason;
 use crate::slay::showdown::roll_state::RollState;
 use crate::slay::state::game::Game;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
 
+// The dice are only thrown once the roll starts, so that the roll is not decided while the
+// choice leading to it is still on offer, and it sees the effects gained in the meantime.
 #[derive(Clone, Debug)]
 pub struct DoRollTask {
-	roll: Option<RollState>,
+	roller_index: ids::PlayerIndex,
+	reason: RollReason,
+	consequences: Option<RollConsequences>,
 }
 
 impl DoRollTask {
-	pub fn new(roll: RollState) -> Self {
-		Self { roll: Some(roll) }
+	pub fn create(
+		roller_index: ids::PlayerIndex,
+		consequences: RollConsequences,
+		reason: RollReason,
+	) -> Box<dyn PlayerTask> {
+		Box::new(Self {
+			roller_index,
+			reason,
+			consequences: Some(consequences),
+		})
 	}
 }
+
 impl PlayerTask for DoRollTask {
 	fn make_progress(
 		&mut self,
@@ -27,19 +43,19 @@ impl PlayerTask for DoRollTask {
 		game: &mut Game,
 		_player_index: ids::PlayerIndex,
 	) -> SlayResult<TaskProgressResult> {
-		if let Some(mut roll) = self.roll.take() {
+		if let Some(consequences) = self.consequences.take() {
+			let mut roll = RollState::create(context, game, self.roller_index, consequences, self.reason);
 			roll.completion_tracker = Some(CompletionTracker::new(
 				game.number_of_players(),
-				deadlines::get_roll_deadline(),
+				deadlines::get_roll_deadline(context),
 			));
-			roll.assign_all_choices(context, game);
-			game.showdown.roll(roll);
+			current_showdown::begin(context, game, ShowDownLevel::Roll(roll));
 			Ok(TaskProgressResult::TaskComplete)
 		} else {
 			Err(SlayError::new("Can only perform a choice once..."))
 		}
 	}
 	fn label(&self) -> String {
-		format!("Doing a roll task for {:?}", self.roll)
+		format!("Doing a roll task for {:?}", self.reason)
 	}
 }
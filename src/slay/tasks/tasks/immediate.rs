@@ -18,7 +18,7 @@ use crate::slay::state::game::Game;
 use crate::slay::state::stack::Card;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::task_params::CardParam;
 
 use crate::slay::actions::place_hero;
 
@@ -28,29 +28,32 @@ pub fn create_play_card_immediately_task(
 	player_index: ids::PlayerIndex,
 	card: &Card,
 ) -> Option<Vec<Box<dyn PlayerTask>>> {
+	let hand_path = CardPath::TopCardIn(DeckPath::Hand(player_index), card.id);
 	match card.card_type {
 		SlayCardSpec::HeroCard(hero_card) => {
-			let hand_path = CardPath::TopCardIn(DeckPath::Hand(player_index), card.id);
 			let party_path = CardPath::TopCardIn(DeckPath::Party(player_index), card.id);
 			if game.maybe_card(hand_path).is_some() {
 				Some(place_hero::create_place_hero_challenges(
-					context,
 					game,
 					player_index,
 					hand_path,
 					hero_card,
 				))
 			} else if game.maybe_card(party_path).is_some() {
-				roll_for_ability::create_roll_for_ability_task(context, game, player_index, card, hero_card)
+				roll_for_ability::create_roll_for_ability_task(game, player_index, card, hero_card)
 					.map(|x| vec![x])
 			} else {
-				unreachable!()
+				None
 			}
 		}
+		// A drawn card can be gone from their hand by the time they are asked, like a stolen one.
+		SlayCardSpec::MagicCard(_) | SlayCardSpec::Item(_) if game.maybe_card(hand_path).is_none() => {
+			None
+		}
 		SlayCardSpec::MagicCard(spell) => Some(vec![cast_magic::create_cast_magic_task(
 			game,
 			player_index,
-			CardPath::TopCardIn(DeckPath::Hand(player_index), card.id),
+			hand_path,
 			spell,
 		)]),
 		SlayCardSpec::Item(_) => {
@@ -71,24 +74,15 @@ pub fn create_play_immediately_choices(
 	card: &Card,
 	mut extra_task: Option<Box<dyn PlayerTask>>,
 ) -> Option<Choices> {
-	let mut play_immediately_tasks = Vec::new();
-	if let Some(tasks) = create_play_card_immediately_task(context, game, player_index, card) {
-		play_immediately_tasks.extend(tasks);
-	}
-
-	if let Some(task) = extra_task.take() {
-		play_immediately_tasks.push(task);
-	}
-
-	// TODO: This still gives them an option of Yes/No when there are no choices...
-	if play_immediately_tasks.is_empty() {
-		return None;
-	}
+	// The extra task comes with playing the card, so there is nothing to offer if it cannot be played.
+	let mut play_immediately_tasks =
+		create_play_card_immediately_task(context, game, player_index, card)?;
+	play_immediately_tasks.extend(extra_task.take());
 
 	let default_choice = context.id_generator.generate();
 	Some(Choices {
 		choices_type: ChoicesType::PlayImmediately(card.card_type),
-		timeline: deadlines::get_refactor_me_deadline(),
+		timeline: deadlines::get_refactor_me_deadline(context),
 		default_choice: Some(default_choice),
 		options: vec![
 			TasksChoice::prepend(
@@ -145,13 +139,13 @@ impl PlayImmediatelyFilter {
 
 #[derive(Clone, Debug)]
 pub struct OfferPlayImmediately {
-	card_param: TaskParamName,
+	card_param: CardParam,
 	filter: PlayImmediatelyFilter,
 	extra_task: Option<Box<dyn PlayerTask>>,
 }
 
 impl OfferPlayImmediately {
-	pub fn create(card_param: TaskParamName, filter: PlayImmediatelyFilter) -> Box<dyn PlayerTask> {
+	pub fn create(card_param: CardParam, filter: PlayImmediatelyFilter) -> Box<dyn PlayerTask> {
 		Box::new(OfferPlayImmediately {
 			card_param,
 			filter,
@@ -160,7 +154,7 @@ impl OfferPlayImmediately {
 	}
 
 	pub fn with_an_extra_task(
-		card_param: TaskParamName,
+		card_param: CardParam,
 		filter: PlayImmediatelyFilter,
 		extra_task: Box<dyn PlayerTask>,
 	) -> Box<dyn PlayerTask> {
@@ -10,6 +10,7 @@ pub mod move_card;
 pub mod offer_challenges;
 pub mod params;
 pub mod place_hero;
+pub mod place_item;
 pub mod pull_again;
 pub mod receive_modifier;
 pub mod remove_action_points;
@@ -1,6 +1,7 @@
 use crate::slay::errors::SlayResult;
 use crate::slay::game_context::GameBookKeeping;
 use crate::slay::ids;
+use crate::slay::notification::Notification;
 
 use crate::slay::showdown::roll_modification::ModificationPath;
 use crate::slay::showdown::roll_modification::RollModification;
@@ -32,12 +33,16 @@ impl PlayerTask for ModifyRollTask {
 		_player_index: ids::PlayerIndex,
 	) -> SlayResult<TaskProgressResult> {
 		let modification = self.modification.to_owned();
+		context.emit(&Notification::Modification(
+			self.modification_path,
+			modification.to_owned(),
+		));
 
 		game
 			.showdown
-			.add_modification(self.modification_path, modification)?;
+			.add_modification(self.modification_path, modification, context.now())?;
 		let modification_task = game.showdown.get_modification_task(context, game);
-		modification_task.apply(context, game);
+		modification_task.apply(context, game)?;
 		Ok(TaskProgressResult::TaskComplete)
 	}
 	fn label(&self) -> String {
@@ -24,8 +24,8 @@ impl PlayerTask for MonsterSlainTask {
 			self.card_id,
 		)?;
 
-		if let Some(stack) = game.deck_mut(DeckPath::NextMonsters).maybe_deal() {
-			game.deck_mut(DeckPath::ActiveMonsters).add(stack);
+		if let Some(stack) = game.deal(DeckPath::NextMonsters) {
+			game.add_stack(DeckPath::ActiveMonsters, stack);
 		}
 		Ok(TaskProgressResult::TaskComplete)
 	}
@@ -5,7 +5,8 @@ use crate::slay::game_context::GameBookKeeping;
 use crate::slay::ids;
 use crate::slay::showdown::completion::Completion;
 use crate::slay::showdown::completion::CompletionTracker;
-use crate::slay::showdown::current_showdown::ShowDown;
+use crate::slay::showdown::current_showdown;
+use crate::slay::showdown::current_showdown::ShowDownLevel;
 use crate::slay::showdown::offer::OfferChallengesState;
 use crate::slay::state::game::Game;
 use crate::slay::tasks::player_tasks::PlayerTask;
@@ -14,11 +15,16 @@ use crate::slay::tasks::player_tasks::TaskProgressResult;
 #[derive(Clone, Debug)]
 pub struct OfferChallengesTask {
 	offer: Option<OfferChallengesState>,
+	// Set once the offer has begun, to the depth of the showdown it started.
+	depth: Option<usize>,
 }
 
 impl OfferChallengesTask {
 	pub fn new(offer: OfferChallengesState) -> Self {
-		Self { offer: Some(offer) }
+		Self {
+			offer: Some(offer),
+			depth: None,
+		}
 	}
 }
 impl PlayerTask for OfferChallengesTask {
@@ -29,18 +35,25 @@ impl PlayerTask for OfferChallengesTask {
 		_player_index: ids::PlayerIndex,
 	) -> SlayResult<TaskProgressResult> {
 		log::info!("making progress");
+		// The player's later tasks wait until the offer, and whatever it led to, is finished.
+		if let Some(depth) = self.depth {
+			if game.showdown.depth() >= depth {
+				return Ok(TaskProgressResult::NothingDone);
+			}
+			return Ok(TaskProgressResult::TaskComplete);
+		}
 		if let Some(mut offer) = self.offer.take() {
 			let mut completion_tracker = CompletionTracker::new(
 				game.number_of_players(),
-				deadlines::get_offer_challenges_deadline(),
+				deadlines::get_offer_challenges_deadline(context),
 			);
 			// The current player is not allowed to challenge himself...
 			completion_tracker.set_player_completion(offer.player_index, Completion::AllDone);
 			offer.completion_tracker = Some(completion_tracker);
-			offer.assign_all_choices(context, game);
-			game.showdown.offer(offer);
+			current_showdown::begin(context, game, ShowDownLevel::OfferChallenges(offer));
+			self.depth = Some(game.showdown.depth());
 			log::info!("set the offer...");
-			Ok(TaskProgressResult::TaskComplete)
+			Ok(TaskProgressResult::ProgressMade)
 		} else {
 			Err(SlayError::new("Can only perform a choice once..."))
 		}
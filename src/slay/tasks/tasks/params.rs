@@ -9,13 +9,15 @@ use crate::slay::errors::SlayError;
 use crate::slay::errors::SlayResult;
 use crate::slay::game_context::GameBookKeeping;
 use crate::slay::ids;
+use crate::slay::state::arena::CardArena;
 use crate::slay::state::deck::Deck;
 use crate::slay::state::deck::PartialDeckPath;
 use crate::slay::state::game::Game;
 use crate::slay::state::stack::Card;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::task_params::CardParam;
+use crate::slay::tasks::task_params::PlayerParam;
 
 // // TODO: remove this class...
 // #[derive(Clone, Debug)]
@@ -28,7 +30,7 @@ use crate::slay::tasks::task_params::TaskParamName;
 
 // #[derive(Clone, Debug)]
 // pub struct ChooseCardParameterTask {
-// 	pub param_name: TaskParamName,
+// 	pub param_name: CardParam,
 // 	pub instructions: String,
 // 	pub card_choices: Vec<CardChoiceInformation>,
 // }
@@ -49,31 +51,28 @@ use crate::slay::tasks::task_params::TaskParamName;
 #[derive(Clone, Debug)]
 pub struct ChoosePlayerParameterTask {
 	// pub parameter_type: TaskParameterType,
-	pub param_name: TaskParamName,
+	pub param_name: PlayerParam,
 	pub players: Option<Vec<ids::PlayerIndex>>,
 
 	exclude_self: bool,
 }
 
 impl ChoosePlayerParameterTask {
-	pub fn exclude_self(param_name: TaskParamName) -> Box<dyn PlayerTask> {
+	pub fn exclude_self(param_name: PlayerParam) -> Box<dyn PlayerTask> {
 		Box::new(Self {
 			param_name,
 			players: None,
 			exclude_self: true,
 		}) as Box<dyn PlayerTask>
 	}
-	pub fn include_self(
-		param_name: TaskParamName,
-		_instructions: &'static str,
-	) -> Box<dyn PlayerTask> {
+	pub fn include_self(param_name: PlayerParam, _instructions: &'static str) -> Box<dyn PlayerTask> {
 		Box::new(Self {
 			param_name,
 			players: None,
 			exclude_self: false,
 		}) as Box<dyn PlayerTask>
 	}
-	pub fn one_of(param_name: TaskParamName, players: Vec<ids::PlayerIndex>) -> Box<dyn PlayerTask> {
+	pub fn one_of(param_name: PlayerParam, players: Vec<ids::PlayerIndex>) -> Box<dyn PlayerTask> {
 		Box::new(Self {
 			param_name,
 			players: Some(players),
@@ -107,11 +106,10 @@ impl PlayerTask for ChoosePlayerParameterTask {
 		let choices = Choices {
 			choices_type: ChoicesType::ChoosePlayerParam(self.param_name),
 			default_choice: None,
-			timeline: deadlines::get_refactor_me_deadline(),
+			timeline: deadlines::get_refactor_me_deadline(context),
 			options: self
 				.get_player_indices(game, player_index)
 				.iter()
-				.filter(|index| **index != player_index)
 				.map(|victim_index| {
 					TasksChoice::prepend(
 						context.id_generator.generate(),
@@ -134,37 +132,18 @@ impl PlayerTask for ChoosePlayerParameterTask {
 	}
 }
 
-#[derive(Clone, Debug, PartialEq, Copy)]
-enum TaskParameterType {
-	Player,
-	Card,
-}
-
 #[derive(Debug, Clone)]
-pub struct SetParameterTask {
-	param_name: TaskParamName,
-	param_type: TaskParameterType,
-
-	player_value: Option<ids::PlayerIndex>,
-	card_value: Option<ids::CardId>,
+pub enum SetParameterTask {
+	Player(PlayerParam, ids::PlayerIndex),
+	Card(CardParam, ids::CardId),
 }
 
 impl SetParameterTask {
-	pub fn set_player(param_name: TaskParamName, chosen_player: ids::PlayerIndex) -> Self {
-		Self {
-			param_name,
-			param_type: TaskParameterType::Player,
-			player_value: Some(chosen_player),
-			card_value: None,
-		}
+	pub fn set_player(param: PlayerParam, chosen_player: ids::PlayerIndex) -> Self {
+		Self::Player(param, chosen_player)
 	}
-	pub fn set_card(param_name: TaskParamName, chosen_card: ids::CardId) -> Self {
-		Self {
-			param_name,
-			param_type: TaskParameterType::Card,
-			player_value: None,
-			card_value: Some(chosen_card),
-		}
+	pub fn set_card(param: CardParam, chosen_card: ids::CardId) -> Self {
+		Self::Card(param, chosen_card)
 	}
 }
 
@@ -176,53 +155,69 @@ impl PlayerTask for SetParameterTask {
 		chooser_player_index: ids::PlayerIndex,
 	) -> SlayResult<TaskProgressResult> {
 		let tasks = &mut game.players[chooser_player_index].tasks;
-		match self.param_type {
-			// TODO: Can this use generics or something?
-			TaskParameterType::Player => tasks.set_player_value(
-				self.param_name,
-				self
-					.player_value
-					.ok_or_else(|| SlayError::new("Expected a player value"))?,
-			),
-			TaskParameterType::Card => tasks.set_card_value(
-				self.param_name,
-				Some(
-					self
-						.card_value
-						.ok_or_else(|| SlayError::new("Expected a card value"))?,
-				),
-			),
+		match self {
+			Self::Player(param, player_index) => tasks.set_player_value(*param, *player_index),
+			Self::Card(param, card_id) => tasks.set_card_value(*param, Some(*card_id)),
 		}?;
 		Ok(TaskProgressResult::TaskComplete)
 	}
 
 	fn label(&self) -> String {
-		format!("Set parameter {:?} to something.", self.param_name)
+		match self {
+			Self::Player(param, _) => format!("Set parameter {} to a player.", param),
+			Self::Card(param, _) => format!("Set parameter {} to a card.", param),
+		}
+	}
+}
+
+// Starts the frame for a chain's params, see ParamScope.
+#[derive(Clone, Debug)]
+pub struct OpenParamsTask {}
+
+impl OpenParamsTask {
+	pub fn create() -> Box<dyn PlayerTask> {
+		Box::new(Self {}) as Box<dyn PlayerTask>
+	}
+}
+
+impl PlayerTask for OpenParamsTask {
+	fn make_progress(
+		&mut self,
+		_context: &mut GameBookKeeping,
+		game: &mut Game,
+		player_index: ids::PlayerIndex,
+	) -> SlayResult<TaskProgressResult> {
+		game.players[player_index].tasks.open_params();
+		Ok(TaskProgressResult::TaskComplete)
+	}
+
+	fn label(&self) -> String {
+		"Opening a players task parameter state.".to_string()
 	}
 }
 
 #[derive(Clone, Debug)]
-pub struct ClearParamsTask {}
+pub struct CloseParamsTask {}
 
-impl ClearParamsTask {
+impl CloseParamsTask {
 	pub fn create() -> Box<dyn PlayerTask> {
 		Box::new(Self {}) as Box<dyn PlayerTask>
 	}
 }
 
-impl PlayerTask for ClearParamsTask {
+impl PlayerTask for CloseParamsTask {
 	fn make_progress(
 		&mut self,
 		_context: &mut GameBookKeeping,
 		game: &mut Game,
 		player_index: ids::PlayerIndex,
 	) -> SlayResult<TaskProgressResult> {
-		game.players[player_index].tasks.clear_params();
+		game.players[player_index].tasks.close_params()?;
 		Ok(TaskProgressResult::TaskComplete)
 	}
 
 	fn label(&self) -> String {
-		"Clearing a players task parameter state.".to_string()
+		"Closing a players task parameter state.".to_string()
 	}
 }
 
@@ -235,16 +230,16 @@ pub enum ChooseCardFilter {
 // Rename to ChooseCardParameterTask
 #[derive(Clone, Debug)]
 pub struct ChooseCardFromPlayerParameterTask {
-	victim_param: TaskParamName,
-	card_param: TaskParamName,
+	victim_param: PlayerParam,
+	card_param: CardParam,
 	deck_path: PartialDeckPath,
 	card_filter: ChooseCardFilter,
 }
 
 impl ChooseCardFromPlayerParameterTask {
 	pub fn create(
-		victim_param: TaskParamName,
-		card_param: TaskParamName,
+		victim_param: PlayerParam,
+		card_param: CardParam,
 		deck_path: PartialDeckPath,
 		_instructions: &'static str,
 	) -> Box<dyn PlayerTask> {
@@ -257,8 +252,8 @@ impl ChooseCardFromPlayerParameterTask {
 	}
 
 	pub fn modifying_cards(
-		victim_param: TaskParamName,
-		card_param: TaskParamName,
+		victim_param: PlayerParam,
+		card_param: CardParam,
 		deck_path: PartialDeckPath,
 		_instructions: &'static str,
 	) -> Box<dyn PlayerTask> {
@@ -270,7 +265,7 @@ impl ChooseCardFromPlayerParameterTask {
 		}) as Box<dyn PlayerTask>
 	}
 
-	pub fn from_party(victim_param: TaskParamName, card_param: TaskParamName) -> Box<dyn PlayerTask> {
+	pub fn from_party(victim_param: PlayerParam, card_param: CardParam) -> Box<dyn PlayerTask> {
 		Box::new(Self {
 			victim_param,
 			card_param,
@@ -279,17 +274,17 @@ impl ChooseCardFromPlayerParameterTask {
 		}) as Box<dyn PlayerTask>
 	}
 
-	fn create_card_choices(&self, deck: &Deck) -> Vec<Card> {
+	fn create_card_choices(&self, cards: &CardArena, deck: &Deck) -> Vec<Card> {
 		// I was over here...
 		match self.card_filter {
-			ChooseCardFilter::AllTopCards => deck.tops().map(|card| card.to_owned()).collect(),
+			ChooseCardFilter::AllTopCards => deck.tops(cards).map(|card| card.to_owned()).collect(),
 			ChooseCardFilter::Modifying => deck
 				.stacks()
 				.flat_map(|stack| {
 					stack
 						.modifiers
 						.iter()
-						.map(|card| card.to_owned())
+						.map(|card_id| cards.card(*card_id).to_owned())
 						.collect::<Vec<_>>()
 				})
 				.collect(),
@@ -310,7 +305,7 @@ impl PlayerTask for ChooseCardFromPlayerParameterTask {
 			.ok_or_else(|| SlayError::new("The parameter must be set."))?;
 
 		let deck_path = self.deck_path.to_deck_path(victim_param);
-		let card_choices: Vec<Card> = self.create_card_choices(game.deck(deck_path));
+		let card_choices: Vec<Card> = self.create_card_choices(&game.cards, game.deck(deck_path));
 
 		if card_choices.is_empty() {
 			game.players[chooser_index]
@@ -321,7 +316,7 @@ impl PlayerTask for ChooseCardFromPlayerParameterTask {
 		game.players[chooser_index].choose(Choices {
 			default_choice: None,
 			choices_type: ChoicesType::ChooseCardParam(self.card_param),
-			timeline: deadlines::get_refactor_me_deadline(),
+			timeline: deadlines::get_refactor_me_deadline(context),
 			options: card_choices
 				.iter()
 				.map(|card_choice| {
@@ -347,11 +342,11 @@ impl PlayerTask for ChooseCardFromPlayerParameterTask {
 
 #[derive(Debug, Clone)]
 pub struct SetParameterToMyself {
-	param_name: TaskParamName,
+	param_name: PlayerParam,
 }
 
 impl SetParameterToMyself {
-	pub fn create(param_name: TaskParamName) -> Box<dyn PlayerTask> {
+	pub fn create(param_name: PlayerParam) -> Box<dyn PlayerTask> {
 		Box::new(Self { param_name })
 	}
 }
@@ -370,6 +365,6 @@ impl PlayerTask for SetParameterToMyself {
 	}
 
 	fn label(&self) -> String {
-		format!("Set parameter {:?} to myself.", self.param_name)
+		format!("Set parameter {} to myself.", self.param_name)
 	}
 }
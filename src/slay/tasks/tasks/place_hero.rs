@@ -34,14 +34,13 @@ impl PlayerTask for PlaceHero {
 		player_index: ids::PlayerIndex,
 	) -> SlayResult<TaskProgressResult> {
 		let mut options = Vec::new();
-		for card in game.players[player_index].hand.tops() {
+		for card in game.players[player_index].hand.tops(&game.cards) {
 			if let SlayCardSpec::HeroCard(hero_card) = card.card_type {
-				options.push(TasksChoice::new(
+				options.push(TasksChoice::prepend(
 					context.id_generator.generate(),
 					Choice::PlaceHeroImmediately(hero_card),
 					ChoiceDisplayType::hand_card(player_index, card.id),
 					place_hero::create_place_hero_challenges(
-						context,
 						game,
 						player_index,
 						CardPath::TopCardIn(DeckPath::Hand(player_index), card.id),
@@ -57,7 +56,7 @@ impl PlayerTask for PlaceHero {
 			choices_type: ChoicesType::PlaceAHeroCard,
 			options,
 			default_choice: None,
-			timeline: deadlines::get_refactor_me_deadline(),
+			timeline: deadlines::get_refactor_me_deadline(context),
 		});
 		Ok(TaskProgressResult::TaskComplete)
 	}
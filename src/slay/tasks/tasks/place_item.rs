@@ -0,0 +1,61 @@
+use crate::slay::choices::CardPath;
+use crate::slay::errors::SlayResult;
+use crate::slay::game_context::GameBookKeeping;
+use crate::slay::ids;
+use crate::slay::state::deck::DeckPath;
+use crate::slay::state::game::Game;
+use crate::slay::tasks::player_tasks::PlayerTask;
+use crate::slay::tasks::player_tasks::TaskProgressResult;
+use crate::slay::tasks::task_params::CardParam;
+use crate::slay::tasks::task_params::PlayerParam;
+
+// Moves the item from the placer's hand onto the chosen hero card.
+#[derive(Debug, Clone)]
+pub struct PlaceItem {
+	item_id: ids::CardId,
+	recipient_param: PlayerParam,
+	hero_param: CardParam,
+}
+
+impl PlaceItem {
+	pub fn create(
+		item_id: ids::CardId,
+		recipient_param: PlayerParam,
+		hero_param: CardParam,
+	) -> Box<dyn PlayerTask> {
+		Box::new(Self {
+			item_id,
+			recipient_param,
+			hero_param,
+		}) as Box<dyn PlayerTask>
+	}
+}
+
+impl PlayerTask for PlaceItem {
+	fn make_progress(
+		&mut self,
+		_context: &mut GameBookKeeping,
+		game: &mut Game,
+		player_index: ids::PlayerIndex,
+	) -> SlayResult<TaskProgressResult> {
+		let recipient_index = game.player_param(player_index, &self.recipient_param)?;
+		let Some(hero_id) = game.card_param(player_index, &self.hero_param)? else {
+			return Ok(TaskProgressResult::TaskComplete);
+		};
+		// The item may have left the hand while its placement was being challenged.
+		let hand = DeckPath::Hand(player_index);
+		if game
+			.maybe_card(CardPath::TopCardIn(hand, self.item_id))
+			.is_none()
+		{
+			return Ok(TaskProgressResult::TaskComplete);
+		}
+		let item = game.take_stack(hand, self.item_id)?;
+		game.attach_stack(DeckPath::Party(recipient_index), hero_id, item)?;
+		Ok(TaskProgressResult::TaskComplete)
+	}
+
+	fn label(&self) -> String {
+		format!("Placing item {}", self.item_id)
+	}
+}
@@ -8,20 +8,21 @@ use crate::slay::state::game::Game;
 use crate::slay::tasks::core::pull::PullFromTask;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::task_params::CardParam;
+use crate::slay::tasks::task_params::PlayerParam;
 use crate::slay::tasks::tasks::immediate::PlayImmediatelyFilter;
 
 #[derive(Clone, Debug)]
 pub struct PullAgain {
-	victim_param: TaskParamName,
-	card_param: TaskParamName,
+	victim_param: PlayerParam,
+	card_param: CardParam,
 	filter: PlayImmediatelyFilter,
 }
 
 impl PullAgain {
 	pub fn create(
-		victim_param: TaskParamName,
-		card_param: TaskParamName,
+		victim_param: PlayerParam,
+		card_param: CardParam,
 		filter: PlayImmediatelyFilter,
 	) -> Box<dyn PlayerTask> {
 		Box::new(Self {
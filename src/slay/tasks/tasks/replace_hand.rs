@@ -1,6 +1,7 @@
 use crate::slay::errors::SlayResult;
 use crate::slay::game_context::GameBookKeeping;
 use crate::slay::ids;
+use crate::slay::state::deck::DeckPath;
 use crate::slay::state::game::Game;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
@@ -15,13 +16,9 @@ impl PlayerTask for ReplaceHandTask {
 		game: &mut Game,
 		player_index: ids::PlayerIndex,
 	) -> SlayResult<TaskProgressResult> {
-		game
-			.discard
-			.extend(game.players[player_index].hand.drain(..));
+		game.move_stacks(DeckPath::Hand(player_index), DeckPath::Discard, ..);
 		game.replentish_for(5);
-		game.players[player_index]
-			.hand
-			.extend(game.draw.drain(0..5));
+		game.move_stacks(DeckPath::Draw, DeckPath::Hand(player_index), 0..5);
 		Ok(TaskProgressResult::TaskComplete)
 	}
 
@@ -59,10 +59,11 @@ impl PlayerTask for ReturnModifierTask {
 		let mut options = Vec::new();
 		for player_index in player_indices {
 			for stack in game.players[player_index].party.stacks() {
-				if let SlayCardSpec::HeroCard(hero_card) = stack.top.card_type {
+				if let SlayCardSpec::HeroCard(hero_card) = game.cards.card_type(stack.top) {
 					for modifier in stack.modifiers.iter() {
+						let modifier = game.cards.card(*modifier);
 						if let SlayCardSpec::Item(item_type) = modifier.card_type {
-							options.push(TasksChoice::new(
+							options.push(TasksChoice::prepend(
 								context.id_generator.generate(),
 								Choice::ReturnItem(item_type, hero_card),
 								ChoiceDisplayType::Card_(modifier.card_type),
@@ -88,7 +89,7 @@ impl PlayerTask for ReturnModifierTask {
 			choices_type: ChoicesType::ReturnAnItemCard,
 			default_choice: None,
 			options,
-			timeline: deadlines::get_refactor_me_deadline(),
+			timeline: deadlines::get_refactor_me_deadline(context),
 		});
 		Ok(TaskProgressResult::TaskComplete)
 	}
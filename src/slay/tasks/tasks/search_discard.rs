@@ -5,6 +5,7 @@ use crate::slay::choices::TasksChoice;
 use crate::slay::deadlines;
 use crate::slay::errors::SlayResult;
 use crate::slay::game_context::GameBookKeeping;
+use crate::slay::i18n::keys::MessageKey;
 use crate::slay::ids;
 use crate::slay::state::deck::DeckPath;
 use crate::slay::state::game::Game;
@@ -12,8 +13,10 @@ use crate::slay::state::stack::Card;
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
 use crate::slay::tasks::tasks::move_card::MoveCardTask;
+use serde::Deserialize;
+use serde::Serialize;
 
-#[derive(Clone, Debug, Copy, PartialEq)]
+#[derive(Clone, Debug, Copy, PartialEq, Serialize, Deserialize)]
 pub enum SearchDiscardFilters {
 	IsHero,
 	IsModifier,
@@ -30,12 +33,12 @@ impl SearchDiscardFilters {
 			SearchDiscardFilters::IsMagic => card.is_magic(),
 		}
 	}
-	pub fn description(&self) -> &'static str {
+	pub fn description_key(&self) -> MessageKey {
 		match self {
-			SearchDiscardFilters::IsHero => "a hero card",
-			SearchDiscardFilters::IsModifier => "a modifier card",
-			SearchDiscardFilters::IsItem => "an item card",
-			SearchDiscardFilters::IsMagic => "a magic card",
+			SearchDiscardFilters::IsHero => MessageKey::FilterHero,
+			SearchDiscardFilters::IsModifier => MessageKey::FilterModifier,
+			SearchDiscardFilters::IsItem => MessageKey::FilterItem,
+			SearchDiscardFilters::IsMagic => MessageKey::FilterMagic,
 		}
 	}
 }
@@ -93,10 +96,10 @@ pub fn create_search_discard_choices(
 ) -> Option<Choices> {
 	let options = game
 		.deck(DeckPath::Discard)
-		.tops()
+		.tops(&game.cards)
 		.filter(|card| filter.filter(card))
 		.map(|card| {
-			TasksChoice::new(
+			TasksChoice::prepend(
 				context.id_generator.generate(),
 				Choice::ChooseDiscardedCard(card.card_type),
 				card.as_choice(),
@@ -115,6 +118,6 @@ pub fn create_search_discard_choices(
 		choices_type: ChoicesType::SearchDiscard(filter),
 		default_choice: None,
 		options,
-		timeline: deadlines::get_refactor_me_deadline(), // This one should probably be longer...
+		timeline: deadlines::get_refactor_me_deadline(context), // This one should probably be longer...
 	})
 }
@@ -42,7 +42,7 @@ fn create_set_complete_choice(
 	persist: Completion,
 	_label: String,
 ) -> TasksChoice {
-	TasksChoice::new(
+	TasksChoice::prepend(
 		id,
 		crate::slay::choices::Choice::SetCompletion(persist),
 		ChoiceDisplayType::SetCompletion(persist),
@@ -2,20 +2,20 @@ use crate::slay::{
 	errors::SlayResult,
 	game_context::GameBookKeeping,
 	ids,
-	state::game::Game,
+	state::{deck::DeckPath, game::Game},
 	tasks::{
 		player_tasks::{PlayerTask, TaskProgressResult},
-		task_params::TaskParamName,
+		task_params::PlayerParam,
 	},
 };
 
 #[derive(Clone, Debug)]
 pub struct TradeHands {
-	param: TaskParamName,
+	param: PlayerParam,
 }
 
 impl TradeHands {
-	pub fn create(param: TaskParamName) -> Box<dyn PlayerTask> {
+	pub fn create(param: PlayerParam) -> Box<dyn PlayerTask> {
 		Box::new(Self { param })
 	}
 }
@@ -29,15 +29,10 @@ impl PlayerTask for TradeHands {
 	) -> SlayResult<TaskProgressResult> {
 		let victim_index = game.player_param(player_index, &self.param)?;
 
-		let mut victim_cards = Vec::new();
-		let mut my_cards = Vec::new();
-
-		victim_cards.extend(game.players[victim_index].hand.drain(..));
-		my_cards.extend(game.players[player_index].hand.drain(..));
-		game.players[player_index]
-			.hand
-			.extend(victim_cards.drain(..));
-		game.players[victim_index].hand.extend(my_cards.drain(..));
+		let victim_cards = game.take_stacks(DeckPath::Hand(victim_index), ..);
+		let my_cards = game.take_stacks(DeckPath::Hand(player_index), ..);
+		game.add_stacks(DeckPath::Hand(player_index), victim_cards);
+		game.add_stacks(DeckPath::Hand(victim_index), my_cards);
 		Ok(TaskProgressResult::TaskComplete)
 	}
 
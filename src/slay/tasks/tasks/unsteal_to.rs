@@ -7,19 +7,18 @@ use crate::slay::specs::hero::HeroAbilityType;
 use crate::slay::state::deck::DeckPath;
 use crate::slay::state::game::Game;
 use crate::slay::tasks::player_tasks::{PlayerTask, TaskProgressResult};
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::task_params::PlayerParam;
 
 #[derive(Clone, Debug)]
 pub struct UnstealTo {
-	victim_param: TaskParamName,
+	victim_param: PlayerParam,
 	// hero_card: HeroAbilityType,
 }
 
 impl UnstealTo {
-	pub fn create(// victim_param: TaskParamName,
-	) -> Box<dyn PlayerTask> {
+	pub fn create(victim_param: PlayerParam) -> Box<dyn PlayerTask> {
 		Box::new(Self {
-			victim_param: TaskParamName::TipsyTootieVictim,
+			victim_param,
 			// hero_card: HeroAbilityType::TipsyTootie,
 		})
 	}
@@ -36,7 +35,7 @@ impl PlayerTask for UnstealTo {
 		let victim_index = game.player_param(player_index, &self.victim_param)?;
 		let maybe_card = game
 			.deck(DeckPath::Party(player_index))
-			.tops()
+			.tops(&game.cards)
 			.find(|card| {
 				matches!(
 					card.card_type,
@@ -5,15 +5,15 @@ use crate::slay::state::game::Game;
 
 use crate::slay::tasks::player_tasks::PlayerTask;
 use crate::slay::tasks::player_tasks::TaskProgressResult;
-use crate::slay::tasks::task_params::TaskParamName;
+use crate::slay::tasks::task_params::PlayerParam;
 
 #[derive(Clone, Debug)]
 pub struct ViewHand {
-	victim_param: TaskParamName,
+	victim_param: PlayerParam,
 }
 
 impl ViewHand {
-	pub fn create(victim_param: TaskParamName) -> Box<dyn PlayerTask> {
+	pub fn create(victim_param: PlayerParam) -> Box<dyn PlayerTask> {
 		Box::new(Self { victim_param }) as Box<dyn PlayerTask>
 	}
 }
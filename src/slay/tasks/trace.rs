@@ -0,0 +1,222 @@
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt;
+
+use crate::slay::ids;
+
+/*
+	An opt-in record of every task the engine runs, for finding out why a chain of tasks stalled.
+	Each call to make_progress is one step. The tasks a step prepends run before anything else
+	the player has queued, so the steps that run them are recorded as its children. A task that
+	was put back shows up once for each time it was tried.
+*/
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TraceResult {
+	NothingDone,
+	ProgressMade,
+	TaskComplete,
+	Failed(String),
+}
+
+impl fmt::Display for TraceResult {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::NothingDone => write!(f, "nothing done"),
+			Self::ProgressMade => write!(f, "progress made"),
+			Self::TaskComplete => write!(f, "complete"),
+			Self::Failed(reason) => write!(f, "failed: {}", reason),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraceStep {
+	pub player_index: ids::PlayerIndex,
+	pub label: String,
+	pub result: TraceResult,
+	pub params_before: String,
+	pub params_after: String,
+	pub prepended: Vec<String>,
+	// The index of the step that prepended this task.
+	pub parent: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TaskTrace {
+	pub steps: Vec<TraceStep>,
+	// For each player, the steps whose prepended tasks have not all completed yet, with how many
+	// of them are left.
+	#[serde(skip)]
+	open: Vec<Vec<(usize, usize)>>,
+}
+
+impl TaskTrace {
+	pub(crate) fn record(&mut self, step: TraceStep) {
+		let player_index = step.player_index;
+		if self.open.len() <= player_index {
+			self.open.resize_with(player_index + 1, Vec::new);
+		}
+		let open = &mut self.open[player_index];
+		let index = self.steps.len();
+		let parent = open.last().map(|(parent, _)| *parent);
+		// A failed task is not tried again, so it is finished just like a completed one.
+		if matches!(
+			step.result,
+			TraceResult::TaskComplete | TraceResult::Failed(_)
+		) {
+			if let Some((_, remaining)) = open.last_mut() {
+				*remaining -= 1;
+			}
+			while matches!(open.last(), Some((_, 0))) {
+				open.pop();
+			}
+		}
+		if !step.prepended.is_empty() {
+			open.push((index, step.prepended.len()));
+		}
+		self.steps.push(TraceStep { parent, ..step });
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.steps.is_empty()
+	}
+
+	// One line per step, indented under the step that prepended it.
+	pub fn render(&self) -> String {
+		let mut children = vec![Vec::new(); self.steps.len()];
+		let mut roots = Vec::new();
+		for (index, step) in self.steps.iter().enumerate() {
+			match step.parent {
+				Some(parent) => children[parent].push(index),
+				None => roots.push(index),
+			}
+		}
+		let mut ret = String::new();
+		for index in roots {
+			self.render_step(&mut ret, &children, index, 0);
+		}
+		ret
+	}
+
+	fn render_step(&self, out: &mut String, children: &[Vec<usize>], index: usize, depth: usize) {
+		let step = &self.steps[index];
+		let indent = "  ".repeat(depth);
+		out.push_str(&format!(
+			"{}[{}] player {}: {} -> {}\n",
+			indent, index, step.player_index, step.label, step.result
+		));
+		if step.params_before != step.params_after {
+			let describe = |params: &str| {
+				if params.is_empty() {
+					"none".to_owned()
+				} else {
+					params.to_owned()
+				}
+			};
+			out.push_str(&format!(
+				"{}  params: {} -> {}\n",
+				indent,
+				describe(&step.params_before),
+				describe(&step.params_after)
+			));
+		}
+		if !step.prepended.is_empty() {
+			out.push_str(&format!(
+				"{}  prepended: {}\n",
+				indent,
+				step.prepended.join(", ")
+			));
+		}
+		for child in children[index].iter() {
+			self.render_step(out, children, *child, depth + 1);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn step(
+		player_index: ids::PlayerIndex,
+		label: &str,
+		result: TraceResult,
+		prepended: &[&str],
+	) -> TraceStep {
+		TraceStep {
+			player_index,
+			label: label.to_owned(),
+			result,
+			params_before: String::new(),
+			params_after: String::new(),
+			prepended: prepended.iter().map(|label| label.to_string()).collect(),
+			parent: None,
+		}
+	}
+
+	fn parents(trace: &TaskTrace) -> Vec<Option<usize>> {
+		trace.steps.iter().map(|step| step.parent).collect()
+	}
+
+	#[test]
+	fn prepended_tasks_are_nested_under_the_step_that_prepended_them() {
+		let mut trace = TaskTrace::default();
+		trace.record(step(0, "a", TraceResult::ProgressMade, &["b", "c"]));
+		trace.record(step(0, "b", TraceResult::TaskComplete, &["d"]));
+		trace.record(step(0, "d", TraceResult::TaskComplete, &[]));
+		trace.record(step(0, "c", TraceResult::TaskComplete, &[]));
+		trace.record(step(0, "a", TraceResult::TaskComplete, &[]));
+		assert_eq!(parents(&trace), vec![None, Some(0), Some(1), Some(0), None]);
+	}
+
+	#[test]
+	fn a_task_that_was_put_back_stays_under_the_same_parent() {
+		let mut trace = TaskTrace::default();
+		trace.record(step(0, "a", TraceResult::ProgressMade, &["b"]));
+		trace.record(step(0, "b", TraceResult::NothingDone, &[]));
+		trace.record(step(0, "b", TraceResult::TaskComplete, &[]));
+		trace.record(step(0, "a", TraceResult::TaskComplete, &[]));
+		assert_eq!(parents(&trace), vec![None, Some(0), Some(0), None]);
+	}
+
+	#[test]
+	fn a_failed_task_is_finished() {
+		let mut trace = TaskTrace::default();
+		trace.record(step(0, "a", TraceResult::ProgressMade, &["b", "c"]));
+		trace.record(step(0, "b", TraceResult::Failed("no card".to_owned()), &[]));
+		trace.record(step(0, "c", TraceResult::TaskComplete, &[]));
+		trace.record(step(0, "d", TraceResult::TaskComplete, &[]));
+		assert_eq!(parents(&trace), vec![None, Some(0), Some(0), None]);
+	}
+
+	#[test]
+	fn each_player_has_their_own_nesting() {
+		let mut trace = TaskTrace::default();
+		trace.record(step(0, "a", TraceResult::ProgressMade, &["b"]));
+		trace.record(step(1, "x", TraceResult::TaskComplete, &[]));
+		trace.record(step(0, "b", TraceResult::TaskComplete, &[]));
+		assert_eq!(parents(&trace), vec![None, None, Some(0)]);
+	}
+
+	#[test]
+	fn steps_are_rendered_as_a_tree() {
+		let mut trace = TaskTrace::default();
+		trace.record(TraceStep {
+			params_after: "card".to_owned(),
+			..step(0, "a", TraceResult::ProgressMade, &["b"])
+		});
+		trace.record(step(0, "b", TraceResult::Failed("no card".to_owned()), &[]));
+		trace.record(step(1, "c", TraceResult::NothingDone, &[]));
+		assert_eq!(
+			trace.render(),
+			concat!(
+				"[0] player 0: a -> progress made\n",
+				"  params: none -> card\n",
+				"  prepended: b\n",
+				"  [1] player 0: b -> failed: no card\n",
+				"[2] player 1: c -> nothing done\n",
+			)
+		);
+	}
+}
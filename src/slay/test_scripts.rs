@@ -38,7 +38,7 @@
 
 // fn find_hero_card(deck: &Deck) -> Option<ids::CardId> {
 // 	deck
-// 		.tops()
+// 		.tops(&game.cards)
 // 		.filter(|card| matches!(card.spec.card_type, CardType::Hero(_)))
 // 		.map(|card| card.id)
 // 		.next()